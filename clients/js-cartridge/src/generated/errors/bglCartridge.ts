@@ -376,6 +376,1480 @@ export class SourceAlreadySetError extends ProgramError {
 codeToErrorMap.set(0x18, SourceAlreadySetError);
 nameToErrorMap.set('SourceAlreadySet', SourceAlreadySetError);
 
+/** MachineOwnerMustSign: Machine Owner must sign */
+export class MachineOwnerMustSignError extends ProgramError {
+  override readonly name: string = 'MachineOwnerMustSign';
+
+  readonly code: number = 0x19; // 25
+
+  constructor(program: Program, cause?: Error) {
+    super('Machine Owner must sign', program, cause);
+  }
+}
+codeToErrorMap.set(0x19, MachineOwnerMustSignError);
+nameToErrorMap.set('MachineOwnerMustSign', MachineOwnerMustSignError);
+
+/** InvalidMachineOperator: Signer is not the operator delegate of the machine */
+export class InvalidMachineOperatorError extends ProgramError {
+  override readonly name: string = 'InvalidMachineOperator';
+
+  readonly code: number = 0x1a; // 26
+
+  constructor(program: Program, cause?: Error) {
+    super('Signer is not the operator delegate of the machine', program, cause);
+  }
+}
+codeToErrorMap.set(0x1a, InvalidMachineOperatorError);
+nameToErrorMap.set('InvalidMachineOperator', InvalidMachineOperatorError);
+
+/** CartridgeNotInMachineOwnerCustody: Operators can only handle cartridges owned by the machine owner */
+export class CartridgeNotInMachineOwnerCustodyError extends ProgramError {
+  override readonly name: string = 'CartridgeNotInMachineOwnerCustody';
+
+  readonly code: number = 0x1b; // 27
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Operators can only handle cartridges owned by the machine owner',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1b, CartridgeNotInMachineOwnerCustodyError);
+nameToErrorMap.set(
+  'CartridgeNotInMachineOwnerCustody',
+  CartridgeNotInMachineOwnerCustodyError
+);
+
+/** MachineCartridgeMismatch: The machine does not hold the provided cartridge */
+export class MachineCartridgeMismatchError extends ProgramError {
+  override readonly name: string = 'MachineCartridgeMismatch';
+
+  readonly code: number = 0x1c; // 28
+
+  constructor(program: Program, cause?: Error) {
+    super('The machine does not hold the provided cartridge', program, cause);
+  }
+}
+codeToErrorMap.set(0x1c, MachineCartridgeMismatchError);
+nameToErrorMap.set('MachineCartridgeMismatch', MachineCartridgeMismatchError);
+
+/** CartridgeAlreadyLinked: The cartridge is already inserted into a machine */
+export class CartridgeAlreadyLinkedError extends ProgramError {
+  override readonly name: string = 'CartridgeAlreadyLinked';
+
+  readonly code: number = 0x1d; // 29
+
+  constructor(program: Program, cause?: Error) {
+    super('The cartridge is already inserted into a machine', program, cause);
+  }
+}
+codeToErrorMap.set(0x1d, CartridgeAlreadyLinkedError);
+nameToErrorMap.set('CartridgeAlreadyLinked', CartridgeAlreadyLinkedError);
+
+/** CartridgeMachineMismatch: The cartridge is not linked to the provided machine */
+export class CartridgeMachineMismatchError extends ProgramError {
+  override readonly name: string = 'CartridgeMachineMismatch';
+
+  readonly code: number = 0x1e; // 30
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The cartridge is not linked to the provided machine',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1e, CartridgeMachineMismatchError);
+nameToErrorMap.set('CartridgeMachineMismatch', CartridgeMachineMismatchError);
+
+/** IncompatibleMachine: The game cannot be played on machines of this collection */
+export class IncompatibleMachineError extends ProgramError {
+  override readonly name: string = 'IncompatibleMachine';
+
+  readonly code: number = 0x1f; // 31
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The game cannot be played on machines of this collection',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1f, IncompatibleMachineError);
+nameToErrorMap.set('IncompatibleMachine', IncompatibleMachineError);
+
+/** PublisherMustSign: The game publisher must sign */
+export class PublisherMustSignError extends ProgramError {
+  override readonly name: string = 'PublisherMustSign';
+
+  readonly code: number = 0x20; // 32
+
+  constructor(program: Program, cause?: Error) {
+    super('The game publisher must sign', program, cause);
+  }
+}
+codeToErrorMap.set(0x20, PublisherMustSignError);
+nameToErrorMap.set('PublisherMustSign', PublisherMustSignError);
+
+/** TooManyMachineCollections: Too many compatible machine collections */
+export class TooManyMachineCollectionsError extends ProgramError {
+  override readonly name: string = 'TooManyMachineCollections';
+
+  readonly code: number = 0x21; // 33
+
+  constructor(program: Program, cause?: Error) {
+    super('Too many compatible machine collections', program, cause);
+  }
+}
+codeToErrorMap.set(0x21, TooManyMachineCollectionsError);
+nameToErrorMap.set('TooManyMachineCollections', TooManyMachineCollectionsError);
+
+/** SessionLocked: The machine session is locked, the machine owner must co-sign the removal */
+export class SessionLockedError extends ProgramError {
+  override readonly name: string = 'SessionLocked';
+
+  readonly code: number = 0x22; // 34
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The machine session is locked, the machine owner must co-sign the removal',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x22, SessionLockedError);
+nameToErrorMap.set('SessionLocked', SessionLockedError);
+
+/** InvalidSessionExpiry: The session lock expiry must be in the future */
+export class InvalidSessionExpiryError extends ProgramError {
+  override readonly name: string = 'InvalidSessionExpiry';
+
+  readonly code: number = 0x23; // 35
+
+  constructor(program: Program, cause?: Error) {
+    super('The session lock expiry must be in the future', program, cause);
+  }
+}
+codeToErrorMap.set(0x23, InvalidSessionExpiryError);
+nameToErrorMap.set('InvalidSessionExpiry', InvalidSessionExpiryError);
+
+/** SaleNotStarted: The game is not on sale yet */
+export class SaleNotStartedError extends ProgramError {
+  override readonly name: string = 'SaleNotStarted';
+
+  readonly code: number = 0x24; // 36
+
+  constructor(program: Program, cause?: Error) {
+    super('The game is not on sale yet', program, cause);
+  }
+}
+codeToErrorMap.set(0x24, SaleNotStartedError);
+nameToErrorMap.set('SaleNotStarted', SaleNotStartedError);
+
+/** SaleEnded: The game is no longer on sale */
+export class SaleEndedError extends ProgramError {
+  override readonly name: string = 'SaleEnded';
+
+  readonly code: number = 0x25; // 37
+
+  constructor(program: Program, cause?: Error) {
+    super('The game is no longer on sale', program, cause);
+  }
+}
+codeToErrorMap.set(0x25, SaleEndedError);
+nameToErrorMap.set('SaleEnded', SaleEndedError);
+
+/** InvalidSaleWindow: The sale must end after it starts and cannot start in the past */
+export class InvalidSaleWindowError extends ProgramError {
+  override readonly name: string = 'InvalidSaleWindow';
+
+  readonly code: number = 0x26; // 38
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The sale must end after it starts and cannot start in the past',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x26, InvalidSaleWindowError);
+nameToErrorMap.set('InvalidSaleWindow', InvalidSaleWindowError);
+
+/** SaleAlreadyStarted: The sale window cannot be changed once the sale has started */
+export class SaleAlreadyStartedError extends ProgramError {
+  override readonly name: string = 'SaleAlreadyStarted';
+
+  readonly code: number = 0x27; // 39
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The sale window cannot be changed once the sale has started',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x27, SaleAlreadyStartedError);
+nameToErrorMap.set('SaleAlreadyStarted', SaleAlreadyStartedError);
+
+/** InvalidPricingModel: Invalid pricing model parameters */
+export class InvalidPricingModelError extends ProgramError {
+  override readonly name: string = 'InvalidPricingModel';
+
+  readonly code: number = 0x28; // 40
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid pricing model parameters', program, cause);
+  }
+}
+codeToErrorMap.set(0x28, InvalidPricingModelError);
+nameToErrorMap.set('InvalidPricingModel', InvalidPricingModelError);
+
+/** NumericalOverflow: Numerical overflow */
+export class NumericalOverflowError extends ProgramError {
+  override readonly name: string = 'NumericalOverflow';
+
+  readonly code: number = 0x29; // 41
+
+  constructor(program: Program, cause?: Error) {
+    super('Numerical overflow', program, cause);
+  }
+}
+codeToErrorMap.set(0x29, NumericalOverflowError);
+nameToErrorMap.set('NumericalOverflow', NumericalOverflowError);
+
+/** NotAllowlisted: The wallet is not on the game allowlist */
+export class NotAllowlistedError extends ProgramError {
+  override readonly name: string = 'NotAllowlisted';
+
+  readonly code: number = 0x2a; // 42
+
+  constructor(program: Program, cause?: Error) {
+    super('The wallet is not on the game allowlist', program, cause);
+  }
+}
+codeToErrorMap.set(0x2a, NotAllowlistedError);
+nameToErrorMap.set('NotAllowlisted', NotAllowlistedError);
+
+/** AllowlistQuotaExceeded: The wallet has used up its allowlist quota */
+export class AllowlistQuotaExceededError extends ProgramError {
+  override readonly name: string = 'AllowlistQuotaExceeded';
+
+  readonly code: number = 0x2b; // 43
+
+  constructor(program: Program, cause?: Error) {
+    super('The wallet has used up its allowlist quota', program, cause);
+  }
+}
+codeToErrorMap.set(0x2b, AllowlistQuotaExceededError);
+nameToErrorMap.set('AllowlistQuotaExceeded', AllowlistQuotaExceededError);
+
+/** InvalidAllowlistReceipt: Invalid allowlist receipt account */
+export class InvalidAllowlistReceiptError extends ProgramError {
+  override readonly name: string = 'InvalidAllowlistReceipt';
+
+  readonly code: number = 0x2c; // 44
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid allowlist receipt account', program, cause);
+  }
+}
+codeToErrorMap.set(0x2c, InvalidAllowlistReceiptError);
+nameToErrorMap.set('InvalidAllowlistReceipt', InvalidAllowlistReceiptError);
+
+/** WalletLimitReached: The owner has reached the per-wallet print limit of this game */
+export class WalletLimitReachedError extends ProgramError {
+  override readonly name: string = 'WalletLimitReached';
+
+  readonly code: number = 0x2d; // 45
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The owner has reached the per-wallet print limit of this game',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x2d, WalletLimitReachedError);
+nameToErrorMap.set('WalletLimitReached', WalletLimitReachedError);
+
+/** InvalidMintCounter: Invalid mint counter account */
+export class InvalidMintCounterError extends ProgramError {
+  override readonly name: string = 'InvalidMintCounter';
+
+  readonly code: number = 0x2e; // 46
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid mint counter account', program, cause);
+  }
+}
+codeToErrorMap.set(0x2e, InvalidMintCounterError);
+nameToErrorMap.set('InvalidMintCounter', InvalidMintCounterError);
+
+/** VouchersDisabled: The game does not accept vouchers */
+export class VouchersDisabledError extends ProgramError {
+  override readonly name: string = 'VouchersDisabled';
+
+  readonly code: number = 0x2f; // 47
+
+  constructor(program: Program, cause?: Error) {
+    super('The game does not accept vouchers', program, cause);
+  }
+}
+codeToErrorMap.set(0x2f, VouchersDisabledError);
+nameToErrorMap.set('VouchersDisabled', VouchersDisabledError);
+
+/** InvalidVoucherSignature: The voucher is not signed by the game voucher signer */
+export class InvalidVoucherSignatureError extends ProgramError {
+  override readonly name: string = 'InvalidVoucherSignature';
+
+  readonly code: number = 0x30; // 48
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The voucher is not signed by the game voucher signer',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x30, InvalidVoucherSignatureError);
+nameToErrorMap.set('InvalidVoucherSignature', InvalidVoucherSignatureError);
+
+/** VoucherExpired: The voucher has expired */
+export class VoucherExpiredError extends ProgramError {
+  override readonly name: string = 'VoucherExpired';
+
+  readonly code: number = 0x31; // 49
+
+  constructor(program: Program, cause?: Error) {
+    super('The voucher has expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x31, VoucherExpiredError);
+nameToErrorMap.set('VoucherExpired', VoucherExpiredError);
+
+/** VoucherAlreadyUsed: The voucher has already been used */
+export class VoucherAlreadyUsedError extends ProgramError {
+  override readonly name: string = 'VoucherAlreadyUsed';
+
+  readonly code: number = 0x32; // 50
+
+  constructor(program: Program, cause?: Error) {
+    super('The voucher has already been used', program, cause);
+  }
+}
+codeToErrorMap.set(0x32, VoucherAlreadyUsedError);
+nameToErrorMap.set('VoucherAlreadyUsed', VoucherAlreadyUsedError);
+
+/** InvalidVoucherRecord: Invalid voucher record account */
+export class InvalidVoucherRecordError extends ProgramError {
+  override readonly name: string = 'InvalidVoucherRecord';
+
+  readonly code: number = 0x33; // 51
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid voucher record account', program, cause);
+  }
+}
+codeToErrorMap.set(0x33, InvalidVoucherRecordError);
+nameToErrorMap.set('InvalidVoucherRecord', InvalidVoucherRecordError);
+
+/** InvalidInstructionsSysvar: Invalid instructions sysvar */
+export class InvalidInstructionsSysvarError extends ProgramError {
+  override readonly name: string = 'InvalidInstructionsSysvar';
+
+  readonly code: number = 0x34; // 52
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid instructions sysvar', program, cause);
+  }
+}
+codeToErrorMap.set(0x34, InvalidInstructionsSysvarError);
+nameToErrorMap.set('InvalidInstructionsSysvar', InvalidInstructionsSysvarError);
+
+/** InvalidReferralRate: Referral rate cannot exceed 2000 basis points */
+export class InvalidReferralRateError extends ProgramError {
+  override readonly name: string = 'InvalidReferralRate';
+
+  readonly code: number = 0x35; // 53
+
+  constructor(program: Program, cause?: Error) {
+    super('Referral rate cannot exceed 2000 basis points', program, cause);
+  }
+}
+codeToErrorMap.set(0x35, InvalidReferralRateError);
+nameToErrorMap.set('InvalidReferralRate', InvalidReferralRateError);
+
+/** InvalidReferrerTokenAccountProgramOwner: Invalid referrer token account program owner */
+export class InvalidReferrerTokenAccountProgramOwnerError extends ProgramError {
+  override readonly name: string = 'InvalidReferrerTokenAccountProgramOwner';
+
+  readonly code: number = 0x36; // 54
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid referrer token account program owner', program, cause);
+  }
+}
+codeToErrorMap.set(0x36, InvalidReferrerTokenAccountProgramOwnerError);
+nameToErrorMap.set(
+  'InvalidReferrerTokenAccountProgramOwner',
+  InvalidReferrerTokenAccountProgramOwnerError
+);
+
+/** InvalidReferrerTokenAccountMint: Invalid referrer token account mint */
+export class InvalidReferrerTokenAccountMintError extends ProgramError {
+  override readonly name: string = 'InvalidReferrerTokenAccountMint';
+
+  readonly code: number = 0x37; // 55
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid referrer token account mint', program, cause);
+  }
+}
+codeToErrorMap.set(0x37, InvalidReferrerTokenAccountMintError);
+nameToErrorMap.set(
+  'InvalidReferrerTokenAccountMint',
+  InvalidReferrerTokenAccountMintError
+);
+
+/** SelfReferral: A print cannot be referred by its payer or owner */
+export class SelfReferralError extends ProgramError {
+  override readonly name: string = 'SelfReferral';
+
+  readonly code: number = 0x38; // 56
+
+  constructor(program: Program, cause?: Error) {
+    super('A print cannot be referred by its payer or owner', program, cause);
+  }
+}
+codeToErrorMap.set(0x38, SelfReferralError);
+nameToErrorMap.set('SelfReferral', SelfReferralError);
+
+/** InvalidBundle: Invalid bundle account */
+export class InvalidBundleError extends ProgramError {
+  override readonly name: string = 'InvalidBundle';
+
+  readonly code: number = 0x39; // 57
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid bundle account', program, cause);
+  }
+}
+codeToErrorMap.set(0x39, InvalidBundleError);
+nameToErrorMap.set('InvalidBundle', InvalidBundleError);
+
+/** InvalidBundleGames: A bundle needs between 2 and 8 distinct games */
+export class InvalidBundleGamesError extends ProgramError {
+  override readonly name: string = 'InvalidBundleGames';
+
+  readonly code: number = 0x3a; // 58
+
+  constructor(program: Program, cause?: Error) {
+    super('A bundle needs between 2 and 8 distinct games', program, cause);
+  }
+}
+codeToErrorMap.set(0x3a, InvalidBundleGamesError);
+nameToErrorMap.set('InvalidBundleGames', InvalidBundleGamesError);
+
+/** InvalidBundleShares: Bundle shares must add up to 10000 basis points */
+export class InvalidBundleSharesError extends ProgramError {
+  override readonly name: string = 'InvalidBundleShares';
+
+  readonly code: number = 0x3b; // 59
+
+  constructor(program: Program, cause?: Error) {
+    super('Bundle shares must add up to 10000 basis points', program, cause);
+  }
+}
+codeToErrorMap.set(0x3b, InvalidBundleSharesError);
+nameToErrorMap.set('InvalidBundleShares', InvalidBundleSharesError);
+
+/** BundleGameMismatch: The game accounts do not match the bundle */
+export class BundleGameMismatchError extends ProgramError {
+  override readonly name: string = 'BundleGameMismatch';
+
+  readonly code: number = 0x3c; // 60
+
+  constructor(program: Program, cause?: Error) {
+    super('The game accounts do not match the bundle', program, cause);
+  }
+}
+codeToErrorMap.set(0x3c, BundleGameMismatchError);
+nameToErrorMap.set('BundleGameMismatch', BundleGameMismatchError);
+
+/** BaseCartridgeRequired: Cartridges of a DLC can only be printed against a base cartridge */
+export class BaseCartridgeRequiredError extends ProgramError {
+  override readonly name: string = 'BaseCartridgeRequired';
+
+  readonly code: number = 0x3d; // 61
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Cartridges of a DLC can only be printed against a base cartridge',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x3d, BaseCartridgeRequiredError);
+nameToErrorMap.set('BaseCartridgeRequired', BaseCartridgeRequiredError);
+
+/** InvalidBaseGame: A DLC must extend another released game of the same publisher */
+export class InvalidBaseGameError extends ProgramError {
+  override readonly name: string = 'InvalidBaseGame';
+
+  readonly code: number = 0x3e; // 62
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'A DLC must extend another released game of the same publisher',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x3e, InvalidBaseGameError);
+nameToErrorMap.set('InvalidBaseGame', InvalidBaseGameError);
+
+/** DlcAlreadyRegistered: The game is already registered as a DLC */
+export class DlcAlreadyRegisteredError extends ProgramError {
+  override readonly name: string = 'DlcAlreadyRegistered';
+
+  readonly code: number = 0x3f; // 63
+
+  constructor(program: Program, cause?: Error) {
+    super('The game is already registered as a DLC', program, cause);
+  }
+}
+codeToErrorMap.set(0x3f, DlcAlreadyRegisteredError);
+nameToErrorMap.set('DlcAlreadyRegistered', DlcAlreadyRegisteredError);
+
+/** NotADlc: The game is not a DLC */
+export class NotADlcError extends ProgramError {
+  override readonly name: string = 'NotADlc';
+
+  readonly code: number = 0x40; // 64
+
+  constructor(program: Program, cause?: Error) {
+    super('The game is not a DLC', program, cause);
+  }
+}
+codeToErrorMap.set(0x40, NotADlcError);
+nameToErrorMap.set('NotADlc', NotADlcError);
+
+/** InvalidBaseCartridge: The base cartridge is not a cartridge of the base game held by the owner */
+export class InvalidBaseCartridgeError extends ProgramError {
+  override readonly name: string = 'InvalidBaseCartridge';
+
+  readonly code: number = 0x41; // 65
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The base cartridge is not a cartridge of the base game held by the owner',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x41, InvalidBaseCartridgeError);
+nameToErrorMap.set('InvalidBaseCartridge', InvalidBaseCartridgeError);
+
+/** SaveDataDisabled: The game has no save authority */
+export class SaveDataDisabledError extends ProgramError {
+  override readonly name: string = 'SaveDataDisabled';
+
+  readonly code: number = 0x42; // 66
+
+  constructor(program: Program, cause?: Error) {
+    super('The game has no save authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x42, SaveDataDisabledError);
+nameToErrorMap.set('SaveDataDisabled', SaveDataDisabledError);
+
+/** InvalidSaveAuthority: Only the save authority of the game can write save data */
+export class InvalidSaveAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidSaveAuthority';
+
+  readonly code: number = 0x43; // 67
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Only the save authority of the game can write save data',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x43, InvalidSaveAuthorityError);
+nameToErrorMap.set('InvalidSaveAuthority', InvalidSaveAuthorityError);
+
+/** SaveDataTooLarge: Save data exceeds the maximum size */
+export class SaveDataTooLargeError extends ProgramError {
+  override readonly name: string = 'SaveDataTooLarge';
+
+  readonly code: number = 0x44; // 68
+
+  constructor(program: Program, cause?: Error) {
+    super('Save data exceeds the maximum size', program, cause);
+  }
+}
+codeToErrorMap.set(0x44, SaveDataTooLargeError);
+nameToErrorMap.set('SaveDataTooLarge', SaveDataTooLargeError);
+
+/** StaleSaveRevision: The save revision must follow the current one */
+export class StaleSaveRevisionError extends ProgramError {
+  override readonly name: string = 'StaleSaveRevision';
+
+  readonly code: number = 0x45; // 69
+
+  constructor(program: Program, cause?: Error) {
+    super('The save revision must follow the current one', program, cause);
+  }
+}
+codeToErrorMap.set(0x45, StaleSaveRevisionError);
+nameToErrorMap.set('StaleSaveRevision', StaleSaveRevisionError);
+
+/** InvalidCartridge: The cartridge does not belong to the game */
+export class InvalidCartridgeError extends ProgramError {
+  override readonly name: string = 'InvalidCartridge';
+
+  readonly code: number = 0x46; // 70
+
+  constructor(program: Program, cause?: Error) {
+    super('The cartridge does not belong to the game', program, cause);
+  }
+}
+codeToErrorMap.set(0x46, InvalidCartridgeError);
+nameToErrorMap.set('InvalidCartridge', InvalidCartridgeError);
+
+/** InvalidRefundWindow: Refund window cannot be negative */
+export class InvalidRefundWindowError extends ProgramError {
+  override readonly name: string = 'InvalidRefundWindow';
+
+  readonly code: number = 0x47; // 71
+
+  constructor(program: Program, cause?: Error) {
+    super('Refund window cannot be negative', program, cause);
+  }
+}
+codeToErrorMap.set(0x47, InvalidRefundWindowError);
+nameToErrorMap.set('InvalidRefundWindow', InvalidRefundWindowError);
+
+/** RefundsDisabled: The game does not offer refunds */
+export class RefundsDisabledError extends ProgramError {
+  override readonly name: string = 'RefundsDisabled';
+
+  readonly code: number = 0x48; // 72
+
+  constructor(program: Program, cause?: Error) {
+    super('The game does not offer refunds', program, cause);
+  }
+}
+codeToErrorMap.set(0x48, RefundsDisabledError);
+nameToErrorMap.set('RefundsDisabled', RefundsDisabledError);
+
+/** RefundWindowClosed: The refund window of the cartridge has closed */
+export class RefundWindowClosedError extends ProgramError {
+  override readonly name: string = 'RefundWindowClosed';
+
+  readonly code: number = 0x49; // 73
+
+  constructor(program: Program, cause?: Error) {
+    super('The refund window of the cartridge has closed', program, cause);
+  }
+}
+codeToErrorMap.set(0x49, RefundWindowClosedError);
+nameToErrorMap.set('RefundWindowClosed', RefundWindowClosedError);
+
+/** CartridgeAlreadyPlayed: Cartridges that have been inserted into a machine cannot be refunded */
+export class CartridgeAlreadyPlayedError extends ProgramError {
+  override readonly name: string = 'CartridgeAlreadyPlayed';
+
+  readonly code: number = 0x4a; // 74
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Cartridges that have been inserted into a machine cannot be refunded',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x4a, CartridgeAlreadyPlayedError);
+nameToErrorMap.set('CartridgeAlreadyPlayed', CartridgeAlreadyPlayedError);
+
+/** InvalidListing: Invalid listing account */
+export class InvalidListingError extends ProgramError {
+  override readonly name: string = 'InvalidListing';
+
+  readonly code: number = 0x4b; // 75
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid listing account', program, cause);
+  }
+}
+codeToErrorMap.set(0x4b, InvalidListingError);
+nameToErrorMap.set('InvalidListing', InvalidListingError);
+
+/** InvalidSellerTokenAccount: The token account does not hold the payment token of the seller */
+export class InvalidSellerTokenAccountError extends ProgramError {
+  override readonly name: string = 'InvalidSellerTokenAccount';
+
+  readonly code: number = 0x4c; // 76
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The token account does not hold the payment token of the seller',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x4c, InvalidSellerTokenAccountError);
+nameToErrorMap.set('InvalidSellerTokenAccount', InvalidSellerTokenAccountError);
+
+/** InvalidCreatorTokenAccount: The token accounts do not hold the payment token of the game creators */
+export class InvalidCreatorTokenAccountError extends ProgramError {
+  override readonly name: string = 'InvalidCreatorTokenAccount';
+
+  readonly code: number = 0x4d; // 77
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The token accounts do not hold the payment token of the game creators',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x4d, InvalidCreatorTokenAccountError);
+nameToErrorMap.set(
+  'InvalidCreatorTokenAccount',
+  InvalidCreatorTokenAccountError
+);
+
+/** InvalidListingPrice: Listing price must be greater than zero */
+export class InvalidListingPriceError extends ProgramError {
+  override readonly name: string = 'InvalidListingPrice';
+
+  readonly code: number = 0x4e; // 78
+
+  constructor(program: Program, cause?: Error) {
+    super('Listing price must be greater than zero', program, cause);
+  }
+}
+codeToErrorMap.set(0x4e, InvalidListingPriceError);
+nameToErrorMap.set('InvalidListingPrice', InvalidListingPriceError);
+
+/** ListingPriceChanged: The listing price differs from the price the buyer agreed to */
+export class ListingPriceChangedError extends ProgramError {
+  override readonly name: string = 'ListingPriceChanged';
+
+  readonly code: number = 0x4f; // 79
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The listing price differs from the price the buyer agreed to',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x4f, ListingPriceChangedError);
+nameToErrorMap.set('ListingPriceChanged', ListingPriceChangedError);
+
+/** InvalidLoan: Invalid loan account */
+export class InvalidLoanError extends ProgramError {
+  override readonly name: string = 'InvalidLoan';
+
+  readonly code: number = 0x50; // 80
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid loan account', program, cause);
+  }
+}
+codeToErrorMap.set(0x50, InvalidLoanError);
+nameToErrorMap.set('InvalidLoan', InvalidLoanError);
+
+/** InvalidLoanDuration: Loan duration must be greater than zero */
+export class InvalidLoanDurationError extends ProgramError {
+  override readonly name: string = 'InvalidLoanDuration';
+
+  readonly code: number = 0x51; // 81
+
+  constructor(program: Program, cause?: Error) {
+    super('Loan duration must be greater than zero', program, cause);
+  }
+}
+codeToErrorMap.set(0x51, InvalidLoanDurationError);
+nameToErrorMap.set('InvalidLoanDuration', InvalidLoanDurationError);
+
+/** InvalidBorrower: A cartridge cannot be lent to its owner */
+export class InvalidBorrowerError extends ProgramError {
+  override readonly name: string = 'InvalidBorrower';
+
+  readonly code: number = 0x52; // 82
+
+  constructor(program: Program, cause?: Error) {
+    super('A cartridge cannot be lent to its owner', program, cause);
+  }
+}
+codeToErrorMap.set(0x52, InvalidBorrowerError);
+nameToErrorMap.set('InvalidBorrower', InvalidBorrowerError);
+
+/** CartridgeOnLoan: The cartridge is on loan */
+export class CartridgeOnLoanError extends ProgramError {
+  override readonly name: string = 'CartridgeOnLoan';
+
+  readonly code: number = 0x53; // 83
+
+  constructor(program: Program, cause?: Error) {
+    super('The cartridge is on loan', program, cause);
+  }
+}
+codeToErrorMap.set(0x53, CartridgeOnLoanError);
+nameToErrorMap.set('CartridgeOnLoan', CartridgeOnLoanError);
+
+/** LoanNotExpired: Only the borrower can return a cartridge before the loan expires */
+export class LoanNotExpiredError extends ProgramError {
+  override readonly name: string = 'LoanNotExpired';
+
+  readonly code: number = 0x54; // 84
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Only the borrower can return a cartridge before the loan expires',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x54, LoanNotExpiredError);
+nameToErrorMap.set('LoanNotExpired', LoanNotExpiredError);
+
+/** InvalidRentalTerms: Rental duration cannot be negative */
+export class InvalidRentalTermsError extends ProgramError {
+  override readonly name: string = 'InvalidRentalTerms';
+
+  readonly code: number = 0x55; // 85
+
+  constructor(program: Program, cause?: Error) {
+    super('Rental duration cannot be negative', program, cause);
+  }
+}
+codeToErrorMap.set(0x55, InvalidRentalTermsError);
+nameToErrorMap.set('InvalidRentalTerms', InvalidRentalTermsError);
+
+/** RentalsDisabled: The game does not offer rentals */
+export class RentalsDisabledError extends ProgramError {
+  override readonly name: string = 'RentalsDisabled';
+
+  readonly code: number = 0x56; // 86
+
+  constructor(program: Program, cause?: Error) {
+    super('The game does not offer rentals', program, cause);
+  }
+}
+codeToErrorMap.set(0x56, RentalsDisabledError);
+nameToErrorMap.set('RentalsDisabled', RentalsDisabledError);
+
+/** CartridgeExpired: The rental cartridge has expired */
+export class CartridgeExpiredError extends ProgramError {
+  override readonly name: string = 'CartridgeExpired';
+
+  readonly code: number = 0x57; // 87
+
+  constructor(program: Program, cause?: Error) {
+    super('The rental cartridge has expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x57, CartridgeExpiredError);
+nameToErrorMap.set('CartridgeExpired', CartridgeExpiredError);
+
+/** CartridgeNotExpired: Only expired rental cartridges can be reclaimed */
+export class CartridgeNotExpiredError extends ProgramError {
+  override readonly name: string = 'CartridgeNotExpired';
+
+  readonly code: number = 0x58; // 88
+
+  constructor(program: Program, cause?: Error) {
+    super('Only expired rental cartridges can be reclaimed', program, cause);
+  }
+}
+codeToErrorMap.set(0x58, CartridgeNotExpiredError);
+nameToErrorMap.set('CartridgeNotExpired', CartridgeNotExpiredError);
+
+/** InvalidRentalAuthority: Invalid rental authority PDA derivation */
+export class InvalidRentalAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidRentalAuthority';
+
+  readonly code: number = 0x59; // 89
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid rental authority PDA derivation', program, cause);
+  }
+}
+codeToErrorMap.set(0x59, InvalidRentalAuthorityError);
+nameToErrorMap.set('InvalidRentalAuthority', InvalidRentalAuthorityError);
+
+/** InvalidRentPayer: The rent payer does not match the one recorded on the cartridge */
+export class InvalidRentPayerError extends ProgramError {
+  override readonly name: string = 'InvalidRentPayer';
+
+  readonly code: number = 0x5a; // 90
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The rent payer does not match the one recorded on the cartridge',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x5a, InvalidRentPayerError);
+nameToErrorMap.set('InvalidRentPayer', InvalidRentPayerError);
+
+/** RentalCartridge: Rental cartridges cannot be lent or sold */
+export class RentalCartridgeError extends ProgramError {
+  override readonly name: string = 'RentalCartridge';
+
+  readonly code: number = 0x5b; // 91
+
+  constructor(program: Program, cause?: Error) {
+    super('Rental cartridges cannot be lent or sold', program, cause);
+  }
+}
+codeToErrorMap.set(0x5b, RentalCartridgeError);
+nameToErrorMap.set('RentalCartridge', RentalCartridgeError);
+
+/** InvalidPassCollection: The collection is not a pass collection of this program */
+export class InvalidPassCollectionError extends ProgramError {
+  override readonly name: string = 'InvalidPassCollection';
+
+  readonly code: number = 0x5c; // 92
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The collection is not a pass collection of this program',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x5c, InvalidPassCollectionError);
+nameToErrorMap.set('InvalidPassCollection', InvalidPassCollectionError);
+
+/** InvalidPassTerms: A pass must last for a positive duration */
+export class InvalidPassTermsError extends ProgramError {
+  override readonly name: string = 'InvalidPassTerms';
+
+  readonly code: number = 0x5d; // 93
+
+  constructor(program: Program, cause?: Error) {
+    super('A pass must last for a positive duration', program, cause);
+  }
+}
+codeToErrorMap.set(0x5d, InvalidPassTermsError);
+nameToErrorMap.set('InvalidPassTerms', InvalidPassTermsError);
+
+/** InvalidPass: The asset is not a pass of the pass collection */
+export class InvalidPassError extends ProgramError {
+  override readonly name: string = 'InvalidPass';
+
+  readonly code: number = 0x5e; // 94
+
+  constructor(program: Program, cause?: Error) {
+    super('The asset is not a pass of the pass collection', program, cause);
+  }
+}
+codeToErrorMap.set(0x5e, InvalidPassError);
+nameToErrorMap.set('InvalidPass', InvalidPassError);
+
+/** PassExpired: The pass has expired */
+export class PassExpiredError extends ProgramError {
+  override readonly name: string = 'PassExpired';
+
+  readonly code: number = 0x5f; // 95
+
+  constructor(program: Program, cause?: Error) {
+    super('The pass has expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x5f, PassExpiredError);
+nameToErrorMap.set('PassExpired', PassExpiredError);
+
+/** GameNotInPass: The game does not accept passes of this collection */
+export class GameNotInPassError extends ProgramError {
+  override readonly name: string = 'GameNotInPass';
+
+  readonly code: number = 0x60; // 96
+
+  constructor(program: Program, cause?: Error) {
+    super('The game does not accept passes of this collection', program, cause);
+  }
+}
+codeToErrorMap.set(0x60, GameNotInPassError);
+nameToErrorMap.set('GameNotInPass', GameNotInPassError);
+
+/** InvalidCrowdfundTerms: A crowdfund needs a goal and a deadline in the future */
+export class InvalidCrowdfundTermsError extends ProgramError {
+  override readonly name: string = 'InvalidCrowdfundTerms';
+
+  readonly code: number = 0x61; // 97
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'A crowdfund needs a goal and a deadline in the future',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x61, InvalidCrowdfundTermsError);
+nameToErrorMap.set('InvalidCrowdfundTerms', InvalidCrowdfundTermsError);
+
+/** InvalidEscrow: Invalid escrow PDA derivation */
+export class InvalidEscrowError extends ProgramError {
+  override readonly name: string = 'InvalidEscrow';
+
+  readonly code: number = 0x62; // 98
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid escrow PDA derivation', program, cause);
+  }
+}
+codeToErrorMap.set(0x62, InvalidEscrowError);
+nameToErrorMap.set('InvalidEscrow', InvalidEscrowError);
+
+/** InvalidPreOrder: Invalid pre-order PDA derivation */
+export class InvalidPreOrderError extends ProgramError {
+  override readonly name: string = 'InvalidPreOrder';
+
+  readonly code: number = 0x63; // 99
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid pre-order PDA derivation', program, cause);
+  }
+}
+codeToErrorMap.set(0x63, InvalidPreOrderError);
+nameToErrorMap.set('InvalidPreOrder', InvalidPreOrderError);
+
+/** InvalidPreOrderQuantity: At least one cartridge must be pre-ordered */
+export class InvalidPreOrderQuantityError extends ProgramError {
+  override readonly name: string = 'InvalidPreOrderQuantity';
+
+  readonly code: number = 0x64; // 100
+
+  constructor(program: Program, cause?: Error) {
+    super('At least one cartridge must be pre-ordered', program, cause);
+  }
+}
+codeToErrorMap.set(0x64, InvalidPreOrderQuantityError);
+nameToErrorMap.set('InvalidPreOrderQuantity', InvalidPreOrderQuantityError);
+
+/** CrowdfundClosed: The crowdfund deadline has passed */
+export class CrowdfundClosedError extends ProgramError {
+  override readonly name: string = 'CrowdfundClosed';
+
+  readonly code: number = 0x65; // 101
+
+  constructor(program: Program, cause?: Error) {
+    super('The crowdfund deadline has passed', program, cause);
+  }
+}
+codeToErrorMap.set(0x65, CrowdfundClosedError);
+nameToErrorMap.set('CrowdfundClosed', CrowdfundClosedError);
+
+/** CrowdfundNotFunded: The crowdfund has not met its goal */
+export class CrowdfundNotFundedError extends ProgramError {
+  override readonly name: string = 'CrowdfundNotFunded';
+
+  readonly code: number = 0x66; // 102
+
+  constructor(program: Program, cause?: Error) {
+    super('The crowdfund has not met its goal', program, cause);
+  }
+}
+codeToErrorMap.set(0x66, CrowdfundNotFundedError);
+nameToErrorMap.set('CrowdfundNotFunded', CrowdfundNotFundedError);
+
+/** CrowdfundNotFailed: Pre-orders can only be refunded once the crowdfund has missed its goal */
+export class CrowdfundNotFailedError extends ProgramError {
+  override readonly name: string = 'CrowdfundNotFailed';
+
+  readonly code: number = 0x67; // 103
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Pre-orders can only be refunded once the crowdfund has missed its goal',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x67, CrowdfundNotFailedError);
+nameToErrorMap.set('CrowdfundNotFailed', CrowdfundNotFailedError);
+
+/** InvalidPublisherTeam: Invalid publisher team account */
+export class InvalidPublisherTeamError extends ProgramError {
+  override readonly name: string = 'InvalidPublisherTeam';
+
+  readonly code: number = 0x68; // 104
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid publisher team account', program, cause);
+  }
+}
+codeToErrorMap.set(0x68, InvalidPublisherTeamError);
+nameToErrorMap.set('InvalidPublisherTeam', InvalidPublisherTeamError);
+
+/** InvalidTeamMembers: A publisher team needs between 1 and 8 distinct members */
+export class InvalidTeamMembersError extends ProgramError {
+  override readonly name: string = 'InvalidTeamMembers';
+
+  readonly code: number = 0x69; // 105
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'A publisher team needs between 1 and 8 distinct members',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x69, InvalidTeamMembersError);
+nameToErrorMap.set('InvalidTeamMembers', InvalidTeamMembersError);
+
+/** InvalidTeamShares: Team shares must add up to 10000 basis points */
+export class InvalidTeamSharesError extends ProgramError {
+  override readonly name: string = 'InvalidTeamShares';
+
+  readonly code: number = 0x6a; // 106
+
+  constructor(program: Program, cause?: Error) {
+    super('Team shares must add up to 10000 basis points', program, cause);
+  }
+}
+codeToErrorMap.set(0x6a, InvalidTeamSharesError);
+nameToErrorMap.set('InvalidTeamShares', InvalidTeamSharesError);
+
+/** InvalidTeamThreshold: The team threshold must be between 1 and the number of members */
+export class InvalidTeamThresholdError extends ProgramError {
+  override readonly name: string = 'InvalidTeamThreshold';
+
+  readonly code: number = 0x6b; // 107
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The team threshold must be between 1 and the number of members',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x6b, InvalidTeamThresholdError);
+nameToErrorMap.set('InvalidTeamThreshold', InvalidTeamThresholdError);
+
+/** TeamThresholdNotMet: Not enough team members approved the action */
+export class TeamThresholdNotMetError extends ProgramError {
+  override readonly name: string = 'TeamThresholdNotMet';
+
+  readonly code: number = 0x6c; // 108
+
+  constructor(program: Program, cause?: Error) {
+    super('Not enough team members approved the action', program, cause);
+  }
+}
+codeToErrorMap.set(0x6c, TeamThresholdNotMetError);
+nameToErrorMap.set('TeamThresholdNotMet', TeamThresholdNotMetError);
+
+/** InvalidRevenueTokenAccount: The token account does not belong to the revenue recipient */
+export class InvalidRevenueTokenAccountError extends ProgramError {
+  override readonly name: string = 'InvalidRevenueTokenAccount';
+
+  readonly code: number = 0x6d; // 109
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The token account does not belong to the revenue recipient',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x6d, InvalidRevenueTokenAccountError);
+nameToErrorMap.set(
+  'InvalidRevenueTokenAccount',
+  InvalidRevenueTokenAccountError
+);
+
+/** InvalidVerifierAuthority: Only the verifier authority can manage the verification and name registries */
+export class InvalidVerifierAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidVerifierAuthority';
+
+  readonly code: number = 0x6e; // 110
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Only the verifier authority can manage the verification and name registries',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x6e, InvalidVerifierAuthorityError);
+nameToErrorMap.set('InvalidVerifierAuthority', InvalidVerifierAuthorityError);
+
+/** InvalidVerification: Invalid verification PDA derivation */
+export class InvalidVerificationError extends ProgramError {
+  override readonly name: string = 'InvalidVerification';
+
+  readonly code: number = 0x6f; // 111
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid verification PDA derivation', program, cause);
+  }
+}
+codeToErrorMap.set(0x6f, InvalidVerificationError);
+nameToErrorMap.set('InvalidVerification', InvalidVerificationError);
+
+/** InvalidVerificationStatus: Invalid verification status */
+export class InvalidVerificationStatusError extends ProgramError {
+  override readonly name: string = 'InvalidVerificationStatus';
+
+  readonly code: number = 0x70; // 112
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid verification status', program, cause);
+  }
+}
+codeToErrorMap.set(0x70, InvalidVerificationStatusError);
+nameToErrorMap.set('InvalidVerificationStatus', InvalidVerificationStatusError);
+
+/** GameDelisted: The game has been delisted */
+export class GameDelistedError extends ProgramError {
+  override readonly name: string = 'GameDelisted';
+
+  readonly code: number = 0x71; // 113
+
+  constructor(program: Program, cause?: Error) {
+    super('The game has been delisted', program, cause);
+  }
+}
+codeToErrorMap.set(0x71, GameDelistedError);
+nameToErrorMap.set('GameDelisted', GameDelistedError);
+
+/** InvalidNameRecord: Invalid name record account */
+export class InvalidNameRecordError extends ProgramError {
+  override readonly name: string = 'InvalidNameRecord';
+
+  readonly code: number = 0x72; // 114
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid name record account', program, cause);
+  }
+}
+codeToErrorMap.set(0x72, InvalidNameRecordError);
+nameToErrorMap.set('InvalidNameRecord', InvalidNameRecordError);
+
+/** NameTaken: The game name is held by another publisher */
+export class NameTakenError extends ProgramError {
+  override readonly name: string = 'NameTaken';
+
+  readonly code: number = 0x73; // 115
+
+  constructor(program: Program, cause?: Error) {
+    super('The game name is held by another publisher', program, cause);
+  }
+}
+codeToErrorMap.set(0x73, NameTakenError);
+nameToErrorMap.set('NameTaken', NameTakenError);
+
+/** InvalidCatalog: Invalid catalog PDA derivation */
+export class InvalidCatalogError extends ProgramError {
+  override readonly name: string = 'InvalidCatalog';
+
+  readonly code: number = 0x74; // 116
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid catalog PDA derivation', program, cause);
+  }
+}
+codeToErrorMap.set(0x74, InvalidCatalogError);
+nameToErrorMap.set('InvalidCatalog', InvalidCatalogError);
+
+/** CatalogEntryNotFound: The game is not in the catalog */
+export class CatalogEntryNotFoundError extends ProgramError {
+  override readonly name: string = 'CatalogEntryNotFound';
+
+  readonly code: number = 0x75; // 117
+
+  constructor(program: Program, cause?: Error) {
+    super('The game is not in the catalog', program, cause);
+  }
+}
+codeToErrorMap.set(0x75, CatalogEntryNotFoundError);
+nameToErrorMap.set('CatalogEntryNotFound', CatalogEntryNotFoundError);
+
+/** PriceAboveMax: The cartridge price is above the most the payer accepts */
+export class PriceAboveMaxError extends ProgramError {
+  override readonly name: string = 'PriceAboveMax';
+
+  readonly code: number = 0x76; // 118
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The cartridge price is above the most the payer accepts',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x76, PriceAboveMaxError);
+nameToErrorMap.set('PriceAboveMax', PriceAboveMaxError);
+
+/** MintCounterRequired: The game limits prints per wallet and needs the mint counter of the owner */
+export class MintCounterRequiredError extends ProgramError {
+  override readonly name: string = 'MintCounterRequired';
+
+  readonly code: number = 0x77; // 119
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The game limits prints per wallet and needs the mint counter of the owner',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x77, MintCounterRequiredError);
+nameToErrorMap.set('MintCounterRequired', MintCounterRequiredError);
+
+/** InvalidDlcReceipt: Invalid DLC receipt PDA derivation */
+export class InvalidDlcReceiptError extends ProgramError {
+  override readonly name: string = 'InvalidDlcReceipt';
+
+  readonly code: number = 0x78; // 120
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid DLC receipt PDA derivation', program, cause);
+  }
+}
+codeToErrorMap.set(0x78, InvalidDlcReceiptError);
+nameToErrorMap.set('InvalidDlcReceipt', InvalidDlcReceiptError);
+
+/** DlcAlreadyPrinted: The base cartridge already has this DLC */
+export class DlcAlreadyPrintedError extends ProgramError {
+  override readonly name: string = 'DlcAlreadyPrinted';
+
+  readonly code: number = 0x79; // 121
+
+  constructor(program: Program, cause?: Error) {
+    super('The base cartridge already has this DLC', program, cause);
+  }
+}
+codeToErrorMap.set(0x79, DlcAlreadyPrintedError);
+nameToErrorMap.set('DlcAlreadyPrinted', DlcAlreadyPrintedError);
+
+/** DlcNotPlayable: DLC cartridges extend a base cartridge and cannot be inserted on their own */
+export class DlcNotPlayableError extends ProgramError {
+  override readonly name: string = 'DlcNotPlayable';
+
+  readonly code: number = 0x7a; // 122
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'DLC cartridges extend a base cartridge and cannot be inserted on their own',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x7a, DlcNotPlayableError);
+nameToErrorMap.set('DlcNotPlayable', DlcNotPlayableError);
+
+/** GameAlreadyPrinted: A game with printed cartridges cannot become a DLC */
+export class GameAlreadyPrintedError extends ProgramError {
+  override readonly name: string = 'GameAlreadyPrinted';
+
+  readonly code: number = 0x7b; // 123
+
+  constructor(program: Program, cause?: Error) {
+    super('A game with printed cartridges cannot become a DLC', program, cause);
+  }
+}
+codeToErrorMap.set(0x7b, GameAlreadyPrintedError);
+nameToErrorMap.set('GameAlreadyPrinted', GameAlreadyPrintedError);
+
+/** PassAuthorityMustSign: The pass authority must approve games joining the pass */
+export class PassAuthorityMustSignError extends ProgramError {
+  override readonly name: string = 'PassAuthorityMustSign';
+
+  readonly code: number = 0x7c; // 124
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The pass authority must approve games joining the pass',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x7c, PassAuthorityMustSignError);
+nameToErrorMap.set('PassAuthorityMustSign', PassAuthorityMustSignError);
+
+/** CrowdfundActive: The crowdfund of the game is still open */
+export class CrowdfundActiveError extends ProgramError {
+  override readonly name: string = 'CrowdfundActive';
+
+  readonly code: number = 0x7d; // 125
+
+  constructor(program: Program, cause?: Error) {
+    super('The crowdfund of the game is still open', program, cause);
+  }
+}
+codeToErrorMap.set(0x7d, CrowdfundActiveError);
+nameToErrorMap.set('CrowdfundActive', CrowdfundActiveError);
+
+/** CatalogEntryExists: The game is already listed in the catalog */
+export class CatalogEntryExistsError extends ProgramError {
+  override readonly name: string = 'CatalogEntryExists';
+
+  readonly code: number = 0x7e; // 126
+
+  constructor(program: Program, cause?: Error) {
+    super('The game is already listed in the catalog', program, cause);
+  }
+}
+codeToErrorMap.set(0x7e, CatalogEntryExistsError);
+nameToErrorMap.set('CatalogEntryExists', CatalogEntryExistsError);
+
+/** CatalogPublisherMismatch: The game is not published by the publisher of the catalog */
+export class CatalogPublisherMismatchError extends ProgramError {
+  override readonly name: string = 'CatalogPublisherMismatch';
+
+  readonly code: number = 0x7f; // 127
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The game is not published by the publisher of the catalog',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x7f, CatalogPublisherMismatchError);
+nameToErrorMap.set('CatalogPublisherMismatch', CatalogPublisherMismatchError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
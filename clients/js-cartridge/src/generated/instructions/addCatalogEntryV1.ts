@@ -0,0 +1,168 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findCatalogPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AddCatalogEntryV1InstructionAccounts = {
+  /** The catalog of the publisher */
+  catalog?: PublicKey | Pda;
+  /** The publisher of the game, or its team */
+  publisher: PublicKey | Pda | Signer;
+  /** The game Collection account to list */
+  game: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddCatalogEntryV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export type AddCatalogEntryV1InstructionDataArgs = {
+  collectionNonce?: number;
+  collectionBump: number;
+};
+
+export function getAddCatalogEntryV1InstructionDataSerializer(): Serializer<
+  AddCatalogEntryV1InstructionDataArgs,
+  AddCatalogEntryV1InstructionData
+> {
+  return mapSerializer<
+    AddCatalogEntryV1InstructionDataArgs,
+    any,
+    AddCatalogEntryV1InstructionData
+  >(
+    struct<AddCatalogEntryV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+      ],
+      { description: 'AddCatalogEntryV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 50,
+      collectionNonce: value.collectionNonce ?? 0,
+    })
+  ) as Serializer<
+    AddCatalogEntryV1InstructionDataArgs,
+    AddCatalogEntryV1InstructionData
+  >;
+}
+
+// Args.
+export type AddCatalogEntryV1InstructionArgs =
+  AddCatalogEntryV1InstructionDataArgs;
+
+// Instruction.
+export function addCatalogEntryV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: AddCatalogEntryV1InstructionAccounts & AddCatalogEntryV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    catalog: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.catalog ?? null,
+    },
+    publisher: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.publisher ?? null,
+    },
+    game: { index: 2, isWritable: false as boolean, value: input.game ?? null },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AddCatalogEntryV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.catalog.value) {
+    resolvedAccounts.catalog.value = findCatalogPda(context, {
+      publisher: expectPublicKey(resolvedAccounts.publisher.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddCatalogEntryV1InstructionDataSerializer().serialize(
+    resolvedArgs as AddCatalogEntryV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,172 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AirdropCartridgesV1InstructionAccounts = {
+  /** The game Collection account */
+  game: PublicKey | Pda;
+  /** The publisher of the game, or its team */
+  publisher: PublicKey | Pda | Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AirdropCartridgesV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+  numApprovers: number;
+};
+
+export type AirdropCartridgesV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+  numApprovers?: number;
+};
+
+export function getAirdropCartridgesV1InstructionDataSerializer(): Serializer<
+  AirdropCartridgesV1InstructionDataArgs,
+  AirdropCartridgesV1InstructionData
+> {
+  return mapSerializer<
+    AirdropCartridgesV1InstructionDataArgs,
+    any,
+    AirdropCartridgesV1InstructionData
+  >(
+    struct<AirdropCartridgesV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+        ['numApprovers', u8()],
+      ],
+      { description: 'AirdropCartridgesV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 13,
+      numApprovers: value.numApprovers ?? 0,
+    })
+  ) as Serializer<
+    AirdropCartridgesV1InstructionDataArgs,
+    AirdropCartridgesV1InstructionData
+  >;
+}
+
+// Args.
+export type AirdropCartridgesV1InstructionArgs =
+  AirdropCartridgesV1InstructionDataArgs;
+
+// Instruction.
+export function airdropCartridgesV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: AirdropCartridgesV1InstructionAccounts &
+    AirdropCartridgesV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    game: { index: 0, isWritable: true as boolean, value: input.game ?? null },
+    publisher: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.publisher ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    mplCoreProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AirdropCartridgesV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAirdropCartridgesV1InstructionDataSerializer().serialize(
+    resolvedArgs as AirdropCartridgesV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,230 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findListingPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type BuyCartridgeV1InstructionAccounts = {
+  /** The game cartridge asset account */
+  cartridge: PublicKey | Pda;
+  /** The game Collection account */
+  game: PublicKey | Pda;
+  /** The listing of the cartridge */
+  listing?: PublicKey | Pda;
+  /** The seller of the cartridge */
+  seller: PublicKey | Pda;
+  /** The token account receiving the sale proceeds */
+  sellerTokenAccount?: PublicKey | Pda;
+  /** The buyer of the cartridge */
+  buyer: Signer;
+  /** The token account paying for the cartridge */
+  buyerTokenAccount?: PublicKey | Pda;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The token program */
+  tokenProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type BuyCartridgeV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+  price: bigint;
+};
+
+export type BuyCartridgeV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+  price: number | bigint;
+};
+
+export function getBuyCartridgeV1InstructionDataSerializer(): Serializer<
+  BuyCartridgeV1InstructionDataArgs,
+  BuyCartridgeV1InstructionData
+> {
+  return mapSerializer<
+    BuyCartridgeV1InstructionDataArgs,
+    any,
+    BuyCartridgeV1InstructionData
+  >(
+    struct<BuyCartridgeV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+        ['price', u64()],
+      ],
+      { description: 'BuyCartridgeV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 21 })
+  ) as Serializer<
+    BuyCartridgeV1InstructionDataArgs,
+    BuyCartridgeV1InstructionData
+  >;
+}
+
+// Args.
+export type BuyCartridgeV1InstructionArgs = BuyCartridgeV1InstructionDataArgs;
+
+// Instruction.
+export function buyCartridgeV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: BuyCartridgeV1InstructionAccounts & BuyCartridgeV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    cartridge: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.cartridge ?? null,
+    },
+    game: { index: 1, isWritable: true as boolean, value: input.game ?? null },
+    listing: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.listing ?? null,
+    },
+    seller: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.seller ?? null,
+    },
+    sellerTokenAccount: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.sellerTokenAccount ?? null,
+    },
+    buyer: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.buyer ?? null,
+    },
+    buyerTokenAccount: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.buyerTokenAccount ?? null,
+    },
+    mplCoreProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    tokenProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: BuyCartridgeV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.listing.value) {
+    resolvedAccounts.listing.value = findListingPda(context, {
+      cartridge: expectPublicKey(resolvedAccounts.cartridge.value),
+    });
+  }
+  if (!resolvedAccounts.sellerTokenAccount.value) {
+    resolvedAccounts.sellerTokenAccount.value = findAssociatedTokenPda(
+      context,
+      {
+        mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+        owner: expectPublicKey(resolvedAccounts.seller.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.buyerTokenAccount.value) {
+    resolvedAccounts.buyerTokenAccount.value = findAssociatedTokenPda(context, {
+      mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+      owner: expectPublicKey(resolvedAccounts.buyer.value),
+    });
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBuyCartridgeV1InstructionDataSerializer().serialize(
+    resolvedArgs as BuyCartridgeV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
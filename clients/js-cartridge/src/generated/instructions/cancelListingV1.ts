@@ -0,0 +1,107 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CancelListingV1InstructionAccounts = {
+  /** The listing of the cartridge */
+  listing: PublicKey | Pda;
+  /** The seller of the cartridge */
+  seller: Signer;
+};
+
+// Data.
+export type CancelListingV1InstructionData = { discriminator: number };
+
+export type CancelListingV1InstructionDataArgs = {};
+
+export function getCancelListingV1InstructionDataSerializer(): Serializer<
+  CancelListingV1InstructionDataArgs,
+  CancelListingV1InstructionData
+> {
+  return mapSerializer<
+    CancelListingV1InstructionDataArgs,
+    any,
+    CancelListingV1InstructionData
+  >(
+    struct<CancelListingV1InstructionData>([['discriminator', u8()]], {
+      description: 'CancelListingV1InstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 22 })
+  ) as Serializer<
+    CancelListingV1InstructionDataArgs,
+    CancelListingV1InstructionData
+  >;
+}
+
+// Instruction.
+export function cancelListingV1(
+  context: Pick<Context, 'programs'>,
+  input: CancelListingV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    listing: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.listing ?? null,
+    },
+    seller: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.seller ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCancelListingV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
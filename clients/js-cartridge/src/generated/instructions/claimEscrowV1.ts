@@ -0,0 +1,176 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findEscrowPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ClaimEscrowV1InstructionAccounts = {
+  /** The crowdfunded game Collection account */
+  game: PublicKey | Pda;
+  /** The escrow of the game */
+  escrow?: PublicKey | Pda;
+  /** The token account of the escrow */
+  escrowTokenAccount?: PublicKey | Pda;
+  /** The token account receiving the payments for the game */
+  gameTokenAccount?: PublicKey | Pda;
+  /** The publisher of the game, or its team */
+  publisher: PublicKey | Pda | Signer;
+  /** The token program */
+  tokenProgram?: PublicKey | Pda;
+  /** The payment token mint */
+  paymentMint?: PublicKey | Pda;
+};
+
+// Data.
+export type ClaimEscrowV1InstructionData = { discriminator: number };
+
+export type ClaimEscrowV1InstructionDataArgs = {};
+
+export function getClaimEscrowV1InstructionDataSerializer(): Serializer<
+  ClaimEscrowV1InstructionDataArgs,
+  ClaimEscrowV1InstructionData
+> {
+  return mapSerializer<
+    ClaimEscrowV1InstructionDataArgs,
+    any,
+    ClaimEscrowV1InstructionData
+  >(
+    struct<ClaimEscrowV1InstructionData>([['discriminator', u8()]], {
+      description: 'ClaimEscrowV1InstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 33 })
+  ) as Serializer<
+    ClaimEscrowV1InstructionDataArgs,
+    ClaimEscrowV1InstructionData
+  >;
+}
+
+// Instruction.
+export function claimEscrowV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: ClaimEscrowV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    game: { index: 0, isWritable: false as boolean, value: input.game ?? null },
+    escrow: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.escrow ?? null,
+    },
+    escrowTokenAccount: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.escrowTokenAccount ?? null,
+    },
+    gameTokenAccount: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.gameTokenAccount ?? null,
+    },
+    publisher: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.publisher ?? null,
+    },
+    tokenProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    paymentMint: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.paymentMint ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.escrow.value) {
+    resolvedAccounts.escrow.value = findEscrowPda(context, {
+      game: expectPublicKey(resolvedAccounts.game.value),
+    });
+  }
+  if (!resolvedAccounts.escrowTokenAccount.value) {
+    resolvedAccounts.escrowTokenAccount.value = findAssociatedTokenPda(
+      context,
+      {
+        mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+        owner: expectPublicKey(resolvedAccounts.escrow.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.gameTokenAccount.value) {
+    resolvedAccounts.gameTokenAccount.value = findAssociatedTokenPda(context, {
+      mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+      owner: expectPublicKey(resolvedAccounts.game.value),
+    });
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.paymentMint.value) {
+    resolvedAccounts.paymentMint.value = publicKey(
+      'BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getClaimEscrowV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,153 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ClaimNameV1InstructionAccounts = {
+  /** The registry record of the game name */
+  nameRecord: PublicKey | Pda;
+  /** The game Collection account */
+  game: PublicKey | Pda;
+  /** The publisher of the game, or its team */
+  publisher: PublicKey | Pda | Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ClaimNameV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export type ClaimNameV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export function getClaimNameV1InstructionDataSerializer(): Serializer<
+  ClaimNameV1InstructionDataArgs,
+  ClaimNameV1InstructionData
+> {
+  return mapSerializer<
+    ClaimNameV1InstructionDataArgs,
+    any,
+    ClaimNameV1InstructionData
+  >(
+    struct<ClaimNameV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+      ],
+      { description: 'ClaimNameV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 48 })
+  ) as Serializer<ClaimNameV1InstructionDataArgs, ClaimNameV1InstructionData>;
+}
+
+// Args.
+export type ClaimNameV1InstructionArgs = ClaimNameV1InstructionDataArgs;
+
+// Instruction.
+export function claimNameV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: ClaimNameV1InstructionAccounts & ClaimNameV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    nameRecord: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.nameRecord ?? null,
+    },
+    game: { index: 1, isWritable: false as boolean, value: input.game ?? null },
+    publisher: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.publisher ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ClaimNameV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getClaimNameV1InstructionDataSerializer().serialize(
+    resolvedArgs as ClaimNameV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
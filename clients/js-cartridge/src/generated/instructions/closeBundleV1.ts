@@ -0,0 +1,114 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CloseBundleV1InstructionAccounts = {
+  /** The bundle account */
+  bundle: PublicKey | Pda;
+  /** The publisher of the bundle, or its team */
+  publisher: PublicKey | Pda | Signer;
+  /** The account receiving the rent of the bundle */
+  destination: PublicKey | Pda;
+};
+
+// Data.
+export type CloseBundleV1InstructionData = { discriminator: number };
+
+export type CloseBundleV1InstructionDataArgs = {};
+
+export function getCloseBundleV1InstructionDataSerializer(): Serializer<
+  CloseBundleV1InstructionDataArgs,
+  CloseBundleV1InstructionData
+> {
+  return mapSerializer<
+    CloseBundleV1InstructionDataArgs,
+    any,
+    CloseBundleV1InstructionData
+  >(
+    struct<CloseBundleV1InstructionData>([['discriminator', u8()]], {
+      description: 'CloseBundleV1InstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 45 })
+  ) as Serializer<
+    CloseBundleV1InstructionDataArgs,
+    CloseBundleV1InstructionData
+  >;
+}
+
+// Instruction.
+export function closeBundleV1(
+  context: Pick<Context, 'programs'>,
+  input: CloseBundleV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    bundle: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.bundle ?? null,
+    },
+    publisher: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.publisher ?? null,
+    },
+    destination: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.destination ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCloseBundleV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,173 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findBundlePda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  expectSome,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  BundleGameArgs,
+  BundleGameArgsArgs,
+  getBundleGameArgsSerializer,
+} from '../types';
+
+// Accounts.
+export type CreateBundleV1InstructionAccounts = {
+  /** The new bundle account */
+  bundle?: PublicKey | Pda;
+  /** The publisher of every member game, or its team */
+  publisher: PublicKey | Pda | Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CreateBundleV1InstructionData = {
+  discriminator: number;
+  name: string;
+  price: bigint;
+  games: Array<BundleGameArgs>;
+};
+
+export type CreateBundleV1InstructionDataArgs = {
+  name: string;
+  price: number | bigint;
+  games: Array<BundleGameArgsArgs>;
+};
+
+export function getCreateBundleV1InstructionDataSerializer(): Serializer<
+  CreateBundleV1InstructionDataArgs,
+  CreateBundleV1InstructionData
+> {
+  return mapSerializer<
+    CreateBundleV1InstructionDataArgs,
+    any,
+    CreateBundleV1InstructionData
+  >(
+    struct<CreateBundleV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['name', string()],
+        ['price', u64()],
+        ['games', array(getBundleGameArgsSerializer())],
+      ],
+      { description: 'CreateBundleV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 14 })
+  ) as Serializer<
+    CreateBundleV1InstructionDataArgs,
+    CreateBundleV1InstructionData
+  >;
+}
+
+// Args.
+export type CreateBundleV1InstructionArgs = CreateBundleV1InstructionDataArgs;
+
+// Instruction.
+export function createBundleV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: CreateBundleV1InstructionAccounts & CreateBundleV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    bundle: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.bundle ?? null,
+    },
+    publisher: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.publisher ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: CreateBundleV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.bundle.value) {
+    resolvedAccounts.bundle.value = findBundlePda(context, {
+      publisher: expectPublicKey(resolvedAccounts.publisher.value),
+      name: expectSome(resolvedArgs.name),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCreateBundleV1InstructionDataSerializer().serialize(
+    resolvedArgs as CreateBundleV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
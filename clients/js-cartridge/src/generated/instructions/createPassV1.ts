@@ -0,0 +1,238 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  mapSerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findPassPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  expectSome,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CreatePassV1InstructionAccounts = {
+  /** The new pass collection account */
+  passCollection?: PublicKey | Pda;
+  /** The token account receiving pass payments */
+  passTokenAccount?: PublicKey | Pda;
+  /** The wallet creating the pass */
+  authority?: Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The payment mint */
+  paymentMint?: PublicKey | Pda;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The token program */
+  tokenProgram?: PublicKey | Pda;
+  /** The associated token program */
+  associatedTokenProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CreatePassV1InstructionData = {
+  discriminator: number;
+  name: string;
+  uri: string;
+  price: bigint;
+  duration: bigint;
+};
+
+export type CreatePassV1InstructionDataArgs = {
+  name: string;
+  uri: string;
+  price: number | bigint;
+  duration: number | bigint;
+};
+
+export function getCreatePassV1InstructionDataSerializer(): Serializer<
+  CreatePassV1InstructionDataArgs,
+  CreatePassV1InstructionData
+> {
+  return mapSerializer<
+    CreatePassV1InstructionDataArgs,
+    any,
+    CreatePassV1InstructionData
+  >(
+    struct<CreatePassV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['name', string()],
+        ['uri', string()],
+        ['price', u64()],
+        ['duration', i64()],
+      ],
+      { description: 'CreatePassV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 27 })
+  ) as Serializer<CreatePassV1InstructionDataArgs, CreatePassV1InstructionData>;
+}
+
+// Args.
+export type CreatePassV1InstructionArgs = CreatePassV1InstructionDataArgs;
+
+// Instruction.
+export function createPassV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: CreatePassV1InstructionAccounts & CreatePassV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    passCollection: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.passCollection ?? null,
+    },
+    passTokenAccount: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.passTokenAccount ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    paymentMint: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.paymentMint ?? null,
+    },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    tokenProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: CreatePassV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.passCollection.value) {
+    resolvedAccounts.passCollection.value = findPassPda(context, {
+      authority: expectPublicKey(resolvedAccounts.authority.value),
+      name: expectSome(resolvedArgs.name),
+    });
+  }
+  if (!resolvedAccounts.passTokenAccount.value) {
+    resolvedAccounts.passTokenAccount.value = findAssociatedTokenPda(context, {
+      mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+      owner: expectPublicKey(resolvedAccounts.passCollection.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.paymentMint.value) {
+    resolvedAccounts.paymentMint.value = publicKey(
+      'BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'
+    );
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value = publicKey(
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+    );
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCreatePassV1InstructionDataSerializer().serialize(
+    resolvedArgs as CreatePassV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,170 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findPublisherTeamPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  expectSome,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  TeamMemberArgs,
+  TeamMemberArgsArgs,
+  getTeamMemberArgsSerializer,
+} from '../types';
+
+// Accounts.
+export type CreatePublisherTeamV1InstructionAccounts = {
+  /** The new publisher team */
+  team?: PublicKey | Pda;
+  /** The wallet creating the team */
+  creator: Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CreatePublisherTeamV1InstructionData = {
+  discriminator: number;
+  name: string;
+  threshold: number;
+  members: Array<TeamMemberArgs>;
+};
+
+export type CreatePublisherTeamV1InstructionDataArgs = {
+  name: string;
+  threshold: number;
+  members: Array<TeamMemberArgsArgs>;
+};
+
+export function getCreatePublisherTeamV1InstructionDataSerializer(): Serializer<
+  CreatePublisherTeamV1InstructionDataArgs,
+  CreatePublisherTeamV1InstructionData
+> {
+  return mapSerializer<
+    CreatePublisherTeamV1InstructionDataArgs,
+    any,
+    CreatePublisherTeamV1InstructionData
+  >(
+    struct<CreatePublisherTeamV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['name', string()],
+        ['threshold', u8()],
+        ['members', array(getTeamMemberArgsSerializer())],
+      ],
+      { description: 'CreatePublisherTeamV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 36 })
+  ) as Serializer<
+    CreatePublisherTeamV1InstructionDataArgs,
+    CreatePublisherTeamV1InstructionData
+  >;
+}
+
+// Args.
+export type CreatePublisherTeamV1InstructionArgs =
+  CreatePublisherTeamV1InstructionDataArgs;
+
+// Instruction.
+export function createPublisherTeamV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: CreatePublisherTeamV1InstructionAccounts &
+    CreatePublisherTeamV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    team: { index: 0, isWritable: true as boolean, value: input.team ?? null },
+    creator: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.creator ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: CreatePublisherTeamV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.team.value) {
+    resolvedAccounts.team.value = findPublisherTeamPda(context, {
+      creator: expectPublicKey(resolvedAccounts.creator.value),
+      name: expectSome(resolvedArgs.name),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCreatePublisherTeamV1InstructionDataSerializer().serialize(
+    resolvedArgs as CreatePublisherTeamV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,178 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type EjectCartridgeV1InstructionAccounts = {
+  /** The game cartridge account */
+  cartridge: PublicKey | Pda;
+  /** The game Collection account */
+  game: PublicKey | Pda;
+  /** The machine asset account */
+  machine: PublicKey | Pda;
+  /** The Core machine collection */
+  machineCollection: PublicKey | Pda;
+  /** The machine owner or operator delegate */
+  authority?: Signer;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type EjectCartridgeV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export type EjectCartridgeV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export function getEjectCartridgeV1InstructionDataSerializer(): Serializer<
+  EjectCartridgeV1InstructionDataArgs,
+  EjectCartridgeV1InstructionData
+> {
+  return mapSerializer<
+    EjectCartridgeV1InstructionDataArgs,
+    any,
+    EjectCartridgeV1InstructionData
+  >(
+    struct<EjectCartridgeV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+      ],
+      { description: 'EjectCartridgeV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 7 })
+  ) as Serializer<
+    EjectCartridgeV1InstructionDataArgs,
+    EjectCartridgeV1InstructionData
+  >;
+}
+
+// Args.
+export type EjectCartridgeV1InstructionArgs =
+  EjectCartridgeV1InstructionDataArgs;
+
+// Instruction.
+export function ejectCartridgeV1(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: EjectCartridgeV1InstructionAccounts & EjectCartridgeV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    cartridge: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.cartridge ?? null,
+    },
+    game: { index: 1, isWritable: true as boolean, value: input.game ?? null },
+    machine: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.machine ?? null,
+    },
+    machineCollection: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.machineCollection ?? null,
+    },
+    authority: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: EjectCartridgeV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getEjectCartridgeV1InstructionDataSerializer().serialize(
+    resolvedArgs as EjectCartridgeV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
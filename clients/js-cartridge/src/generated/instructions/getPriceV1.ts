@@ -0,0 +1,92 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type GetPriceV1InstructionAccounts = {
+  /** The game Collection account */
+  game: PublicKey | Pda;
+};
+
+// Data.
+export type GetPriceV1InstructionData = { discriminator: number };
+
+export type GetPriceV1InstructionDataArgs = {};
+
+export function getGetPriceV1InstructionDataSerializer(): Serializer<
+  GetPriceV1InstructionDataArgs,
+  GetPriceV1InstructionData
+> {
+  return mapSerializer<
+    GetPriceV1InstructionDataArgs,
+    any,
+    GetPriceV1InstructionData
+  >(
+    struct<GetPriceV1InstructionData>([['discriminator', u8()]], {
+      description: 'GetPriceV1InstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 10 })
+  ) as Serializer<GetPriceV1InstructionDataArgs, GetPriceV1InstructionData>;
+}
+
+// Instruction.
+export function getPriceV1(
+  context: Pick<Context, 'programs'>,
+  input: GetPriceV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    game: { index: 0, isWritable: false as boolean, value: input.game ?? null },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getGetPriceV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -6,9 +6,57 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './addCatalogEntryV1';
+export * from './airdropCartridgesV1';
+export * from './buyCartridgeV1';
+export * from './cancelListingV1';
+export * from './claimEscrowV1';
+export * from './claimNameV1';
+export * from './closeBundleV1';
 export * from './commissionMachineV1';
+export * from './createBundleV1';
+export * from './createPassV1';
+export * from './createPublisherTeamV1';
+export * from './ejectCartridgeV1';
+export * from './getPriceV1';
 export * from './insertCartridgeV1';
+export * from './insertCartridgeV2';
+export * from './insertPassV1';
+export * from './lendCartridgeV1';
+export * from './listCartridgeV1';
+export * from './preOrderV1';
+export * from './printBundleV1';
+export * from './printDlcV1';
+export * from './printGameCartridgeAllowlistV1';
 export * from './printGameCartridgeV1';
+export * from './printGameCartridgeV2';
+export * from './printGameCartridgeVoucherV1';
+export * from './printRentalCartridgeV1';
+export * from './purchasePassV1';
+export * from './reassignNameV1';
+export * from './reclaimExpiredCartridgeV1';
+export * from './redeemPreOrderV1';
+export * from './refundCartridgeV1';
+export * from './refundPreOrderV1';
+export * from './registerDlcV1';
 export * from './releaseGameV1';
+export * from './releaseGameV2';
 export * from './removeCartridgeV1';
+export * from './removeCartridgeV2';
+export * from './removePassV1';
+export * from './renewPassV1';
+export * from './reserveNameV1';
+export * from './restoreCatalogEntryV1';
+export * from './retireCatalogEntryV1';
+export * from './returnCartridgeV1';
 export * from './setCartridgeSourceV1';
+export * from './setMachineOperatorV1';
+export * from './setSessionLockV1';
+export * from './setVerificationV1';
+export * from './transferNameV1';
+export * from './updateBundleV1';
+export * from './updateGameV1';
+export * from './updatePublisherTeamV1';
+export * from './withdrawGameRevenueV1';
+export * from './withdrawPassRevenueV1';
+export * from './writeSaveDataV1';
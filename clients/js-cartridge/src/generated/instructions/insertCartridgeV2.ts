@@ -0,0 +1,189 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InsertCartridgeV2InstructionAccounts = {
+  /** The game cartridge account */
+  cartridge: PublicKey | Pda;
+  /** The game Collection account */
+  game: PublicKey | Pda;
+  /** The owner of the game cartridge */
+  cartridgeOwner: PublicKey | Pda | Signer;
+  /** The machine asset account */
+  machine: PublicKey | Pda;
+  /** The Core machine collection */
+  machineCollection: PublicKey | Pda;
+  /** The owner of the machine */
+  machineOwner: PublicKey | Pda;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The machine operator delegate signing in place of the cartridge owner */
+  operator?: Signer;
+};
+
+// Data.
+export type InsertCartridgeV2InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export type InsertCartridgeV2InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export function getInsertCartridgeV2InstructionDataSerializer(): Serializer<
+  InsertCartridgeV2InstructionDataArgs,
+  InsertCartridgeV2InstructionData
+> {
+  return mapSerializer<
+    InsertCartridgeV2InstructionDataArgs,
+    any,
+    InsertCartridgeV2InstructionData
+  >(
+    struct<InsertCartridgeV2InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+      ],
+      { description: 'InsertCartridgeV2InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 52 })
+  ) as Serializer<
+    InsertCartridgeV2InstructionDataArgs,
+    InsertCartridgeV2InstructionData
+  >;
+}
+
+// Args.
+export type InsertCartridgeV2InstructionArgs =
+  InsertCartridgeV2InstructionDataArgs;
+
+// Instruction.
+export function insertCartridgeV2(
+  context: Pick<Context, 'programs'>,
+  input: InsertCartridgeV2InstructionAccounts & InsertCartridgeV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    cartridge: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.cartridge ?? null,
+    },
+    game: { index: 1, isWritable: true as boolean, value: input.game ?? null },
+    cartridgeOwner: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.cartridgeOwner ?? null,
+    },
+    machine: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.machine ?? null,
+    },
+    machineCollection: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.machineCollection ?? null,
+    },
+    machineOwner: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.machineOwner ?? null,
+    },
+    mplCoreProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    operator: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.operator ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: InsertCartridgeV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInsertCartridgeV2InstructionDataSerializer().serialize(
+    resolvedArgs as InsertCartridgeV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,174 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InsertPassV1InstructionAccounts = {
+  /** The pass asset account */
+  pass: PublicKey | Pda;
+  /** The pass collection account */
+  passCollection: PublicKey | Pda;
+  /** The game Collection account to play */
+  game: PublicKey | Pda;
+  /** The owner of the pass */
+  passOwner: Signer;
+  /** The machine asset account */
+  machine: PublicKey | Pda;
+  /** The Core collection of the machine */
+  machineCollection: PublicKey | Pda;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type InsertPassV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export type InsertPassV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export function getInsertPassV1InstructionDataSerializer(): Serializer<
+  InsertPassV1InstructionDataArgs,
+  InsertPassV1InstructionData
+> {
+  return mapSerializer<
+    InsertPassV1InstructionDataArgs,
+    any,
+    InsertPassV1InstructionData
+  >(
+    struct<InsertPassV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+      ],
+      { description: 'InsertPassV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 30 })
+  ) as Serializer<InsertPassV1InstructionDataArgs, InsertPassV1InstructionData>;
+}
+
+// Args.
+export type InsertPassV1InstructionArgs = InsertPassV1InstructionDataArgs;
+
+// Instruction.
+export function insertPassV1(
+  context: Pick<Context, 'programs'>,
+  input: InsertPassV1InstructionAccounts & InsertPassV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    pass: { index: 0, isWritable: true as boolean, value: input.pass ?? null },
+    passCollection: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.passCollection ?? null,
+    },
+    game: { index: 2, isWritable: false as boolean, value: input.game ?? null },
+    passOwner: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.passOwner ?? null,
+    },
+    machine: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.machine ?? null,
+    },
+    machineCollection: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.machineCollection ?? null,
+    },
+    mplCoreProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: InsertPassV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInsertPassV1InstructionDataSerializer().serialize(
+    resolvedArgs as InsertPassV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,181 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findLoanPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type LendCartridgeV1InstructionAccounts = {
+  /** The game cartridge asset account */
+  cartridge: PublicKey | Pda;
+  /** The game Collection account */
+  game: PublicKey | Pda;
+  /** The new loan of the cartridge */
+  loan?: PublicKey | Pda;
+  /** The owner of the cartridge */
+  lender: Signer;
+  /** The wallet borrowing the cartridge */
+  borrower: PublicKey | Pda;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type LendCartridgeV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+  duration: bigint;
+};
+
+export type LendCartridgeV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+  duration: number | bigint;
+};
+
+export function getLendCartridgeV1InstructionDataSerializer(): Serializer<
+  LendCartridgeV1InstructionDataArgs,
+  LendCartridgeV1InstructionData
+> {
+  return mapSerializer<
+    LendCartridgeV1InstructionDataArgs,
+    any,
+    LendCartridgeV1InstructionData
+  >(
+    struct<LendCartridgeV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+        ['duration', i64()],
+      ],
+      { description: 'LendCartridgeV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 23 })
+  ) as Serializer<
+    LendCartridgeV1InstructionDataArgs,
+    LendCartridgeV1InstructionData
+  >;
+}
+
+// Args.
+export type LendCartridgeV1InstructionArgs = LendCartridgeV1InstructionDataArgs;
+
+// Instruction.
+export function lendCartridgeV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: LendCartridgeV1InstructionAccounts & LendCartridgeV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    cartridge: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.cartridge ?? null,
+    },
+    game: { index: 1, isWritable: true as boolean, value: input.game ?? null },
+    loan: { index: 2, isWritable: true as boolean, value: input.loan ?? null },
+    lender: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.lender ?? null,
+    },
+    borrower: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.borrower ?? null,
+    },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: LendCartridgeV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.loan.value) {
+    resolvedAccounts.loan.value = findLoanPda(context, {
+      cartridge: expectPublicKey(resolvedAccounts.cartridge.value),
+    });
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getLendCartridgeV1InstructionDataSerializer().serialize(
+    resolvedArgs as LendCartridgeV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
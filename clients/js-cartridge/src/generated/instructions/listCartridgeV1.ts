@@ -0,0 +1,156 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findListingPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ListCartridgeV1InstructionAccounts = {
+  /** The game cartridge asset account */
+  cartridge: PublicKey | Pda;
+  /** The game Collection account */
+  game: PublicKey | Pda;
+  /** The new listing of the cartridge */
+  listing?: PublicKey | Pda;
+  /** The owner of the cartridge */
+  seller: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ListCartridgeV1InstructionData = {
+  discriminator: number;
+  price: bigint;
+};
+
+export type ListCartridgeV1InstructionDataArgs = { price: number | bigint };
+
+export function getListCartridgeV1InstructionDataSerializer(): Serializer<
+  ListCartridgeV1InstructionDataArgs,
+  ListCartridgeV1InstructionData
+> {
+  return mapSerializer<
+    ListCartridgeV1InstructionDataArgs,
+    any,
+    ListCartridgeV1InstructionData
+  >(
+    struct<ListCartridgeV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['price', u64()],
+      ],
+      { description: 'ListCartridgeV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 20 })
+  ) as Serializer<
+    ListCartridgeV1InstructionDataArgs,
+    ListCartridgeV1InstructionData
+  >;
+}
+
+// Args.
+export type ListCartridgeV1InstructionArgs = ListCartridgeV1InstructionDataArgs;
+
+// Instruction.
+export function listCartridgeV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: ListCartridgeV1InstructionAccounts & ListCartridgeV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    cartridge: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.cartridge ?? null,
+    },
+    game: { index: 1, isWritable: false as boolean, value: input.game ?? null },
+    listing: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.listing ?? null,
+    },
+    seller: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.seller ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ListCartridgeV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.listing.value) {
+    resolvedAccounts.listing.value = findListingPda(context, {
+      cartridge: expectPublicKey(resolvedAccounts.cartridge.value),
+    });
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getListCartridgeV1InstructionDataSerializer().serialize(
+    resolvedArgs as ListCartridgeV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
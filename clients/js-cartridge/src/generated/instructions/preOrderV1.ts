@@ -0,0 +1,207 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findEscrowPda, findPreOrderPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PreOrderV1InstructionAccounts = {
+  /** The crowdfunded game Collection account */
+  game: PublicKey | Pda;
+  /** The escrow of the game */
+  escrow?: PublicKey | Pda;
+  /** The token account of the escrow */
+  escrowTokenAccount?: PublicKey | Pda;
+  /** The pre-orders of the backer */
+  preOrder?: PublicKey | Pda;
+  /** The wallet pre-ordering the cartridges */
+  backer: Signer;
+  /** The token account paying for the pre-orders */
+  backerTokenAccount?: PublicKey | Pda;
+  /** The token program */
+  tokenProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type PreOrderV1InstructionData = {
+  discriminator: number;
+  quantity: number;
+};
+
+export type PreOrderV1InstructionDataArgs = { quantity: number };
+
+export function getPreOrderV1InstructionDataSerializer(): Serializer<
+  PreOrderV1InstructionDataArgs,
+  PreOrderV1InstructionData
+> {
+  return mapSerializer<
+    PreOrderV1InstructionDataArgs,
+    any,
+    PreOrderV1InstructionData
+  >(
+    struct<PreOrderV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['quantity', u32()],
+      ],
+      { description: 'PreOrderV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 32 })
+  ) as Serializer<PreOrderV1InstructionDataArgs, PreOrderV1InstructionData>;
+}
+
+// Args.
+export type PreOrderV1InstructionArgs = PreOrderV1InstructionDataArgs;
+
+// Instruction.
+export function preOrderV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: PreOrderV1InstructionAccounts & PreOrderV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    game: { index: 0, isWritable: false as boolean, value: input.game ?? null },
+    escrow: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.escrow ?? null,
+    },
+    escrowTokenAccount: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.escrowTokenAccount ?? null,
+    },
+    preOrder: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.preOrder ?? null,
+    },
+    backer: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.backer ?? null,
+    },
+    backerTokenAccount: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.backerTokenAccount ?? null,
+    },
+    tokenProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: PreOrderV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.escrow.value) {
+    resolvedAccounts.escrow.value = findEscrowPda(context, {
+      game: expectPublicKey(resolvedAccounts.game.value),
+    });
+  }
+  if (!resolvedAccounts.escrowTokenAccount.value) {
+    resolvedAccounts.escrowTokenAccount.value = findAssociatedTokenPda(
+      context,
+      {
+        mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+        owner: expectPublicKey(resolvedAccounts.escrow.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.preOrder.value) {
+    resolvedAccounts.preOrder.value = findPreOrderPda(context, {
+      game: expectPublicKey(resolvedAccounts.game.value),
+      backer: expectPublicKey(resolvedAccounts.backer.value),
+    });
+  }
+  if (!resolvedAccounts.backerTokenAccount.value) {
+    resolvedAccounts.backerTokenAccount.value = findAssociatedTokenPda(
+      context,
+      {
+        mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+        owner: expectPublicKey(resolvedAccounts.backer.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPreOrderV1InstructionDataSerializer().serialize(
+    resolvedArgs as PreOrderV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,205 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PrintBundleV1InstructionAccounts = {
+  /** The bundle account */
+  bundle: PublicKey | Pda;
+  /** The owner of the new cartridges */
+  owner: PublicKey | Pda;
+  /** The account paying for the bundle and the storage fees */
+  payer?: Signer;
+  /** The token account paying for the bundle */
+  payerTokenAccount?: PublicKey | Pda;
+  /** The payment mint */
+  paymentMint?: PublicKey | Pda;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The token program */
+  tokenProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type PrintBundleV1InstructionData = {
+  discriminator: number;
+  maxPrice: bigint;
+};
+
+export type PrintBundleV1InstructionDataArgs = { maxPrice: number | bigint };
+
+export function getPrintBundleV1InstructionDataSerializer(): Serializer<
+  PrintBundleV1InstructionDataArgs,
+  PrintBundleV1InstructionData
+> {
+  return mapSerializer<
+    PrintBundleV1InstructionDataArgs,
+    any,
+    PrintBundleV1InstructionData
+  >(
+    struct<PrintBundleV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['maxPrice', u64()],
+      ],
+      { description: 'PrintBundleV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 15 })
+  ) as Serializer<
+    PrintBundleV1InstructionDataArgs,
+    PrintBundleV1InstructionData
+  >;
+}
+
+// Args.
+export type PrintBundleV1InstructionArgs = PrintBundleV1InstructionDataArgs;
+
+// Instruction.
+export function printBundleV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: PrintBundleV1InstructionAccounts & PrintBundleV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    bundle: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.bundle ?? null,
+    },
+    owner: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    payerTokenAccount: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payerTokenAccount ?? null,
+    },
+    paymentMint: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.paymentMint ?? null,
+    },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    tokenProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: PrintBundleV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.payerTokenAccount.value) {
+    resolvedAccounts.payerTokenAccount.value = findAssociatedTokenPda(context, {
+      mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+      owner: expectPublicKey(resolvedAccounts.payer.value),
+    });
+  }
+  if (!resolvedAccounts.paymentMint.value) {
+    resolvedAccounts.paymentMint.value = publicKey(
+      'BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'
+    );
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPrintBundleV1InstructionDataSerializer().serialize(
+    resolvedArgs as PrintBundleV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
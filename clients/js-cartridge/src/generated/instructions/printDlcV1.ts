@@ -0,0 +1,263 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findDlcReceiptPda, findMintCounterPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PrintDlcV1InstructionAccounts = {
+  /** The new DLC asset account */
+  cartridge: Signer;
+  /** The DLC game Collection account */
+  game: PublicKey | Pda;
+  /** The token account receiving the payment for the DLC */
+  gameTokenAccount?: PublicKey | Pda;
+  /** The owner of the base cartridge and the new DLC */
+  owner: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The account paying for the storage fees and the DLC cost */
+  payerTokenAccount?: PublicKey | Pda;
+  /** The base game cartridge held by the owner */
+  baseCartridge: PublicKey | Pda;
+  /** The payment mint */
+  paymentMint?: PublicKey | Pda;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The token program */
+  tokenProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The counter of cartridges printed to the owner, when the game limits prints per wallet */
+  mintCounter?: PublicKey | Pda;
+  /** The token account of the wallet that referred the print */
+  referrerTokenAccount?: PublicKey | Pda;
+  /** The receipt of the DLC printed for the base cartridge */
+  dlcReceipt?: PublicKey | Pda;
+};
+
+// Data.
+export type PrintDlcV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export type PrintDlcV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export function getPrintDlcV1InstructionDataSerializer(): Serializer<
+  PrintDlcV1InstructionDataArgs,
+  PrintDlcV1InstructionData
+> {
+  return mapSerializer<
+    PrintDlcV1InstructionDataArgs,
+    any,
+    PrintDlcV1InstructionData
+  >(
+    struct<PrintDlcV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+      ],
+      { description: 'PrintDlcV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 17 })
+  ) as Serializer<PrintDlcV1InstructionDataArgs, PrintDlcV1InstructionData>;
+}
+
+// Args.
+export type PrintDlcV1InstructionArgs = PrintDlcV1InstructionDataArgs;
+
+// Instruction.
+export function printDlcV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: PrintDlcV1InstructionAccounts & PrintDlcV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    cartridge: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.cartridge ?? null,
+    },
+    game: { index: 1, isWritable: true as boolean, value: input.game ?? null },
+    gameTokenAccount: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.gameTokenAccount ?? null,
+    },
+    owner: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+    payer: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    payerTokenAccount: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.payerTokenAccount ?? null,
+    },
+    baseCartridge: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.baseCartridge ?? null,
+    },
+    paymentMint: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.paymentMint ?? null,
+    },
+    mplCoreProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    tokenProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    mintCounter: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.mintCounter ?? null,
+    },
+    referrerTokenAccount: {
+      index: 12,
+      isWritable: true as boolean,
+      value: input.referrerTokenAccount ?? null,
+    },
+    dlcReceipt: {
+      index: 13,
+      isWritable: true as boolean,
+      value: input.dlcReceipt ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: PrintDlcV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.gameTokenAccount.value) {
+    resolvedAccounts.gameTokenAccount.value = findAssociatedTokenPda(context, {
+      mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+      owner: expectPublicKey(resolvedAccounts.game.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.payerTokenAccount.value) {
+    resolvedAccounts.payerTokenAccount.value = findAssociatedTokenPda(context, {
+      mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+      owner: expectPublicKey(resolvedAccounts.payer.value),
+    });
+  }
+  if (!resolvedAccounts.paymentMint.value) {
+    resolvedAccounts.paymentMint.value = publicKey(
+      'BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'
+    );
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.mintCounter.value) {
+    resolvedAccounts.mintCounter.value = findMintCounterPda(context, {
+      game: expectPublicKey(resolvedAccounts.game.value),
+      owner: expectPublicKey(resolvedAccounts.owner.value),
+    });
+  }
+  if (!resolvedAccounts.dlcReceipt.value) {
+    resolvedAccounts.dlcReceipt.value = findDlcReceiptPda(context, {
+      dlc: expectPublicKey(resolvedAccounts.game.value),
+      baseCartridge: expectPublicKey(resolvedAccounts.baseCartridge.value),
+    });
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPrintDlcV1InstructionDataSerializer().serialize(
+    resolvedArgs as PrintDlcV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -9,17 +9,22 @@
 import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
   TransactionBuilder,
+  none,
   publicKey,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
   mapSerializer,
+  option,
   struct,
+  u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
@@ -60,11 +65,13 @@ export type PrintGameCartridgeV1InstructionData = {
   discriminator: number;
   collectionNonce: number;
   collectionBump: number;
+  maxPrice: Option<bigint>;
 };
 
 export type PrintGameCartridgeV1InstructionDataArgs = {
   collectionNonce: number;
   collectionBump: number;
+  maxPrice?: OptionOrNullable<number | bigint>;
 };
 
 export function getPrintGameCartridgeV1InstructionDataSerializer(): Serializer<
@@ -81,10 +88,15 @@ export function getPrintGameCartridgeV1InstructionDataSerializer(): Serializer<
         ['discriminator', u8()],
         ['collectionNonce', u8()],
         ['collectionBump', u8()],
+        ['maxPrice', option(u64())],
       ],
       { description: 'PrintGameCartridgeV1InstructionData' }
     ),
-    (value) => ({ ...value, discriminator: 2 })
+    (value) => ({
+      ...value,
+      discriminator: 2,
+      maxPrice: value.maxPrice ?? none(),
+    })
   ) as Serializer<
     PrintGameCartridgeV1InstructionDataArgs,
     PrintGameCartridgeV1InstructionData
@@ -0,0 +1,286 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findMintCounterPda, findVoucherRecordPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  expectSome,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PrintGameCartridgeVoucherV1InstructionAccounts = {
+  /** The new game asset account */
+  cartridge: Signer;
+  /** The game Collection account */
+  game: PublicKey | Pda;
+  /** The token account receiving the payment for the game */
+  gameTokenAccount?: PublicKey | Pda;
+  /** The owner of the game */
+  owner: PublicKey | Pda;
+  /** The wallet the voucher was issued to */
+  payer?: Signer;
+  /** The account paying for the storage fees and the game cost */
+  payerTokenAccount?: PublicKey | Pda;
+  /** The record marking the voucher nonce as used */
+  voucherRecord?: PublicKey | Pda;
+  /** The payment mint */
+  paymentMint?: PublicKey | Pda;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The token program */
+  tokenProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The counter of cartridges printed to the owner, when the game limits prints per wallet */
+  mintCounter?: PublicKey | Pda;
+  /** The instructions sysvar */
+  sysvarInstructions?: PublicKey | Pda;
+  /** The token account of the wallet that referred the print */
+  referrerTokenAccount?: PublicKey | Pda;
+};
+
+// Data.
+export type PrintGameCartridgeVoucherV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+  price: bigint;
+  expiresAt: bigint;
+  voucherNonce: bigint;
+};
+
+export type PrintGameCartridgeVoucherV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+  price: number | bigint;
+  expiresAt: number | bigint;
+  voucherNonce: number | bigint;
+};
+
+export function getPrintGameCartridgeVoucherV1InstructionDataSerializer(): Serializer<
+  PrintGameCartridgeVoucherV1InstructionDataArgs,
+  PrintGameCartridgeVoucherV1InstructionData
+> {
+  return mapSerializer<
+    PrintGameCartridgeVoucherV1InstructionDataArgs,
+    any,
+    PrintGameCartridgeVoucherV1InstructionData
+  >(
+    struct<PrintGameCartridgeVoucherV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+        ['price', u64()],
+        ['expiresAt', i64()],
+        ['voucherNonce', u64()],
+      ],
+      { description: 'PrintGameCartridgeVoucherV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 12 })
+  ) as Serializer<
+    PrintGameCartridgeVoucherV1InstructionDataArgs,
+    PrintGameCartridgeVoucherV1InstructionData
+  >;
+}
+
+// Args.
+export type PrintGameCartridgeVoucherV1InstructionArgs =
+  PrintGameCartridgeVoucherV1InstructionDataArgs;
+
+// Instruction.
+export function printGameCartridgeVoucherV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: PrintGameCartridgeVoucherV1InstructionAccounts &
+    PrintGameCartridgeVoucherV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    cartridge: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.cartridge ?? null,
+    },
+    game: { index: 1, isWritable: true as boolean, value: input.game ?? null },
+    gameTokenAccount: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.gameTokenAccount ?? null,
+    },
+    owner: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+    payer: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    payerTokenAccount: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.payerTokenAccount ?? null,
+    },
+    voucherRecord: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.voucherRecord ?? null,
+    },
+    paymentMint: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.paymentMint ?? null,
+    },
+    mplCoreProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    tokenProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    mintCounter: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.mintCounter ?? null,
+    },
+    sysvarInstructions: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.sysvarInstructions ?? null,
+    },
+    referrerTokenAccount: {
+      index: 13,
+      isWritable: true as boolean,
+      value: input.referrerTokenAccount ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: PrintGameCartridgeVoucherV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.gameTokenAccount.value) {
+    resolvedAccounts.gameTokenAccount.value = findAssociatedTokenPda(context, {
+      mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+      owner: expectPublicKey(resolvedAccounts.game.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.payerTokenAccount.value) {
+    resolvedAccounts.payerTokenAccount.value = findAssociatedTokenPda(context, {
+      mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+      owner: expectPublicKey(resolvedAccounts.payer.value),
+    });
+  }
+  if (!resolvedAccounts.voucherRecord.value) {
+    resolvedAccounts.voucherRecord.value = findVoucherRecordPda(context, {
+      game: expectPublicKey(resolvedAccounts.game.value),
+      voucherNonce: expectSome(resolvedArgs.voucherNonce),
+    });
+  }
+  if (!resolvedAccounts.paymentMint.value) {
+    resolvedAccounts.paymentMint.value = publicKey(
+      'BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'
+    );
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.mintCounter.value) {
+    resolvedAccounts.mintCounter.value = findMintCounterPda(context, {
+      game: expectPublicKey(resolvedAccounts.game.value),
+      owner: expectPublicKey(resolvedAccounts.owner.value),
+    });
+  }
+  if (!resolvedAccounts.sysvarInstructions.value) {
+    resolvedAccounts.sysvarInstructions.value = publicKey(
+      'Sysvar1nstructions1111111111111111111111111'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getPrintGameCartridgeVoucherV1InstructionDataSerializer().serialize(
+      resolvedArgs as PrintGameCartridgeVoucherV1InstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,119 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ReassignNameV1InstructionAccounts = {
+  /** The registry record of the name */
+  nameRecord: PublicKey | Pda;
+  /** The publisher, or team, receiving the name */
+  newOwner: PublicKey | Pda;
+  /** The verifier authority */
+  authority?: Signer;
+};
+
+// Data.
+export type ReassignNameV1InstructionData = { discriminator: number };
+
+export type ReassignNameV1InstructionDataArgs = {};
+
+export function getReassignNameV1InstructionDataSerializer(): Serializer<
+  ReassignNameV1InstructionDataArgs,
+  ReassignNameV1InstructionData
+> {
+  return mapSerializer<
+    ReassignNameV1InstructionDataArgs,
+    any,
+    ReassignNameV1InstructionData
+  >(
+    struct<ReassignNameV1InstructionData>([['discriminator', u8()]], {
+      description: 'ReassignNameV1InstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 49 })
+  ) as Serializer<
+    ReassignNameV1InstructionDataArgs,
+    ReassignNameV1InstructionData
+  >;
+}
+
+// Instruction.
+export function reassignNameV1(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: ReassignNameV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    nameRecord: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.nameRecord ?? null,
+    },
+    newOwner: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.newOwner ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getReassignNameV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
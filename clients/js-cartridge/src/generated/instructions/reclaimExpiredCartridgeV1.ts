@@ -0,0 +1,201 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findRentalAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ReclaimExpiredCartridgeV1InstructionAccounts = {
+  /** The expired rental cartridge */
+  cartridge: PublicKey | Pda;
+  /** The game Collection account */
+  game: PublicKey | Pda;
+  /** The rental authority PDA of the game, collecting the rent of the burn */
+  rentalAuthority?: PublicKey | Pda;
+  /** The wallet that paid the rent of the cartridge */
+  rentPayer: PublicKey | Pda;
+  /** The account cranking the reclaim */
+  payer?: Signer;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The machine the cartridge is inserted in */
+  machine?: PublicKey | Pda;
+  /** The Core collection of the machine */
+  machineCollection?: PublicKey | Pda;
+};
+
+// Data.
+export type ReclaimExpiredCartridgeV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export type ReclaimExpiredCartridgeV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export function getReclaimExpiredCartridgeV1InstructionDataSerializer(): Serializer<
+  ReclaimExpiredCartridgeV1InstructionDataArgs,
+  ReclaimExpiredCartridgeV1InstructionData
+> {
+  return mapSerializer<
+    ReclaimExpiredCartridgeV1InstructionDataArgs,
+    any,
+    ReclaimExpiredCartridgeV1InstructionData
+  >(
+    struct<ReclaimExpiredCartridgeV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+      ],
+      { description: 'ReclaimExpiredCartridgeV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 26 })
+  ) as Serializer<
+    ReclaimExpiredCartridgeV1InstructionDataArgs,
+    ReclaimExpiredCartridgeV1InstructionData
+  >;
+}
+
+// Args.
+export type ReclaimExpiredCartridgeV1InstructionArgs =
+  ReclaimExpiredCartridgeV1InstructionDataArgs;
+
+// Instruction.
+export function reclaimExpiredCartridgeV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: ReclaimExpiredCartridgeV1InstructionAccounts &
+    ReclaimExpiredCartridgeV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    cartridge: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.cartridge ?? null,
+    },
+    game: { index: 1, isWritable: true as boolean, value: input.game ?? null },
+    rentalAuthority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.rentalAuthority ?? null,
+    },
+    rentPayer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.rentPayer ?? null,
+    },
+    payer: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    machine: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.machine ?? null,
+    },
+    machineCollection: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.machineCollection ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ReclaimExpiredCartridgeV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.rentalAuthority.value) {
+    resolvedAccounts.rentalAuthority.value = findRentalAuthorityPda(context, {
+      game: expectPublicKey(resolvedAccounts.game.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getReclaimExpiredCartridgeV1InstructionDataSerializer().serialize(
+      resolvedArgs as ReclaimExpiredCartridgeV1InstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,188 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findEscrowPda, findPreOrderPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RedeemPreOrderV1InstructionAccounts = {
+  /** The new game asset account */
+  cartridge: Signer;
+  /** The crowdfunded game Collection account */
+  game: PublicKey | Pda;
+  /** The escrow of the game */
+  escrow?: PublicKey | Pda;
+  /** The pre-orders of the backer */
+  preOrder?: PublicKey | Pda;
+  /** The wallet that pre-ordered the cartridge */
+  backer: Signer;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RedeemPreOrderV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export type RedeemPreOrderV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export function getRedeemPreOrderV1InstructionDataSerializer(): Serializer<
+  RedeemPreOrderV1InstructionDataArgs,
+  RedeemPreOrderV1InstructionData
+> {
+  return mapSerializer<
+    RedeemPreOrderV1InstructionDataArgs,
+    any,
+    RedeemPreOrderV1InstructionData
+  >(
+    struct<RedeemPreOrderV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+      ],
+      { description: 'RedeemPreOrderV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 34 })
+  ) as Serializer<
+    RedeemPreOrderV1InstructionDataArgs,
+    RedeemPreOrderV1InstructionData
+  >;
+}
+
+// Args.
+export type RedeemPreOrderV1InstructionArgs =
+  RedeemPreOrderV1InstructionDataArgs;
+
+// Instruction.
+export function redeemPreOrderV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: RedeemPreOrderV1InstructionAccounts & RedeemPreOrderV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    cartridge: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.cartridge ?? null,
+    },
+    game: { index: 1, isWritable: true as boolean, value: input.game ?? null },
+    escrow: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.escrow ?? null,
+    },
+    preOrder: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.preOrder ?? null,
+    },
+    backer: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.backer ?? null,
+    },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RedeemPreOrderV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.escrow.value) {
+    resolvedAccounts.escrow.value = findEscrowPda(context, {
+      game: expectPublicKey(resolvedAccounts.game.value),
+    });
+  }
+  if (!resolvedAccounts.preOrder.value) {
+    resolvedAccounts.preOrder.value = findPreOrderPda(context, {
+      game: expectPublicKey(resolvedAccounts.game.value),
+      backer: expectPublicKey(resolvedAccounts.backer.value),
+    });
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRedeemPreOrderV1InstructionDataSerializer().serialize(
+    resolvedArgs as RedeemPreOrderV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
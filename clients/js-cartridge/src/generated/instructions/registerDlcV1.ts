@@ -0,0 +1,176 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RegisterDlcV1InstructionAccounts = {
+  /** The DLC game Collection account */
+  game: PublicKey | Pda;
+  /** The base game Collection account */
+  baseGame: PublicKey | Pda;
+  /** The publisher of both games, or its team */
+  publisher: PublicKey | Pda | Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RegisterDlcV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+  baseCollectionNonce: number;
+  baseCollectionBump: number;
+};
+
+export type RegisterDlcV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+  baseCollectionNonce: number;
+  baseCollectionBump: number;
+};
+
+export function getRegisterDlcV1InstructionDataSerializer(): Serializer<
+  RegisterDlcV1InstructionDataArgs,
+  RegisterDlcV1InstructionData
+> {
+  return mapSerializer<
+    RegisterDlcV1InstructionDataArgs,
+    any,
+    RegisterDlcV1InstructionData
+  >(
+    struct<RegisterDlcV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+        ['baseCollectionNonce', u8()],
+        ['baseCollectionBump', u8()],
+      ],
+      { description: 'RegisterDlcV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 16 })
+  ) as Serializer<
+    RegisterDlcV1InstructionDataArgs,
+    RegisterDlcV1InstructionData
+  >;
+}
+
+// Args.
+export type RegisterDlcV1InstructionArgs = RegisterDlcV1InstructionDataArgs;
+
+// Instruction.
+export function registerDlcV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: RegisterDlcV1InstructionAccounts & RegisterDlcV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    game: { index: 0, isWritable: true as boolean, value: input.game ?? null },
+    baseGame: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.baseGame ?? null,
+    },
+    publisher: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.publisher ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    mplCoreProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RegisterDlcV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRegisterDlcV1InstructionDataSerializer().serialize(
+    resolvedArgs as RegisterDlcV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
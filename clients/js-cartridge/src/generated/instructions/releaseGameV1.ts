@@ -9,16 +9,21 @@
 import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
   TransactionBuilder,
+  none,
   publicKey,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  i64,
   mapSerializer,
+  option,
   string,
   struct,
   u64,
@@ -33,7 +38,14 @@ import {
   expectSome,
   getAccountMetasAndSigners,
 } from '../shared';
-import { PriceType, PriceTypeArgs, getPriceTypeSerializer } from '../types';
+import {
+  CrowdfundTerms,
+  CrowdfundTermsArgs,
+  PriceType,
+  PriceTypeArgs,
+  getCrowdfundTermsSerializer,
+  getPriceTypeSerializer,
+} from '../types';
 
 // Accounts.
 export type ReleaseGameV1InstructionAccounts = {
@@ -65,6 +77,9 @@ export type ReleaseGameV1InstructionData = {
   nonce: number;
   priceType: PriceType;
   price: bigint;
+  saleStart: Option<bigint>;
+  saleEnd: Option<bigint>;
+  crowdfund: Option<CrowdfundTerms>;
 };
 
 export type ReleaseGameV1InstructionDataArgs = {
@@ -73,6 +88,9 @@ export type ReleaseGameV1InstructionDataArgs = {
   nonce?: number;
   priceType: PriceTypeArgs;
   price: number | bigint;
+  saleStart?: OptionOrNullable<number | bigint>;
+  saleEnd?: OptionOrNullable<number | bigint>;
+  crowdfund?: OptionOrNullable<CrowdfundTermsArgs>;
 };
 
 export function getReleaseGameV1InstructionDataSerializer(): Serializer<
@@ -92,10 +110,20 @@ export function getReleaseGameV1InstructionDataSerializer(): Serializer<
         ['nonce', u8()],
         ['priceType', getPriceTypeSerializer()],
         ['price', u64()],
+        ['saleStart', option(i64())],
+        ['saleEnd', option(i64())],
+        ['crowdfund', option(getCrowdfundTermsSerializer())],
       ],
       { description: 'ReleaseGameV1InstructionData' }
     ),
-    (value) => ({ ...value, discriminator: 1, nonce: value.nonce ?? 0 })
+    (value) => ({
+      ...value,
+      discriminator: 1,
+      nonce: value.nonce ?? 0,
+      saleStart: value.saleStart ?? none(),
+      saleEnd: value.saleEnd ?? none(),
+      crowdfund: value.crowdfund ?? none(),
+    })
   ) as Serializer<
     ReleaseGameV1InstructionDataArgs,
     ReleaseGameV1InstructionData
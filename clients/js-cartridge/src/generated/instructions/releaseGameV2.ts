@@ -0,0 +1,317 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  mapSerializer,
+  option,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findCatalogPda, findGamePda, findNameRecordPda } from '../../hooked';
+import {
+  PickPartial,
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  expectSome,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  CrowdfundTerms,
+  CrowdfundTermsArgs,
+  PriceType,
+  PriceTypeArgs,
+  getCrowdfundTermsSerializer,
+  getPriceTypeSerializer,
+} from '../types';
+
+// Accounts.
+export type ReleaseGameV2InstructionAccounts = {
+  /** The new game Collection account */
+  game?: PublicKey | Pda;
+  /** The token account receiving the payment for the game */
+  gameTokenAccount?: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The authority signing for account creation */
+  authority?: Signer;
+  /** The Mint address for the payment token */
+  paymentMint?: PublicKey | Pda;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The token program */
+  tokenProgram?: PublicKey | Pda;
+  /** The associated token program */
+  associatedTokenProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The escrow of pre-order payments, when crowdfunding */
+  escrow?: PublicKey | Pda;
+  /** The token account of the escrow, when crowdfunding */
+  escrowTokenAccount?: PublicKey | Pda;
+  /** The publisher team releasing the game, approved by its members */
+  team?: PublicKey | Pda;
+  /** The registry record of the game name */
+  nameRecord?: PublicKey | Pda;
+  /** The catalog of the publisher */
+  catalog?: PublicKey | Pda;
+};
+
+// Data.
+export type ReleaseGameV2InstructionData = {
+  discriminator: number;
+  name: string;
+  uri: string;
+  nonce: number;
+  priceType: PriceType;
+  price: bigint;
+  saleStart: Option<bigint>;
+  saleEnd: Option<bigint>;
+  crowdfund: Option<CrowdfundTerms>;
+};
+
+export type ReleaseGameV2InstructionDataArgs = {
+  name: string;
+  uri: string;
+  nonce?: number;
+  priceType: PriceTypeArgs;
+  price: number | bigint;
+  saleStart?: OptionOrNullable<number | bigint>;
+  saleEnd?: OptionOrNullable<number | bigint>;
+  crowdfund?: OptionOrNullable<CrowdfundTermsArgs>;
+};
+
+export function getReleaseGameV2InstructionDataSerializer(): Serializer<
+  ReleaseGameV2InstructionDataArgs,
+  ReleaseGameV2InstructionData
+> {
+  return mapSerializer<
+    ReleaseGameV2InstructionDataArgs,
+    any,
+    ReleaseGameV2InstructionData
+  >(
+    struct<ReleaseGameV2InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['name', string()],
+        ['uri', string()],
+        ['nonce', u8()],
+        ['priceType', getPriceTypeSerializer()],
+        ['price', u64()],
+        ['saleStart', option(i64())],
+        ['saleEnd', option(i64())],
+        ['crowdfund', option(getCrowdfundTermsSerializer())],
+      ],
+      { description: 'ReleaseGameV2InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 47,
+      nonce: value.nonce ?? 0,
+      saleStart: value.saleStart ?? none(),
+      saleEnd: value.saleEnd ?? none(),
+      crowdfund: value.crowdfund ?? none(),
+    })
+  ) as Serializer<
+    ReleaseGameV2InstructionDataArgs,
+    ReleaseGameV2InstructionData
+  >;
+}
+
+// Args.
+export type ReleaseGameV2InstructionArgs = PickPartial<
+  ReleaseGameV2InstructionDataArgs,
+  'nonce'
+>;
+
+// Instruction.
+export function releaseGameV2(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: ReleaseGameV2InstructionAccounts & ReleaseGameV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    game: { index: 0, isWritable: true as boolean, value: input.game ?? null },
+    gameTokenAccount: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.gameTokenAccount ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    paymentMint: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.paymentMint ?? null,
+    },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    tokenProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    escrow: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.escrow ?? null,
+    },
+    escrowTokenAccount: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.escrowTokenAccount ?? null,
+    },
+    team: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.team ?? null,
+    },
+    nameRecord: {
+      index: 12,
+      isWritable: true as boolean,
+      value: input.nameRecord ?? null,
+    },
+    catalog: {
+      index: 13,
+      isWritable: true as boolean,
+      value: input.catalog ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ReleaseGameV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedArgs.nonce) {
+    resolvedArgs.nonce = 0;
+  }
+  if (!resolvedAccounts.game.value) {
+    resolvedAccounts.game.value = findGamePda(context, {
+      name: expectSome(resolvedArgs.name),
+      nonce: expectSome(resolvedArgs.nonce),
+    });
+  }
+  if (!resolvedAccounts.gameTokenAccount.value) {
+    resolvedAccounts.gameTokenAccount.value = findAssociatedTokenPda(context, {
+      mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+      owner: expectPublicKey(resolvedAccounts.game.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.paymentMint.value) {
+    resolvedAccounts.paymentMint.value = publicKey(
+      'BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'
+    );
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value = publicKey(
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+    );
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.nameRecord.value) {
+    resolvedAccounts.nameRecord.value = findNameRecordPda(context, {
+      name: expectSome(resolvedArgs.name),
+    });
+  }
+  if (!resolvedAccounts.catalog.value) {
+    resolvedAccounts.catalog.value = findCatalogPda(context, {
+      publisher: expectPublicKey(resolvedAccounts.payer.value),
+    });
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getReleaseGameV2InstructionDataSerializer().serialize(
+    resolvedArgs as ReleaseGameV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
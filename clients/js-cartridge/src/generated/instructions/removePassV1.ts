@@ -0,0 +1,154 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RemovePassV1InstructionAccounts = {
+  /** The pass asset account */
+  pass: PublicKey | Pda;
+  /** The pass collection account */
+  passCollection: PublicKey | Pda;
+  /** The pass owner, machine owner or machine operator */
+  authority?: Signer;
+  /** The machine asset account */
+  machine: PublicKey | Pda;
+  /** The Core collection of the machine */
+  machineCollection: PublicKey | Pda;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RemovePassV1InstructionData = { discriminator: number };
+
+export type RemovePassV1InstructionDataArgs = {};
+
+export function getRemovePassV1InstructionDataSerializer(): Serializer<
+  RemovePassV1InstructionDataArgs,
+  RemovePassV1InstructionData
+> {
+  return mapSerializer<
+    RemovePassV1InstructionDataArgs,
+    any,
+    RemovePassV1InstructionData
+  >(
+    struct<RemovePassV1InstructionData>([['discriminator', u8()]], {
+      description: 'RemovePassV1InstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 31 })
+  ) as Serializer<RemovePassV1InstructionDataArgs, RemovePassV1InstructionData>;
+}
+
+// Instruction.
+export function removePassV1(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: RemovePassV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    pass: { index: 0, isWritable: true as boolean, value: input.pass ?? null },
+    passCollection: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.passCollection ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    machine: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.machine ?? null,
+    },
+    machineCollection: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.machineCollection ?? null,
+    },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRemovePassV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
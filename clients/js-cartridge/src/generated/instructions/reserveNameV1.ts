@@ -0,0 +1,166 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findNameRecordPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectSome,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ReserveNameV1InstructionAccounts = {
+  /** The registry record of the name */
+  nameRecord?: PublicKey | Pda;
+  /** The publisher, or team, to hold the name */
+  owner: PublicKey | Pda;
+  /** The verifier authority */
+  authority?: Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ReserveNameV1InstructionData = {
+  discriminator: number;
+  name: string;
+};
+
+export type ReserveNameV1InstructionDataArgs = { name: string };
+
+export function getReserveNameV1InstructionDataSerializer(): Serializer<
+  ReserveNameV1InstructionDataArgs,
+  ReserveNameV1InstructionData
+> {
+  return mapSerializer<
+    ReserveNameV1InstructionDataArgs,
+    any,
+    ReserveNameV1InstructionData
+  >(
+    struct<ReserveNameV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['name', string()],
+      ],
+      { description: 'ReserveNameV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 40 })
+  ) as Serializer<
+    ReserveNameV1InstructionDataArgs,
+    ReserveNameV1InstructionData
+  >;
+}
+
+// Args.
+export type ReserveNameV1InstructionArgs = ReserveNameV1InstructionDataArgs;
+
+// Instruction.
+export function reserveNameV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: ReserveNameV1InstructionAccounts & ReserveNameV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    nameRecord: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.nameRecord ?? null,
+    },
+    owner: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ReserveNameV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.nameRecord.value) {
+    resolvedAccounts.nameRecord.value = findNameRecordPda(context, {
+      name: expectSome(resolvedArgs.name),
+    });
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getReserveNameV1InstructionDataSerializer().serialize(
+    resolvedArgs as ReserveNameV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,119 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findCatalogPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RetireCatalogEntryV1InstructionAccounts = {
+  /** The catalog of the publisher */
+  catalog?: PublicKey | Pda;
+  /** The publisher of the catalog, or its team */
+  publisher: PublicKey | Pda | Signer;
+  /** The game Collection account to retire */
+  game: PublicKey | Pda;
+};
+
+// Data.
+export type RetireCatalogEntryV1InstructionData = { discriminator: number };
+
+export type RetireCatalogEntryV1InstructionDataArgs = {};
+
+export function getRetireCatalogEntryV1InstructionDataSerializer(): Serializer<
+  RetireCatalogEntryV1InstructionDataArgs,
+  RetireCatalogEntryV1InstructionData
+> {
+  return mapSerializer<
+    RetireCatalogEntryV1InstructionDataArgs,
+    any,
+    RetireCatalogEntryV1InstructionData
+  >(
+    struct<RetireCatalogEntryV1InstructionData>([['discriminator', u8()]], {
+      description: 'RetireCatalogEntryV1InstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 42 })
+  ) as Serializer<
+    RetireCatalogEntryV1InstructionDataArgs,
+    RetireCatalogEntryV1InstructionData
+  >;
+}
+
+// Instruction.
+export function retireCatalogEntryV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: RetireCatalogEntryV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    catalog: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.catalog ?? null,
+    },
+    publisher: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.publisher ?? null,
+    },
+    game: { index: 2, isWritable: false as boolean, value: input.game ?? null },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.catalog.value) {
+    resolvedAccounts.catalog.value = findCatalogPda(context, {
+      publisher: expectPublicKey(resolvedAccounts.publisher.value),
+    });
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRetireCatalogEntryV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
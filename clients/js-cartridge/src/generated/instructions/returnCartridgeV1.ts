@@ -0,0 +1,195 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findLoanPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ReturnCartridgeV1InstructionAccounts = {
+  /** The game cartridge asset account */
+  cartridge: PublicKey | Pda;
+  /** The game Collection account */
+  game: PublicKey | Pda;
+  /** The loan of the cartridge */
+  loan?: PublicKey | Pda;
+  /** The lender the cartridge returns to */
+  lender: PublicKey | Pda;
+  /** The borrower, or anyone once the loan has expired */
+  authority?: Signer;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The machine the cartridge is inserted in */
+  machine?: PublicKey | Pda;
+  /** The Core collection of the machine */
+  machineCollection?: PublicKey | Pda;
+};
+
+// Data.
+export type ReturnCartridgeV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export type ReturnCartridgeV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export function getReturnCartridgeV1InstructionDataSerializer(): Serializer<
+  ReturnCartridgeV1InstructionDataArgs,
+  ReturnCartridgeV1InstructionData
+> {
+  return mapSerializer<
+    ReturnCartridgeV1InstructionDataArgs,
+    any,
+    ReturnCartridgeV1InstructionData
+  >(
+    struct<ReturnCartridgeV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+      ],
+      { description: 'ReturnCartridgeV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 24 })
+  ) as Serializer<
+    ReturnCartridgeV1InstructionDataArgs,
+    ReturnCartridgeV1InstructionData
+  >;
+}
+
+// Args.
+export type ReturnCartridgeV1InstructionArgs =
+  ReturnCartridgeV1InstructionDataArgs;
+
+// Instruction.
+export function returnCartridgeV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: ReturnCartridgeV1InstructionAccounts & ReturnCartridgeV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    cartridge: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.cartridge ?? null,
+    },
+    game: { index: 1, isWritable: true as boolean, value: input.game ?? null },
+    loan: { index: 2, isWritable: true as boolean, value: input.loan ?? null },
+    lender: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.lender ?? null,
+    },
+    authority: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    machine: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.machine ?? null,
+    },
+    machineCollection: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.machineCollection ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ReturnCartridgeV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.loan.value) {
+    resolvedAccounts.loan.value = findLoanPda(context, {
+      cartridge: expectPublicKey(resolvedAccounts.cartridge.value),
+    });
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getReturnCartridgeV1InstructionDataSerializer().serialize(
+    resolvedArgs as ReturnCartridgeV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,161 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetMachineOperatorV1InstructionAccounts = {
+  /** The machine asset account */
+  machine: PublicKey | Pda;
+  /** The Core machine collection */
+  machineCollection: PublicKey | Pda;
+  /** The owner of the machine */
+  machineOwner: Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The new operator delegate */
+  operator?: PublicKey | Pda;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetMachineOperatorV1InstructionData = { discriminator: number };
+
+export type SetMachineOperatorV1InstructionDataArgs = {};
+
+export function getSetMachineOperatorV1InstructionDataSerializer(): Serializer<
+  SetMachineOperatorV1InstructionDataArgs,
+  SetMachineOperatorV1InstructionData
+> {
+  return mapSerializer<
+    SetMachineOperatorV1InstructionDataArgs,
+    any,
+    SetMachineOperatorV1InstructionData
+  >(
+    struct<SetMachineOperatorV1InstructionData>([['discriminator', u8()]], {
+      description: 'SetMachineOperatorV1InstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 6 })
+  ) as Serializer<
+    SetMachineOperatorV1InstructionDataArgs,
+    SetMachineOperatorV1InstructionData
+  >;
+}
+
+// Instruction.
+export function setMachineOperatorV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: SetMachineOperatorV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    machine: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.machine ?? null,
+    },
+    machineCollection: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.machineCollection ?? null,
+    },
+    machineOwner: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.machineOwner ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    operator: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.operator ?? null,
+    },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetMachineOperatorV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
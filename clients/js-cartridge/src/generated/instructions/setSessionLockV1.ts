@@ -0,0 +1,183 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  i64,
+  mapSerializer,
+  option,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetSessionLockV1InstructionAccounts = {
+  /** The machine asset account */
+  machine: PublicKey | Pda;
+  /** The Core machine collection */
+  machineCollection: PublicKey | Pda;
+  /** The machine owner or operator delegate */
+  authority?: Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetSessionLockV1InstructionData = {
+  discriminator: number;
+  locked: boolean;
+  expiresAt: Option<bigint>;
+};
+
+export type SetSessionLockV1InstructionDataArgs = {
+  locked: boolean;
+  expiresAt: OptionOrNullable<number | bigint>;
+};
+
+export function getSetSessionLockV1InstructionDataSerializer(): Serializer<
+  SetSessionLockV1InstructionDataArgs,
+  SetSessionLockV1InstructionData
+> {
+  return mapSerializer<
+    SetSessionLockV1InstructionDataArgs,
+    any,
+    SetSessionLockV1InstructionData
+  >(
+    struct<SetSessionLockV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['locked', bool()],
+        ['expiresAt', option(i64())],
+      ],
+      { description: 'SetSessionLockV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 9 })
+  ) as Serializer<
+    SetSessionLockV1InstructionDataArgs,
+    SetSessionLockV1InstructionData
+  >;
+}
+
+// Args.
+export type SetSessionLockV1InstructionArgs =
+  SetSessionLockV1InstructionDataArgs;
+
+// Instruction.
+export function setSessionLockV1(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: SetSessionLockV1InstructionAccounts & SetSessionLockV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    machine: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.machine ?? null,
+    },
+    machineCollection: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.machineCollection ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    mplCoreProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetSessionLockV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetSessionLockV1InstructionDataSerializer().serialize(
+    resolvedArgs as SetSessionLockV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,191 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findVerificationPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  VerificationStatus,
+  VerificationStatusArgs,
+  getVerificationStatusSerializer,
+} from '../types';
+
+// Accounts.
+export type SetVerificationV1InstructionAccounts = {
+  /** The publisher key or game Collection account */
+  subject: PublicKey | Pda;
+  /** The verification flags of the subject */
+  verification?: PublicKey | Pda;
+  /** The verifier authority */
+  authority?: Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The mpl core program, when the subject is a game */
+  mplCoreProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetVerificationV1InstructionData = {
+  discriminator: number;
+  status: VerificationStatus;
+  collectionNonce: number;
+  collectionBump: number;
+};
+
+export type SetVerificationV1InstructionDataArgs = {
+  status: VerificationStatusArgs;
+  collectionNonce?: number;
+  collectionBump?: number;
+};
+
+export function getSetVerificationV1InstructionDataSerializer(): Serializer<
+  SetVerificationV1InstructionDataArgs,
+  SetVerificationV1InstructionData
+> {
+  return mapSerializer<
+    SetVerificationV1InstructionDataArgs,
+    any,
+    SetVerificationV1InstructionData
+  >(
+    struct<SetVerificationV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['status', getVerificationStatusSerializer()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+      ],
+      { description: 'SetVerificationV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 39,
+      collectionNonce: value.collectionNonce ?? 0,
+      collectionBump: value.collectionBump ?? 0,
+    })
+  ) as Serializer<
+    SetVerificationV1InstructionDataArgs,
+    SetVerificationV1InstructionData
+  >;
+}
+
+// Args.
+export type SetVerificationV1InstructionArgs =
+  SetVerificationV1InstructionDataArgs;
+
+// Instruction.
+export function setVerificationV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: SetVerificationV1InstructionAccounts & SetVerificationV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    subject: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.subject ?? null,
+    },
+    verification: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.verification ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetVerificationV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.verification.value) {
+    resolvedAccounts.verification.value = findVerificationPda(context, {
+      subject: expectPublicKey(resolvedAccounts.subject.value),
+    });
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetVerificationV1InstructionDataSerializer().serialize(
+    resolvedArgs as SetVerificationV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,114 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type TransferNameV1InstructionAccounts = {
+  /** The registry record of the name */
+  nameRecord: PublicKey | Pda;
+  /** The publisher holding the name, or its team */
+  owner: PublicKey | Pda | Signer;
+  /** The publisher, or team, receiving the name */
+  newOwner: PublicKey | Pda;
+};
+
+// Data.
+export type TransferNameV1InstructionData = { discriminator: number };
+
+export type TransferNameV1InstructionDataArgs = {};
+
+export function getTransferNameV1InstructionDataSerializer(): Serializer<
+  TransferNameV1InstructionDataArgs,
+  TransferNameV1InstructionData
+> {
+  return mapSerializer<
+    TransferNameV1InstructionDataArgs,
+    any,
+    TransferNameV1InstructionData
+  >(
+    struct<TransferNameV1InstructionData>([['discriminator', u8()]], {
+      description: 'TransferNameV1InstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 41 })
+  ) as Serializer<
+    TransferNameV1InstructionDataArgs,
+    TransferNameV1InstructionData
+  >;
+}
+
+// Instruction.
+export function transferNameV1(
+  context: Pick<Context, 'programs'>,
+  input: TransferNameV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    nameRecord: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.nameRecord ?? null,
+    },
+    owner: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+    newOwner: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.newOwner ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getTransferNameV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
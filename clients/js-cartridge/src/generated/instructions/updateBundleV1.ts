@@ -0,0 +1,133 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  struct,
+  u16,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateBundleV1InstructionAccounts = {
+  /** The bundle account */
+  bundle: PublicKey | Pda;
+  /** The publisher of the bundle, or its team */
+  publisher: PublicKey | Pda | Signer;
+};
+
+// Data.
+export type UpdateBundleV1InstructionData = {
+  discriminator: number;
+  price: Option<bigint>;
+  sharesBps: Option<Array<number>>;
+};
+
+export type UpdateBundleV1InstructionDataArgs = {
+  price: OptionOrNullable<number | bigint>;
+  sharesBps: OptionOrNullable<Array<number>>;
+};
+
+export function getUpdateBundleV1InstructionDataSerializer(): Serializer<
+  UpdateBundleV1InstructionDataArgs,
+  UpdateBundleV1InstructionData
+> {
+  return mapSerializer<
+    UpdateBundleV1InstructionDataArgs,
+    any,
+    UpdateBundleV1InstructionData
+  >(
+    struct<UpdateBundleV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['price', option(u64())],
+        ['sharesBps', option(array(u16()))],
+      ],
+      { description: 'UpdateBundleV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 44 })
+  ) as Serializer<
+    UpdateBundleV1InstructionDataArgs,
+    UpdateBundleV1InstructionData
+  >;
+}
+
+// Args.
+export type UpdateBundleV1InstructionArgs = UpdateBundleV1InstructionDataArgs;
+
+// Instruction.
+export function updateBundleV1(
+  context: Pick<Context, 'programs'>,
+  input: UpdateBundleV1InstructionAccounts & UpdateBundleV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    bundle: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.bundle ?? null,
+    },
+    publisher: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.publisher ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateBundleV1InstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateBundleV1InstructionDataSerializer().serialize(
+    resolvedArgs as UpdateBundleV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,245 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u16,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  Allowlist,
+  AllowlistArgs,
+  PricingModel,
+  PricingModelArgs,
+  RentalTerms,
+  RentalTermsArgs,
+  SaleWindow,
+  SaleWindowArgs,
+  getAllowlistSerializer,
+  getPricingModelSerializer,
+  getRentalTermsSerializer,
+  getSaleWindowSerializer,
+} from '../types';
+
+// Accounts.
+export type UpdateGameV1InstructionAccounts = {
+  /** The game Collection account */
+  game: PublicKey | Pda;
+  /** The publisher of the game, or its team */
+  publisher: PublicKey | Pda | Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The mpl core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The pass collection the game joins */
+  passCollection?: PublicKey | Pda;
+  /** The authority of the pass collection the game joins */
+  passAuthority?: Signer;
+};
+
+// Data.
+export type UpdateGameV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+  compatibleMachineCollections: Option<Array<PublicKey>>;
+  saleWindow: Option<SaleWindow>;
+  pricing: Option<PricingModel>;
+  allowlist: Option<Allowlist>;
+  maxPerWallet: Option<number>;
+  voucherSigner: Option<PublicKey>;
+  referralBps: Option<number>;
+  saveAuthority: Option<PublicKey>;
+  refundWindow: Option<bigint>;
+  rental: Option<RentalTerms>;
+  pass: Option<PublicKey>;
+};
+
+export type UpdateGameV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+  compatibleMachineCollections?: OptionOrNullable<Array<PublicKey>>;
+  saleWindow?: OptionOrNullable<SaleWindowArgs>;
+  pricing?: OptionOrNullable<PricingModelArgs>;
+  allowlist?: OptionOrNullable<AllowlistArgs>;
+  maxPerWallet?: OptionOrNullable<number>;
+  voucherSigner?: OptionOrNullable<PublicKey>;
+  referralBps?: OptionOrNullable<number>;
+  saveAuthority?: OptionOrNullable<PublicKey>;
+  refundWindow?: OptionOrNullable<number | bigint>;
+  rental?: OptionOrNullable<RentalTermsArgs>;
+  pass?: OptionOrNullable<PublicKey>;
+};
+
+export function getUpdateGameV1InstructionDataSerializer(): Serializer<
+  UpdateGameV1InstructionDataArgs,
+  UpdateGameV1InstructionData
+> {
+  return mapSerializer<
+    UpdateGameV1InstructionDataArgs,
+    any,
+    UpdateGameV1InstructionData
+  >(
+    struct<UpdateGameV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+        ['compatibleMachineCollections', option(array(publicKeySerializer()))],
+        ['saleWindow', option(getSaleWindowSerializer())],
+        ['pricing', option(getPricingModelSerializer())],
+        ['allowlist', option(getAllowlistSerializer())],
+        ['maxPerWallet', option(u32())],
+        ['voucherSigner', option(publicKeySerializer())],
+        ['referralBps', option(u16())],
+        ['saveAuthority', option(publicKeySerializer())],
+        ['refundWindow', option(i64())],
+        ['rental', option(getRentalTermsSerializer())],
+        ['pass', option(publicKeySerializer())],
+      ],
+      { description: 'UpdateGameV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 8,
+      compatibleMachineCollections:
+        value.compatibleMachineCollections ?? none(),
+      saleWindow: value.saleWindow ?? none(),
+      pricing: value.pricing ?? none(),
+      allowlist: value.allowlist ?? none(),
+      maxPerWallet: value.maxPerWallet ?? none(),
+      voucherSigner: value.voucherSigner ?? none(),
+      referralBps: value.referralBps ?? none(),
+      saveAuthority: value.saveAuthority ?? none(),
+      refundWindow: value.refundWindow ?? none(),
+      rental: value.rental ?? none(),
+      pass: value.pass ?? none(),
+    })
+  ) as Serializer<UpdateGameV1InstructionDataArgs, UpdateGameV1InstructionData>;
+}
+
+// Args.
+export type UpdateGameV1InstructionArgs = UpdateGameV1InstructionDataArgs;
+
+// Instruction.
+export function updateGameV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: UpdateGameV1InstructionAccounts & UpdateGameV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    game: { index: 0, isWritable: true as boolean, value: input.game ?? null },
+    publisher: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.publisher ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    mplCoreProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    passCollection: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.passCollection ?? null,
+    },
+    passAuthority: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.passAuthority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateGameV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateGameV1InstructionDataSerializer().serialize(
+    resolvedArgs as UpdateGameV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
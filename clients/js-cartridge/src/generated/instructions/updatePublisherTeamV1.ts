@@ -0,0 +1,123 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  TeamMemberArgs,
+  TeamMemberArgsArgs,
+  getTeamMemberArgsSerializer,
+} from '../types';
+
+// Accounts.
+export type UpdatePublisherTeamV1InstructionAccounts = {
+  /** The publisher team */
+  team: PublicKey | Pda;
+};
+
+// Data.
+export type UpdatePublisherTeamV1InstructionData = {
+  discriminator: number;
+  threshold: number;
+  members: Array<TeamMemberArgs>;
+};
+
+export type UpdatePublisherTeamV1InstructionDataArgs = {
+  threshold: number;
+  members: Array<TeamMemberArgsArgs>;
+};
+
+export function getUpdatePublisherTeamV1InstructionDataSerializer(): Serializer<
+  UpdatePublisherTeamV1InstructionDataArgs,
+  UpdatePublisherTeamV1InstructionData
+> {
+  return mapSerializer<
+    UpdatePublisherTeamV1InstructionDataArgs,
+    any,
+    UpdatePublisherTeamV1InstructionData
+  >(
+    struct<UpdatePublisherTeamV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['threshold', u8()],
+        ['members', array(getTeamMemberArgsSerializer())],
+      ],
+      { description: 'UpdatePublisherTeamV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 37 })
+  ) as Serializer<
+    UpdatePublisherTeamV1InstructionDataArgs,
+    UpdatePublisherTeamV1InstructionData
+  >;
+}
+
+// Args.
+export type UpdatePublisherTeamV1InstructionArgs =
+  UpdatePublisherTeamV1InstructionDataArgs;
+
+// Instruction.
+export function updatePublisherTeamV1(
+  context: Pick<Context, 'programs'>,
+  input: UpdatePublisherTeamV1InstructionAccounts &
+    UpdatePublisherTeamV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    team: { index: 0, isWritable: true as boolean, value: input.team ?? null },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdatePublisherTeamV1InstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdatePublisherTeamV1InstructionDataSerializer().serialize(
+    resolvedArgs as UpdatePublisherTeamV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,161 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type WithdrawGameRevenueV1InstructionAccounts = {
+  /** The game Collection account */
+  game: PublicKey | Pda;
+  /** The token account holding the game revenue */
+  gameTokenAccount?: PublicKey | Pda;
+  /** The publisher of the game, or its team */
+  publisher: PublicKey | Pda | Signer;
+  /** The token program */
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type WithdrawGameRevenueV1InstructionData = {
+  discriminator: number;
+  collectionNonce: number;
+  collectionBump: number;
+  amount: bigint;
+};
+
+export type WithdrawGameRevenueV1InstructionDataArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+  amount: number | bigint;
+};
+
+export function getWithdrawGameRevenueV1InstructionDataSerializer(): Serializer<
+  WithdrawGameRevenueV1InstructionDataArgs,
+  WithdrawGameRevenueV1InstructionData
+> {
+  return mapSerializer<
+    WithdrawGameRevenueV1InstructionDataArgs,
+    any,
+    WithdrawGameRevenueV1InstructionData
+  >(
+    struct<WithdrawGameRevenueV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['collectionNonce', u8()],
+        ['collectionBump', u8()],
+        ['amount', u64()],
+      ],
+      { description: 'WithdrawGameRevenueV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 38 })
+  ) as Serializer<
+    WithdrawGameRevenueV1InstructionDataArgs,
+    WithdrawGameRevenueV1InstructionData
+  >;
+}
+
+// Args.
+export type WithdrawGameRevenueV1InstructionArgs =
+  WithdrawGameRevenueV1InstructionDataArgs;
+
+// Instruction.
+export function withdrawGameRevenueV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: WithdrawGameRevenueV1InstructionAccounts &
+    WithdrawGameRevenueV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    game: { index: 0, isWritable: false as boolean, value: input.game ?? null },
+    gameTokenAccount: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.gameTokenAccount ?? null,
+    },
+    publisher: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.publisher ?? null,
+    },
+    tokenProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: WithdrawGameRevenueV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.gameTokenAccount.value) {
+    resolvedAccounts.gameTokenAccount.value = findAssociatedTokenPda(context, {
+      mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+      owner: expectPublicKey(resolvedAccounts.game.value),
+    });
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getWithdrawGameRevenueV1InstructionDataSerializer().serialize(
+    resolvedArgs as WithdrawGameRevenueV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
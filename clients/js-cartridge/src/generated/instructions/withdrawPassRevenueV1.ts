@@ -0,0 +1,178 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type WithdrawPassRevenueV1InstructionAccounts = {
+  /** The pass collection account */
+  passCollection: PublicKey | Pda;
+  /** The token account holding the pass revenue */
+  passTokenAccount?: PublicKey | Pda;
+  /** The authority of the pass collection */
+  authority?: Signer;
+  /** The token account of the authority receiving the revenue */
+  authorityTokenAccount?: PublicKey | Pda;
+  /** The token program */
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type WithdrawPassRevenueV1InstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
+
+export type WithdrawPassRevenueV1InstructionDataArgs = {
+  amount: number | bigint;
+};
+
+export function getWithdrawPassRevenueV1InstructionDataSerializer(): Serializer<
+  WithdrawPassRevenueV1InstructionDataArgs,
+  WithdrawPassRevenueV1InstructionData
+> {
+  return mapSerializer<
+    WithdrawPassRevenueV1InstructionDataArgs,
+    any,
+    WithdrawPassRevenueV1InstructionData
+  >(
+    struct<WithdrawPassRevenueV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['amount', u64()],
+      ],
+      { description: 'WithdrawPassRevenueV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 46 })
+  ) as Serializer<
+    WithdrawPassRevenueV1InstructionDataArgs,
+    WithdrawPassRevenueV1InstructionData
+  >;
+}
+
+// Args.
+export type WithdrawPassRevenueV1InstructionArgs =
+  WithdrawPassRevenueV1InstructionDataArgs;
+
+// Instruction.
+export function withdrawPassRevenueV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: WithdrawPassRevenueV1InstructionAccounts &
+    WithdrawPassRevenueV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    'CART9hmcuf38a58NCYhRJmtGXJjh16eXmLr9hmhAqPZo'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    passCollection: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.passCollection ?? null,
+    },
+    passTokenAccount: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.passTokenAccount ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    authorityTokenAccount: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.authorityTokenAccount ?? null,
+    },
+    tokenProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: WithdrawPassRevenueV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.passTokenAccount.value) {
+    resolvedAccounts.passTokenAccount.value = findAssociatedTokenPda(context, {
+      mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+      owner: expectPublicKey(resolvedAccounts.passCollection.value),
+    });
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.authorityTokenAccount.value) {
+    resolvedAccounts.authorityTokenAccount.value = findAssociatedTokenPda(
+      context,
+      {
+        mint: publicKey('BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump'),
+        owner: expectPublicKey(resolvedAccounts.authority.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getWithdrawPassRevenueV1InstructionDataSerializer().serialize(
+    resolvedArgs as WithdrawPassRevenueV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
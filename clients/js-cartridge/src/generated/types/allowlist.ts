@@ -0,0 +1,33 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  option,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Allowlist = { merkleRoot: Uint8Array; price: Option<bigint> };
+
+export type AllowlistArgs = {
+  merkleRoot: Uint8Array;
+  price: OptionOrNullable<number | bigint>;
+};
+
+export function getAllowlistSerializer(): Serializer<AllowlistArgs, Allowlist> {
+  return struct<Allowlist>(
+    [
+      ['merkleRoot', bytes({ size: 32 })],
+      ['price', option(u64())],
+    ],
+    { description: 'Allowlist' }
+  ) as Serializer<AllowlistArgs, Allowlist>;
+}
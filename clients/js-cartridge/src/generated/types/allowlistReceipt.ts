@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type AllowlistReceipt = {
+  game: PublicKey;
+  wallet: PublicKey;
+  printed: number;
+  padding: Array<number>;
+};
+
+export type AllowlistReceiptArgs = {
+  game: PublicKey;
+  wallet: PublicKey;
+  printed: number;
+  padding: Array<number>;
+};
+
+export function getAllowlistReceiptSerializer(): Serializer<
+  AllowlistReceiptArgs,
+  AllowlistReceipt
+> {
+  return struct<AllowlistReceipt>(
+    [
+      ['game', publicKeySerializer()],
+      ['wallet', publicKeySerializer()],
+      ['printed', u32()],
+      ['padding', array(u8(), { size: 4 })],
+    ],
+    { description: 'AllowlistReceipt' }
+  ) as Serializer<AllowlistReceiptArgs, AllowlistReceipt>;
+}
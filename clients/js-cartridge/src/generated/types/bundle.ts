@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { BundleMember, BundleMemberArgs, getBundleMemberSerializer } from '.';
+
+export type Bundle = {
+  publisher: PublicKey;
+  price: bigint;
+  games: Array<BundleMember>;
+  numGames: number;
+  padding: Array<number>;
+};
+
+export type BundleArgs = {
+  publisher: PublicKey;
+  price: number | bigint;
+  games: Array<BundleMemberArgs>;
+  numGames: number;
+  padding: Array<number>;
+};
+
+export function getBundleSerializer(): Serializer<BundleArgs, Bundle> {
+  return struct<Bundle>(
+    [
+      ['publisher', publicKeySerializer()],
+      ['price', u64()],
+      ['games', array(getBundleMemberSerializer(), { size: 8 })],
+      ['numGames', u8()],
+      ['padding', array(u8(), { size: 7 })],
+    ],
+    { description: 'Bundle' }
+  ) as Serializer<BundleArgs, Bundle>;
+}
@@ -0,0 +1,40 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  struct,
+  u16,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type BundleGameArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+  shareBps: number;
+};
+
+export type BundleGameArgsArgs = {
+  collectionNonce: number;
+  collectionBump: number;
+  shareBps: number;
+};
+
+export function getBundleGameArgsSerializer(): Serializer<
+  BundleGameArgsArgs,
+  BundleGameArgs
+> {
+  return struct<BundleGameArgs>(
+    [
+      ['collectionNonce', u8()],
+      ['collectionBump', u8()],
+      ['shareBps', u16()],
+    ],
+    { description: 'BundleGameArgs' }
+  ) as Serializer<BundleGameArgsArgs, BundleGameArgs>;
+}
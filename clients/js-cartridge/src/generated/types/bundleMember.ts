@@ -0,0 +1,49 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u16,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type BundleMember = {
+  game: PublicKey;
+  shareBps: number;
+  collectionNonce: number;
+  collectionBump: number;
+  padding: Array<number>;
+};
+
+export type BundleMemberArgs = {
+  game: PublicKey;
+  shareBps: number;
+  collectionNonce: number;
+  collectionBump: number;
+  padding: Array<number>;
+};
+
+export function getBundleMemberSerializer(): Serializer<
+  BundleMemberArgs,
+  BundleMember
+> {
+  return struct<BundleMember>(
+    [
+      ['game', publicKeySerializer()],
+      ['shareBps', u16()],
+      ['collectionNonce', u8()],
+      ['collectionBump', u8()],
+      ['padding', array(u8(), { size: 4 })],
+    ],
+    { description: 'BundleMember' }
+  ) as Serializer<BundleMemberArgs, BundleMember>;
+}
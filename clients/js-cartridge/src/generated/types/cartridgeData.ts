@@ -6,12 +6,45 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
-import { Serializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
 import { Source, SourceArgs, getSourceSerializer } from '.';
 
-export type CartridgeData = { version: number; source: Source };
+export type CartridgeData = {
+  version: number;
+  source: Source;
+  referrer: Option<PublicKey>;
+  baseCartridge: Option<PublicKey>;
+  purchasePrice: bigint;
+  printedAt: bigint;
+  lender: Option<PublicKey>;
+  expiresAt: Option<bigint>;
+  rentPayer: Option<PublicKey>;
+  refundableUntil: Option<bigint>;
+  rentPaid: bigint;
+};
 
-export type CartridgeDataArgs = { version: number; source: SourceArgs };
+export type CartridgeDataArgs = {
+  version: number;
+  source: SourceArgs;
+  referrer: OptionOrNullable<PublicKey>;
+  baseCartridge: OptionOrNullable<PublicKey>;
+  purchasePrice: number | bigint;
+  printedAt: number | bigint;
+  lender: OptionOrNullable<PublicKey>;
+  expiresAt: OptionOrNullable<number | bigint>;
+  rentPayer: OptionOrNullable<PublicKey>;
+  refundableUntil: OptionOrNullable<number | bigint>;
+  rentPaid: number | bigint;
+};
 
 export function getCartridgeDataSerializer(): Serializer<
   CartridgeDataArgs,
@@ -21,6 +54,15 @@ export function getCartridgeDataSerializer(): Serializer<
     [
       ['version', u8()],
       ['source', getSourceSerializer()],
+      ['referrer', option(publicKeySerializer())],
+      ['baseCartridge', option(publicKeySerializer())],
+      ['purchasePrice', u64()],
+      ['printedAt', i64()],
+      ['lender', option(publicKeySerializer())],
+      ['expiresAt', option(i64())],
+      ['rentPayer', option(publicKeySerializer())],
+      ['refundableUntil', option(i64())],
+      ['rentPaid', u64()],
     ],
     { description: 'CartridgeData' }
   ) as Serializer<CartridgeDataArgs, CartridgeData>;
@@ -0,0 +1,40 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Catalog = {
+  publisher: PublicKey;
+  numEntries: number;
+  padding: Array<number>;
+};
+
+export type CatalogArgs = {
+  publisher: PublicKey;
+  numEntries: number;
+  padding: Array<number>;
+};
+
+export function getCatalogSerializer(): Serializer<CatalogArgs, Catalog> {
+  return struct<Catalog>(
+    [
+      ['publisher', publicKeySerializer()],
+      ['numEntries', u32()],
+      ['padding', array(u8(), { size: 4 })],
+    ],
+    { description: 'Catalog' }
+  ) as Serializer<CatalogArgs, Catalog>;
+}
@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type CatalogEntry = {
+  game: PublicKey;
+  releasedAt: bigint;
+  retired: number;
+  padding: Array<number>;
+};
+
+export type CatalogEntryArgs = {
+  game: PublicKey;
+  releasedAt: number | bigint;
+  retired: number;
+  padding: Array<number>;
+};
+
+export function getCatalogEntrySerializer(): Serializer<
+  CatalogEntryArgs,
+  CatalogEntry
+> {
+  return struct<CatalogEntry>(
+    [
+      ['game', publicKeySerializer()],
+      ['releasedAt', i64()],
+      ['retired', u8()],
+      ['padding', array(u8(), { size: 7 })],
+    ],
+    { description: 'CatalogEntry' }
+  ) as Serializer<CatalogEntryArgs, CatalogEntry>;
+}
@@ -0,0 +1,34 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  i64,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type CrowdfundTerms = { goal: bigint; deadline: bigint };
+
+export type CrowdfundTermsArgs = {
+  goal: number | bigint;
+  deadline: number | bigint;
+};
+
+export function getCrowdfundTermsSerializer(): Serializer<
+  CrowdfundTermsArgs,
+  CrowdfundTerms
+> {
+  return struct<CrowdfundTerms>(
+    [
+      ['goal', u64()],
+      ['deadline', i64()],
+    ],
+    { description: 'CrowdfundTerms' }
+  ) as Serializer<CrowdfundTermsArgs, CrowdfundTerms>;
+}
@@ -0,0 +1,40 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type DlcReceipt = {
+  dlc: PublicKey;
+  baseCartridge: PublicKey;
+  cartridge: PublicKey;
+};
+
+export type DlcReceiptArgs = {
+  dlc: PublicKey;
+  baseCartridge: PublicKey;
+  cartridge: PublicKey;
+};
+
+export function getDlcReceiptSerializer(): Serializer<
+  DlcReceiptArgs,
+  DlcReceipt
+> {
+  return struct<DlcReceipt>(
+    [
+      ['dlc', publicKeySerializer()],
+      ['baseCartridge', publicKeySerializer()],
+      ['cartridge', publicKeySerializer()],
+    ],
+    { description: 'DlcReceipt' }
+  ) as Serializer<DlcReceiptArgs, DlcReceipt>;
+}
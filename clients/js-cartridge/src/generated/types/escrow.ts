@@ -0,0 +1,42 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Escrow = {
+  game: PublicKey;
+  goal: bigint;
+  deadline: bigint;
+  raised: bigint;
+};
+
+export type EscrowArgs = {
+  game: PublicKey;
+  goal: number | bigint;
+  deadline: number | bigint;
+  raised: number | bigint;
+};
+
+export function getEscrowSerializer(): Serializer<EscrowArgs, Escrow> {
+  return struct<Escrow>(
+    [
+      ['game', publicKeySerializer()],
+      ['goal', u64()],
+      ['deadline', i64()],
+      ['raised', u64()],
+    ],
+    { description: 'Escrow' }
+  ) as Serializer<EscrowArgs, Escrow>;
+}
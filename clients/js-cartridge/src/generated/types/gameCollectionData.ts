@@ -6,21 +6,55 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
-import { PublicKey } from '@metaplex-foundation/umi';
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  array,
+  bool,
+  i64,
+  option,
   publicKey as publicKeySerializer,
   struct,
+  u16,
+  u32,
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
-import { PriceType, PriceTypeArgs, getPriceTypeSerializer } from '.';
+import {
+  Allowlist,
+  AllowlistArgs,
+  PriceType,
+  PriceTypeArgs,
+  PricingModel,
+  PricingModelArgs,
+  RentalTerms,
+  RentalTermsArgs,
+  getAllowlistSerializer,
+  getPriceTypeSerializer,
+  getPricingModelSerializer,
+  getRentalTermsSerializer,
+} from '.';
 
 export type GameCollectionData = {
   version: number;
   priceType: PriceType;
   price: bigint;
   publisher: PublicKey;
+  compatibleMachineCollections: Array<PublicKey>;
+  saleStart: Option<bigint>;
+  saleEnd: Option<bigint>;
+  pricing: PricingModel;
+  allowlist: Allowlist;
+  maxPerWallet: Option<number>;
+  voucherSigner: PublicKey;
+  referralBps: number;
+  baseGame: PublicKey;
+  saveAuthority: PublicKey;
+  refundWindow: Option<bigint>;
+  rental: Option<RentalTerms>;
+  pass: PublicKey;
+  crowdfundDeadline: Option<bigint>;
+  delisted: boolean;
 };
 
 export type GameCollectionDataArgs = {
@@ -28,6 +62,21 @@ export type GameCollectionDataArgs = {
   priceType: PriceTypeArgs;
   price: number | bigint;
   publisher: PublicKey;
+  compatibleMachineCollections: Array<PublicKey>;
+  saleStart: OptionOrNullable<number | bigint>;
+  saleEnd: OptionOrNullable<number | bigint>;
+  pricing: PricingModelArgs;
+  allowlist: AllowlistArgs;
+  maxPerWallet: OptionOrNullable<number>;
+  voucherSigner: PublicKey;
+  referralBps: number;
+  baseGame: PublicKey;
+  saveAuthority: PublicKey;
+  refundWindow: OptionOrNullable<number | bigint>;
+  rental: OptionOrNullable<RentalTermsArgs>;
+  pass: PublicKey;
+  crowdfundDeadline: OptionOrNullable<number | bigint>;
+  delisted: boolean;
 };
 
 export function getGameCollectionDataSerializer(): Serializer<
@@ -40,6 +89,21 @@ export function getGameCollectionDataSerializer(): Serializer<
       ['priceType', getPriceTypeSerializer()],
       ['price', u64()],
       ['publisher', publicKeySerializer()],
+      ['compatibleMachineCollections', array(publicKeySerializer())],
+      ['saleStart', option(i64())],
+      ['saleEnd', option(i64())],
+      ['pricing', getPricingModelSerializer()],
+      ['allowlist', getAllowlistSerializer()],
+      ['maxPerWallet', option(u32())],
+      ['voucherSigner', publicKeySerializer()],
+      ['referralBps', u16()],
+      ['baseGame', publicKeySerializer()],
+      ['saveAuthority', publicKeySerializer()],
+      ['refundWindow', option(i64())],
+      ['rental', option(getRentalTermsSerializer())],
+      ['pass', publicKeySerializer()],
+      ['crowdfundDeadline', option(i64())],
+      ['delisted', bool()],
     ],
     { description: 'GameCollectionData' }
   ) as Serializer<GameCollectionDataArgs, GameCollectionData>;
@@ -6,7 +6,37 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './allowlist';
+export * from './allowlistReceipt';
+export * from './bundle';
+export * from './bundleGameArgs';
+export * from './bundleMember';
 export * from './cartridgeData';
+export * from './catalog';
+export * from './catalogEntry';
+export * from './crowdfundTerms';
+export * from './dlcReceipt';
+export * from './escrow';
 export * from './gameCollectionData';
+export * from './insertionRecord';
+export * from './listing';
+export * from './loan';
+export * from './machineData';
+export * from './mintCounter';
+export * from './nameRecord';
+export * from './passCollectionData';
+export * from './passData';
+export * from './preOrder';
 export * from './priceType';
+export * from './pricingModel';
+export * from './publisherTeam';
+export * from './rentalTerms';
+export * from './saleWindow';
+export * from './saveData';
+export * from './sessionLock';
 export * from './source';
+export * from './teamMember';
+export * from './teamMemberArgs';
+export * from './verification';
+export * from './verificationStatus';
+export * from './voucherRecord';
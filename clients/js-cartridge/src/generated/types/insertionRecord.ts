@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type InsertionRecord = {
+  cartridge: PublicKey;
+  game: PublicKey;
+  inserter: PublicKey;
+  insertedAt: bigint;
+  removedAt: bigint;
+};
+
+export type InsertionRecordArgs = {
+  cartridge: PublicKey;
+  game: PublicKey;
+  inserter: PublicKey;
+  insertedAt: number | bigint;
+  removedAt: number | bigint;
+};
+
+export function getInsertionRecordSerializer(): Serializer<
+  InsertionRecordArgs,
+  InsertionRecord
+> {
+  return struct<InsertionRecord>(
+    [
+      ['cartridge', publicKeySerializer()],
+      ['game', publicKeySerializer()],
+      ['inserter', publicKeySerializer()],
+      ['insertedAt', i64()],
+      ['removedAt', i64()],
+    ],
+    { description: 'InsertionRecord' }
+  ) as Serializer<InsertionRecordArgs, InsertionRecord>;
+}
@@ -0,0 +1,41 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Listing = {
+  cartridge: PublicKey;
+  game: PublicKey;
+  seller: PublicKey;
+  price: bigint;
+};
+
+export type ListingArgs = {
+  cartridge: PublicKey;
+  game: PublicKey;
+  seller: PublicKey;
+  price: number | bigint;
+};
+
+export function getListingSerializer(): Serializer<ListingArgs, Listing> {
+  return struct<Listing>(
+    [
+      ['cartridge', publicKeySerializer()],
+      ['game', publicKeySerializer()],
+      ['seller', publicKeySerializer()],
+      ['price', u64()],
+    ],
+    { description: 'Listing' }
+  ) as Serializer<ListingArgs, Listing>;
+}
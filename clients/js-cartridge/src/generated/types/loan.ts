@@ -0,0 +1,44 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Loan = {
+  cartridge: PublicKey;
+  game: PublicKey;
+  lender: PublicKey;
+  borrower: PublicKey;
+  expiresAt: bigint;
+};
+
+export type LoanArgs = {
+  cartridge: PublicKey;
+  game: PublicKey;
+  lender: PublicKey;
+  borrower: PublicKey;
+  expiresAt: number | bigint;
+};
+
+export function getLoanSerializer(): Serializer<LoanArgs, Loan> {
+  return struct<Loan>(
+    [
+      ['cartridge', publicKeySerializer()],
+      ['game', publicKeySerializer()],
+      ['lender', publicKeySerializer()],
+      ['borrower', publicKeySerializer()],
+      ['expiresAt', i64()],
+    ],
+    { description: 'Loan' }
+  ) as Serializer<LoanArgs, Loan>;
+}
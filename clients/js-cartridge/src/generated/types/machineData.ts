@@ -0,0 +1,59 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  InsertionRecord,
+  InsertionRecordArgs,
+  SessionLock,
+  SessionLockArgs,
+  getInsertionRecordSerializer,
+  getSessionLockSerializer,
+} from '.';
+
+export type MachineData = {
+  version: number;
+  operator: PublicKey;
+  current: InsertionRecord;
+  historyHead: number;
+  history: Array<InsertionRecord>;
+  sessionLock: SessionLock;
+};
+
+export type MachineDataArgs = {
+  version: number;
+  operator: PublicKey;
+  current: InsertionRecordArgs;
+  historyHead: number;
+  history: Array<InsertionRecordArgs>;
+  sessionLock: SessionLockArgs;
+};
+
+export function getMachineDataSerializer(): Serializer<
+  MachineDataArgs,
+  MachineData
+> {
+  return struct<MachineData>(
+    [
+      ['version', u8()],
+      ['operator', publicKeySerializer()],
+      ['current', getInsertionRecordSerializer()],
+      ['historyHead', u8()],
+      ['history', array(getInsertionRecordSerializer(), { size: 8 })],
+      ['sessionLock', getSessionLockSerializer()],
+    ],
+    { description: 'MachineData' }
+  ) as Serializer<MachineDataArgs, MachineData>;
+}
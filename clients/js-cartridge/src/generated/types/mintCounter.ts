@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type MintCounter = {
+  game: PublicKey;
+  owner: PublicKey;
+  count: number;
+  padding: Array<number>;
+};
+
+export type MintCounterArgs = {
+  game: PublicKey;
+  owner: PublicKey;
+  count: number;
+  padding: Array<number>;
+};
+
+export function getMintCounterSerializer(): Serializer<
+  MintCounterArgs,
+  MintCounter
+> {
+  return struct<MintCounter>(
+    [
+      ['game', publicKeySerializer()],
+      ['owner', publicKeySerializer()],
+      ['count', u32()],
+      ['padding', array(u8(), { size: 4 })],
+    ],
+    { description: 'MintCounter' }
+  ) as Serializer<MintCounterArgs, MintCounter>;
+}
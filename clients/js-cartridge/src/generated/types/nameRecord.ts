@@ -0,0 +1,27 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type NameRecord = { owner: PublicKey };
+
+export type NameRecordArgs = { owner: PublicKey };
+
+export function getNameRecordSerializer(): Serializer<
+  NameRecordArgs,
+  NameRecord
+> {
+  return struct<NameRecord>([['owner', publicKeySerializer()]], {
+    description: 'NameRecord',
+  }) as Serializer<NameRecordArgs, NameRecord>;
+}
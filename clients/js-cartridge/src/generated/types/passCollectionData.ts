@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type PassCollectionData = {
+  version: number;
+  authority: PublicKey;
+  price: bigint;
+  duration: bigint;
+};
+
+export type PassCollectionDataArgs = {
+  version: number;
+  authority: PublicKey;
+  price: number | bigint;
+  duration: number | bigint;
+};
+
+export function getPassCollectionDataSerializer(): Serializer<
+  PassCollectionDataArgs,
+  PassCollectionData
+> {
+  return struct<PassCollectionData>(
+    [
+      ['version', u8()],
+      ['authority', publicKeySerializer()],
+      ['price', u64()],
+      ['duration', i64()],
+    ],
+    { description: 'PassCollectionData' }
+  ) as Serializer<PassCollectionDataArgs, PassCollectionData>;
+}
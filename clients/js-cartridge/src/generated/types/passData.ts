@@ -0,0 +1,28 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  i64,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type PassData = { version: number; expiresAt: bigint };
+
+export type PassDataArgs = { version: number; expiresAt: number | bigint };
+
+export function getPassDataSerializer(): Serializer<PassDataArgs, PassData> {
+  return struct<PassData>(
+    [
+      ['version', u8()],
+      ['expiresAt', i64()],
+    ],
+    { description: 'PassData' }
+  ) as Serializer<PassDataArgs, PassData>;
+}
@@ -0,0 +1,45 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type PreOrder = {
+  game: PublicKey;
+  backer: PublicKey;
+  amount: bigint;
+  quantity: number;
+  redeemed: number;
+};
+
+export type PreOrderArgs = {
+  game: PublicKey;
+  backer: PublicKey;
+  amount: number | bigint;
+  quantity: number;
+  redeemed: number;
+};
+
+export function getPreOrderSerializer(): Serializer<PreOrderArgs, PreOrder> {
+  return struct<PreOrder>(
+    [
+      ['game', publicKeySerializer()],
+      ['backer', publicKeySerializer()],
+      ['amount', u64()],
+      ['quantity', u32()],
+      ['redeemed', u32()],
+    ],
+    { description: 'PreOrder' }
+  ) as Serializer<PreOrderArgs, PreOrder>;
+}
@@ -0,0 +1,107 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  i64,
+  struct,
+  u32,
+  u64,
+  unit,
+} from '@metaplex-foundation/umi/serializers';
+
+export type PricingModel =
+  | { __kind: 'Flat' }
+  | {
+      __kind: 'LinearDecay';
+      startPrice: bigint;
+      endPrice: bigint;
+      startTime: bigint;
+      duration: bigint;
+    }
+  | {
+      __kind: 'SupplyStepped';
+      basePrice: bigint;
+      stepSize: number;
+      stepIncrement: bigint;
+    };
+
+export type PricingModelArgs =
+  | { __kind: 'Flat' }
+  | {
+      __kind: 'LinearDecay';
+      startPrice: number | bigint;
+      endPrice: number | bigint;
+      startTime: number | bigint;
+      duration: number | bigint;
+    }
+  | {
+      __kind: 'SupplyStepped';
+      basePrice: number | bigint;
+      stepSize: number;
+      stepIncrement: number | bigint;
+    };
+
+export function getPricingModelSerializer(): Serializer<
+  PricingModelArgs,
+  PricingModel
+> {
+  return dataEnum<PricingModel>(
+    [
+      ['Flat', unit()],
+      [
+        'LinearDecay',
+        struct<GetDataEnumKindContent<PricingModel, 'LinearDecay'>>([
+          ['startPrice', u64()],
+          ['endPrice', u64()],
+          ['startTime', i64()],
+          ['duration', i64()],
+        ]),
+      ],
+      [
+        'SupplyStepped',
+        struct<GetDataEnumKindContent<PricingModel, 'SupplyStepped'>>([
+          ['basePrice', u64()],
+          ['stepSize', u32()],
+          ['stepIncrement', u64()],
+        ]),
+      ],
+    ],
+    { description: 'PricingModel' }
+  ) as Serializer<PricingModelArgs, PricingModel>;
+}
+
+// Data Enum Helpers.
+export function pricingModel(
+  kind: 'Flat'
+): GetDataEnumKind<PricingModelArgs, 'Flat'>;
+export function pricingModel(
+  kind: 'LinearDecay',
+  data: GetDataEnumKindContent<PricingModelArgs, 'LinearDecay'>
+): GetDataEnumKind<PricingModelArgs, 'LinearDecay'>;
+export function pricingModel(
+  kind: 'SupplyStepped',
+  data: GetDataEnumKindContent<PricingModelArgs, 'SupplyStepped'>
+): GetDataEnumKind<PricingModelArgs, 'SupplyStepped'>;
+export function pricingModel<K extends PricingModelArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<PricingModelArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isPricingModel<K extends PricingModel['__kind']>(
+  kind: K,
+  value: PricingModel
+): value is PricingModel & { __kind: K } {
+  return value.__kind === kind;
+}
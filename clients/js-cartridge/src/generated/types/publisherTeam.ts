@@ -0,0 +1,44 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { TeamMember, TeamMemberArgs, getTeamMemberSerializer } from '.';
+
+export type PublisherTeam = {
+  members: Array<TeamMember>;
+  numMembers: number;
+  threshold: number;
+  padding: Array<number>;
+};
+
+export type PublisherTeamArgs = {
+  members: Array<TeamMemberArgs>;
+  numMembers: number;
+  threshold: number;
+  padding: Array<number>;
+};
+
+export function getPublisherTeamSerializer(): Serializer<
+  PublisherTeamArgs,
+  PublisherTeam
+> {
+  return struct<PublisherTeam>(
+    [
+      ['members', array(getTeamMemberSerializer(), { size: 8 })],
+      ['numMembers', u8()],
+      ['threshold', u8()],
+      ['padding', array(u8(), { size: 6 })],
+    ],
+    { description: 'PublisherTeam' }
+  ) as Serializer<PublisherTeamArgs, PublisherTeam>;
+}
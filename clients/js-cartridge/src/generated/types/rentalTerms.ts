@@ -0,0 +1,34 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  i64,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type RentalTerms = { price: bigint; duration: bigint };
+
+export type RentalTermsArgs = {
+  price: number | bigint;
+  duration: number | bigint;
+};
+
+export function getRentalTermsSerializer(): Serializer<
+  RentalTermsArgs,
+  RentalTerms
+> {
+  return struct<RentalTerms>(
+    [
+      ['price', u64()],
+      ['duration', i64()],
+    ],
+    { description: 'RentalTerms' }
+  ) as Serializer<RentalTermsArgs, RentalTerms>;
+}
@@ -0,0 +1,35 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  option,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type SaleWindow = { saleStart: Option<bigint>; saleEnd: Option<bigint> };
+
+export type SaleWindowArgs = {
+  saleStart: OptionOrNullable<number | bigint>;
+  saleEnd: OptionOrNullable<number | bigint>;
+};
+
+export function getSaleWindowSerializer(): Serializer<
+  SaleWindowArgs,
+  SaleWindow
+> {
+  return struct<SaleWindow>(
+    [
+      ['saleStart', option(i64())],
+      ['saleEnd', option(i64())],
+    ],
+    { description: 'SaleWindow' }
+  ) as Serializer<SaleWindowArgs, SaleWindow>;
+}
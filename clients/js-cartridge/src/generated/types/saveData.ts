@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  bytes,
+  i64,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type SaveData = {
+  version: number;
+  revision: bigint;
+  updatedAt: bigint;
+  contentHash: Uint8Array;
+  data: Uint8Array;
+};
+
+export type SaveDataArgs = {
+  version: number;
+  revision: number | bigint;
+  updatedAt: number | bigint;
+  contentHash: Uint8Array;
+  data: Uint8Array;
+};
+
+export function getSaveDataSerializer(): Serializer<SaveDataArgs, SaveData> {
+  return struct<SaveData>(
+    [
+      ['version', u8()],
+      ['revision', u64()],
+      ['updatedAt', i64()],
+      ['contentHash', bytes({ size: 32 })],
+      ['data', bytes({ size: u32() })],
+    ],
+    { description: 'SaveData' }
+  ) as Serializer<SaveDataArgs, SaveData>;
+}
@@ -0,0 +1,31 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  bool,
+  i64,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type SessionLock = { locked: boolean; expiresAt: bigint };
+
+export type SessionLockArgs = { locked: boolean; expiresAt: number | bigint };
+
+export function getSessionLockSerializer(): Serializer<
+  SessionLockArgs,
+  SessionLock
+> {
+  return struct<SessionLock>(
+    [
+      ['locked', bool()],
+      ['expiresAt', i64()],
+    ],
+    { description: 'SessionLock' }
+  ) as Serializer<SessionLockArgs, SessionLock>;
+}
@@ -12,6 +12,7 @@ export enum Source {
   Unknown,
   Crypto,
   Stripe,
+  PublisherGrant,
 }
 
 export type SourceArgs = Source;
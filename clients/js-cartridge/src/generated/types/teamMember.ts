@@ -0,0 +1,43 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u16,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type TeamMember = {
+  address: PublicKey;
+  shareBps: number;
+  padding: Array<number>;
+};
+
+export type TeamMemberArgs = {
+  address: PublicKey;
+  shareBps: number;
+  padding: Array<number>;
+};
+
+export function getTeamMemberSerializer(): Serializer<
+  TeamMemberArgs,
+  TeamMember
+> {
+  return struct<TeamMember>(
+    [
+      ['address', publicKeySerializer()],
+      ['shareBps', u16()],
+      ['padding', array(u8(), { size: 6 })],
+    ],
+    { description: 'TeamMember' }
+  ) as Serializer<TeamMemberArgs, TeamMember>;
+}
@@ -0,0 +1,32 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u16,
+} from '@metaplex-foundation/umi/serializers';
+
+export type TeamMemberArgs = { address: PublicKey; shareBps: number };
+
+export type TeamMemberArgsArgs = { address: PublicKey; shareBps: number };
+
+export function getTeamMemberArgsSerializer(): Serializer<
+  TeamMemberArgsArgs,
+  TeamMemberArgs
+> {
+  return struct<TeamMemberArgs>(
+    [
+      ['address', publicKeySerializer()],
+      ['shareBps', u16()],
+    ],
+    { description: 'TeamMemberArgs' }
+  ) as Serializer<TeamMemberArgsArgs, TeamMemberArgs>;
+}
@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  VerificationStatus,
+  VerificationStatusArgs,
+  getVerificationStatusSerializer,
+} from '.';
+
+export type Verification = {
+  subject: PublicKey;
+  status: VerificationStatus;
+  padding: Array<number>;
+};
+
+export type VerificationArgs = {
+  subject: PublicKey;
+  status: VerificationStatusArgs;
+  padding: Array<number>;
+};
+
+export function getVerificationSerializer(): Serializer<
+  VerificationArgs,
+  Verification
+> {
+  return struct<Verification>(
+    [
+      ['subject', publicKeySerializer()],
+      ['status', getVerificationStatusSerializer()],
+      ['padding', array(u8(), { size: 7 })],
+    ],
+    { description: 'Verification' }
+  ) as Serializer<VerificationArgs, Verification>;
+}
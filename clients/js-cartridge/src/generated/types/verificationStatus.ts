@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum VerificationStatus {
+  Unverified,
+  Verified,
+  Delisted,
+}
+
+export type VerificationStatusArgs = VerificationStatus;
+
+export function getVerificationStatusSerializer(): Serializer<
+  VerificationStatusArgs,
+  VerificationStatus
+> {
+  return scalarEnum<VerificationStatus>(VerificationStatus, {
+    description: 'VerificationStatus',
+  }) as Serializer<VerificationStatusArgs, VerificationStatus>;
+}
@@ -0,0 +1,41 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type VoucherRecord = {
+  game: PublicKey;
+  wallet: PublicKey;
+  nonce: bigint;
+};
+
+export type VoucherRecordArgs = {
+  game: PublicKey;
+  wallet: PublicKey;
+  nonce: number | bigint;
+};
+
+export function getVoucherRecordSerializer(): Serializer<
+  VoucherRecordArgs,
+  VoucherRecord
+> {
+  return struct<VoucherRecord>(
+    [
+      ['game', publicKeySerializer()],
+      ['wallet', publicKeySerializer()],
+      ['nonce', u64()],
+    ],
+    { description: 'VoucherRecord' }
+  ) as Serializer<VoucherRecordArgs, VoucherRecord>;
+}
@@ -2,6 +2,7 @@ import { Context, Pda, PublicKey } from '@metaplex-foundation/umi';
 import {
   publicKey as publicKeySerializer,
   string,
+  u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import { BGL_CARTRIDGE_PROGRAM_ID } from '../generated';
@@ -46,3 +47,299 @@ export function findGamePda(
     u8().serialize(seeds.nonce),
   ]);
 }
+
+export function findAllowlistReceiptPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the game */
+    game: PublicKey;
+    /** The address of the allowlisted wallet */
+    wallet: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('allowlist'),
+    publicKeySerializer().serialize(seeds.game),
+    publicKeySerializer().serialize(seeds.wallet),
+  ]);
+}
+
+export function findMintCounterPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the game */
+    game: PublicKey;
+    /** The owner the cartridges are printed to */
+    owner: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('mint_count'),
+    publicKeySerializer().serialize(seeds.game),
+    publicKeySerializer().serialize(seeds.owner),
+  ]);
+}
+
+export function findVoucherRecordPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the game */
+    game: PublicKey;
+    /** The nonce of the voucher */
+    voucherNonce: number | bigint;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('voucher'),
+    publicKeySerializer().serialize(seeds.game),
+    u64().serialize(seeds.voucherNonce),
+  ]);
+}
+
+export function findBundlePda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The publisher of the bundle */
+    publisher: PublicKey;
+    /** The name of the bundle */
+    name: string;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('bundle'),
+    publicKeySerializer().serialize(seeds.publisher),
+    string({ size: 'variable' }).serialize(seeds.name),
+  ]);
+}
+
+export function findDlcReceiptPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the DLC game */
+    dlc: PublicKey;
+    /** The address of the base cartridge */
+    baseCartridge: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('dlc'),
+    publicKeySerializer().serialize(seeds.dlc),
+    publicKeySerializer().serialize(seeds.baseCartridge),
+  ]);
+}
+
+export function findListingPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The listed cartridge */
+    cartridge: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('listing'),
+    publicKeySerializer().serialize(seeds.cartridge),
+  ]);
+}
+
+export function findLoanPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The lent cartridge */
+    cartridge: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('loan'),
+    publicKeySerializer().serialize(seeds.cartridge),
+  ]);
+}
+
+export function findRentalAuthorityPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The game renting out cartridges */
+    game: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('rental'),
+    publicKeySerializer().serialize(seeds.game),
+  ]);
+}
+
+export function findPassPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The authority of the pass */
+    authority: PublicKey;
+    /** The name of the pass */
+    name: string;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('pass'),
+    publicKeySerializer().serialize(seeds.authority),
+    string({ size: 'variable' }).serialize(seeds.name),
+  ]);
+}
+
+export function findEscrowPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The crowdfunded game */
+    game: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('escrow'),
+    publicKeySerializer().serialize(seeds.game),
+  ]);
+}
+
+export function findPreOrderPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The crowdfunded game */
+    game: PublicKey;
+    /** The wallet pre-ordering cartridges */
+    backer: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('pre_order'),
+    publicKeySerializer().serialize(seeds.game),
+    publicKeySerializer().serialize(seeds.backer),
+  ]);
+}
+
+export function findPublisherTeamPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The wallet that created the team */
+    creator: PublicKey;
+    /** The name of the team */
+    name: string;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('team'),
+    publicKeySerializer().serialize(seeds.creator),
+    string({ size: 'variable' }).serialize(seeds.name),
+  ]);
+}
+
+export function findVerificationPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The publisher key or game being flagged */
+    subject: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('verification'),
+    publicKeySerializer().serialize(seeds.subject),
+  ]);
+}
+
+export function findNameRecordPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The game name, normalized to lowercase ASCII by the program */
+    name: string;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('name'),
+    string({ size: 'variable' }).serialize(
+      seeds.name.replace(/[A-Z]/g, (letter) => letter.toLowerCase())
+    ),
+  ]);
+}
+
+export function findCatalogPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The publisher of the catalog, a wallet or a team */
+    publisher: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'bglCartridge',
+    BGL_CARTRIDGE_PROGRAM_ID
+  );
+
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('catalog'),
+    publicKeySerializer().serialize(seeds.publisher),
+  ]);
+}
@@ -1,10 +1,28 @@
 /* eslint-disable import/no-extraneous-dependencies */
 import { createUmi as basecreateUmi } from '@metaplex-foundation/umi-bundle-tests';
+import {
+  generateSigner,
+  PublicKey,
+  Umi,
+} from '@metaplex-foundation/umi';
+import { createCollection, fetchAsset } from '@metaplex-foundation/mpl-core';
 import {
   createAssociatedToken,
   mplToolbox,
 } from '@metaplex-foundation/mpl-toolbox';
-import { bglCartridge, PAYMENT_TOKEN_MINT } from '../src';
+import {
+  bglCartridge,
+  commissionMachineV1,
+  findCatalogPda,
+  findGamePda,
+  findMachinePda,
+  findNameRecordPda,
+  getMachineDataSerializer,
+  PAYMENT_TOKEN_MINT,
+  PriceType,
+  printGameCartridgeV2,
+  releaseGameV2,
+} from '../src';
 
 export const createUmi = async () => {
   const umi = (await basecreateUmi()).use(mplToolbox()).use(bglCartridge());
@@ -16,3 +34,87 @@ export const createUmi = async () => {
   }).sendAndConfirm(umi);
   return umi;
 };
+
+// Creates a machine collection and commissions a machine in it.
+export const setupMachine = async (
+  umi: Umi,
+  owner: PublicKey = umi.identity.publicKey
+) => {
+  const machineCollection = generateSigner(umi);
+  await createCollection(umi, {
+    collection: machineCollection,
+    name: 'Machine Collection',
+    uri: 'https://machine-collection.com',
+  }).sendAndConfirm(umi);
+
+  await commissionMachineV1(umi, {
+    name: 'Test Machine',
+    uri: 'https://test-machine.com',
+    machineCollection: machineCollection.publicKey,
+    owner,
+  }).sendAndConfirm(umi);
+
+  const [machine] = findMachinePda(umi, {
+    machineCollection: machineCollection.publicKey,
+    name: 'Test Machine',
+  });
+  return { machine, machineCollection: machineCollection.publicKey };
+};
+
+// Releases a free game with a random name.
+export const setupGame = async (umi: Umi) => {
+  const name = Math.random().toString(36).substring(2, 15);
+  await releaseGameV2(umi, {
+    name,
+    uri: 'https://test-game.com',
+    priceType: PriceType.Transfer,
+    price: 0,
+  }).sendAndConfirm(umi);
+
+  const [game, gameBump] = findGamePda(umi, { name, nonce: 0 });
+  return { name, game, gameBump };
+};
+
+// The accounts trailing a V1 release: the name record of the game name and
+// the catalog of the publisher.
+export const releaseV1Accounts = (
+  umi: Umi,
+  name: string,
+  publisher: PublicKey = umi.identity.publicKey
+) => [
+  {
+    pubkey: findNameRecordPda(umi, { name })[0],
+    isSigner: false,
+    isWritable: false,
+  },
+  {
+    pubkey: findCatalogPda(umi, { publisher })[0],
+    isSigner: false,
+    isWritable: true,
+  },
+];
+
+// Prints a cartridge of the given game.
+export const printCartridge = async (
+  umi: Umi,
+  game: PublicKey,
+  gameBump: number,
+  owner: PublicKey = umi.identity.publicKey
+) => {
+  const cartridge = generateSigner(umi);
+  await printGameCartridgeV2(umi, {
+    game,
+    cartridge,
+    owner,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+  return cartridge.publicKey;
+};
+
+// Decodes the MachineData stored in the machine's AppData plugin.
+export const fetchMachineData = async (umi: Umi, machine: PublicKey) => {
+  const asset = await fetchAsset(umi, machine);
+  const [data] = getMachineDataSerializer().deserialize(asset.appDatas![0].data);
+  return data;
+};
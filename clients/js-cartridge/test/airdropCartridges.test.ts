@@ -0,0 +1,72 @@
+import { generateSigner, sol } from '@metaplex-foundation/umi';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import test from 'ava';
+import {
+  airdropCartridgesV1,
+  getCartridgeDataSerializer,
+  Source,
+} from '../src';
+import { createUmi, setupGame } from './_setup';
+
+test('the publisher can airdrop cartridges to several wallets', async (t) => {
+  // Given a game and two recipients.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  const recipients = [generateSigner(umi), generateSigner(umi)].map(
+    (signer) => signer.publicKey
+  );
+  const cartridges = [generateSigner(umi), generateSigner(umi)];
+
+  // When the publisher airdrops a cartridge to each of them.
+  await airdropCartridgesV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  })
+    .addRemainingAccounts(
+      recipients.flatMap((owner, i) => [
+        { pubkey: owner, isSigner: false, isWritable: false },
+        { signer: cartridges[i], isWritable: true },
+      ])
+    )
+    .sendAndConfirm(umi);
+
+  // Then each recipient owns a sequentially numbered publisher grant.
+  await Promise.all(
+    cartridges.map(async (cartridge, i) => {
+      const asset = await fetchAsset(umi, cartridge.publicKey);
+      t.is(asset.owner, recipients[i]);
+      t.is(asset.edition?.number, i + 1);
+      t.like(
+        getCartridgeDataSerializer().deserialize(asset.appDatas![0].data!)[0],
+        { source: Source.PublisherGrant }
+      );
+    })
+  );
+});
+
+test('only the publisher can airdrop cartridges', async (t) => {
+  // Given a game and a stranger.
+  const umi = await createUmi();
+  const stranger = generateSigner(umi);
+  await umi.rpc.airdrop(stranger.publicKey, sol(1));
+  const { game, gameBump } = await setupGame(umi);
+
+  // When the stranger tries to airdrop a cartridge to themselves.
+  const promise = airdropCartridgesV1(umi, {
+    game,
+    publisher: stranger,
+    payer: stranger,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  })
+    .addRemainingAccounts([
+      { pubkey: stranger.publicKey, isSigner: false, isWritable: false },
+      { signer: generateSigner(umi), isWritable: true },
+    ])
+    .sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'PublisherMustSign' });
+});
@@ -0,0 +1,75 @@
+import { generateSigner, sol } from '@metaplex-foundation/umi';
+import { createAssociatedToken } from '@metaplex-foundation/mpl-toolbox';
+import test from 'ava';
+import {
+  buyCartridgeV1,
+  findListingPda,
+  listCartridgeV1,
+  PAYMENT_TOKEN_MINT,
+} from '../src';
+import { createUmi, printCartridge, setupGame } from './_setup';
+
+const setupListing = async (umi: Awaited<ReturnType<typeof createUmi>>) => {
+  // A seller with a payment token account listing a cartridge.
+  const { game, gameBump } = await setupGame(umi);
+  const seller = generateSigner(umi);
+  await umi.rpc.airdrop(seller.publicKey, sol(1));
+  await createAssociatedToken(umi, {
+    mint: PAYMENT_TOKEN_MINT,
+    owner: seller.publicKey,
+  }).sendAndConfirm(umi);
+  const cartridge = await printCartridge(
+    umi,
+    game,
+    gameBump,
+    seller.publicKey
+  );
+  await listCartridgeV1(umi, {
+    cartridge,
+    game,
+    seller,
+    price: 100,
+  }).sendAndConfirm(umi);
+  const [listing] = findListingPda(umi, { cartridge });
+  return { game, gameBump, seller, cartridge, listing };
+};
+
+test('it cannot buy a cartridge at another price than listed', async (t) => {
+  // Given a cartridge listed at 100.
+  const umi = await createUmi();
+  const { game, gameBump, seller, cartridge } = await setupListing(umi);
+
+  // When we try to buy it at 50.
+  const promise = buyCartridgeV1(umi, {
+    cartridge,
+    game,
+    seller: seller.publicKey,
+    buyer: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    price: 50,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'ListingPriceChanged' });
+});
+
+test('it cannot buy a cartridge with another seller', async (t) => {
+  // Given a listed cartridge.
+  const umi = await createUmi();
+  const { game, gameBump, cartridge } = await setupListing(umi);
+
+  // When we try to buy it paying ourselves as the seller.
+  const promise = buyCartridgeV1(umi, {
+    cartridge,
+    game,
+    seller: umi.identity.publicKey,
+    buyer: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    price: 100,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidListing' });
+});
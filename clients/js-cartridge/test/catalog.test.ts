@@ -0,0 +1,173 @@
+/* eslint-disable import/no-extraneous-dependencies */
+import { generateSigner, Umi } from '@metaplex-foundation/umi';
+import { array } from '@metaplex-foundation/umi/serializers';
+import test from 'ava';
+import {
+  addCatalogEntryV1,
+  findCatalogPda,
+  findGamePda,
+  getCatalogEntrySerializer,
+  getCatalogSerializer,
+  PriceType,
+  releaseGameV1,
+  restoreCatalogEntryV1,
+  retireCatalogEntryV1,
+} from '../src';
+import { createUmi, releaseV1Accounts, setupGame } from './_setup';
+
+// Reads the header and entries of the catalog of the given publisher.
+const fetchCatalog = async (umi: Umi) => {
+  const [catalog] = findCatalogPda(umi, { publisher: umi.identity.publicKey });
+  const account = await umi.rpc.getAccount(catalog);
+  if (!account.exists) {
+    throw new Error('Catalog not found');
+  }
+
+  const [header, offset] = getCatalogSerializer().deserialize(account.data);
+  const [entries] = array(getCatalogEntrySerializer(), {
+    size: 'remainder',
+  }).deserialize(account.data, offset);
+  return { catalog, header, entries };
+};
+
+test('releasing games lists them in the publisher catalog', async (t) => {
+  // Given a publisher releasing two games.
+  const umi = await createUmi();
+  const { game: first } = await setupGame(umi);
+  const { game: second } = await setupGame(umi);
+
+  // Then both are listed in release order.
+  const { header, entries } = await fetchCatalog(umi);
+  t.is(header.publisher, umi.identity.publicKey);
+  t.is(header.numEntries, 2);
+  t.is(entries.length, 2);
+  t.like(entries[0], { game: first, retired: 0 });
+  t.like(entries[1], { game: second, retired: 0 });
+  t.true(entries[1].releasedAt >= entries[0].releasedAt);
+});
+
+test('the publisher can retire a game from its catalog', async (t) => {
+  // Given a publisher with two released games.
+  const umi = await createUmi();
+  const { game: first } = await setupGame(umi);
+  const { game: second } = await setupGame(umi);
+
+  // When they retire the first one.
+  await retireCatalogEntryV1(umi, {
+    publisher: umi.identity,
+    game: first,
+  }).sendAndConfirm(umi);
+
+  // Then it stays listed but is marked retired.
+  const { header, entries } = await fetchCatalog(umi);
+  t.is(header.numEntries, 2);
+  t.like(entries[0], { game: first, retired: 1 });
+  t.like(entries[1], { game: second, retired: 0 });
+});
+
+test('a game missing from the catalog cannot be retired', async (t) => {
+  // Given a publisher with a released game.
+  const umi = await createUmi();
+  await setupGame(umi);
+
+  // When they retire a game they did not release.
+  const promise = retireCatalogEntryV1(umi, {
+    publisher: umi.identity,
+    game: generateSigner(umi).publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CatalogEntryNotFound' });
+});
+
+test('only the publisher can retire its games', async (t) => {
+  // Given a publisher with a released game.
+  const umi = await createUmi();
+  const { game } = await setupGame(umi);
+  const [catalog] = findCatalogPda(umi, { publisher: umi.identity.publicKey });
+
+  // When someone else retires it without the publisher signing.
+  const otherUmi = await createUmi();
+  const promise = retireCatalogEntryV1(otherUmi, {
+    catalog,
+    publisher: umi.identity.publicKey,
+    game,
+  }).sendAndConfirm(otherUmi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'PublisherMustSign' });
+});
+
+test('the publisher can restore a retired game', async (t) => {
+  // Given a publisher with a retired game.
+  const umi = await createUmi();
+  const { game } = await setupGame(umi);
+  await retireCatalogEntryV1(umi, {
+    publisher: umi.identity,
+    game,
+  }).sendAndConfirm(umi);
+
+  // When they restore it.
+  await restoreCatalogEntryV1(umi, {
+    publisher: umi.identity,
+    game,
+  }).sendAndConfirm(umi);
+
+  // Then it is no longer marked retired.
+  const { entries } = await fetchCatalog(umi);
+  t.like(entries[0], { game, retired: 0 });
+});
+
+test('releasing a game through V1 lists it in the publisher catalog', async (t) => {
+  // Given a game released through V1.
+  const umi = await createUmi();
+  const name = Math.random().toString(36).substring(2, 15);
+  await releaseGameV1(umi, {
+    name,
+    uri: 'https://test-game.com',
+    priceType: PriceType.Transfer,
+    price: 0,
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, name))
+    .sendAndConfirm(umi);
+  const [game, collectionBump] = findGamePda(umi, { name, nonce: 0 });
+
+  // Then it is listed.
+  const { header, entries } = await fetchCatalog(umi);
+  t.is(header.numEntries, 1);
+  t.like(entries[0], { game, retired: 0 });
+
+  // And it cannot be added twice.
+  const promise = addCatalogEntryV1(umi, {
+    publisher: umi.identity,
+    game,
+    collectionBump,
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(promise, { name: 'CatalogEntryExists' });
+});
+
+test('only the publisher of a game can add it to its catalog', async (t) => {
+  // Given a game released by a publisher.
+  const umi = await createUmi();
+  const name = Math.random().toString(36).substring(2, 15);
+  await releaseGameV1(umi, {
+    name,
+    uri: 'https://test-game.com',
+    priceType: PriceType.Transfer,
+    price: 0,
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, name))
+    .sendAndConfirm(umi);
+  const [game, collectionBump] = findGamePda(umi, { name, nonce: 0 });
+
+  // When another publisher adds it to their catalog.
+  const otherUmi = await createUmi();
+  const promise = addCatalogEntryV1(otherUmi, {
+    publisher: otherUmi.identity,
+    game,
+    collectionBump,
+  }).sendAndConfirm(otherUmi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CatalogPublisherMismatch' });
+});
@@ -0,0 +1,158 @@
+import {
+  defaultPublicKey,
+  generateSigner,
+  none,
+  publicKey,
+  sol,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  ejectCartridgeV1,
+  insertCartridgeV1,
+  setMachineOperatorV1,
+  setSessionLockV1,
+} from '../src';
+import {
+  createUmi,
+  fetchMachineData,
+  printCartridge,
+  setupGame,
+  setupMachine,
+} from './_setup';
+
+test('the machine operator can force-eject a cartridge', async (t) => {
+  // Given a machine with an operator and a player's cartridge inserted.
+  const umi = await createUmi();
+  const player = generateSigner(umi);
+  const operator = generateSigner(umi);
+  await umi.rpc.airdrop(player.publicKey, sol(1));
+  await umi.rpc.airdrop(operator.publicKey, sol(1));
+  const { machine, machineCollection } = await setupMachine(umi);
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump, player.publicKey);
+
+  await setMachineOperatorV1(umi, {
+    machine,
+    machineCollection,
+    machineOwner: umi.identity,
+    operator: operator.publicKey,
+  }).sendAndConfirm(umi);
+
+  await insertCartridgeV1(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: player,
+    machine,
+    machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // When the operator ejects the player's cartridge.
+  await ejectCartridgeV1(umi, {
+    cartridge,
+    game,
+    machine,
+    machineCollection,
+    authority: operator,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then the machine slot is empty and the operator is kept.
+  const machineData = await fetchMachineData(umi, publicKey(machine));
+  t.like(machineData, {
+    operator: operator.publicKey,
+    current: { cartridge: defaultPublicKey() },
+  });
+  t.like(machineData.history[0], { cartridge, inserter: player.publicKey });
+});
+
+test('a stranger cannot eject a cartridge', async (t) => {
+  // Given a machine with a cartridge inserted.
+  const umi = await createUmi();
+  const stranger = generateSigner(umi);
+  await umi.rpc.airdrop(stranger.publicKey, sol(1));
+  const { machine, machineCollection } = await setupMachine(umi);
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+
+  await insertCartridgeV1(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: umi.identity,
+    machine,
+    machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // When a stranger tries to eject it.
+  const promise = ejectCartridgeV1(umi, {
+    cartridge,
+    game,
+    machine,
+    machineCollection,
+    authority: stranger,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it should fail.
+  await t.throwsAsync(promise, { name: 'InvalidMachineOperator' });
+});
+
+test('the operator cannot eject a cartridge during a locked session', async (t) => {
+  // Given a machine with an operator, a player's cartridge inserted and a
+  // locked session.
+  const umi = await createUmi();
+  const player = generateSigner(umi);
+  const operator = generateSigner(umi);
+  await umi.rpc.airdrop(player.publicKey, sol(1));
+  await umi.rpc.airdrop(operator.publicKey, sol(1));
+  const { machine, machineCollection } = await setupMachine(umi);
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump, player.publicKey);
+
+  await setMachineOperatorV1(umi, {
+    machine,
+    machineCollection,
+    machineOwner: umi.identity,
+    operator: operator.publicKey,
+  }).sendAndConfirm(umi);
+
+  await insertCartridgeV1(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: player,
+    machine,
+    machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  await setSessionLockV1(umi, {
+    machine,
+    machineCollection,
+    authority: umi.identity,
+    locked: true,
+    expiresAt: none(),
+  }).sendAndConfirm(umi);
+
+  // When the operator tries to eject the cartridge.
+  const promise = ejectCartridgeV1(umi, {
+    cartridge,
+    game,
+    machine,
+    machineCollection,
+    authority: operator,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it should fail.
+  await t.throwsAsync(promise, { name: 'SessionLocked' });
+});
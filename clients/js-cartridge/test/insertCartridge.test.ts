@@ -1,4 +1,8 @@
-import { generateSigner, publicKey } from '@metaplex-foundation/umi';
+import {
+  generateSigner,
+  publicKey,
+  sol,
+} from '@metaplex-foundation/umi';
 import test from 'ava';
 import {
   AppDataPlugin,
@@ -14,11 +18,20 @@ import {
   findGamePda,
   findMachinePda,
   insertCartridgeV1,
+  insertCartridgeV2,
   PriceType,
   printGameCartridgeV1,
   releaseGameV1,
+  setMachineOperatorV1,
 } from '../src';
-import { createUmi } from './_setup';
+import {
+  createUmi,
+  fetchMachineData,
+  printCartridge,
+  releaseV1Accounts,
+  setupGame,
+  setupMachine,
+} from './_setup';
 
 test('it can insert a cartridge into a machine', async (t) => {
   // Given a Umi instance and new signers.
@@ -58,7 +71,9 @@ test('it can insert a cartridge into a machine', async (t) => {
     uri: 'https://test-game.com',
     priceType: PriceType.Transfer,
     price: 0,
-  }).sendAndConfirm(umi);
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, gameName))
+    .sendAndConfirm(umi);
 
   const [, collectionBump] = findGamePda(umi, {
     name: gameName,
@@ -95,7 +110,7 @@ test('it can insert a cartridge into a machine', async (t) => {
     collectionBump,
   }).sendAndConfirm(umi);
 
-  // Then the cartridge's linkedAppData should point at itself
+  // Then the cartridge's linkedAppData should point at the machine
   const cartridgeData = await fetchAsset(umi, cartridge.publicKey);
   t.like(cartridgeData, <AssetV1>{
     key: MplCoreKey.AssetV1,
@@ -108,7 +123,7 @@ test('it can insert a cartridge into a machine', async (t) => {
     {
       authority: { type: 'UpdateAuthority' },
       dataAuthority: { type: 'UpdateAuthority' },
-      data: base58.serialize(cartridge.publicKey),
+      data: base58.serialize(publicKey(machine)),
     },
   ]);
 
@@ -128,9 +143,21 @@ test('it can insert a cartridge into a machine', async (t) => {
     {
       authority: { type: 'UpdateAuthority' },
       dataAuthority: { type: 'Address', address: publicKey(machine) },
-      data: base58.serialize(cartridge.publicKey),
     },
   ]);
+
+  // And the insertion should be recorded in the machine's current slot
+  const machineState = await fetchMachineData(umi, publicKey(machine));
+  t.like(machineState, {
+    version: 0,
+    current: {
+      cartridge: cartridge.publicKey,
+      game: publicKey(game),
+      inserter: umi.identity.publicKey,
+      removedAt: 0n,
+    },
+  });
+  t.true(machineState.current.insertedAt > 0n);
 });
 
 test('it fails when cartridge owner does not sign', async (t) => {
@@ -166,7 +193,9 @@ test('it fails when cartridge owner does not sign', async (t) => {
     uri: 'https://test-game.com',
     priceType: PriceType.Transfer,
     price: 0,
-  }).sendAndConfirm(umi);
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, gameName))
+    .sendAndConfirm(umi);
 
   const [game, collectionBump] = findGamePda(umi, {
     name: gameName,
@@ -236,7 +265,9 @@ test('it fails when a cartridge is already inserted', async (t) => {
     uri: 'https://test-game.com',
     priceType: PriceType.Transfer,
     price: 0,
-  }).sendAndConfirm(umi);
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, gameName))
+    .sendAndConfirm(umi);
 
   const [, collectionBump] = findGamePda(umi, {
     name: gameName,
@@ -273,14 +304,9 @@ test('it fails when a cartridge is already inserted', async (t) => {
   }).sendAndConfirm(umi);
 
   // Verify first cartridge is inserted
-  const machineData = await fetchAsset(umi, publicKey(machine));
-  t.like(machineData.appDatas, <AppDataPlugin[]>[
-    {
-      authority: { type: 'UpdateAuthority' },
-      dataAuthority: { type: 'Address', address: publicKey(machine) },
-      data: base58.serialize(firstCartridge.publicKey),
-    },
-  ]);
+  t.like(await fetchMachineData(umi, publicKey(machine)), {
+    current: { cartridge: firstCartridge.publicKey },
+  });
 
   // When we try to insert a second cartridge into the same machine
   const promise = insertCartridgeV1(umi, {
@@ -297,3 +323,108 @@ test('it fails when a cartridge is already inserted', async (t) => {
   // Then it should fail
   await t.throwsAsync(promise, { name: 'CartridgeAlreadyInserted' });
 });
+
+test('the machine operator can insert a cartridge owned by the machine owner', async (t) => {
+  // Given a machine with an operator and a cartridge owned by the machine owner.
+  const umi = await createUmi();
+  const operator = generateSigner(umi);
+  await umi.rpc.airdrop(operator.publicKey, sol(1));
+  const { machine, machineCollection } = await setupMachine(umi);
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+
+  await setMachineOperatorV1(umi, {
+    machine,
+    machineCollection,
+    machineOwner: umi.identity,
+    operator: operator.publicKey,
+  }).sendAndConfirm(umi);
+
+  // When the operator inserts the cartridge without the owner signing.
+  await insertCartridgeV2(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: umi.identity.publicKey,
+    machine,
+    machineCollection,
+    machineOwner: umi.identity.publicKey,
+    operator,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then the machine holds the cartridge, inserted by the operator.
+  t.like(await fetchMachineData(umi, publicKey(machine)), {
+    operator: operator.publicKey,
+    current: { cartridge, inserter: operator.publicKey },
+  });
+});
+
+test('the machine operator cannot insert a cartridge owned by someone else', async (t) => {
+  // Given a machine with an operator and a cartridge owned by a player.
+  const umi = await createUmi();
+  const operator = generateSigner(umi);
+  const player = generateSigner(umi);
+  await umi.rpc.airdrop(operator.publicKey, sol(1));
+  const { machine, machineCollection } = await setupMachine(umi);
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump, player.publicKey);
+
+  await setMachineOperatorV1(umi, {
+    machine,
+    machineCollection,
+    machineOwner: umi.identity,
+    operator: operator.publicKey,
+  }).sendAndConfirm(umi);
+
+  // When the operator tries to insert the player's cartridge.
+  const promise = insertCartridgeV2(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: player.publicKey,
+    machine,
+    machineCollection,
+    machineOwner: umi.identity.publicKey,
+    operator,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it should fail.
+  await t.throwsAsync(promise, { name: 'CartridgeNotInMachineOwnerCustody' });
+});
+
+test('it fails when the cartridge is already inserted into another machine', async (t) => {
+  // Given a cartridge inserted into a first machine.
+  const umi = await createUmi();
+  const first = await setupMachine(umi);
+  const second = await setupMachine(umi);
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+
+  await insertCartridgeV1(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: umi.identity,
+    machine: first.machine,
+    machineCollection: first.machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // When we try to insert it into a second machine.
+  const promise = insertCartridgeV1(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: umi.identity,
+    machine: second.machine,
+    machineCollection: second.machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it should fail.
+  await t.throwsAsync(promise, { name: 'CartridgeAlreadyLinked' });
+});
@@ -0,0 +1,153 @@
+import { generateSigner, publicKey, sol } from '@metaplex-foundation/umi';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import test from 'ava';
+import {
+  findLoanPda,
+  getCartridgeDataSerializer,
+  insertCartridgeV1,
+  lendCartridgeV1,
+  listCartridgeV1,
+  returnCartridgeV1,
+} from '../src';
+import {
+  createUmi,
+  fetchMachineData,
+  printCartridge,
+  setupGame,
+  setupMachine,
+} from './_setup';
+
+const setupLoan = async (umi: Awaited<ReturnType<typeof createUmi>>) => {
+  // A cartridge lent to a borrower for a day.
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+  const borrower = generateSigner(umi);
+  await umi.rpc.airdrop(borrower.publicKey, sol(1));
+  await lendCartridgeV1(umi, {
+    cartridge,
+    game,
+    lender: umi.identity,
+    borrower: borrower.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    duration: 86400,
+  }).sendAndConfirm(umi);
+  const [loan] = findLoanPda(umi, { cartridge });
+  return { game, gameBump, cartridge, borrower, loan };
+};
+
+test('it can lend a cartridge', async (t) => {
+  // Given a cartridge lent to a borrower.
+  const umi = await createUmi();
+  const { cartridge, borrower, loan } = await setupLoan(umi);
+
+  // Then the borrower holds the frozen cartridge.
+  const asset = await fetchAsset(umi, cartridge);
+  t.is(asset.owner, borrower.publicKey);
+  t.true(asset.permanentFreezeDelegate?.frozen);
+
+  // And the cartridge is marked as lent.
+  const [data] = getCartridgeDataSerializer().deserialize(
+    asset.appDatas![0].data!
+  );
+  t.like(data, { version: 1, lender: { value: umi.identity.publicKey } });
+  t.true(await umi.rpc.accountExists(loan));
+});
+
+test('the borrower can return a cartridge early', async (t) => {
+  // Given a lent cartridge.
+  const umi = await createUmi();
+  const { game, gameBump, cartridge, borrower, loan } = await setupLoan(umi);
+
+  // When the borrower returns it.
+  await returnCartridgeV1(umi, {
+    cartridge,
+    game,
+    lender: umi.identity.publicKey,
+    authority: borrower,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then the lender holds it again and the loan is closed.
+  const asset = await fetchAsset(umi, cartridge);
+  t.is(asset.owner, umi.identity.publicKey);
+  t.true(asset.permanentFreezeDelegate?.frozen);
+  t.false(await umi.rpc.accountExists(loan));
+});
+
+test('returning a cartridge ejects it from the machine', async (t) => {
+  // Given a lent cartridge inserted into the borrower's machine.
+  const umi = await createUmi();
+  const { game, gameBump, cartridge, borrower } = await setupLoan(umi);
+  const { machine, machineCollection } = await setupMachine(
+    umi,
+    borrower.publicKey
+  );
+  await insertCartridgeV1(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: borrower,
+    machine,
+    machineCollection,
+    machineOwner: borrower.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // When the borrower returns it.
+  await returnCartridgeV1(umi, {
+    cartridge,
+    game,
+    lender: umi.identity.publicKey,
+    authority: borrower,
+    machine,
+    machineCollection,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then the machine slot is empty and the lender holds the cartridge.
+  const machineData = await fetchMachineData(umi, publicKey(machine));
+  t.like(machineData.history[0], { cartridge });
+  const asset = await fetchAsset(umi, cartridge);
+  t.is(asset.owner, umi.identity.publicKey);
+});
+
+test('a stranger cannot return a cartridge before the loan expires', async (t) => {
+  // Given a lent cartridge.
+  const umi = await createUmi();
+  const { game, gameBump, cartridge } = await setupLoan(umi);
+  const stranger = generateSigner(umi);
+  await umi.rpc.airdrop(stranger.publicKey, sol(1));
+
+  // When a stranger tries to return it.
+  const promise = returnCartridgeV1(umi, {
+    cartridge,
+    game,
+    lender: umi.identity.publicKey,
+    authority: stranger,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'LoanNotExpired' });
+});
+
+test('the borrower cannot list a lent cartridge', async (t) => {
+  // Given a lent cartridge.
+  const umi = await createUmi();
+  const { game, cartridge, borrower } = await setupLoan(umi);
+
+  // When the borrower tries to list it.
+  const promise = listCartridgeV1(umi, {
+    cartridge,
+    game,
+    seller: borrower,
+    price: 100,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CartridgeOnLoan' });
+});
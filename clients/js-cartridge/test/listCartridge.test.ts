@@ -0,0 +1,128 @@
+import { generateSigner, sol } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  cancelListingV1,
+  findListingPda,
+  getListingSerializer,
+  insertCartridgeV1,
+  listCartridgeV1,
+} from '../src';
+import { createUmi, printCartridge, setupGame, setupMachine } from './_setup';
+
+test('it can list a cartridge for sale', async (t) => {
+  // Given a cartridge.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+
+  // When the owner lists it.
+  await listCartridgeV1(umi, {
+    cartridge,
+    game,
+    seller: umi.identity,
+    price: 100,
+  }).sendAndConfirm(umi);
+
+  // Then the listing records the ask.
+  const [listing] = findListingPda(umi, { cartridge });
+  const account = await umi.rpc.getAccount(listing);
+  t.true(account.exists);
+  if (account.exists) {
+    t.like(getListingSerializer().deserialize(account.data)[0], {
+      cartridge,
+      game,
+      seller: umi.identity.publicKey,
+      price: 100n,
+    });
+  }
+});
+
+test('it can cancel a listing', async (t) => {
+  // Given a listed cartridge.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+  await listCartridgeV1(umi, {
+    cartridge,
+    game,
+    seller: umi.identity,
+    price: 100,
+  }).sendAndConfirm(umi);
+  const [listing] = findListingPda(umi, { cartridge });
+
+  // When the seller cancels the listing.
+  await cancelListingV1(umi, {
+    listing,
+    seller: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // Then the listing is closed.
+  t.false(await umi.rpc.accountExists(listing));
+});
+
+test('it cannot list a cartridge of another owner', async (t) => {
+  // Given a cartridge held by someone else.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  const owner = generateSigner(umi);
+  await umi.rpc.airdrop(owner.publicKey, sol(1));
+  const cartridge = await printCartridge(umi, game, gameBump, owner.publicKey);
+
+  // When we try to list it.
+  const promise = listCartridgeV1(umi, {
+    cartridge,
+    game,
+    seller: umi.identity,
+    price: 100,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CartridgeOwnerMustSign' });
+});
+
+test('it cannot list a cartridge for free', async (t) => {
+  // Given a cartridge.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+
+  // When the owner tries to list it at no cost.
+  const promise = listCartridgeV1(umi, {
+    cartridge,
+    game,
+    seller: umi.identity,
+    price: 0,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidListingPrice' });
+});
+
+test('it cannot list a cartridge inserted into a machine', async (t) => {
+  // Given a cartridge inserted into a machine.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  const { machine, machineCollection } = await setupMachine(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+  await insertCartridgeV1(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: umi.identity,
+    machine,
+    machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // When the owner tries to list it.
+  const promise = listCartridgeV1(umi, {
+    cartridge,
+    game,
+    seller: umi.identity,
+    price: 100,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CartridgeAlreadyInserted' });
+});
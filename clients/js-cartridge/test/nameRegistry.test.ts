@@ -0,0 +1,209 @@
+/* eslint-disable import/no-extraneous-dependencies */
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { createSignerFromKeypair, Signer, Umi } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  claimNameV1,
+  findGamePda,
+  findNameRecordPda,
+  getNameRecordSerializer,
+  PriceType,
+  reassignNameV1,
+  releaseGameV1,
+  releaseGameV2,
+  reserveNameV1,
+  transferNameV1,
+} from '../src';
+import { createUmi, releaseV1Accounts } from './_setup';
+
+const loadVerifierAuthority = (umi: Umi): Signer => {
+  // On localnet the verifier authority is the same fixture keypair as the
+  // source authority.
+  const path = join(
+    __dirname,
+    '..',
+    '..',
+    'test',
+    'fixtures',
+    'source-authority.json'
+  );
+  const secret = new Uint8Array(JSON.parse(readFileSync(path, 'utf-8')));
+  const keypair = umi.eddsa.createKeypairFromSecretKey(secret);
+  return createSignerFromKeypair(umi, keypair);
+};
+
+const release = (umi: Umi, name: string, nonce: number) =>
+  releaseGameV2(umi, {
+    name,
+    uri: 'https://test-game.com',
+    nonce,
+    priceType: PriceType.Transfer,
+    price: 0,
+  }).sendAndConfirm(umi);
+
+test('releasing a game claims its name for the publisher', async (t) => {
+  // Given a game released under a new name.
+  const umi = await createUmi();
+  const name = Math.random().toString(36).substring(2, 15);
+  await release(umi, name, 0);
+
+  // Then the publisher holds the name.
+  const [nameRecord] = findNameRecordPda(umi, { name });
+  const account = await umi.rpc.getAccount(nameRecord);
+  t.true(account.exists);
+  if (account.exists) {
+    t.like(getNameRecordSerializer().deserialize(account.data)[0], {
+      owner: umi.identity.publicKey,
+    });
+  }
+
+  // And they can release again under the same name with another nonce.
+  await release(umi, name, 1);
+});
+
+test('a name held by another publisher cannot be released', async (t) => {
+  // Given a name held by a publisher.
+  const umi = await createUmi();
+  const name = Math.random().toString(36).substring(2, 15);
+  await release(umi, name, 0);
+
+  // When another publisher releases it with another nonce and case.
+  const otherUmi = await createUmi();
+  const promise = release(otherUmi, name.toUpperCase(), 1);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'NameTaken' });
+});
+
+test('a name held by another publisher cannot be released through V1', async (t) => {
+  // Given a name held by a publisher.
+  const umi = await createUmi();
+  const name = Math.random().toString(36).substring(2, 15);
+  await release(umi, name, 0);
+
+  // When another publisher releases it through V1 with another nonce.
+  const otherUmi = await createUmi();
+  const promise = releaseGameV1(otherUmi, {
+    name,
+    uri: 'https://test-game.com',
+    nonce: 1,
+    priceType: PriceType.Transfer,
+    price: 0,
+  })
+    .addRemainingAccounts(releaseV1Accounts(otherUmi, name))
+    .sendAndConfirm(otherUmi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'NameTaken' });
+});
+
+test('a name can be transferred to another publisher', async (t) => {
+  // Given a name held by a publisher.
+  const umi = await createUmi();
+  const otherUmi = await createUmi();
+  const name = Math.random().toString(36).substring(2, 15);
+  await release(umi, name, 0);
+
+  // When they transfer it to another publisher.
+  const [nameRecord] = findNameRecordPda(umi, { name });
+  await transferNameV1(umi, {
+    nameRecord,
+    owner: umi.identity,
+    newOwner: otherUmi.identity.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the new holder can release under the name.
+  await release(otherUmi, name, 1);
+
+  // But the previous holder no longer can.
+  const promise = release(umi, name, 2);
+  await t.throwsAsync(promise, { name: 'NameTaken' });
+});
+
+test('the protocol can reserve a name for a publisher', async (t) => {
+  // Given a name reserved for a publisher.
+  const umi = await createUmi();
+  const publisherUmi = await createUmi();
+  const name = Math.random().toString(36).substring(2, 15);
+  await reserveNameV1(umi, {
+    name,
+    owner: publisherUmi.identity.publicKey,
+    authority: loadVerifierAuthority(umi),
+  }).sendAndConfirm(umi);
+
+  // When someone else releases under the name.
+  const promise = release(umi, name, 0);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'NameTaken' });
+
+  // But the publisher can release it.
+  await release(publisherUmi, name, 0);
+});
+
+test('a held name cannot be reserved but can be reassigned', async (t) => {
+  // Given a name held by a publisher.
+  const umi = await createUmi();
+  const publisherUmi = await createUmi();
+  const name = Math.random().toString(36).substring(2, 15);
+  await release(umi, name, 0);
+
+  // When the protocol reserves it for another publisher.
+  const promise = reserveNameV1(umi, {
+    name,
+    owner: publisherUmi.identity.publicKey,
+    authority: loadVerifierAuthority(umi),
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'NameTaken' });
+
+  // But the protocol can reassign it explicitly.
+  const [nameRecord] = findNameRecordPda(umi, { name });
+  await reassignNameV1(umi, {
+    nameRecord,
+    newOwner: publisherUmi.identity.publicKey,
+    authority: loadVerifierAuthority(umi),
+  }).sendAndConfirm(umi);
+  await release(publisherUmi, name, 1);
+});
+
+test('a game released without the registry can claim its name', async (t) => {
+  // Given a game released through V1, which leaves its name unregistered.
+  const umi = await createUmi();
+  const name = Math.random().toString(36).substring(2, 15);
+  await releaseGameV1(umi, {
+    name,
+    uri: 'https://test-game.com',
+    priceType: PriceType.Transfer,
+    price: 0,
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, name))
+    .sendAndConfirm(umi);
+  const [game, gameBump] = findGamePda(umi, { name, nonce: 0 });
+  const [nameRecord] = findNameRecordPda(umi, { name });
+  t.false(await umi.rpc.accountExists(nameRecord));
+
+  // When its publisher claims the name.
+  await claimNameV1(umi, {
+    nameRecord,
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then they hold it.
+  const account = await umi.rpc.getAccount(nameRecord);
+  t.true(account.exists);
+  if (account.exists) {
+    t.like(getNameRecordSerializer().deserialize(account.data)[0], {
+      owner: umi.identity.publicKey,
+    });
+  }
+
+  // And other publishers can no longer release under it.
+  const otherUmi = await createUmi();
+  await t.throwsAsync(release(otherUmi, name, 1), { name: 'NameTaken' });
+});
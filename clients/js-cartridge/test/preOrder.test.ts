@@ -0,0 +1,180 @@
+import { generateSigner, some, Umi } from '@metaplex-foundation/umi';
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import test from 'ava';
+import {
+  claimEscrowV1,
+  findEscrowPda,
+  findGamePda,
+  findPreOrderPda,
+  getEscrowSerializer,
+  getPreOrderSerializer,
+  PAYMENT_TOKEN_MINT,
+  PriceType,
+  preOrderV1,
+  redeemPreOrderV1,
+  refundPreOrderV1,
+  releaseGameV2,
+} from '../src';
+import { createUmi, printCartridge } from './_setup';
+
+const sleep = (ms: number) =>
+  new Promise((resolve) => {
+    setTimeout(resolve, ms);
+  });
+
+// Releases a free crowdfunded game whose pre-orders close after the given
+// number of seconds.
+const setupCrowdfundedGame = async (umi: Umi, duration: number = 86400) => {
+  const name = Math.random().toString(36).substring(2, 15);
+  const [game, gameBump] = findGamePda(umi, { name, nonce: 0 });
+  const [escrow] = findEscrowPda(umi, { game });
+  const deadline = BigInt(Math.floor(Date.now() / 1000) + duration);
+  await releaseGameV2(umi, {
+    name,
+    uri: 'https://test-game.com',
+    priceType: PriceType.Transfer,
+    price: 0,
+    crowdfund: some({ goal: 1000, deadline }),
+    escrow,
+    escrowTokenAccount: findAssociatedTokenPda(umi, {
+      mint: PAYMENT_TOKEN_MINT,
+      owner: escrow,
+    }),
+  }).sendAndConfirm(umi);
+  return { game, gameBump, escrow, deadline };
+};
+
+test('it can release a crowdfunded game and pre-order it', async (t) => {
+  // Given a crowdfunded game.
+  const umi = await createUmi();
+  const { game, escrow, deadline } = await setupCrowdfundedGame(umi);
+
+  // When a backer pre-orders two cartridges.
+  await preOrderV1(umi, {
+    game,
+    backer: umi.identity,
+    quantity: 2,
+  }).sendAndConfirm(umi);
+
+  // Then the escrow records the goal and deadline.
+  const escrowAccount = await umi.rpc.getAccount(escrow);
+  t.true(escrowAccount.exists);
+  if (escrowAccount.exists) {
+    t.like(getEscrowSerializer().deserialize(escrowAccount.data)[0], {
+      game,
+      goal: 1000n,
+      deadline,
+    });
+  }
+
+  // And the position of the backer is tracked.
+  const [preOrder] = findPreOrderPda(umi, {
+    game,
+    backer: umi.identity.publicKey,
+  });
+  const preOrderAccount = await umi.rpc.getAccount(preOrder);
+  t.true(preOrderAccount.exists);
+  if (preOrderAccount.exists) {
+    t.like(getPreOrderSerializer().deserialize(preOrderAccount.data)[0], {
+      game,
+      backer: umi.identity.publicKey,
+      quantity: 2,
+      redeemed: 0,
+    });
+  }
+});
+
+test('cartridges cannot be printed while pre-orders are open', async (t) => {
+  // Given a crowdfunded game.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupCrowdfundedGame(umi);
+
+  // When a cartridge is printed before the deadline.
+  const promise = printCartridge(umi, game, gameBump);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CrowdfundActive' });
+});
+
+test('pre-orders cannot be claimed before the goal is met', async (t) => {
+  // Given a crowdfunded game with a pre-order.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupCrowdfundedGame(umi);
+  await preOrderV1(umi, {
+    game,
+    backer: umi.identity,
+    quantity: 1,
+  }).sendAndConfirm(umi);
+
+  // When the publisher claims the escrow.
+  const claim = claimEscrowV1(umi, {
+    game,
+    publisher: umi.identity,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(claim, { name: 'CrowdfundNotFunded' });
+
+  // And the backer cannot redeem the pre-order either.
+  const redeem = redeemPreOrderV1(umi, {
+    cartridge: generateSigner(umi),
+    game,
+    backer: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(redeem, { name: 'CrowdfundNotFunded' });
+});
+
+test('pre-orders cannot be refunded before the deadline', async (t) => {
+  // Given a crowdfunded game with a pre-order.
+  const umi = await createUmi();
+  const { game } = await setupCrowdfundedGame(umi);
+  await preOrderV1(umi, {
+    game,
+    backer: umi.identity,
+    quantity: 1,
+  }).sendAndConfirm(umi);
+
+  // When anyone tries to refund it.
+  const promise = refundPreOrderV1(umi, {
+    game,
+    backer: umi.identity.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CrowdfundNotFailed' });
+});
+
+test('pre-orders of a game that missed its goal can be refunded', async (t) => {
+  // Given a crowdfunded game whose deadline passed below its goal.
+  const umi = await createUmi();
+  const { game } = await setupCrowdfundedGame(umi, 2);
+  await preOrderV1(umi, {
+    game,
+    backer: umi.identity,
+    quantity: 1,
+  }).sendAndConfirm(umi);
+  await sleep(3000);
+
+  // When pre-ordering after the deadline.
+  const promise = preOrderV1(umi, {
+    game,
+    backer: umi.identity,
+    quantity: 1,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CrowdfundClosed' });
+
+  // And the pre-order can be refunded, closing the position.
+  await refundPreOrderV1(umi, {
+    game,
+    backer: umi.identity.publicKey,
+  }).sendAndConfirm(umi);
+  const [preOrder] = findPreOrderPda(umi, {
+    game,
+    backer: umi.identity.publicKey,
+  });
+  t.false(await umi.rpc.accountExists(preOrder));
+});
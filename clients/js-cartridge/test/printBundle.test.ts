@@ -0,0 +1,246 @@
+import {
+  generateSigner,
+  none,
+  PublicKey,
+  Signer,
+  some,
+  Umi,
+} from '@metaplex-foundation/umi';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import test from 'ava';
+import {
+  BGL_CARTRIDGE_PROGRAM_ID,
+  closeBundleV1,
+  createBundleV1,
+  findBundlePda,
+  getBundleSerializer,
+  PAYMENT_TOKEN_MINT,
+  printBundleV1,
+  updateBundleV1,
+} from '../src';
+import { createUmi, setupGame } from './_setup';
+
+// The (game, game token account, cartridge, mint counter) accounts of every
+// member of a bundle, none of the games limiting prints per wallet.
+const memberAccounts = (
+  umi: Umi,
+  games: { game: PublicKey }[],
+  cartridges: Signer[]
+) =>
+  games.flatMap(({ game }, i) => [
+    { pubkey: game, isSigner: false, isWritable: true },
+    {
+      pubkey: findAssociatedTokenPda(umi, {
+        mint: PAYMENT_TOKEN_MINT,
+        owner: game,
+      })[0],
+      isSigner: false,
+      isWritable: true,
+    },
+    { signer: cartridges[i], isWritable: true },
+    {
+      pubkey: BGL_CARTRIDGE_PROGRAM_ID,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]);
+
+test('it can print one cartridge of every game in a bundle', async (t) => {
+  // Given two games of the same publisher.
+  const umi = await createUmi();
+  const games = [await setupGame(umi), await setupGame(umi)];
+
+  // And a bundle of both, split 70/30.
+  await createBundleV1(umi, {
+    publisher: umi.identity,
+    name: 'Double Pack',
+    price: 0,
+    games: games.map(({ gameBump }, i) => ({
+      collectionNonce: 0,
+      collectionBump: gameBump,
+      shareBps: i === 0 ? 7000 : 3000,
+    })),
+  })
+    .addRemainingAccounts(
+      games.map(({ game }) => ({
+        pubkey: game,
+        isSigner: false,
+        isWritable: false,
+      }))
+    )
+    .sendAndConfirm(umi);
+  const [bundle] = findBundlePda(umi, {
+    publisher: umi.identity.publicKey,
+    name: 'Double Pack',
+  });
+  const account = await umi.rpc.getAccount(bundle);
+  t.true(account.exists);
+  if (account.exists) {
+    t.like(getBundleSerializer().deserialize(account.data)[0], {
+      publisher: umi.identity.publicKey,
+      numGames: 2,
+    });
+  }
+
+  // When we print the bundle.
+  const owner = generateSigner(umi).publicKey;
+  const cartridges = [generateSigner(umi), generateSigner(umi)];
+  await printBundleV1(umi, { bundle, owner, maxPrice: 0 })
+    .addRemainingAccounts(memberAccounts(umi, games, cartridges))
+    .sendAndConfirm(umi);
+
+  // Then the owner holds a cartridge of each game.
+  await Promise.all(
+    cartridges.map(async (cartridge, i) => {
+      const asset = await fetchAsset(umi, cartridge.publicKey);
+      t.is(asset.owner, owner);
+      t.like(asset.updateAuthority, { address: games[i].game });
+    })
+  );
+});
+
+test('it cannot print a bundle above the price the payer accepts', async (t) => {
+  // Given a free bundle of two games of the same publisher.
+  const umi = await createUmi();
+  const games = [await setupGame(umi), await setupGame(umi)];
+  await createBundleV1(umi, {
+    publisher: umi.identity,
+    name: 'Free Pack',
+    price: 0,
+    games: games.map(({ gameBump }) => ({
+      collectionNonce: 0,
+      collectionBump: gameBump,
+      shareBps: 5000,
+    })),
+  })
+    .addRemainingAccounts(
+      games.map(({ game }) => ({
+        pubkey: game,
+        isSigner: false,
+        isWritable: false,
+      }))
+    )
+    .sendAndConfirm(umi);
+  const [bundle] = findBundlePda(umi, {
+    publisher: umi.identity.publicKey,
+    name: 'Free Pack',
+  });
+
+  // And the publisher raising its price after the payer quoted it.
+  await updateBundleV1(umi, {
+    bundle,
+    publisher: umi.identity,
+    price: some(100),
+    sharesBps: none(),
+  }).sendAndConfirm(umi);
+
+  // When the payer prints it at the quoted price.
+  const cartridges = [generateSigner(umi), generateSigner(umi)];
+  const promise = printBundleV1(umi, {
+    bundle,
+    owner: umi.identity.publicKey,
+    maxPrice: 0,
+  })
+    .addRemainingAccounts(memberAccounts(umi, games, cartridges))
+    .sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'PriceAboveMax' });
+});
+
+test('it cannot create a bundle whose shares do not add up', async (t) => {
+  // Given two games of the same publisher.
+  const umi = await createUmi();
+  const games = [await setupGame(umi), await setupGame(umi)];
+
+  // When we create a bundle paying out only half of its price.
+  const promise = createBundleV1(umi, {
+    publisher: umi.identity,
+    name: 'Half Pack',
+    price: 100,
+    games: games.map(({ gameBump }) => ({
+      collectionNonce: 0,
+      collectionBump: gameBump,
+      shareBps: 2500,
+    })),
+  })
+    .addRemainingAccounts(
+      games.map(({ game }) => ({
+        pubkey: game,
+        isSigner: false,
+        isWritable: false,
+      }))
+    )
+    .sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidBundleShares' });
+});
+
+test('it can update and close a bundle', async (t) => {
+  // Given a bundle of two games of the same publisher.
+  const umi = await createUmi();
+  const games = [await setupGame(umi), await setupGame(umi)];
+  await createBundleV1(umi, {
+    publisher: umi.identity,
+    name: 'Reprice Pack',
+    price: 100,
+    games: games.map(({ gameBump }) => ({
+      collectionNonce: 0,
+      collectionBump: gameBump,
+      shareBps: 5000,
+    })),
+  })
+    .addRemainingAccounts(
+      games.map(({ game }) => ({
+        pubkey: game,
+        isSigner: false,
+        isWritable: false,
+      }))
+    )
+    .sendAndConfirm(umi);
+  const [bundle] = findBundlePda(umi, {
+    publisher: umi.identity.publicKey,
+    name: 'Reprice Pack',
+  });
+
+  // When the publisher changes its price and shares.
+  await updateBundleV1(umi, {
+    bundle,
+    publisher: umi.identity,
+    price: some(200),
+    sharesBps: some([8000, 2000]),
+  }).sendAndConfirm(umi);
+
+  // Then the bundle is updated.
+  const account = await umi.rpc.getAccount(bundle);
+  t.true(account.exists);
+  if (account.exists) {
+    const data = getBundleSerializer().deserialize(account.data)[0];
+    t.is(data.price, 200n);
+    t.deepEqual(
+      data.games.slice(0, 2).map(({ shareBps }) => shareBps),
+      [8000, 2000]
+    );
+  }
+
+  // And shares that do not add up are rejected.
+  const promise = updateBundleV1(umi, {
+    bundle,
+    publisher: umi.identity,
+    price: none(),
+    sharesBps: some([8000, 1000]),
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(promise, { name: 'InvalidBundleShares' });
+
+  // When the publisher closes the bundle.
+  await closeBundleV1(umi, {
+    bundle,
+    publisher: umi.identity,
+    destination: umi.identity.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then it no longer exists.
+  t.false(await umi.rpc.accountExists(bundle));
+});
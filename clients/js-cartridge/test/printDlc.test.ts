@@ -0,0 +1,134 @@
+import { generateSigner, some } from '@metaplex-foundation/umi';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import test from 'ava';
+import {
+  getCartridgeDataSerializer,
+  printDlcV1,
+  registerDlcV1,
+} from '../src';
+import { createUmi, printCartridge, setupGame } from './_setup';
+
+const setupDlc = async (umi: Awaited<ReturnType<typeof createUmi>>) => {
+  const base = await setupGame(umi);
+  const dlc = await setupGame(umi);
+  await registerDlcV1(umi, {
+    game: dlc.game,
+    baseGame: base.game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: dlc.gameBump,
+    baseCollectionNonce: 0,
+    baseCollectionBump: base.gameBump,
+  }).sendAndConfirm(umi);
+  return { base, dlc };
+};
+
+test('it can print a DLC bound to a base cartridge', async (t) => {
+  // Given a DLC of a base game and a base cartridge.
+  const umi = await createUmi();
+  const { base, dlc } = await setupDlc(umi);
+  const baseCartridge = await printCartridge(umi, base.game, base.gameBump);
+
+  // When we print the DLC for the base cartridge.
+  const cartridge = generateSigner(umi);
+  await printDlcV1(umi, {
+    cartridge,
+    game: dlc.game,
+    owner: umi.identity.publicKey,
+    baseCartridge,
+    collectionNonce: 0,
+    collectionBump: dlc.gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then the DLC records the base cartridge it is bound to.
+  const asset = await fetchAsset(umi, cartridge.publicKey);
+  const [data] = getCartridgeDataSerializer().deserialize(
+    asset.appDatas![0].data!
+  );
+  t.like(data, {
+    baseCartridge: some(baseCartridge),
+  });
+});
+
+test('it cannot print a DLC without owning a base cartridge', async (t) => {
+  // Given a DLC and a base cartridge held by someone else.
+  const umi = await createUmi();
+  const { base, dlc } = await setupDlc(umi);
+  const stranger = generateSigner(umi).publicKey;
+  const baseCartridge = await printCartridge(
+    umi,
+    base.game,
+    base.gameBump,
+    stranger
+  );
+
+  // When we try to print the DLC with that cartridge.
+  const promise = printDlcV1(umi, {
+    cartridge: generateSigner(umi),
+    game: dlc.game,
+    owner: umi.identity.publicKey,
+    baseCartridge,
+    collectionNonce: 0,
+    collectionBump: dlc.gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidBaseCartridge' });
+});
+
+test('it cannot print a DLC through the regular print', async (t) => {
+  // Given a DLC.
+  const umi = await createUmi();
+  const { dlc } = await setupDlc(umi);
+
+  // When we print it like a regular game.
+  const promise = printCartridge(umi, dlc.game, dlc.gameBump);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'BaseCartridgeRequired' });
+});
+
+test('a base cartridge can only get a DLC once', async (t) => {
+  // Given a DLC printed for a base cartridge.
+  const umi = await createUmi();
+  const { base, dlc } = await setupDlc(umi);
+  const baseCartridge = await printCartridge(umi, base.game, base.gameBump);
+  const print = () =>
+    printDlcV1(umi, {
+      cartridge: generateSigner(umi),
+      game: dlc.game,
+      owner: umi.identity.publicKey,
+      baseCartridge,
+      collectionNonce: 0,
+      collectionBump: dlc.gameBump,
+    }).sendAndConfirm(umi);
+  await print();
+
+  // When we print the DLC for the same base cartridge again.
+  const promise = print();
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'DlcAlreadyPrinted' });
+});
+
+test('a game with printed cartridges cannot become a DLC', async (t) => {
+  // Given two games, one with a printed cartridge.
+  const umi = await createUmi();
+  const base = await setupGame(umi);
+  const game = await setupGame(umi);
+  await printCartridge(umi, game.game, game.gameBump);
+
+  // When we register the printed game as a DLC.
+  const promise = registerDlcV1(umi, {
+    game: game.game,
+    baseGame: base.game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: game.gameBump,
+    baseCollectionNonce: 0,
+    baseCollectionBump: base.gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'GameAlreadyPrinted' });
+});
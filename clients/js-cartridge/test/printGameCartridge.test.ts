@@ -1,4 +1,9 @@
-import { generateSigner, publicKey } from '@metaplex-foundation/umi';
+import {
+  generateSigner,
+  none,
+  publicKey,
+  some,
+} from '@metaplex-foundation/umi';
 import test from 'ava';
 import {
   AppDataPlugin,
@@ -8,15 +13,29 @@ import {
   fetchCollection,
   Key as MplCoreKey,
 } from '@metaplex-foundation/mpl-core';
+import {
+  createAssociatedToken,
+  findAssociatedTokenPda,
+} from '@metaplex-foundation/mpl-toolbox';
 import {
   findGamePda,
+  findMintCounterPda,
+  PAYMENT_TOKEN_MINT,
   getCartridgeDataSerializer,
+  getMintCounterSerializer,
   PriceType,
   printGameCartridgeV1,
+  printGameCartridgeV2,
   releaseGameV1,
   Source,
+  updateGameV1,
 } from '../src';
-import { createUmi } from './_setup';
+import {
+  createUmi,
+  printCartridge,
+  releaseV1Accounts,
+  setupGame,
+} from './_setup';
 
 test('it can print a new game cartridge with the default Unknown source', async (t) => {
   // Given a Umi instance and a new signer.
@@ -34,7 +53,9 @@ test('it can print a new game cartridge with the default Unknown source', async
     uri: 'https://test-game.com',
     priceType: PriceType.Burn,
     price: 0,
-  }).sendAndConfirm(umi);
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, gameName))
+    .sendAndConfirm(umi);
 
   // Then an account was created with the correct data.
   t.like(await fetchCollection(umi, publicKey(game)), <CollectionV1>{
@@ -73,16 +94,312 @@ test('it can print a new game cartridge with the default Unknown source', async
 
   // And the source AppData plugin should default to Unknown — only the AML
   // authority can later upgrade it via setCartridgeSourceV1.
-  const expectedData = getCartridgeDataSerializer().serialize({
-    version: 0,
-    source: Source.Unknown,
-  });
   t.like(asset.appDatas, <AppDataPlugin[]>[
     {
       type: 'AppData',
       authority: { type: 'UpdateAuthority' },
       dataAuthority: { type: 'UpdateAuthority' },
-      data: expectedData,
     },
   ]);
+  const [data] = getCartridgeDataSerializer().deserialize(
+    asset.appDatas![0].data!
+  );
+  t.like(data, {
+    version: 1,
+    source: Source.Unknown,
+    referrer: none(),
+    baseCartridge: none(),
+    purchasePrice: 0n,
+  });
+  t.true(data.printedAt > 0n);
+});
+
+test('it cannot print a cartridge outside of the sale window', async (t) => {
+  // Given a game whose sale starts in an hour.
+  const umi = await createUmi();
+  const name = Math.random().toString(36).substring(2, 15);
+  const [game, gameBump] = findGamePda(umi, { name, nonce: 0 });
+  const now = BigInt(Math.floor(Date.now() / 1000));
+  await releaseGameV1(umi, {
+    name,
+    uri: 'https://test-game.com',
+    priceType: PriceType.Burn,
+    price: 0,
+    saleStart: some(now + 3600n),
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, name))
+    .sendAndConfirm(umi);
+
+  // When we try to print a cartridge.
+  const promise = printGameCartridgeV1(umi, {
+    game,
+    cartridge: generateSigner(umi),
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'SaleNotStarted' });
+
+  // And once the publisher ends the sale before it starts, it never opens.
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    saleWindow: some({
+      saleStart: some(now + 3600n),
+      saleEnd: some(now + 3601n),
+    }),
+  }).sendAndConfirm(umi);
+  const late = printGameCartridgeV1(umi, {
+    game,
+    cartridge: generateSigner(umi),
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(late, { name: 'SaleNotStarted' });
+});
+
+test('the sale window cannot be moved into the past', async (t) => {
+  // Given a game whose sale starts in an hour.
+  const umi = await createUmi();
+  const name = Math.random().toString(36).substring(2, 15);
+  const [game, gameBump] = findGamePda(umi, { name, nonce: 0 });
+  const now = BigInt(Math.floor(Date.now() / 1000));
+  await releaseGameV1(umi, {
+    name,
+    uri: 'https://test-game.com',
+    priceType: PriceType.Burn,
+    price: 0,
+    saleStart: some(now + 3600n),
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, name))
+    .sendAndConfirm(umi);
+
+  // When the publisher moves the start into the past.
+  const promise = updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    saleWindow: some({
+      saleStart: some(now - 7200n),
+      saleEnd: some(now - 3600n),
+    }),
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidSaleWindow' });
+});
+
+test('the sale window of a game on sale from release is frozen', async (t) => {
+  // Given a game released without a scheduled start.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+
+  // When the publisher schedules a sale window.
+  const now = BigInt(Math.floor(Date.now() / 1000));
+  const promise = updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    saleWindow: some({
+      saleStart: some(now + 3600n),
+      saleEnd: none(),
+    }),
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'SaleAlreadyStarted' });
+});
+
+test('it cannot print a cartridge above the max price', async (t) => {
+  // Given a game priced at 100.
+  const umi = await createUmi();
+  const name = Math.random().toString(36).substring(2, 15);
+  const [game, gameBump] = findGamePda(umi, { name, nonce: 0 });
+  await releaseGameV1(umi, {
+    name,
+    uri: 'https://test-game.com',
+    priceType: PriceType.Transfer,
+    price: 100,
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, name))
+    .sendAndConfirm(umi);
+
+  // When we print a cartridge accepting to pay at most 99.
+  const promise = printGameCartridgeV1(umi, {
+    game,
+    cartridge: generateSigner(umi),
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    maxPrice: some(99),
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'PriceAboveMax' });
+});
+
+test('it cannot print more than the per-wallet limit to an owner', async (t) => {
+  // Given a game limited to one cartridge per wallet.
+  const umi = await createUmi();
+  const owner = generateSigner(umi).publicKey;
+  const { game, gameBump } = await setupGame(umi);
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    maxPerWallet: some(1),
+  }).sendAndConfirm(umi);
+
+  // And a first cartridge printed to the owner.
+  await printCartridge(umi, game, gameBump, owner);
+  const [mintCounter] = findMintCounterPda(umi, { game, owner });
+  const account = await umi.rpc.getAccount(mintCounter);
+  t.true(account.exists);
+  if (account.exists) {
+    t.like(getMintCounterSerializer().deserialize(account.data)[0], {
+      game,
+      owner,
+      count: 1,
+    });
+  }
+
+  // When we print a second cartridge to the same owner.
+  const promise = printCartridge(umi, game, gameBump, owner);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'WalletLimitReached' });
+});
+
+test('it cannot print a limited game without the mint counter', async (t) => {
+  // Given a game limited to one cartridge per wallet.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    maxPerWallet: some(1),
+  }).sendAndConfirm(umi);
+
+  // When we print a cartridge through the first version of the instruction.
+  const promise = printGameCartridgeV1(umi, {
+    game,
+    cartridge: generateSigner(umi),
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'MintCounterRequired' });
+});
+
+test('it does not count prints of unlimited games', async (t) => {
+  // Given a game without a per-wallet limit.
+  const umi = await createUmi();
+  const owner = generateSigner(umi).publicKey;
+  const { game, gameBump } = await setupGame(umi);
+
+  // When we print a cartridge to the owner.
+  await printCartridge(umi, game, gameBump, owner);
+
+  // Then no mint counter was created.
+  const [mintCounter] = findMintCounterPda(umi, { game, owner });
+  t.false(await umi.rpc.accountExists(mintCounter));
+});
+
+test('it records the referrer of a print in the cartridge data', async (t) => {
+  // Given a game paying referrers 10% of the price.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    referralBps: some(1000),
+  }).sendAndConfirm(umi);
+
+  // And a referrer with a payment token account.
+  const referrer = generateSigner(umi).publicKey;
+  await createAssociatedToken(umi, {
+    mint: PAYMENT_TOKEN_MINT,
+    owner: referrer,
+  }).sendAndConfirm(umi);
+  const [referrerTokenAccount] = findAssociatedTokenPda(umi, {
+    mint: PAYMENT_TOKEN_MINT,
+    owner: referrer,
+  });
+
+  // When we print a cartridge through the referrer.
+  const cartridge = generateSigner(umi);
+  await printGameCartridgeV2(umi, {
+    game,
+    cartridge,
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    referrerTokenAccount,
+  }).sendAndConfirm(umi);
+
+  // Then the referrer is recorded on the cartridge.
+  const asset = await fetchAsset(umi, cartridge.publicKey);
+  const [referred] = getCartridgeDataSerializer().deserialize(
+    asset.appDatas![0].data!
+  );
+  t.like(referred, {
+    version: 1,
+    source: Source.Unknown,
+    referrer: some(referrer),
+  });
+});
+
+test('it cannot pay referrers more than a fifth of the price', async (t) => {
+  // Given a game.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+
+  // When the publisher sets a referral rate of 25%.
+  const promise = updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    referralBps: some(2500),
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidReferralRate' });
+});
+
+test('it cannot refer its own print', async (t) => {
+  // Given a game.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  const [payerTokenAccount] = findAssociatedTokenPda(umi, {
+    mint: PAYMENT_TOKEN_MINT,
+    owner: umi.identity.publicKey,
+  });
+
+  // When the payer passes their own token account as the referrer.
+  const promise = printGameCartridgeV2(umi, {
+    game,
+    cartridge: generateSigner(umi),
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    referrerTokenAccount: payerTokenAccount,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'SelfReferral' });
 });
@@ -0,0 +1,203 @@
+import { createHash } from 'crypto';
+import {
+  generateSigner,
+  none,
+  PublicKey,
+  publicKeyBytes,
+  sol,
+  some,
+} from '@metaplex-foundation/umi';
+import { createAssociatedToken } from '@metaplex-foundation/mpl-toolbox';
+import test from 'ava';
+import {
+  getAllowlistReceiptSerializer,
+  findAllowlistReceiptPda,
+  findGamePda,
+  PAYMENT_TOKEN_MINT,
+  PriceType,
+  printGameCartridgeAllowlistV1,
+  releaseGameV1,
+  updateGameV1,
+} from '../src';
+import { createUmi, releaseV1Accounts, setupGame } from './_setup';
+
+const sha256 = (...parts: Uint8Array[]) => {
+  const hash = createHash('sha256');
+  parts.forEach((part) => hash.update(part));
+  return new Uint8Array(hash.digest());
+};
+
+const leaf = (wallet: PublicKey, quota: number) => {
+  const quotaBytes = new Uint8Array(4);
+  new DataView(quotaBytes.buffer).setUint32(0, quota, true);
+  return sha256(publicKeyBytes(wallet), quotaBytes);
+};
+
+const hashPair = (a: Uint8Array, b: Uint8Array) =>
+  Buffer.compare(a, b) <= 0 ? sha256(a, b) : sha256(b, a);
+
+test('an allowlisted wallet can print up to its quota', async (t) => {
+  // Given a game with a two-wallet allowlist giving the identity one print.
+  const umi = await createUmi();
+  const other = generateSigner(umi);
+  const { game, gameBump } = await setupGame(umi);
+  const identityLeaf = leaf(umi.identity.publicKey, 1);
+  const otherLeaf = leaf(other.publicKey, 0);
+
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    allowlist: some({
+      merkleRoot: hashPair(identityLeaf, otherLeaf),
+      price: some(0),
+    }),
+  }).sendAndConfirm(umi);
+
+  // When the identity prints with its proof.
+  await printGameCartridgeAllowlistV1(umi, {
+    game,
+    cartridge: generateSigner(umi),
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    quota: 1,
+    proof: [otherLeaf],
+  }).sendAndConfirm(umi);
+
+  // Then the print is counted in its receipt.
+  const [receipt] = findAllowlistReceiptPda(umi, {
+    game,
+    wallet: umi.identity.publicKey,
+  });
+  const account = await umi.rpc.getAccount(receipt);
+  t.true(account.exists);
+  if (account.exists) {
+    t.like(getAllowlistReceiptSerializer().deserialize(account.data)[0], {
+      game,
+      wallet: umi.identity.publicKey,
+      printed: 1,
+    });
+  }
+
+  // And a second print exceeds the quota.
+  const promise = printGameCartridgeAllowlistV1(umi, {
+    game,
+    cartridge: generateSigner(umi),
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    quota: 1,
+    proof: [otherLeaf],
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(promise, { name: 'AllowlistQuotaExceeded' });
+});
+
+test('a wallet cannot print with a forged quota', async (t) => {
+  // Given a game whose allowlist only holds the identity with one print.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    allowlist: some({
+      merkleRoot: leaf(umi.identity.publicKey, 1),
+      price: none(),
+    }),
+  }).sendAndConfirm(umi);
+
+  // When the identity claims an unlimited quota.
+  const promise = printGameCartridgeAllowlistV1(umi, {
+    game,
+    cartridge: generateSigner(umi),
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    quota: 0,
+    proof: [],
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'NotAllowlisted' });
+});
+
+test('a wallet outside of the allowlist cannot print', async (t) => {
+  // Given a game allowlisting only the identity.
+  const umi = await createUmi();
+  const stranger = generateSigner(umi);
+  await umi.rpc.airdrop(stranger.publicKey, sol(1));
+  await createAssociatedToken(umi, {
+    mint: PAYMENT_TOKEN_MINT,
+    owner: stranger.publicKey,
+  }).sendAndConfirm(umi);
+  const { game, gameBump } = await setupGame(umi);
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    allowlist: some({
+      merkleRoot: leaf(umi.identity.publicKey, 0),
+      price: none(),
+    }),
+  }).sendAndConfirm(umi);
+
+  // When a stranger tries to print with the identity's leaf.
+  const promise = printGameCartridgeAllowlistV1(umi, {
+    game,
+    cartridge: generateSigner(umi),
+    owner: stranger.publicKey,
+    payer: stranger,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    quota: 0,
+    proof: [],
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'NotAllowlisted' });
+});
+
+test('an allowlisted wallet cannot print before the sale starts', async (t) => {
+  // Given a game whose sale starts in an hour, allowlisting the identity.
+  const umi = await createUmi();
+  const name = Math.random().toString(36).substring(2, 15);
+  const [game, gameBump] = findGamePda(umi, { name, nonce: 0 });
+  const now = BigInt(Math.floor(Date.now() / 1000));
+  await releaseGameV1(umi, {
+    name,
+    uri: 'https://test-game.com',
+    priceType: PriceType.Transfer,
+    price: 0,
+    saleStart: some(now + 3600n),
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, name))
+    .sendAndConfirm(umi);
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    allowlist: some({
+      merkleRoot: leaf(umi.identity.publicKey, 0),
+      price: none(),
+    }),
+  }).sendAndConfirm(umi);
+
+  // When the identity prints with its proof.
+  const promise = printGameCartridgeAllowlistV1(umi, {
+    game,
+    cartridge: generateSigner(umi),
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    quota: 0,
+    proof: [],
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'SaleNotStarted' });
+});
@@ -0,0 +1,173 @@
+import {
+  generateSigner,
+  Instruction,
+  KeypairSigner,
+  publicKey,
+  PublicKey,
+  publicKeyBytes,
+  some,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  findVoucherRecordPda,
+  printGameCartridgeVoucherV1,
+  updateGameV1,
+} from '../src';
+import { createUmi, setupGame } from './_setup';
+
+const ED25519_PROGRAM_ID = publicKey(
+  'Ed25519SigVerify111111111111111111111111111'
+);
+
+type Voucher = {
+  game: PublicKey;
+  wallet: PublicKey;
+  price: bigint;
+  expiresAt: bigint;
+  voucherNonce: bigint;
+};
+
+// game || wallet || price || expires_at || voucher_nonce, little-endian.
+const voucherMessage = (voucher: Voucher) => {
+  const message = new Uint8Array(88);
+  const view = new DataView(message.buffer);
+  message.set(publicKeyBytes(voucher.game), 0);
+  message.set(publicKeyBytes(voucher.wallet), 32);
+  view.setBigUint64(64, voucher.price, true);
+  view.setBigInt64(72, voucher.expiresAt, true);
+  view.setBigUint64(80, voucher.voucherNonce, true);
+  return message;
+};
+
+// Builds an Ed25519 program instruction verifying a single signature whose
+// data all lives in the instruction itself.
+const ed25519Instruction = (
+  umi: Umi,
+  signer: KeypairSigner,
+  message: Uint8Array
+): Instruction => {
+  const signature = umi.eddsa.sign(message, signer);
+  const data = new Uint8Array(16 + 32 + 64 + message.length);
+  const view = new DataView(data.buffer);
+  data[0] = 1;
+  [48, 0xffff, 16, 0xffff, 112, message.length, 0xffff].forEach((value, i) =>
+    view.setUint16(2 + i * 2, value, true)
+  );
+  data.set(publicKeyBytes(signer.publicKey), 16);
+  data.set(signature, 48);
+  data.set(message, 112);
+  return { programId: ED25519_PROGRAM_ID, keys: [], data };
+};
+
+test('a wallet can print once with a signed voucher', async (t) => {
+  // Given a game with a voucher signer.
+  const umi = await createUmi();
+  const voucherSigner = generateSigner(umi);
+  const { game, gameBump } = await setupGame(umi);
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    voucherSigner: some(voucherSigner.publicKey),
+  }).sendAndConfirm(umi);
+
+  // And a free voucher for the identity.
+  const voucher: Voucher = {
+    game,
+    wallet: umi.identity.publicKey,
+    price: 0n,
+    expiresAt: BigInt(Math.floor(Date.now() / 1000) + 3600),
+    voucherNonce: 42n,
+  };
+  const print = () =>
+    transactionBuilder()
+      .add({
+        instruction: ed25519Instruction(
+          umi,
+          voucherSigner,
+          voucherMessage(voucher)
+        ),
+        signers: [],
+        bytesCreatedOnChain: 0,
+      })
+      .add(
+        printGameCartridgeVoucherV1(umi, {
+          game,
+          cartridge: generateSigner(umi),
+          owner: umi.identity.publicKey,
+          collectionNonce: 0,
+          collectionBump: gameBump,
+          price: voucher.price,
+          expiresAt: voucher.expiresAt,
+          voucherNonce: voucher.voucherNonce,
+        })
+      )
+      .sendAndConfirm(umi);
+
+  // When the identity redeems the voucher.
+  await print();
+
+  // Then the voucher nonce is recorded as used.
+  const [voucherRecord] = findVoucherRecordPda(umi, {
+    game,
+    voucherNonce: voucher.voucherNonce,
+  });
+  t.true(await umi.rpc.accountExists(voucherRecord));
+
+  // And the voucher cannot be replayed.
+  await t.throwsAsync(print(), { name: 'VoucherAlreadyUsed' });
+});
+
+test('a wallet cannot redeem a voucher issued to another wallet', async (t) => {
+  // Given a game with a voucher signer.
+  const umi = await createUmi();
+  const voucherSigner = generateSigner(umi);
+  const { game, gameBump } = await setupGame(umi);
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    voucherSigner: some(voucherSigner.publicKey),
+  }).sendAndConfirm(umi);
+
+  // And a voucher issued to someone else.
+  const voucher: Voucher = {
+    game,
+    wallet: generateSigner(umi).publicKey,
+    price: 0n,
+    expiresAt: BigInt(Math.floor(Date.now() / 1000) + 3600),
+    voucherNonce: 1n,
+  };
+
+  // When the identity tries to redeem it.
+  const promise = transactionBuilder()
+    .add({
+      instruction: ed25519Instruction(
+        umi,
+        voucherSigner,
+        voucherMessage(voucher)
+      ),
+      signers: [],
+      bytesCreatedOnChain: 0,
+    })
+    .add(
+      printGameCartridgeVoucherV1(umi, {
+        game,
+        cartridge: generateSigner(umi),
+        owner: umi.identity.publicKey,
+        collectionNonce: 0,
+        collectionBump: gameBump,
+        price: voucher.price,
+        expiresAt: voucher.expiresAt,
+        voucherNonce: voucher.voucherNonce,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidVoucherSignature' });
+});
@@ -0,0 +1,215 @@
+import {
+  generateSigner,
+  PublicKey,
+  sol,
+  some,
+} from '@metaplex-foundation/umi';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import {
+  createAssociatedToken,
+  findAssociatedTokenPda,
+} from '@metaplex-foundation/mpl-toolbox';
+import test from 'ava';
+import {
+  getCartridgeDataSerializer,
+  insertCartridgeV1,
+  listCartridgeV1,
+  PAYMENT_TOKEN_MINT,
+  printRentalCartridgeV1,
+  reclaimExpiredCartridgeV1,
+  updateGameV1,
+} from '../src';
+import { createUmi, setupGame, setupMachine } from './_setup';
+
+const sleep = (ms: number) =>
+  new Promise((resolve) => {
+    setTimeout(resolve, ms);
+  });
+
+// Releases a game offering free rentals of the given duration.
+const setupRentalGame = async (
+  umi: Awaited<ReturnType<typeof createUmi>>,
+  duration: number
+) => {
+  const { game, gameBump } = await setupGame(umi);
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    rental: some({ price: 0, duration }),
+  }).sendAndConfirm(umi);
+  return { game, gameBump };
+};
+
+const printRental = async (
+  umi: Awaited<ReturnType<typeof createUmi>>,
+  game: PublicKey,
+  gameBump: number
+) => {
+  const cartridge = generateSigner(umi);
+  await printRentalCartridgeV1(umi, {
+    cartridge,
+    game,
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+  return cartridge.publicKey;
+};
+
+test('it can print a rental cartridge', async (t) => {
+  // Given a game renting cartridges for a day.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupRentalGame(umi, 86400);
+
+  // When a rental cartridge is printed.
+  const cartridge = await printRental(umi, game, gameBump);
+
+  // Then it expires a day later and records the rent its payer paid.
+  const asset = await fetchAsset(umi, cartridge);
+  const [data] = getCartridgeDataSerializer().deserialize(
+    asset.appDatas![0].data!
+  );
+  t.like(data, {
+    version: 1,
+    expiresAt: some(data.printedAt + 86400n),
+    rentPayer: some(umi.identity.publicKey),
+    rentPaid: asset.header.lamports.basisPoints,
+  });
+
+  // And it is burned through the burn delegate of the game.
+  t.is(asset.permanentBurnDelegate, undefined);
+});
+
+test('it cannot print a rental cartridge of a game without rentals', async (t) => {
+  // Given a game without rental terms.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+
+  // When a rental cartridge is printed.
+  const promise = printRental(umi, game, gameBump);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'RentalsDisabled' });
+});
+
+test('it cannot list a rental cartridge', async (t) => {
+  // Given a rental cartridge.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupRentalGame(umi, 86400);
+  const cartridge = await printRental(umi, game, gameBump);
+
+  // When the renter tries to list it.
+  const promise = listCartridgeV1(umi, {
+    cartridge,
+    game,
+    seller: umi.identity,
+    price: 100,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'RentalCartridge' });
+});
+
+test('it cannot reclaim a rental cartridge before it expires', async (t) => {
+  // Given a rental cartridge that expires in a day.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupRentalGame(umi, 86400);
+  const cartridge = await printRental(umi, game, gameBump);
+
+  // When someone tries to reclaim it.
+  const promise = reclaimExpiredCartridgeV1(umi, {
+    cartridge,
+    game,
+    rentPayer: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CartridgeNotExpired' });
+});
+
+test('an expired rental cartridge cannot be inserted and can be reclaimed', async (t) => {
+  // Given a one second rental that has expired.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupRentalGame(umi, 1);
+  const { machine, machineCollection } = await setupMachine(umi);
+  const cartridge = await printRental(umi, game, gameBump);
+  await sleep(2000);
+
+  // When the renter tries to insert it.
+  const promise = insertCartridgeV1(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: umi.identity,
+    machine,
+    machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CartridgeExpired' });
+
+  // And anyone can reclaim it, burning the cartridge and returning its rent
+  // to the payer.
+  const asset = await fetchAsset(umi, cartridge);
+  const [data] = getCartridgeDataSerializer().deserialize(
+    asset.appDatas![0].data!
+  );
+  const balanceBefore = await umi.rpc.getBalance(umi.identity.publicKey);
+  const cranker = generateSigner(umi);
+  await umi.rpc.airdrop(cranker.publicKey, sol(1));
+  await reclaimExpiredCartridgeV1(umi, {
+    cartridge,
+    game,
+    rentPayer: umi.identity.publicKey,
+    payer: cranker,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(fetchAsset(umi, cartridge));
+  const balanceAfter = await umi.rpc.getBalance(umi.identity.publicKey);
+  t.is(
+    balanceAfter.basisPoints - balanceBefore.basisPoints,
+    data.rentPaid
+  );
+});
+
+test('it records the referrer of a rental', async (t) => {
+  // Given a game renting cartridges for a day.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupRentalGame(umi, 86400);
+
+  // And a referrer with a payment token account.
+  const referrer = generateSigner(umi).publicKey;
+  await createAssociatedToken(umi, {
+    mint: PAYMENT_TOKEN_MINT,
+    owner: referrer,
+  }).sendAndConfirm(umi);
+  const [referrerTokenAccount] = findAssociatedTokenPda(umi, {
+    mint: PAYMENT_TOKEN_MINT,
+    owner: referrer,
+  });
+
+  // When a rental cartridge is printed through the referrer.
+  const cartridge = generateSigner(umi);
+  await printRentalCartridgeV1(umi, {
+    cartridge,
+    game,
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    referrerTokenAccount,
+  }).sendAndConfirm(umi);
+
+  // Then the referrer is recorded on the rental.
+  const asset = await fetchAsset(umi, cartridge.publicKey);
+  const [data] = getCartridgeDataSerializer().deserialize(
+    asset.appDatas![0].data!
+  );
+  t.like(data, { referrer: some(referrer) });
+});
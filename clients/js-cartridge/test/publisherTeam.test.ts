@@ -0,0 +1,266 @@
+import {
+  generateSigner,
+  publicKey,
+  sol,
+  some,
+  Signer,
+  Umi,
+} from '@metaplex-foundation/umi';
+import {
+  createAssociatedToken,
+  findAssociatedTokenPda,
+} from '@metaplex-foundation/mpl-toolbox';
+import { fetchAsset, fetchCollection } from '@metaplex-foundation/mpl-core';
+import test from 'ava';
+import {
+  airdropCartridgesV1,
+  createPublisherTeamV1,
+  findCatalogPda,
+  findGamePda,
+  findPublisherTeamPda,
+  getGameCollectionDataSerializer,
+  getPublisherTeamSerializer,
+  PAYMENT_TOKEN_MINT,
+  PriceType,
+  releaseGameV2,
+  updateGameV1,
+  withdrawGameRevenueV1,
+} from '../src';
+import { createUmi } from './_setup';
+
+// The identity and the given members approving as remaining signers.
+const approvals = (umi: Umi, members: Signer[]) =>
+  [umi.identity, ...members].map((signer) => ({ signer, isWritable: false }));
+
+// Creates a two-member team needing both members to approve, and releases a
+// game published by it.
+const setupTeamGame = async (umi: Umi) => {
+  const partner = generateSigner(umi);
+  await umi.rpc.airdrop(partner.publicKey, sol(1));
+  await createAssociatedToken(umi, {
+    mint: PAYMENT_TOKEN_MINT,
+    owner: partner.publicKey,
+  }).sendAndConfirm(umi);
+
+  const teamName = Math.random().toString(36).substring(2, 15);
+  await createPublisherTeamV1(umi, {
+    creator: umi.identity,
+    name: teamName,
+    threshold: 2,
+    members: [
+      { address: umi.identity.publicKey, shareBps: 6000 },
+      { address: partner.publicKey, shareBps: 4000 },
+    ],
+  }).sendAndConfirm(umi);
+  const [team] = findPublisherTeamPda(umi, {
+    creator: umi.identity.publicKey,
+    name: teamName,
+  });
+
+  const name = Math.random().toString(36).substring(2, 15);
+  const [game, gameBump] = findGamePda(umi, { name, nonce: 0 });
+  await releaseGameV2(umi, {
+    name,
+    uri: 'https://test-game.com',
+    priceType: PriceType.Transfer,
+    price: 0,
+    team,
+    catalog: findCatalogPda(umi, { publisher: team }),
+  })
+    .addRemainingAccounts(approvals(umi, [partner]))
+    .sendAndConfirm(umi);
+
+  return { team, partner, game, gameBump };
+};
+
+test('it can create a publisher team', async (t) => {
+  // Given a Umi instance and a partner.
+  const umi = await createUmi();
+  const partner = generateSigner(umi).publicKey;
+
+  // When we create a team splitting the revenue 60/40.
+  await createPublisherTeamV1(umi, {
+    creator: umi.identity,
+    name: 'studio',
+    threshold: 1,
+    members: [
+      { address: umi.identity.publicKey, shareBps: 6000 },
+      { address: partner, shareBps: 4000 },
+    ],
+  }).sendAndConfirm(umi);
+
+  // Then the members, shares and threshold are recorded.
+  const [team] = findPublisherTeamPda(umi, {
+    creator: umi.identity.publicKey,
+    name: 'studio',
+  });
+  const account = await umi.rpc.getAccount(team);
+  t.true(account.exists);
+  if (account.exists) {
+    const [teamData] = getPublisherTeamSerializer().deserialize(account.data);
+    t.is(teamData.numMembers, 2);
+    t.is(teamData.threshold, 1);
+    t.like(teamData.members[0], {
+      address: umi.identity.publicKey,
+      shareBps: 6000,
+    });
+    t.like(teamData.members[1], { address: partner, shareBps: 4000 });
+  }
+});
+
+test('it cannot create a team whose shares do not add up', async (t) => {
+  // Given a Umi instance.
+  const umi = await createUmi();
+
+  // When we create a team with 90% of the shares.
+  const promise = createPublisherTeamV1(umi, {
+    creator: umi.identity,
+    name: 'studio',
+    threshold: 1,
+    members: [
+      { address: umi.identity.publicKey, shareBps: 6000 },
+      { address: generateSigner(umi).publicKey, shareBps: 3000 },
+    ],
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidTeamShares' });
+});
+
+test('a game released by a team records the team as publisher', async (t) => {
+  // Given a game released by a team.
+  const umi = await createUmi();
+  const { team, partner, game } = await setupTeamGame(umi);
+
+  // Then the team is the publisher of the game.
+  const collection = await fetchCollection(umi, publicKey(game));
+  const [gameData] = getGameCollectionDataSerializer().deserialize(
+    collection.dataSections![0].data!
+  );
+  t.is(gameData.publisher, team);
+
+  // And the royalties are split between the members by share.
+  t.like(collection.royalties?.creators, [
+    { address: umi.identity.publicKey, percentage: 54 },
+    { address: partner.publicKey, percentage: 36 },
+    { percentage: 10 },
+  ]);
+});
+
+test('updating a team game needs the team threshold', async (t) => {
+  // Given a game released by a team needing two approvals.
+  const umi = await createUmi();
+  const { team, partner, game, gameBump } = await setupTeamGame(umi);
+
+  // When a single member updates the game.
+  const promise = updateGameV1(umi, {
+    game,
+    publisher: team,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    maxPerWallet: some(2),
+  })
+    .addRemainingAccounts(approvals(umi, []))
+    .sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'TeamThresholdNotMet' });
+
+  // But both members together can update it.
+  await updateGameV1(umi, {
+    game,
+    publisher: team,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    maxPerWallet: some(2),
+  })
+    .addRemainingAccounts(approvals(umi, [partner]))
+    .sendAndConfirm(umi);
+  const collection = await fetchCollection(umi, publicKey(game));
+  const [gameData] = getGameCollectionDataSerializer().deserialize(
+    collection.dataSections![0].data!
+  );
+  t.deepEqual(gameData.maxPerWallet, some(2));
+});
+
+test('team revenue is withdrawn to every member', async (t) => {
+  // Given a game released by a team.
+  const umi = await createUmi();
+  const { team, partner, game, gameBump } = await setupTeamGame(umi);
+  const memberTokenAccounts = [umi.identity.publicKey, partner.publicKey].map(
+    (owner) => findAssociatedTokenPda(umi, { mint: PAYMENT_TOKEN_MINT, owner })
+  );
+
+  // When the members are paid in the wrong order.
+  const promise = withdrawGameRevenueV1(umi, {
+    game,
+    publisher: team,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    amount: 0,
+  })
+    .addRemainingAccounts([
+      ...[...memberTokenAccounts].reverse().map(([pubkey]) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      })),
+      ...approvals(umi, [partner]),
+    ])
+    .sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidRevenueTokenAccount' });
+
+  // But the withdrawal succeeds with every member in order.
+  await withdrawGameRevenueV1(umi, {
+    game,
+    publisher: team,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    amount: 0,
+  })
+    .addRemainingAccounts([
+      ...memberTokenAccounts.map(([pubkey]) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      })),
+      ...approvals(umi, [partner]),
+    ])
+    .sendAndConfirm(umi);
+  t.pass();
+});
+
+test('a team airdrop needs the team threshold', async (t) => {
+  // Given a game released by a team and a recipient.
+  const umi = await createUmi();
+  const { team, partner, game, gameBump } = await setupTeamGame(umi);
+  const recipient = generateSigner(umi).publicKey;
+  const cartridge = generateSigner(umi);
+  const airdrop = (members: Signer[]) =>
+    airdropCartridgesV1(umi, {
+      game,
+      publisher: team,
+      collectionNonce: 0,
+      collectionBump: gameBump,
+      numApprovers: members.length + 1,
+    })
+      .addRemainingAccounts([
+        ...approvals(umi, members),
+        { pubkey: recipient, isSigner: false, isWritable: false },
+        { signer: cartridge, isWritable: true },
+      ])
+      .sendAndConfirm(umi);
+
+  // When a single member airdrops a cartridge.
+  const promise = airdrop([]);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'TeamThresholdNotMet' });
+
+  // But both members together can airdrop it.
+  await airdrop([partner]);
+  const asset = await fetchAsset(umi, cartridge.publicKey);
+  t.is(asset.owner, recipient);
+});
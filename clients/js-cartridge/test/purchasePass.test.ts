@@ -0,0 +1,217 @@
+import { generateSigner, PublicKey, some, Umi } from '@metaplex-foundation/umi';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import test from 'ava';
+import {
+  createPassV1,
+  findPassPda,
+  getPassDataSerializer,
+  insertPassV1,
+  purchasePassV1,
+  removePassV1,
+  renewPassV1,
+  updateGameV1,
+  withdrawPassRevenueV1,
+} from '../src';
+import { createUmi, fetchMachineData, setupGame, setupMachine } from './_setup';
+
+const sleep = (ms: number) =>
+  new Promise((resolve) => {
+    setTimeout(resolve, ms);
+  });
+
+// Creates a free pass with a random name and the given duration.
+const setupPass = async (umi: Umi, duration: number = 2_592_000) => {
+  const name = Math.random().toString(36).substring(2, 15);
+  await createPassV1(umi, {
+    name,
+    uri: 'https://test-pass.com',
+    authority: umi.identity,
+    price: 0,
+    duration,
+  }).sendAndConfirm(umi);
+
+  const [passCollection] = findPassPda(umi, {
+    authority: umi.identity.publicKey,
+    name,
+  });
+  return passCollection;
+};
+
+const purchasePass = async (umi: Umi, passCollection: PublicKey) => {
+  const pass = generateSigner(umi);
+  await purchasePassV1(umi, {
+    pass,
+    passCollection,
+    owner: umi.identity.publicKey,
+  }).sendAndConfirm(umi);
+  return pass.publicKey;
+};
+
+const fetchPassData = async (umi: Umi, pass: PublicKey) => {
+  const asset = await fetchAsset(umi, pass);
+  const [data] = getPassDataSerializer().deserialize(asset.appDatas![0].data!);
+  return data;
+};
+
+// Releases a game that accepts passes of the given collection.
+const setupPassGame = async (umi: Umi, passCollection: PublicKey) => {
+  const { game, gameBump } = await setupGame(umi);
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    pass: some(passCollection),
+    passCollection,
+    passAuthority: umi.identity,
+  }).sendAndConfirm(umi);
+  return { game, gameBump };
+};
+
+test('it can purchase and renew a pass', async (t) => {
+  // Given a pass lasting thirty days.
+  const umi = await createUmi();
+  const passCollection = await setupPass(umi);
+
+  // When a pass is purchased.
+  const pass = await purchasePass(umi, passCollection);
+
+  // Then the owner holds a frozen pass valid for thirty days.
+  const asset = await fetchAsset(umi, pass);
+  t.is(asset.owner, umi.identity.publicKey);
+  t.true(asset.permanentFreezeDelegate?.frozen);
+  const { expiresAt } = await fetchPassData(umi, pass);
+  t.true(expiresAt > BigInt(Math.floor(Date.now() / 1000)));
+
+  // And renewing it extends it by another thirty days.
+  await renewPassV1(umi, { pass, passCollection }).sendAndConfirm(umi);
+  t.like(await fetchPassData(umi, pass), {
+    expiresAt: expiresAt + 2_592_000n,
+  });
+});
+
+test('a pass can be inserted for a game that opted into it', async (t) => {
+  // Given a pass and a game accepting it.
+  const umi = await createUmi();
+  const passCollection = await setupPass(umi);
+  const pass = await purchasePass(umi, passCollection);
+  const { game, gameBump } = await setupPassGame(umi, passCollection);
+  const { machine, machineCollection } = await setupMachine(umi);
+
+  // When the pass is inserted to play the game.
+  await insertPassV1(umi, {
+    pass,
+    passCollection,
+    game,
+    passOwner: umi.identity,
+    machine,
+    machineCollection,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then the machine plays the game through the pass.
+  const machineData = await fetchMachineData(umi, machine);
+  t.like(machineData.current, { cartridge: pass, game });
+
+  // And the owner can take the pass out again.
+  await removePassV1(umi, {
+    pass,
+    passCollection,
+    authority: umi.identity,
+    machine,
+    machineCollection,
+  }).sendAndConfirm(umi);
+  t.like((await fetchMachineData(umi, machine)).history[0], {
+    cartridge: pass,
+    game,
+  });
+});
+
+test('a pass cannot be inserted for a game outside of it', async (t) => {
+  // Given a pass and a game that did not opt into it.
+  const umi = await createUmi();
+  const passCollection = await setupPass(umi);
+  const pass = await purchasePass(umi, passCollection);
+  const { game, gameBump } = await setupGame(umi);
+  const { machine, machineCollection } = await setupMachine(umi);
+
+  // When the pass is inserted to play the game.
+  const promise = insertPassV1(umi, {
+    pass,
+    passCollection,
+    game,
+    passOwner: umi.identity,
+    machine,
+    machineCollection,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'GameNotInPass' });
+});
+
+test('an expired pass cannot be inserted', async (t) => {
+  // Given a one second pass that has expired.
+  const umi = await createUmi();
+  const passCollection = await setupPass(umi, 1);
+  const pass = await purchasePass(umi, passCollection);
+  const { game, gameBump } = await setupPassGame(umi, passCollection);
+  const { machine, machineCollection } = await setupMachine(umi);
+  await sleep(2000);
+
+  // When the pass is inserted.
+  const promise = insertPassV1(umi, {
+    pass,
+    passCollection,
+    game,
+    passOwner: umi.identity,
+    machine,
+    machineCollection,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'PassExpired' });
+});
+
+test('a game cannot join a pass without its authority', async (t) => {
+  // Given a pass owned by someone else and a game.
+  const umi = await createUmi();
+  const passCollection = await setupPass(umi);
+  const publisherUmi = await createUmi();
+  const { game, gameBump } = await setupGame(publisherUmi);
+
+  // When the publisher opts the game into the pass on their own.
+  const promise = updateGameV1(publisherUmi, {
+    game,
+    publisher: publisherUmi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    pass: some(passCollection),
+    passCollection,
+    passAuthority: publisherUmi.identity,
+  }).sendAndConfirm(publisherUmi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'PassAuthorityMustSign' });
+});
+
+test('only the pass authority can withdraw its revenue', async (t) => {
+  // Given a pass.
+  const umi = await createUmi();
+  const passCollection = await setupPass(umi);
+
+  // When someone else withdraws its revenue.
+  const otherUmi = await createUmi();
+  const promise = withdrawPassRevenueV1(otherUmi, {
+    passCollection,
+    authority: otherUmi.identity,
+    amount: 0,
+  }).sendAndConfirm(otherUmi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'AuthorityMustSign' });
+});
@@ -0,0 +1,150 @@
+import { generateSigner, sol, some } from '@metaplex-foundation/umi';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import test from 'ava';
+import {
+  insertCartridgeV1,
+  lendCartridgeV1,
+  refundCartridgeV1,
+  updateGameV1,
+} from '../src';
+import { createUmi, printCartridge, setupGame, setupMachine } from './_setup';
+
+const setupRefundableGame = async (
+  umi: Awaited<ReturnType<typeof createUmi>>
+) => {
+  const { game, gameBump } = await setupGame(umi);
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    refundWindow: some(3600),
+  }).sendAndConfirm(umi);
+  return { game, gameBump };
+};
+
+test('it can refund a cartridge within the refund window', async (t) => {
+  // Given a game with a one hour refund window and a cartridge.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupRefundableGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+
+  // When the owner refunds it.
+  await refundCartridgeV1(umi, {
+    cartridge,
+    game,
+    owner: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then the cartridge is burned.
+  await t.throwsAsync(fetchAsset(umi, cartridge));
+});
+
+test('it cannot refund a cartridge that was inserted into a machine', async (t) => {
+  // Given a refundable cartridge that was inserted into a machine.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupRefundableGame(umi);
+  const { machine, machineCollection } = await setupMachine(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+  await insertCartridgeV1(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: umi.identity,
+    machine,
+    machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // When the owner tries to refund it.
+  const promise = refundCartridgeV1(umi, {
+    cartridge,
+    game,
+    owner: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CartridgeAlreadyPlayed' });
+});
+
+test('it cannot refund a cartridge of a game without refunds', async (t) => {
+  // Given a game without a refund window and a cartridge.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+
+  // When the owner tries to refund it.
+  const promise = refundCartridgeV1(umi, {
+    cartridge,
+    game,
+    owner: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'RefundsDisabled' });
+});
+
+test('it refunds under the terms the cartridge was printed with', async (t) => {
+  // Given a game without refunds and a cartridge.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+
+  // And the publisher opening refunds after the print.
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    refundWindow: some(3600),
+  }).sendAndConfirm(umi);
+
+  // When the owner tries to refund it.
+  const promise = refundCartridgeV1(umi, {
+    cartridge,
+    game,
+    owner: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'RefundsDisabled' });
+});
+
+test('it cannot refund a lent cartridge', async (t) => {
+  // Given a refundable cartridge lent to a borrower.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupRefundableGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+  const borrower = generateSigner(umi);
+  await umi.rpc.airdrop(borrower.publicKey, sol(1));
+  await lendCartridgeV1(umi, {
+    cartridge,
+    game,
+    lender: umi.identity,
+    borrower: borrower.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    duration: 86400,
+  }).sendAndConfirm(umi);
+
+  // When the borrower tries to refund it.
+  const promise = refundCartridgeV1(umi, {
+    cartridge,
+    game,
+    owner: borrower,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CartridgeOnLoan' });
+});
@@ -1,4 +1,4 @@
-import { publicKey } from '@metaplex-foundation/umi';
+import { defaultPublicKey, none, publicKey } from '@metaplex-foundation/umi';
 import test from 'ava';
 import {
   CollectionV1,
@@ -17,9 +17,10 @@ import {
   getGameCollectionDataSerializer,
   PAYMENT_TOKEN_MINT,
   PriceType,
+  pricingModel,
   releaseGameV1,
 } from '../src';
-import { createUmi } from './_setup';
+import { createUmi, releaseV1Accounts } from './_setup';
 
 test('it can release a new game', async (t) => {
   // Given a Umi instance and a new signer.
@@ -37,7 +38,9 @@ test('it can release a new game', async (t) => {
     uri: 'https://test-game.com',
     priceType: PriceType.Transfer,
     price: 100,
-  }).sendAndConfirm(umi);
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, name))
+    .sendAndConfirm(umi);
 
   // Then an account was created with the correct data.
   const collectionData = await fetchCollection(umi, publicKey(game));
@@ -58,10 +61,25 @@ test('it can release a new game', async (t) => {
     },
   ]);
   const expectedData = getGameCollectionDataSerializer().serialize({
-    version: 0,
+    version: 1,
     priceType: PriceType.Transfer,
     price: 100,
     publisher: umi.identity.publicKey,
+    compatibleMachineCollections: [],
+    saleStart: none(),
+    saleEnd: none(),
+    pricing: pricingModel('Flat'),
+    allowlist: { merkleRoot: new Uint8Array(32), price: none() },
+    maxPerWallet: none(),
+    voucherSigner: defaultPublicKey(),
+    referralBps: 0,
+    baseGame: defaultPublicKey(),
+    saveAuthority: defaultPublicKey(),
+    refundWindow: none(),
+    rental: none(),
+    pass: defaultPublicKey(),
+    crowdfundDeadline: none(),
+    delisted: false,
   });
   t.like(collectionData.dataSections, <DataSectionPlugin[]>[
     {
@@ -1,7 +1,10 @@
-import { generateSigner, publicKey } from '@metaplex-foundation/umi';
+import {
+  defaultPublicKey,
+  generateSigner,
+  publicKey,
+} from '@metaplex-foundation/umi';
 import test from 'ava';
 import {
-  AppDataPlugin,
   AssetV1,
   createCollection,
   fetchAsset,
@@ -19,7 +22,14 @@ import {
   releaseGameV1,
   removeCartridgeV1,
 } from '../src';
-import { createUmi } from './_setup';
+import {
+  createUmi,
+  fetchMachineData,
+  printCartridge,
+  releaseV1Accounts,
+  setupGame,
+  setupMachine,
+} from './_setup';
 
 test('it can remove a cartridge from a machine', async (t) => {
   // Given a Umi instance and new signers.
@@ -59,7 +69,9 @@ test('it can remove a cartridge from a machine', async (t) => {
     uri: 'https://test-game.com',
     priceType: PriceType.Transfer,
     price: 0,
-  }).sendAndConfirm(umi);
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, gameName))
+    .sendAndConfirm(umi);
 
   const [, collectionBump] = findGamePda(umi, {
     name: gameName,
@@ -93,18 +105,13 @@ test('it can remove a cartridge from a machine', async (t) => {
     {
       authority: { type: 'UpdateAuthority' },
       dataAuthority: { type: 'UpdateAuthority' },
-      data: base58.serialize(cartridge.publicKey),
+      data: base58.serialize(publicKey(machine)),
     },
   ]);
 
-  const machineDataBefore = await fetchAsset(umi, publicKey(machine));
-  t.like(machineDataBefore.appDatas, <AppDataPlugin[]>[
-    {
-      authority: { type: 'UpdateAuthority' },
-      dataAuthority: { type: 'Address', address: publicKey(machine) },
-      data: base58.serialize(cartridge.publicKey),
-    },
-  ]);
+  t.like(await fetchMachineData(umi, publicKey(machine)), {
+    current: { cartridge: cartridge.publicKey },
+  });
 
   // When we remove the cartridge from the machine
   await removeCartridgeV1(umi, {
@@ -137,15 +144,20 @@ test('it can remove a cartridge from a machine', async (t) => {
     },
   ]);
 
-  // And the machine's appData should be empty
-  const machineDataAfter = await fetchAsset(umi, publicKey(machine));
-  t.like(machineDataAfter.appDatas, <AppDataPlugin[]>[
-    {
-      authority: { type: 'UpdateAuthority' },
-      dataAuthority: { type: 'Address', address: publicKey(machine) },
-      data: Uint8Array.from([]),
-    },
-  ]);
+  // And the machine's current slot should be empty
+  const machineDataAfter = await fetchMachineData(umi, publicKey(machine));
+  t.like(machineDataAfter, {
+    current: { cartridge: defaultPublicKey(), insertedAt: 0n },
+    historyHead: 1,
+  });
+
+  // And the insertion should have moved to the machine's history
+  t.like(machineDataAfter.history[0], {
+    cartridge: cartridge.publicKey,
+    game: publicKey(game),
+    inserter: umi.identity.publicKey,
+  });
+  t.true(machineDataAfter.history[0].removedAt > 0n);
 });
 
 test('it fails when cartridge owner does not sign', async (t) => {
@@ -181,7 +193,9 @@ test('it fails when cartridge owner does not sign', async (t) => {
     uri: 'https://test-game.com',
     priceType: PriceType.Transfer,
     price: 0,
-  }).sendAndConfirm(umi);
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, gameName))
+    .sendAndConfirm(umi);
 
   const [game, collectionBump] = findGamePda(umi, {
     name: gameName,
@@ -256,7 +270,9 @@ test('it fails when no cartridge is inserted', async (t) => {
     uri: 'https://test-game.com',
     priceType: PriceType.Transfer,
     price: 0,
-  }).sendAndConfirm(umi);
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, gameName))
+    .sendAndConfirm(umi);
 
   const [game, collectionBump] = findGamePda(umi, {
     name: gameName,
@@ -286,3 +302,38 @@ test('it fails when no cartridge is inserted', async (t) => {
   // Then it should fail
   await t.throwsAsync(promise, { name: 'CartridgeNotInserted' });
 });
+
+test('it fails when removing a cartridge the machine does not hold', async (t) => {
+  // Given a machine holding one of two cartridges.
+  const umi = await createUmi();
+  const { machine, machineCollection } = await setupMachine(umi);
+  const { game, gameBump } = await setupGame(umi);
+  const inserted = await printCartridge(umi, game, gameBump);
+  const other = await printCartridge(umi, game, gameBump);
+
+  await insertCartridgeV1(umi, {
+    cartridge: inserted,
+    game,
+    cartridgeOwner: umi.identity,
+    machine,
+    machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // When we try to remove the other cartridge from the machine.
+  const promise = removeCartridgeV1(umi, {
+    cartridge: other,
+    game,
+    cartridgeOwner: umi.identity,
+    machine,
+    machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it should fail.
+  await t.throwsAsync(promise, { name: 'MachineCartridgeMismatch' });
+});
@@ -4,12 +4,17 @@ import { join } from 'path';
 import {
   createSignerFromKeypair,
   generateSigner,
+  none,
   publicKey,
   Signer,
   Umi,
 } from '@metaplex-foundation/umi';
-import test from 'ava';
-import { AppDataPlugin, fetchAsset } from '@metaplex-foundation/mpl-core';
+import test, { ExecutionContext } from 'ava';
+import {
+  AppDataPlugin,
+  AssetV1,
+  fetchAsset,
+} from '@metaplex-foundation/mpl-core';
 import {
   findGamePda,
   getCartridgeDataSerializer,
@@ -19,7 +24,7 @@ import {
   setCartridgeSourceV1,
   Source,
 } from '../src';
-import { createUmi } from './_setup';
+import { createUmi, releaseV1Accounts } from './_setup';
 
 const loadSourceAuthority = (umi: Umi): Signer => {
   // ava compiles tests to dist/test/, so __dirname is dist/test/. The fixture
@@ -53,7 +58,9 @@ const releaseAndPrint = async (
     uri: 'https://test-game.com',
     priceType: PriceType.Burn,
     price: 0,
-  }).sendAndConfirm(umi);
+  })
+    .addRemainingAccounts(releaseV1Accounts(umi, gameName))
+    .sendAndConfirm(umi);
 
   await printGameCartridgeV1(umi, {
     game: publicKey(game),
@@ -66,19 +73,27 @@ const releaseAndPrint = async (
   return { cartridge, game: publicKey(game), gameBump };
 };
 
-const expectedAppData = (source: Source): AppDataPlugin[] => {
-  const data = getCartridgeDataSerializer().serialize({
-    version: 0,
-    source,
-  });
-  return <AppDataPlugin[]>[
+const expectCartridgeData = (
+  t: ExecutionContext,
+  asset: AssetV1,
+  source: Source
+) => {
+  t.like(asset.appDatas, <AppDataPlugin[]>[
     {
       type: 'AppData',
       authority: { type: 'UpdateAuthority' },
       dataAuthority: { type: 'UpdateAuthority' },
-      data,
     },
-  ];
+  ]);
+  const [data] = getCartridgeDataSerializer().deserialize(
+    asset.appDatas![0].data!
+  );
+  t.like(data, {
+    version: 1,
+    source,
+    referrer: none(),
+    baseCartridge: none(),
+  });
 };
 
 test('the Source authority can set the source to Crypto', async (t) => {
@@ -96,7 +111,7 @@ test('the Source authority can set the source to Crypto', async (t) => {
   }).sendAndConfirm(umi);
 
   const asset = await fetchAsset(umi, cartridge.publicKey);
-  t.like(asset.appDatas, expectedAppData(Source.Crypto));
+  expectCartridgeData(t, asset, Source.Crypto);
 });
 
 test('the Source authority can set the source to Stripe', async (t) => {
@@ -114,7 +129,7 @@ test('the Source authority can set the source to Stripe', async (t) => {
   }).sendAndConfirm(umi);
 
   const asset = await fetchAsset(umi, cartridge.publicKey);
-  t.like(asset.appDatas, expectedAppData(Source.Stripe));
+  expectCartridgeData(t, asset, Source.Stripe);
 });
 
 test('it rejects setting the source to Unknown', async (t) => {
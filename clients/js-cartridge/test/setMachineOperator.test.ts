@@ -0,0 +1,47 @@
+import {
+  defaultPublicKey,
+  generateSigner,
+  publicKey,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import { setMachineOperatorV1 } from '../src';
+import { createUmi, fetchMachineData, setupMachine } from './_setup';
+
+test('the machine owner can appoint an operator', async (t) => {
+  // Given a machine owned by the identity.
+  const umi = await createUmi();
+  const { machine, machineCollection } = await setupMachine(umi);
+  const operator = generateSigner(umi);
+
+  // When the owner appoints an operator.
+  await setMachineOperatorV1(umi, {
+    machine,
+    machineCollection,
+    machineOwner: umi.identity,
+    operator: operator.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the operator is recorded in the machine AppData.
+  t.like(await fetchMachineData(umi, publicKey(machine)), {
+    operator: operator.publicKey,
+    current: { cartridge: defaultPublicKey() },
+  });
+});
+
+test('someone else cannot appoint an operator', async (t) => {
+  // Given a machine owned by the identity.
+  const umi = await createUmi();
+  const { machine, machineCollection } = await setupMachine(umi);
+  const intruder = generateSigner(umi);
+
+  // When another wallet tries to appoint itself as the operator.
+  const promise = setMachineOperatorV1(umi, {
+    machine,
+    machineCollection,
+    machineOwner: intruder,
+    operator: intruder.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then it should fail.
+  await t.throwsAsync(promise, { name: 'MachineOwnerMustSign' });
+});
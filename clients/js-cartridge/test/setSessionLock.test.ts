@@ -0,0 +1,103 @@
+import {
+  defaultPublicKey,
+  generateSigner,
+  none,
+  publicKey,
+  sol,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  insertCartridgeV1,
+  removeCartridgeV1,
+  removeCartridgeV2,
+  setSessionLockV1,
+} from '../src';
+import {
+  createUmi,
+  fetchMachineData,
+  printCartridge,
+  setupGame,
+  setupMachine,
+} from './_setup';
+
+test('a locked session needs the machine owner to co-sign removal', async (t) => {
+  // Given a machine owned by the identity with a player's cartridge inserted.
+  const umi = await createUmi();
+  const player = generateSigner(umi);
+  await umi.rpc.airdrop(player.publicKey, sol(1));
+  const { machine, machineCollection } = await setupMachine(umi);
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump, player.publicKey);
+
+  await insertCartridgeV1(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: player,
+    machine,
+    machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // When the machine owner locks the session without expiry.
+  await setSessionLockV1(umi, {
+    machine,
+    machineCollection,
+    authority: umi.identity,
+    locked: true,
+    expiresAt: none(),
+  }).sendAndConfirm(umi);
+  t.like(await fetchMachineData(umi, publicKey(machine)), {
+    sessionLock: { locked: true, expiresAt: 0n },
+  });
+
+  // Then the player cannot remove the cartridge alone.
+  const promise = removeCartridgeV1(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: player,
+    machine,
+    machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(promise, { name: 'SessionLocked' });
+
+  // But can with the machine owner co-signing, which ends the session.
+  await removeCartridgeV2(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: player,
+    machine,
+    machineCollection,
+    machineOwner: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+  t.like(await fetchMachineData(umi, publicKey(machine)), {
+    current: { cartridge: defaultPublicKey() },
+    sessionLock: { locked: false },
+  });
+});
+
+test('a stranger cannot lock a session', async (t) => {
+  // Given a machine and a stranger.
+  const umi = await createUmi();
+  const stranger = generateSigner(umi);
+  await umi.rpc.airdrop(stranger.publicKey, sol(1));
+  const { machine, machineCollection } = await setupMachine(umi);
+
+  // When the stranger tries to lock the session.
+  const promise = setSessionLockV1(umi, {
+    machine,
+    machineCollection,
+    authority: stranger,
+    locked: true,
+    expiresAt: none(),
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidMachineOperator' });
+});
@@ -0,0 +1,169 @@
+/* eslint-disable import/no-extraneous-dependencies */
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import {
+  createSignerFromKeypair,
+  generateSigner,
+  Signer,
+  Umi,
+} from '@metaplex-foundation/umi';
+import { MPL_CORE_PROGRAM_ID } from '@metaplex-foundation/mpl-core';
+import test from 'ava';
+import {
+  airdropCartridgesV1,
+  findVerificationPda,
+  getVerificationSerializer,
+  printGameCartridgeV2,
+  setVerificationV1,
+  VerificationStatus,
+} from '../src';
+import { createUmi, setupGame } from './_setup';
+
+const loadVerifierAuthority = (umi: Umi): Signer => {
+  // On localnet the verifier authority is the same fixture keypair as the
+  // source authority.
+  const path = join(
+    __dirname,
+    '..',
+    '..',
+    'test',
+    'fixtures',
+    'source-authority.json'
+  );
+  const secret = new Uint8Array(JSON.parse(readFileSync(path, 'utf-8')));
+  const keypair = umi.eddsa.createKeypairFromSecretKey(secret);
+  return createSignerFromKeypair(umi, keypair);
+};
+
+test('the verifier authority can verify a publisher', async (t) => {
+  // Given a publisher.
+  const umi = await createUmi();
+  const publisher = generateSigner(umi).publicKey;
+
+  // When the verifier authority verifies it.
+  await setVerificationV1(umi, {
+    subject: publisher,
+    authority: loadVerifierAuthority(umi),
+    status: VerificationStatus.Verified,
+  }).sendAndConfirm(umi);
+
+  // Then the flag is recorded.
+  const [verification] = findVerificationPda(umi, { subject: publisher });
+  const account = await umi.rpc.getAccount(verification);
+  t.true(account.exists);
+  if (account.exists) {
+    t.like(getVerificationSerializer().deserialize(account.data)[0], {
+      subject: publisher,
+      status: VerificationStatus.Verified,
+    });
+  }
+});
+
+test('only the verifier authority can set verification flags', async (t) => {
+  // Given a game.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+
+  // When someone else tries to verify it.
+  const promise = setVerificationV1(umi, {
+    subject: game,
+    authority: umi.identity,
+    status: VerificationStatus.Verified,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidVerifierAuthority' });
+});
+
+test('a delisted game cannot be printed', async (t) => {
+  // Given a game delisted by the verifier authority.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  await setVerificationV1(umi, {
+    subject: game,
+    authority: loadVerifierAuthority(umi),
+    status: VerificationStatus.Delisted,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+  }).sendAndConfirm(umi);
+
+  // When we print a cartridge of the game.
+  const promise = printGameCartridgeV2(umi, {
+    cartridge: generateSigner(umi),
+    game,
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'GameDelisted' });
+
+  // But it can be printed again once the delisting is reverted.
+  await setVerificationV1(umi, {
+    subject: game,
+    authority: loadVerifierAuthority(umi),
+    status: VerificationStatus.Unverified,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+  }).sendAndConfirm(umi);
+  await printGameCartridgeV2(umi, {
+    cartridge: generateSigner(umi),
+    game,
+    owner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+  t.pass();
+});
+
+test('a delisted game cannot be airdropped either', async (t) => {
+  // Given a game delisted by the verifier authority.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  await setVerificationV1(umi, {
+    subject: game,
+    authority: loadVerifierAuthority(umi),
+    status: VerificationStatus.Delisted,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+  }).sendAndConfirm(umi);
+
+  // When the publisher airdrops a cartridge of the game.
+  const promise = airdropCartridgesV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  })
+    .addRemainingAccounts([
+      { pubkey: umi.identity.publicKey, isSigner: false, isWritable: false },
+      { signer: generateSigner(umi), isWritable: true },
+    ])
+    .sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'GameDelisted' });
+});
+
+test('a game cannot be delisted without updating its game data', async (t) => {
+  // Given a game.
+  const umi = await createUmi();
+  const { game } = await setupGame(umi);
+
+  // When the verifier authority delists it without the mpl core program.
+  const promise = setVerificationV1(umi, {
+    subject: game,
+    authority: loadVerifierAuthority(umi),
+    status: VerificationStatus.Delisted,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidMplCoreProgram' });
+});
@@ -0,0 +1,143 @@
+import { generateSigner, publicKey, some, sol } from '@metaplex-foundation/umi';
+import test from 'ava';
+import { fetchCollection } from '@metaplex-foundation/mpl-core';
+import {
+  getGameCollectionDataSerializer,
+  insertCartridgeV1,
+  pricingModel,
+  updateGameV1,
+} from '../src';
+import {
+  createUmi,
+  fetchMachineData,
+  printCartridge,
+  setupGame,
+  setupMachine,
+} from './_setup';
+
+test('the publisher can restrict a game to machine collections', async (t) => {
+  // Given a game and two machines from different collections.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  const certified = await setupMachine(umi);
+  const other = await setupMachine(umi);
+
+  // When the publisher only allows the certified collection.
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    compatibleMachineCollections: some([certified.machineCollection]),
+  }).sendAndConfirm(umi);
+
+  // Then the list is recorded in the game data.
+  const collection = await fetchCollection(umi, publicKey(game));
+  const [gameData] = getGameCollectionDataSerializer().deserialize(
+    collection.dataSections![0].data!
+  );
+  t.deepEqual(gameData.compatibleMachineCollections, [
+    certified.machineCollection,
+  ]);
+
+  // And a cartridge cannot be inserted into the other machine.
+  const cartridge = await printCartridge(umi, game, gameBump);
+  const promise = insertCartridgeV1(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: umi.identity,
+    machine: other.machine,
+    machineCollection: other.machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(promise, { name: 'IncompatibleMachine' });
+
+  // But it can be inserted into the certified machine.
+  await insertCartridgeV1(umi, {
+    cartridge,
+    game,
+    cartridgeOwner: umi.identity,
+    machine: certified.machine,
+    machineCollection: certified.machineCollection,
+    machineOwner: umi.identity.publicKey,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+  }).sendAndConfirm(umi);
+  t.like(await fetchMachineData(umi, publicKey(certified.machine)), {
+    current: { cartridge },
+  });
+});
+
+test('only the publisher can update a game', async (t) => {
+  // Given a game and a stranger.
+  const umi = await createUmi();
+  const stranger = generateSigner(umi);
+  await umi.rpc.airdrop(stranger.publicKey, sol(1));
+  const { game, gameBump } = await setupGame(umi);
+
+  // When the stranger tries to update the game.
+  const promise = updateGameV1(umi, {
+    game,
+    publisher: stranger,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    compatibleMachineCollections: some([]),
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'PublisherMustSign' });
+});
+
+test('the publisher can set a pricing curve', async (t) => {
+  // Given a game.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+
+  // When the publisher sets a supply-stepped price.
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    pricing: some(
+      pricingModel('SupplyStepped', {
+        basePrice: 0,
+        stepSize: 10,
+        stepIncrement: 5,
+      })
+    ),
+  }).sendAndConfirm(umi);
+
+  // Then the curve is recorded in the game data.
+  const collection = await fetchCollection(umi, publicKey(game));
+  const [gameData] = getGameCollectionDataSerializer().deserialize(
+    collection.dataSections![0].data!
+  );
+  t.deepEqual(
+    gameData.pricing,
+    pricingModel('SupplyStepped', {
+      basePrice: 0n,
+      stepSize: 10,
+      stepIncrement: 5n,
+    })
+  );
+
+  // And a decay that rises in price is rejected.
+  const promise = updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    pricing: some(
+      pricingModel('LinearDecay', {
+        startPrice: 1,
+        endPrice: 2,
+        startTime: 0,
+        duration: 3600,
+      })
+    ),
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(promise, { name: 'InvalidPricingModel' });
+});
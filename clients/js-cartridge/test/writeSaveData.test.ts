@@ -0,0 +1,81 @@
+import { generateSigner, some } from '@metaplex-foundation/umi';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import test from 'ava';
+import {
+  getSaveDataSerializer,
+  updateGameV1,
+  writeSaveDataV1,
+} from '../src';
+import { createUmi, printCartridge, setupGame } from './_setup';
+
+test('the save authority can write save data to a cartridge', async (t) => {
+  // Given a game with a save authority and a cartridge.
+  const umi = await createUmi();
+  const server = generateSigner(umi);
+  const { game, gameBump } = await setupGame(umi);
+  await updateGameV1(umi, {
+    game,
+    publisher: umi.identity,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    saveAuthority: some(server.publicKey),
+  }).sendAndConfirm(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+
+  // When the server writes two saves in order.
+  for (const revision of [1, 2]) {
+    // eslint-disable-next-line no-await-in-loop
+    await writeSaveDataV1(umi, {
+      cartridge,
+      game,
+      saveAuthority: server,
+      collectionNonce: 0,
+      collectionBump: gameBump,
+      revision,
+      data: new Uint8Array([revision, 42]),
+    }).sendAndConfirm(umi);
+  }
+
+  // Then the latest save is stored in its own AppData plugin.
+  const asset = await fetchAsset(umi, cartridge);
+  const saveData = asset.appDatas!.find(
+    (appData) =>
+      appData.dataAuthority.type === 'Address' &&
+      appData.dataAuthority.address === server.publicKey
+  );
+  const [save] = getSaveDataSerializer().deserialize(saveData!.data!);
+  t.like(save, { revision: 2n, data: new Uint8Array([2, 42]) });
+
+  // And replaying an old revision fails.
+  const replay = writeSaveDataV1(umi, {
+    cartridge,
+    game,
+    saveAuthority: server,
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    revision: 2,
+    data: new Uint8Array([0]),
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(replay, { name: 'StaleSaveRevision' });
+});
+
+test('only the save authority can write save data', async (t) => {
+  // Given a game without a save authority and a cartridge.
+  const umi = await createUmi();
+  const { game, gameBump } = await setupGame(umi);
+  const cartridge = await printCartridge(umi, game, gameBump);
+
+  // When someone tries to write save data.
+  const promise = writeSaveDataV1(umi, {
+    cartridge,
+    game,
+    saveAuthority: generateSigner(umi),
+    collectionNonce: 0,
+    collectionBump: gameBump,
+    revision: 1,
+    data: new Uint8Array([1]),
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'SaveDataDisabled' });
+});
@@ -85,6 +85,315 @@ pub enum BglCartridgeError {
     /// 24 (0x18) - Cartridge source has already been set and cannot be changed
     #[error("Cartridge source has already been set and cannot be changed")]
     SourceAlreadySet,
+    /// 25 (0x19) - Machine Owner must sign
+    #[error("Machine Owner must sign")]
+    MachineOwnerMustSign,
+    /// 26 (0x1A) - Signer is not the operator delegate of the machine
+    #[error("Signer is not the operator delegate of the machine")]
+    InvalidMachineOperator,
+    /// 27 (0x1B) - Operators can only handle cartridges owned by the machine owner
+    #[error("Operators can only handle cartridges owned by the machine owner")]
+    CartridgeNotInMachineOwnerCustody,
+    /// 28 (0x1C) - The machine does not hold the provided cartridge
+    #[error("The machine does not hold the provided cartridge")]
+    MachineCartridgeMismatch,
+    /// 29 (0x1D) - The cartridge is already inserted into a machine
+    #[error("The cartridge is already inserted into a machine")]
+    CartridgeAlreadyLinked,
+    /// 30 (0x1E) - The cartridge is not linked to the provided machine
+    #[error("The cartridge is not linked to the provided machine")]
+    CartridgeMachineMismatch,
+    /// 31 (0x1F) - The game cannot be played on machines of this collection
+    #[error("The game cannot be played on machines of this collection")]
+    IncompatibleMachine,
+    /// 32 (0x20) - The game publisher must sign
+    #[error("The game publisher must sign")]
+    PublisherMustSign,
+    /// 33 (0x21) - Too many compatible machine collections
+    #[error("Too many compatible machine collections")]
+    TooManyMachineCollections,
+    /// 34 (0x22) - The machine session is locked, the machine owner must co-sign the removal
+    #[error("The machine session is locked, the machine owner must co-sign the removal")]
+    SessionLocked,
+    /// 35 (0x23) - The session lock expiry must be in the future
+    #[error("The session lock expiry must be in the future")]
+    InvalidSessionExpiry,
+    /// 36 (0x24) - The game is not on sale yet
+    #[error("The game is not on sale yet")]
+    SaleNotStarted,
+    /// 37 (0x25) - The game is no longer on sale
+    #[error("The game is no longer on sale")]
+    SaleEnded,
+    /// 38 (0x26) - The sale must end after it starts and cannot start in the past
+    #[error("The sale must end after it starts and cannot start in the past")]
+    InvalidSaleWindow,
+    /// 39 (0x27) - The sale window cannot be changed once the sale has started
+    #[error("The sale window cannot be changed once the sale has started")]
+    SaleAlreadyStarted,
+    /// 40 (0x28) - Invalid pricing model parameters
+    #[error("Invalid pricing model parameters")]
+    InvalidPricingModel,
+    /// 41 (0x29) - Numerical overflow
+    #[error("Numerical overflow")]
+    NumericalOverflow,
+    /// 42 (0x2A) - The wallet is not on the game allowlist
+    #[error("The wallet is not on the game allowlist")]
+    NotAllowlisted,
+    /// 43 (0x2B) - The wallet has used up its allowlist quota
+    #[error("The wallet has used up its allowlist quota")]
+    AllowlistQuotaExceeded,
+    /// 44 (0x2C) - Invalid allowlist receipt account
+    #[error("Invalid allowlist receipt account")]
+    InvalidAllowlistReceipt,
+    /// 45 (0x2D) - The owner has reached the per-wallet print limit of this game
+    #[error("The owner has reached the per-wallet print limit of this game")]
+    WalletLimitReached,
+    /// 46 (0x2E) - Invalid mint counter account
+    #[error("Invalid mint counter account")]
+    InvalidMintCounter,
+    /// 47 (0x2F) - The game does not accept vouchers
+    #[error("The game does not accept vouchers")]
+    VouchersDisabled,
+    /// 48 (0x30) - The voucher is not signed by the game voucher signer
+    #[error("The voucher is not signed by the game voucher signer")]
+    InvalidVoucherSignature,
+    /// 49 (0x31) - The voucher has expired
+    #[error("The voucher has expired")]
+    VoucherExpired,
+    /// 50 (0x32) - The voucher has already been used
+    #[error("The voucher has already been used")]
+    VoucherAlreadyUsed,
+    /// 51 (0x33) - Invalid voucher record account
+    #[error("Invalid voucher record account")]
+    InvalidVoucherRecord,
+    /// 52 (0x34) - Invalid instructions sysvar
+    #[error("Invalid instructions sysvar")]
+    InvalidInstructionsSysvar,
+    /// 53 (0x35) - Referral rate cannot exceed 2000 basis points
+    #[error("Referral rate cannot exceed 2000 basis points")]
+    InvalidReferralRate,
+    /// 54 (0x36) - Invalid referrer token account program owner
+    #[error("Invalid referrer token account program owner")]
+    InvalidReferrerTokenAccountProgramOwner,
+    /// 55 (0x37) - Invalid referrer token account mint
+    #[error("Invalid referrer token account mint")]
+    InvalidReferrerTokenAccountMint,
+    /// 56 (0x38) - A print cannot be referred by its payer or owner
+    #[error("A print cannot be referred by its payer or owner")]
+    SelfReferral,
+    /// 57 (0x39) - Invalid bundle account
+    #[error("Invalid bundle account")]
+    InvalidBundle,
+    /// 58 (0x3A) - A bundle needs between 2 and 8 distinct games
+    #[error("A bundle needs between 2 and 8 distinct games")]
+    InvalidBundleGames,
+    /// 59 (0x3B) - Bundle shares must add up to 10000 basis points
+    #[error("Bundle shares must add up to 10000 basis points")]
+    InvalidBundleShares,
+    /// 60 (0x3C) - The game accounts do not match the bundle
+    #[error("The game accounts do not match the bundle")]
+    BundleGameMismatch,
+    /// 61 (0x3D) - Cartridges of a DLC can only be printed against a base cartridge
+    #[error("Cartridges of a DLC can only be printed against a base cartridge")]
+    BaseCartridgeRequired,
+    /// 62 (0x3E) - A DLC must extend another released game of the same publisher
+    #[error("A DLC must extend another released game of the same publisher")]
+    InvalidBaseGame,
+    /// 63 (0x3F) - The game is already registered as a DLC
+    #[error("The game is already registered as a DLC")]
+    DlcAlreadyRegistered,
+    /// 64 (0x40) - The game is not a DLC
+    #[error("The game is not a DLC")]
+    NotADlc,
+    /// 65 (0x41) - The base cartridge is not a cartridge of the base game held by the owner
+    #[error("The base cartridge is not a cartridge of the base game held by the owner")]
+    InvalidBaseCartridge,
+    /// 66 (0x42) - The game has no save authority
+    #[error("The game has no save authority")]
+    SaveDataDisabled,
+    /// 67 (0x43) - Only the save authority of the game can write save data
+    #[error("Only the save authority of the game can write save data")]
+    InvalidSaveAuthority,
+    /// 68 (0x44) - Save data exceeds the maximum size
+    #[error("Save data exceeds the maximum size")]
+    SaveDataTooLarge,
+    /// 69 (0x45) - The save revision must follow the current one
+    #[error("The save revision must follow the current one")]
+    StaleSaveRevision,
+    /// 70 (0x46) - The cartridge does not belong to the game
+    #[error("The cartridge does not belong to the game")]
+    InvalidCartridge,
+    /// 71 (0x47) - Refund window cannot be negative
+    #[error("Refund window cannot be negative")]
+    InvalidRefundWindow,
+    /// 72 (0x48) - The game does not offer refunds
+    #[error("The game does not offer refunds")]
+    RefundsDisabled,
+    /// 73 (0x49) - The refund window of the cartridge has closed
+    #[error("The refund window of the cartridge has closed")]
+    RefundWindowClosed,
+    /// 74 (0x4A) - Cartridges that have been inserted into a machine cannot be refunded
+    #[error("Cartridges that have been inserted into a machine cannot be refunded")]
+    CartridgeAlreadyPlayed,
+    /// 75 (0x4B) - Invalid listing account
+    #[error("Invalid listing account")]
+    InvalidListing,
+    /// 76 (0x4C) - The token account does not hold the payment token of the seller
+    #[error("The token account does not hold the payment token of the seller")]
+    InvalidSellerTokenAccount,
+    /// 77 (0x4D) - The token accounts do not hold the payment token of the game creators
+    #[error("The token accounts do not hold the payment token of the game creators")]
+    InvalidCreatorTokenAccount,
+    /// 78 (0x4E) - Listing price must be greater than zero
+    #[error("Listing price must be greater than zero")]
+    InvalidListingPrice,
+    /// 79 (0x4F) - The listing price differs from the price the buyer agreed to
+    #[error("The listing price differs from the price the buyer agreed to")]
+    ListingPriceChanged,
+    /// 80 (0x50) - Invalid loan account
+    #[error("Invalid loan account")]
+    InvalidLoan,
+    /// 81 (0x51) - Loan duration must be greater than zero
+    #[error("Loan duration must be greater than zero")]
+    InvalidLoanDuration,
+    /// 82 (0x52) - A cartridge cannot be lent to its owner
+    #[error("A cartridge cannot be lent to its owner")]
+    InvalidBorrower,
+    /// 83 (0x53) - The cartridge is on loan
+    #[error("The cartridge is on loan")]
+    CartridgeOnLoan,
+    /// 84 (0x54) - Only the borrower can return a cartridge before the loan expires
+    #[error("Only the borrower can return a cartridge before the loan expires")]
+    LoanNotExpired,
+    /// 85 (0x55) - Rental duration cannot be negative
+    #[error("Rental duration cannot be negative")]
+    InvalidRentalTerms,
+    /// 86 (0x56) - The game does not offer rentals
+    #[error("The game does not offer rentals")]
+    RentalsDisabled,
+    /// 87 (0x57) - The rental cartridge has expired
+    #[error("The rental cartridge has expired")]
+    CartridgeExpired,
+    /// 88 (0x58) - Only expired rental cartridges can be reclaimed
+    #[error("Only expired rental cartridges can be reclaimed")]
+    CartridgeNotExpired,
+    /// 89 (0x59) - Invalid rental authority PDA derivation
+    #[error("Invalid rental authority PDA derivation")]
+    InvalidRentalAuthority,
+    /// 90 (0x5A) - The rent payer does not match the one recorded on the cartridge
+    #[error("The rent payer does not match the one recorded on the cartridge")]
+    InvalidRentPayer,
+    /// 91 (0x5B) - Rental cartridges cannot be lent or sold
+    #[error("Rental cartridges cannot be lent or sold")]
+    RentalCartridge,
+    /// 92 (0x5C) - The collection is not a pass collection of this program
+    #[error("The collection is not a pass collection of this program")]
+    InvalidPassCollection,
+    /// 93 (0x5D) - A pass must last for a positive duration
+    #[error("A pass must last for a positive duration")]
+    InvalidPassTerms,
+    /// 94 (0x5E) - The asset is not a pass of the pass collection
+    #[error("The asset is not a pass of the pass collection")]
+    InvalidPass,
+    /// 95 (0x5F) - The pass has expired
+    #[error("The pass has expired")]
+    PassExpired,
+    /// 96 (0x60) - The game does not accept passes of this collection
+    #[error("The game does not accept passes of this collection")]
+    GameNotInPass,
+    /// 97 (0x61) - A crowdfund needs a goal and a deadline in the future
+    #[error("A crowdfund needs a goal and a deadline in the future")]
+    InvalidCrowdfundTerms,
+    /// 98 (0x62) - Invalid escrow PDA derivation
+    #[error("Invalid escrow PDA derivation")]
+    InvalidEscrow,
+    /// 99 (0x63) - Invalid pre-order PDA derivation
+    #[error("Invalid pre-order PDA derivation")]
+    InvalidPreOrder,
+    /// 100 (0x64) - At least one cartridge must be pre-ordered
+    #[error("At least one cartridge must be pre-ordered")]
+    InvalidPreOrderQuantity,
+    /// 101 (0x65) - The crowdfund deadline has passed
+    #[error("The crowdfund deadline has passed")]
+    CrowdfundClosed,
+    /// 102 (0x66) - The crowdfund has not met its goal
+    #[error("The crowdfund has not met its goal")]
+    CrowdfundNotFunded,
+    /// 103 (0x67) - Pre-orders can only be refunded once the crowdfund has missed its goal
+    #[error("Pre-orders can only be refunded once the crowdfund has missed its goal")]
+    CrowdfundNotFailed,
+    /// 104 (0x68) - Invalid publisher team account
+    #[error("Invalid publisher team account")]
+    InvalidPublisherTeam,
+    /// 105 (0x69) - A publisher team needs between 1 and 8 distinct members
+    #[error("A publisher team needs between 1 and 8 distinct members")]
+    InvalidTeamMembers,
+    /// 106 (0x6A) - Team shares must add up to 10000 basis points
+    #[error("Team shares must add up to 10000 basis points")]
+    InvalidTeamShares,
+    /// 107 (0x6B) - The team threshold must be between 1 and the number of members
+    #[error("The team threshold must be between 1 and the number of members")]
+    InvalidTeamThreshold,
+    /// 108 (0x6C) - Not enough team members approved the action
+    #[error("Not enough team members approved the action")]
+    TeamThresholdNotMet,
+    /// 109 (0x6D) - The token account does not belong to the revenue recipient
+    #[error("The token account does not belong to the revenue recipient")]
+    InvalidRevenueTokenAccount,
+    /// 110 (0x6E) - Only the verifier authority can manage the verification and name registries
+    #[error("Only the verifier authority can manage the verification and name registries")]
+    InvalidVerifierAuthority,
+    /// 111 (0x6F) - Invalid verification PDA derivation
+    #[error("Invalid verification PDA derivation")]
+    InvalidVerification,
+    /// 112 (0x70) - Invalid verification status
+    #[error("Invalid verification status")]
+    InvalidVerificationStatus,
+    /// 113 (0x71) - The game has been delisted
+    #[error("The game has been delisted")]
+    GameDelisted,
+    /// 114 (0x72) - Invalid name record account
+    #[error("Invalid name record account")]
+    InvalidNameRecord,
+    /// 115 (0x73) - The game name is held by another publisher
+    #[error("The game name is held by another publisher")]
+    NameTaken,
+    /// 116 (0x74) - Invalid catalog PDA derivation
+    #[error("Invalid catalog PDA derivation")]
+    InvalidCatalog,
+    /// 117 (0x75) - The game is not in the catalog
+    #[error("The game is not in the catalog")]
+    CatalogEntryNotFound,
+    /// 118 (0x76) - The cartridge price is above the most the payer accepts
+    #[error("The cartridge price is above the most the payer accepts")]
+    PriceAboveMax,
+    /// 119 (0x77) - The game limits prints per wallet and needs the mint counter of the owner
+    #[error("The game limits prints per wallet and needs the mint counter of the owner")]
+    MintCounterRequired,
+    /// 120 (0x78) - Invalid DLC receipt PDA derivation
+    #[error("Invalid DLC receipt PDA derivation")]
+    InvalidDlcReceipt,
+    /// 121 (0x79) - The base cartridge already has this DLC
+    #[error("The base cartridge already has this DLC")]
+    DlcAlreadyPrinted,
+    /// 122 (0x7A) - DLC cartridges extend a base cartridge and cannot be inserted on their own
+    #[error("DLC cartridges extend a base cartridge and cannot be inserted on their own")]
+    DlcNotPlayable,
+    /// 123 (0x7B) - A game with printed cartridges cannot become a DLC
+    #[error("A game with printed cartridges cannot become a DLC")]
+    GameAlreadyPrinted,
+    /// 124 (0x7C) - The pass authority must approve games joining the pass
+    #[error("The pass authority must approve games joining the pass")]
+    PassAuthorityMustSign,
+    /// 125 (0x7D) - The crowdfund of the game is still open
+    #[error("The crowdfund of the game is still open")]
+    CrowdfundActive,
+    /// 126 (0x7E) - The game is already listed in the catalog
+    #[error("The game is already listed in the catalog")]
+    CatalogEntryExists,
+    /// 127 (0x7F) - The game is not published by the publisher of the catalog
+    #[error("The game is not published by the publisher of the catalog")]
+    CatalogPublisherMismatch,
 }
 
 impl solana_program::program_error::PrintProgramError for BglCartridgeError {
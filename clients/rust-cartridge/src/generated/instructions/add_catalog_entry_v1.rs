@@ -0,0 +1,513 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct AddCatalogEntryV1 {
+    /// The catalog of the publisher
+    pub catalog: solana_program::pubkey::Pubkey,
+    /// The publisher of the game, or its team
+    pub publisher: (solana_program::pubkey::Pubkey, bool),
+    /// The game Collection account to list
+    pub game: solana_program::pubkey::Pubkey,
+    /// The account paying for the storage fees
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl AddCatalogEntryV1 {
+    pub fn instruction(
+        &self,
+        args: AddCatalogEntryV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: AddCatalogEntryV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.catalog,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.publisher.0,
+            self.publisher.1,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.game, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = AddCatalogEntryV1InstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct AddCatalogEntryV1InstructionData {
+    discriminator: u8,
+}
+
+impl AddCatalogEntryV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 50 }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddCatalogEntryV1InstructionArgs {
+    pub collection_nonce: u8,
+    pub collection_bump: u8,
+}
+
+/// Instruction builder for `AddCatalogEntryV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` catalog
+///   1. `[signer]` publisher
+///   2. `[]` game
+///   3. `[writable, signer]` payer
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct AddCatalogEntryV1Builder {
+    catalog: Option<solana_program::pubkey::Pubkey>,
+    publisher: Option<(solana_program::pubkey::Pubkey, bool)>,
+    game: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    collection_nonce: Option<u8>,
+    collection_bump: Option<u8>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AddCatalogEntryV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The catalog of the publisher
+    #[inline(always)]
+    pub fn catalog(&mut self, catalog: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.catalog = Some(catalog);
+        self
+    }
+    /// The publisher of the game, or its team
+    #[inline(always)]
+    pub fn publisher(
+        &mut self,
+        publisher: solana_program::pubkey::Pubkey,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.publisher = Some((publisher, as_signer));
+        self
+    }
+    /// The game Collection account to list
+    #[inline(always)]
+    pub fn game(&mut self, game: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.game = Some(game);
+        self
+    }
+    /// The account paying for the storage fees
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn collection_nonce(&mut self, collection_nonce: u8) -> &mut Self {
+        self.collection_nonce = Some(collection_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_bump(&mut self, collection_bump: u8) -> &mut Self {
+        self.collection_bump = Some(collection_bump);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AddCatalogEntryV1 {
+            catalog: self.catalog.expect("catalog is not set"),
+            publisher: self.publisher.expect("publisher is not set"),
+            game: self.game.expect("game is not set"),
+            payer: self.payer.expect("payer is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = AddCatalogEntryV1InstructionArgs {
+            collection_nonce: self.collection_nonce.clone().unwrap_or(0),
+            collection_bump: self
+                .collection_bump
+                .clone()
+                .expect("collection_bump is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `add_catalog_entry_v1` CPI accounts.
+pub struct AddCatalogEntryV1CpiAccounts<'a, 'b> {
+    /// The catalog of the publisher
+    pub catalog: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The publisher of the game, or its team
+    pub publisher: (&'b solana_program::account_info::AccountInfo<'a>, bool),
+    /// The game Collection account to list
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account paying for the storage fees
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `add_catalog_entry_v1` CPI instruction.
+pub struct AddCatalogEntryV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The catalog of the publisher
+    pub catalog: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The publisher of the game, or its team
+    pub publisher: (&'b solana_program::account_info::AccountInfo<'a>, bool),
+    /// The game Collection account to list
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account paying for the storage fees
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: AddCatalogEntryV1InstructionArgs,
+}
+
+impl<'a, 'b> AddCatalogEntryV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AddCatalogEntryV1CpiAccounts<'a, 'b>,
+        args: AddCatalogEntryV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            catalog: accounts.catalog,
+            publisher: accounts.publisher,
+            game: accounts.game,
+            payer: accounts.payer,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.catalog.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.publisher.0.key,
+            self.publisher.1,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.game.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = AddCatalogEntryV1InstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.catalog.clone());
+        account_infos.push(self.publisher.0.clone());
+        account_infos.push(self.game.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AddCatalogEntryV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` catalog
+///   1. `[signer]` publisher
+///   2. `[]` game
+///   3. `[writable, signer]` payer
+///   4. `[]` system_program
+pub struct AddCatalogEntryV1CpiBuilder<'a, 'b> {
+    instruction: Box<AddCatalogEntryV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AddCatalogEntryV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AddCatalogEntryV1CpiBuilderInstruction {
+            __program: program,
+            catalog: None,
+            publisher: None,
+            game: None,
+            payer: None,
+            system_program: None,
+            collection_nonce: None,
+            collection_bump: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The catalog of the publisher
+    #[inline(always)]
+    pub fn catalog(
+        &mut self,
+        catalog: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.catalog = Some(catalog);
+        self
+    }
+    /// The publisher of the game, or its team
+    #[inline(always)]
+    pub fn publisher(
+        &mut self,
+        publisher: &'b solana_program::account_info::AccountInfo<'a>,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.instruction.publisher = Some((publisher, as_signer));
+        self
+    }
+    /// The game Collection account to list
+    #[inline(always)]
+    pub fn game(&mut self, game: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.game = Some(game);
+        self
+    }
+    /// The account paying for the storage fees
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn collection_nonce(&mut self, collection_nonce: u8) -> &mut Self {
+        self.instruction.collection_nonce = Some(collection_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_bump(&mut self, collection_bump: u8) -> &mut Self {
+        self.instruction.collection_bump = Some(collection_bump);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = AddCatalogEntryV1InstructionArgs {
+            collection_nonce: self.instruction.collection_nonce.clone().unwrap_or(0),
+            collection_bump: self
+                .instruction
+                .collection_bump
+                .clone()
+                .expect("collection_bump is not set"),
+        };
+        let instruction = AddCatalogEntryV1Cpi {
+            __program: self.instruction.__program,
+
+            catalog: self.instruction.catalog.expect("catalog is not set"),
+
+            publisher: self.instruction.publisher.expect("publisher is not set"),
+
+            game: self.instruction.game.expect("game is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct AddCatalogEntryV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    catalog: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    publisher: Option<(&'b solana_program::account_info::AccountInfo<'a>, bool)>,
+    game: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection_nonce: Option<u8>,
+    collection_bump: Option<u8>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
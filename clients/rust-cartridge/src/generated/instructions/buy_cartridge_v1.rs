@@ -0,0 +1,751 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct BuyCartridgeV1 {
+    /// The game cartridge asset account
+    pub cartridge: solana_program::pubkey::Pubkey,
+    /// The game Collection account
+    pub game: solana_program::pubkey::Pubkey,
+    /// The listing of the cartridge
+    pub listing: solana_program::pubkey::Pubkey,
+    /// The seller of the cartridge
+    pub seller: solana_program::pubkey::Pubkey,
+    /// The token account receiving the sale proceeds
+    pub seller_token_account: solana_program::pubkey::Pubkey,
+    /// The buyer of the cartridge
+    pub buyer: solana_program::pubkey::Pubkey,
+    /// The token account paying for the cartridge
+    pub buyer_token_account: solana_program::pubkey::Pubkey,
+    /// The mpl core program
+    pub mpl_core_program: solana_program::pubkey::Pubkey,
+    /// The token program
+    pub token_program: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl BuyCartridgeV1 {
+    pub fn instruction(
+        &self,
+        args: BuyCartridgeV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: BuyCartridgeV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.cartridge,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.game, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.listing,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.seller,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.seller_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.buyer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.buyer_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mpl_core_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = BuyCartridgeV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct BuyCartridgeV1InstructionData {
+    discriminator: u8,
+}
+
+impl BuyCartridgeV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 21 }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuyCartridgeV1InstructionArgs {
+    pub collection_nonce: u8,
+    pub collection_bump: u8,
+    pub price: u64,
+}
+
+/// Instruction builder for `BuyCartridgeV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` cartridge
+///   1. `[writable]` game
+///   2. `[writable]` listing
+///   3. `[writable]` seller
+///   4. `[writable]` seller_token_account
+///   5. `[writable, signer]` buyer
+///   6. `[writable]` buyer_token_account
+///   7. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct BuyCartridgeV1Builder {
+    cartridge: Option<solana_program::pubkey::Pubkey>,
+    game: Option<solana_program::pubkey::Pubkey>,
+    listing: Option<solana_program::pubkey::Pubkey>,
+    seller: Option<solana_program::pubkey::Pubkey>,
+    seller_token_account: Option<solana_program::pubkey::Pubkey>,
+    buyer: Option<solana_program::pubkey::Pubkey>,
+    buyer_token_account: Option<solana_program::pubkey::Pubkey>,
+    mpl_core_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    collection_nonce: Option<u8>,
+    collection_bump: Option<u8>,
+    price: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl BuyCartridgeV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The game cartridge asset account
+    #[inline(always)]
+    pub fn cartridge(&mut self, cartridge: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.cartridge = Some(cartridge);
+        self
+    }
+    /// The game Collection account
+    #[inline(always)]
+    pub fn game(&mut self, game: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.game = Some(game);
+        self
+    }
+    /// The listing of the cartridge
+    #[inline(always)]
+    pub fn listing(&mut self, listing: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.listing = Some(listing);
+        self
+    }
+    /// The seller of the cartridge
+    #[inline(always)]
+    pub fn seller(&mut self, seller: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.seller = Some(seller);
+        self
+    }
+    /// The token account receiving the sale proceeds
+    #[inline(always)]
+    pub fn seller_token_account(
+        &mut self,
+        seller_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.seller_token_account = Some(seller_token_account);
+        self
+    }
+    /// The buyer of the cartridge
+    #[inline(always)]
+    pub fn buyer(&mut self, buyer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.buyer = Some(buyer);
+        self
+    }
+    /// The token account paying for the cartridge
+    #[inline(always)]
+    pub fn buyer_token_account(
+        &mut self,
+        buyer_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.buyer_token_account = Some(buyer_token_account);
+        self
+    }
+    /// `[optional account, default to 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d']`
+    /// The mpl core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// The token program
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_nonce(&mut self, collection_nonce: u8) -> &mut Self {
+        self.collection_nonce = Some(collection_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_bump(&mut self, collection_bump: u8) -> &mut Self {
+        self.collection_bump = Some(collection_bump);
+        self
+    }
+    #[inline(always)]
+    pub fn price(&mut self, price: u64) -> &mut Self {
+        self.price = Some(price);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = BuyCartridgeV1 {
+            cartridge: self.cartridge.expect("cartridge is not set"),
+            game: self.game.expect("game is not set"),
+            listing: self.listing.expect("listing is not set"),
+            seller: self.seller.expect("seller is not set"),
+            seller_token_account: self
+                .seller_token_account
+                .expect("seller_token_account is not set"),
+            buyer: self.buyer.expect("buyer is not set"),
+            buyer_token_account: self
+                .buyer_token_account
+                .expect("buyer_token_account is not set"),
+            mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
+                "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+            )),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = BuyCartridgeV1InstructionArgs {
+            collection_nonce: self
+                .collection_nonce
+                .clone()
+                .expect("collection_nonce is not set"),
+            collection_bump: self
+                .collection_bump
+                .clone()
+                .expect("collection_bump is not set"),
+            price: self.price.clone().expect("price is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `buy_cartridge_v1` CPI accounts.
+pub struct BuyCartridgeV1CpiAccounts<'a, 'b> {
+    /// The game cartridge asset account
+    pub cartridge: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The game Collection account
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The listing of the cartridge
+    pub listing: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The seller of the cartridge
+    pub seller: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account receiving the sale proceeds
+    pub seller_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The buyer of the cartridge
+    pub buyer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account paying for the cartridge
+    pub buyer_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `buy_cartridge_v1` CPI instruction.
+pub struct BuyCartridgeV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The game cartridge asset account
+    pub cartridge: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The game Collection account
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The listing of the cartridge
+    pub listing: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The seller of the cartridge
+    pub seller: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account receiving the sale proceeds
+    pub seller_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The buyer of the cartridge
+    pub buyer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account paying for the cartridge
+    pub buyer_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: BuyCartridgeV1InstructionArgs,
+}
+
+impl<'a, 'b> BuyCartridgeV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: BuyCartridgeV1CpiAccounts<'a, 'b>,
+        args: BuyCartridgeV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            cartridge: accounts.cartridge,
+            game: accounts.game,
+            listing: accounts.listing,
+            seller: accounts.seller,
+            seller_token_account: accounts.seller_token_account,
+            buyer: accounts.buyer,
+            buyer_token_account: accounts.buyer_token_account,
+            mpl_core_program: accounts.mpl_core_program,
+            token_program: accounts.token_program,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.cartridge.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.game.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.listing.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.seller.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.seller_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.buyer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.buyer_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mpl_core_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = BuyCartridgeV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.cartridge.clone());
+        account_infos.push(self.game.clone());
+        account_infos.push(self.listing.clone());
+        account_infos.push(self.seller.clone());
+        account_infos.push(self.seller_token_account.clone());
+        account_infos.push(self.buyer.clone());
+        account_infos.push(self.buyer_token_account.clone());
+        account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `BuyCartridgeV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` cartridge
+///   1. `[writable]` game
+///   2. `[writable]` listing
+///   3. `[writable]` seller
+///   4. `[writable]` seller_token_account
+///   5. `[writable, signer]` buyer
+///   6. `[writable]` buyer_token_account
+///   7. `[]` mpl_core_program
+///   8. `[]` token_program
+///   9. `[]` system_program
+pub struct BuyCartridgeV1CpiBuilder<'a, 'b> {
+    instruction: Box<BuyCartridgeV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> BuyCartridgeV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(BuyCartridgeV1CpiBuilderInstruction {
+            __program: program,
+            cartridge: None,
+            game: None,
+            listing: None,
+            seller: None,
+            seller_token_account: None,
+            buyer: None,
+            buyer_token_account: None,
+            mpl_core_program: None,
+            token_program: None,
+            system_program: None,
+            collection_nonce: None,
+            collection_bump: None,
+            price: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The game cartridge asset account
+    #[inline(always)]
+    pub fn cartridge(
+        &mut self,
+        cartridge: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.cartridge = Some(cartridge);
+        self
+    }
+    /// The game Collection account
+    #[inline(always)]
+    pub fn game(&mut self, game: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.game = Some(game);
+        self
+    }
+    /// The listing of the cartridge
+    #[inline(always)]
+    pub fn listing(
+        &mut self,
+        listing: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.listing = Some(listing);
+        self
+    }
+    /// The seller of the cartridge
+    #[inline(always)]
+    pub fn seller(
+        &mut self,
+        seller: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.seller = Some(seller);
+        self
+    }
+    /// The token account receiving the sale proceeds
+    #[inline(always)]
+    pub fn seller_token_account(
+        &mut self,
+        seller_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.seller_token_account = Some(seller_token_account);
+        self
+    }
+    /// The buyer of the cartridge
+    #[inline(always)]
+    pub fn buyer(&mut self, buyer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.buyer = Some(buyer);
+        self
+    }
+    /// The token account paying for the cartridge
+    #[inline(always)]
+    pub fn buyer_token_account(
+        &mut self,
+        buyer_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.buyer_token_account = Some(buyer_token_account);
+        self
+    }
+    /// The mpl core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// The token program
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_nonce(&mut self, collection_nonce: u8) -> &mut Self {
+        self.instruction.collection_nonce = Some(collection_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_bump(&mut self, collection_bump: u8) -> &mut Self {
+        self.instruction.collection_bump = Some(collection_bump);
+        self
+    }
+    #[inline(always)]
+    pub fn price(&mut self, price: u64) -> &mut Self {
+        self.instruction.price = Some(price);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = BuyCartridgeV1InstructionArgs {
+            collection_nonce: self
+                .instruction
+                .collection_nonce
+                .clone()
+                .expect("collection_nonce is not set"),
+            collection_bump: self
+                .instruction
+                .collection_bump
+                .clone()
+                .expect("collection_bump is not set"),
+            price: self.instruction.price.clone().expect("price is not set"),
+        };
+        let instruction = BuyCartridgeV1Cpi {
+            __program: self.instruction.__program,
+
+            cartridge: self.instruction.cartridge.expect("cartridge is not set"),
+
+            game: self.instruction.game.expect("game is not set"),
+
+            listing: self.instruction.listing.expect("listing is not set"),
+
+            seller: self.instruction.seller.expect("seller is not set"),
+
+            seller_token_account: self
+                .instruction
+                .seller_token_account
+                .expect("seller_token_account is not set"),
+
+            buyer: self.instruction.buyer.expect("buyer is not set"),
+
+            buyer_token_account: self
+                .instruction
+                .buyer_token_account
+                .expect("buyer_token_account is not set"),
+
+            mpl_core_program: self
+                .instruction
+                .mpl_core_program
+                .expect("mpl_core_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct BuyCartridgeV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    cartridge: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    game: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    listing: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    seller: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    seller_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    buyer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    buyer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection_nonce: Option<u8>,
+    collection_bump: Option<u8>,
+    price: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,649 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct InsertPassV1 {
+    /// The pass asset account
+    pub pass: solana_program::pubkey::Pubkey,
+    /// The pass collection account
+    pub pass_collection: solana_program::pubkey::Pubkey,
+    /// The game Collection account to play
+    pub game: solana_program::pubkey::Pubkey,
+    /// The owner of the pass
+    pub pass_owner: solana_program::pubkey::Pubkey,
+    /// The machine asset account
+    pub machine: solana_program::pubkey::Pubkey,
+    /// The Core collection of the machine
+    pub machine_collection: solana_program::pubkey::Pubkey,
+    /// The mpl core program
+    pub mpl_core_program: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl InsertPassV1 {
+    pub fn instruction(
+        &self,
+        args: InsertPassV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: InsertPassV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.pass, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.pass_collection,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.game, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.pass_owner,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.machine,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.machine_collection,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mpl_core_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = InsertPassV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct InsertPassV1InstructionData {
+    discriminator: u8,
+}
+
+impl InsertPassV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 30 }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsertPassV1InstructionArgs {
+    pub collection_nonce: u8,
+    pub collection_bump: u8,
+}
+
+/// Instruction builder for `InsertPassV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` pass
+///   1. `[writable]` pass_collection
+///   2. `[]` game
+///   3. `[writable, signer]` pass_owner
+///   4. `[writable]` machine
+///   5. `[writable]` machine_collection
+///   6. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct InsertPassV1Builder {
+    pass: Option<solana_program::pubkey::Pubkey>,
+    pass_collection: Option<solana_program::pubkey::Pubkey>,
+    game: Option<solana_program::pubkey::Pubkey>,
+    pass_owner: Option<solana_program::pubkey::Pubkey>,
+    machine: Option<solana_program::pubkey::Pubkey>,
+    machine_collection: Option<solana_program::pubkey::Pubkey>,
+    mpl_core_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    collection_nonce: Option<u8>,
+    collection_bump: Option<u8>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl InsertPassV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The pass asset account
+    #[inline(always)]
+    pub fn pass(&mut self, pass: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.pass = Some(pass);
+        self
+    }
+    /// The pass collection account
+    #[inline(always)]
+    pub fn pass_collection(
+        &mut self,
+        pass_collection: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.pass_collection = Some(pass_collection);
+        self
+    }
+    /// The game Collection account to play
+    #[inline(always)]
+    pub fn game(&mut self, game: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.game = Some(game);
+        self
+    }
+    /// The owner of the pass
+    #[inline(always)]
+    pub fn pass_owner(&mut self, pass_owner: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.pass_owner = Some(pass_owner);
+        self
+    }
+    /// The machine asset account
+    #[inline(always)]
+    pub fn machine(&mut self, machine: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.machine = Some(machine);
+        self
+    }
+    /// The Core collection of the machine
+    #[inline(always)]
+    pub fn machine_collection(
+        &mut self,
+        machine_collection: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.machine_collection = Some(machine_collection);
+        self
+    }
+    /// `[optional account, default to 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d']`
+    /// The mpl core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_nonce(&mut self, collection_nonce: u8) -> &mut Self {
+        self.collection_nonce = Some(collection_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_bump(&mut self, collection_bump: u8) -> &mut Self {
+        self.collection_bump = Some(collection_bump);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = InsertPassV1 {
+            pass: self.pass.expect("pass is not set"),
+            pass_collection: self.pass_collection.expect("pass_collection is not set"),
+            game: self.game.expect("game is not set"),
+            pass_owner: self.pass_owner.expect("pass_owner is not set"),
+            machine: self.machine.expect("machine is not set"),
+            machine_collection: self
+                .machine_collection
+                .expect("machine_collection is not set"),
+            mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
+                "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = InsertPassV1InstructionArgs {
+            collection_nonce: self
+                .collection_nonce
+                .clone()
+                .expect("collection_nonce is not set"),
+            collection_bump: self
+                .collection_bump
+                .clone()
+                .expect("collection_bump is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `insert_pass_v1` CPI accounts.
+pub struct InsertPassV1CpiAccounts<'a, 'b> {
+    /// The pass asset account
+    pub pass: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The pass collection account
+    pub pass_collection: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The game Collection account to play
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the pass
+    pub pass_owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The machine asset account
+    pub machine: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The Core collection of the machine
+    pub machine_collection: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `insert_pass_v1` CPI instruction.
+pub struct InsertPassV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The pass asset account
+    pub pass: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The pass collection account
+    pub pass_collection: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The game Collection account to play
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the pass
+    pub pass_owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The machine asset account
+    pub machine: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The Core collection of the machine
+    pub machine_collection: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: InsertPassV1InstructionArgs,
+}
+
+impl<'a, 'b> InsertPassV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: InsertPassV1CpiAccounts<'a, 'b>,
+        args: InsertPassV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            pass: accounts.pass,
+            pass_collection: accounts.pass_collection,
+            game: accounts.game,
+            pass_owner: accounts.pass_owner,
+            machine: accounts.machine,
+            machine_collection: accounts.machine_collection,
+            mpl_core_program: accounts.mpl_core_program,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.pass.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.pass_collection.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.game.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.pass_owner.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.machine.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.machine_collection.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mpl_core_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = InsertPassV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.pass.clone());
+        account_infos.push(self.pass_collection.clone());
+        account_infos.push(self.game.clone());
+        account_infos.push(self.pass_owner.clone());
+        account_infos.push(self.machine.clone());
+        account_infos.push(self.machine_collection.clone());
+        account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `InsertPassV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` pass
+///   1. `[writable]` pass_collection
+///   2. `[]` game
+///   3. `[writable, signer]` pass_owner
+///   4. `[writable]` machine
+///   5. `[writable]` machine_collection
+///   6. `[]` mpl_core_program
+///   7. `[]` system_program
+pub struct InsertPassV1CpiBuilder<'a, 'b> {
+    instruction: Box<InsertPassV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> InsertPassV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(InsertPassV1CpiBuilderInstruction {
+            __program: program,
+            pass: None,
+            pass_collection: None,
+            game: None,
+            pass_owner: None,
+            machine: None,
+            machine_collection: None,
+            mpl_core_program: None,
+            system_program: None,
+            collection_nonce: None,
+            collection_bump: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The pass asset account
+    #[inline(always)]
+    pub fn pass(&mut self, pass: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.pass = Some(pass);
+        self
+    }
+    /// The pass collection account
+    #[inline(always)]
+    pub fn pass_collection(
+        &mut self,
+        pass_collection: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pass_collection = Some(pass_collection);
+        self
+    }
+    /// The game Collection account to play
+    #[inline(always)]
+    pub fn game(&mut self, game: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.game = Some(game);
+        self
+    }
+    /// The owner of the pass
+    #[inline(always)]
+    pub fn pass_owner(
+        &mut self,
+        pass_owner: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pass_owner = Some(pass_owner);
+        self
+    }
+    /// The machine asset account
+    #[inline(always)]
+    pub fn machine(
+        &mut self,
+        machine: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.machine = Some(machine);
+        self
+    }
+    /// The Core collection of the machine
+    #[inline(always)]
+    pub fn machine_collection(
+        &mut self,
+        machine_collection: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.machine_collection = Some(machine_collection);
+        self
+    }
+    /// The mpl core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_nonce(&mut self, collection_nonce: u8) -> &mut Self {
+        self.instruction.collection_nonce = Some(collection_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_bump(&mut self, collection_bump: u8) -> &mut Self {
+        self.instruction.collection_bump = Some(collection_bump);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = InsertPassV1InstructionArgs {
+            collection_nonce: self
+                .instruction
+                .collection_nonce
+                .clone()
+                .expect("collection_nonce is not set"),
+            collection_bump: self
+                .instruction
+                .collection_bump
+                .clone()
+                .expect("collection_bump is not set"),
+        };
+        let instruction = InsertPassV1Cpi {
+            __program: self.instruction.__program,
+
+            pass: self.instruction.pass.expect("pass is not set"),
+
+            pass_collection: self
+                .instruction
+                .pass_collection
+                .expect("pass_collection is not set"),
+
+            game: self.instruction.game.expect("game is not set"),
+
+            pass_owner: self.instruction.pass_owner.expect("pass_owner is not set"),
+
+            machine: self.instruction.machine.expect("machine is not set"),
+
+            machine_collection: self
+                .instruction
+                .machine_collection
+                .expect("machine_collection is not set"),
+
+            mpl_core_program: self
+                .instruction
+                .mpl_core_program
+                .expect("mpl_core_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct InsertPassV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    pass: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pass_collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    game: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pass_owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    machine: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    machine_collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection_nonce: Option<u8>,
+    collection_bump: Option<u8>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
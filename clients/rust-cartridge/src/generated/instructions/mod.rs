@@ -5,16 +5,112 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#add_catalog_entry_v1;
+pub(crate) mod r#airdrop_cartridges_v1;
+pub(crate) mod r#buy_cartridge_v1;
+pub(crate) mod r#cancel_listing_v1;
+pub(crate) mod r#claim_escrow_v1;
+pub(crate) mod r#claim_name_v1;
+pub(crate) mod r#close_bundle_v1;
 pub(crate) mod r#commission_machine_v1;
+pub(crate) mod r#create_bundle_v1;
+pub(crate) mod r#create_pass_v1;
+pub(crate) mod r#create_publisher_team_v1;
+pub(crate) mod r#eject_cartridge_v1;
+pub(crate) mod r#get_price_v1;
 pub(crate) mod r#insert_cartridge_v1;
+pub(crate) mod r#insert_cartridge_v2;
+pub(crate) mod r#insert_pass_v1;
+pub(crate) mod r#lend_cartridge_v1;
+pub(crate) mod r#list_cartridge_v1;
+pub(crate) mod r#pre_order_v1;
+pub(crate) mod r#print_bundle_v1;
+pub(crate) mod r#print_dlc_v1;
+pub(crate) mod r#print_game_cartridge_allowlist_v1;
 pub(crate) mod r#print_game_cartridge_v1;
+pub(crate) mod r#print_game_cartridge_v2;
+pub(crate) mod r#print_game_cartridge_voucher_v1;
+pub(crate) mod r#print_rental_cartridge_v1;
+pub(crate) mod r#purchase_pass_v1;
+pub(crate) mod r#reassign_name_v1;
+pub(crate) mod r#reclaim_expired_cartridge_v1;
+pub(crate) mod r#redeem_pre_order_v1;
+pub(crate) mod r#refund_cartridge_v1;
+pub(crate) mod r#refund_pre_order_v1;
+pub(crate) mod r#register_dlc_v1;
 pub(crate) mod r#release_game_v1;
+pub(crate) mod r#release_game_v2;
 pub(crate) mod r#remove_cartridge_v1;
+pub(crate) mod r#remove_cartridge_v2;
+pub(crate) mod r#remove_pass_v1;
+pub(crate) mod r#renew_pass_v1;
+pub(crate) mod r#reserve_name_v1;
+pub(crate) mod r#restore_catalog_entry_v1;
+pub(crate) mod r#retire_catalog_entry_v1;
+pub(crate) mod r#return_cartridge_v1;
 pub(crate) mod r#set_cartridge_source_v1;
+pub(crate) mod r#set_machine_operator_v1;
+pub(crate) mod r#set_session_lock_v1;
+pub(crate) mod r#set_verification_v1;
+pub(crate) mod r#transfer_name_v1;
+pub(crate) mod r#update_bundle_v1;
+pub(crate) mod r#update_game_v1;
+pub(crate) mod r#update_publisher_team_v1;
+pub(crate) mod r#withdraw_game_revenue_v1;
+pub(crate) mod r#withdraw_pass_revenue_v1;
+pub(crate) mod r#write_save_data_v1;
 
+pub use self::r#add_catalog_entry_v1::*;
+pub use self::r#airdrop_cartridges_v1::*;
+pub use self::r#buy_cartridge_v1::*;
+pub use self::r#cancel_listing_v1::*;
+pub use self::r#claim_escrow_v1::*;
+pub use self::r#claim_name_v1::*;
+pub use self::r#close_bundle_v1::*;
 pub use self::r#commission_machine_v1::*;
+pub use self::r#create_bundle_v1::*;
+pub use self::r#create_pass_v1::*;
+pub use self::r#create_publisher_team_v1::*;
+pub use self::r#eject_cartridge_v1::*;
+pub use self::r#get_price_v1::*;
 pub use self::r#insert_cartridge_v1::*;
+pub use self::r#insert_cartridge_v2::*;
+pub use self::r#insert_pass_v1::*;
+pub use self::r#lend_cartridge_v1::*;
+pub use self::r#list_cartridge_v1::*;
+pub use self::r#pre_order_v1::*;
+pub use self::r#print_bundle_v1::*;
+pub use self::r#print_dlc_v1::*;
+pub use self::r#print_game_cartridge_allowlist_v1::*;
 pub use self::r#print_game_cartridge_v1::*;
+pub use self::r#print_game_cartridge_v2::*;
+pub use self::r#print_game_cartridge_voucher_v1::*;
+pub use self::r#print_rental_cartridge_v1::*;
+pub use self::r#purchase_pass_v1::*;
+pub use self::r#reassign_name_v1::*;
+pub use self::r#reclaim_expired_cartridge_v1::*;
+pub use self::r#redeem_pre_order_v1::*;
+pub use self::r#refund_cartridge_v1::*;
+pub use self::r#refund_pre_order_v1::*;
+pub use self::r#register_dlc_v1::*;
 pub use self::r#release_game_v1::*;
+pub use self::r#release_game_v2::*;
 pub use self::r#remove_cartridge_v1::*;
+pub use self::r#remove_cartridge_v2::*;
+pub use self::r#remove_pass_v1::*;
+pub use self::r#renew_pass_v1::*;
+pub use self::r#reserve_name_v1::*;
+pub use self::r#restore_catalog_entry_v1::*;
+pub use self::r#retire_catalog_entry_v1::*;
+pub use self::r#return_cartridge_v1::*;
 pub use self::r#set_cartridge_source_v1::*;
+pub use self::r#set_machine_operator_v1::*;
+pub use self::r#set_session_lock_v1::*;
+pub use self::r#set_verification_v1::*;
+pub use self::r#transfer_name_v1::*;
+pub use self::r#update_bundle_v1::*;
+pub use self::r#update_game_v1::*;
+pub use self::r#update_publisher_team_v1::*;
+pub use self::r#withdraw_game_revenue_v1::*;
+pub use self::r#withdraw_pass_revenue_v1::*;
+pub use self::r#write_save_data_v1::*;
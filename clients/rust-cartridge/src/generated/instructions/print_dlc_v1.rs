@@ -0,0 +1,941 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct PrintDlcV1 {
+    /// The new DLC asset account
+    pub cartridge: solana_program::pubkey::Pubkey,
+    /// The DLC game Collection account
+    pub game: solana_program::pubkey::Pubkey,
+    /// The token account receiving the payment for the DLC
+    pub game_token_account: solana_program::pubkey::Pubkey,
+    /// The owner of the base cartridge and the new DLC
+    pub owner: solana_program::pubkey::Pubkey,
+    /// The account paying for the storage fees
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The account paying for the storage fees and the DLC cost
+    pub payer_token_account: solana_program::pubkey::Pubkey,
+    /// The base game cartridge held by the owner
+    pub base_cartridge: solana_program::pubkey::Pubkey,
+    /// The payment mint
+    pub payment_mint: solana_program::pubkey::Pubkey,
+    /// The mpl core program
+    pub mpl_core_program: solana_program::pubkey::Pubkey,
+    /// The token program
+    pub token_program: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// The counter of cartridges printed to the owner, when the game limits prints per wallet
+    pub mint_counter: Option<solana_program::pubkey::Pubkey>,
+    /// The token account of the wallet that referred the print
+    pub referrer_token_account: Option<solana_program::pubkey::Pubkey>,
+    /// The receipt of the DLC printed for the base cartridge
+    pub dlc_receipt: solana_program::pubkey::Pubkey,
+}
+
+impl PrintDlcV1 {
+    pub fn instruction(
+        &self,
+        args: PrintDlcV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: PrintDlcV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.cartridge,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.game, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.game_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.owner, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.base_cartridge,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payment_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mpl_core_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        if let Some(mint_counter) = self.mint_counter {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                mint_counter,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        if let Some(referrer_token_account) = self.referrer_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                referrer_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.dlc_receipt,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = PrintDlcV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct PrintDlcV1InstructionData {
+    discriminator: u8,
+}
+
+impl PrintDlcV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 17 }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrintDlcV1InstructionArgs {
+    pub collection_nonce: u8,
+    pub collection_bump: u8,
+}
+
+/// Instruction builder for `PrintDlcV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` cartridge
+///   1. `[writable]` game
+///   2. `[writable]` game_token_account
+///   3. `[]` owner
+///   4. `[writable, signer]` payer
+///   5. `[writable]` payer_token_account
+///   6. `[]` base_cartridge
+///   7. `[writable, optional]` payment_mint (default to `BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump`)
+///   8. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   11. `[writable, optional]` mint_counter
+///   12. `[writable, optional]` referrer_token_account
+///   13. `[writable]` dlc_receipt
+#[derive(Default)]
+pub struct PrintDlcV1Builder {
+    cartridge: Option<solana_program::pubkey::Pubkey>,
+    game: Option<solana_program::pubkey::Pubkey>,
+    game_token_account: Option<solana_program::pubkey::Pubkey>,
+    owner: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    payer_token_account: Option<solana_program::pubkey::Pubkey>,
+    base_cartridge: Option<solana_program::pubkey::Pubkey>,
+    payment_mint: Option<solana_program::pubkey::Pubkey>,
+    mpl_core_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    mint_counter: Option<solana_program::pubkey::Pubkey>,
+    referrer_token_account: Option<solana_program::pubkey::Pubkey>,
+    dlc_receipt: Option<solana_program::pubkey::Pubkey>,
+    collection_nonce: Option<u8>,
+    collection_bump: Option<u8>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl PrintDlcV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The new DLC asset account
+    #[inline(always)]
+    pub fn cartridge(&mut self, cartridge: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.cartridge = Some(cartridge);
+        self
+    }
+    /// The DLC game Collection account
+    #[inline(always)]
+    pub fn game(&mut self, game: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.game = Some(game);
+        self
+    }
+    /// The token account receiving the payment for the DLC
+    #[inline(always)]
+    pub fn game_token_account(
+        &mut self,
+        game_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.game_token_account = Some(game_token_account);
+        self
+    }
+    /// The owner of the base cartridge and the new DLC
+    #[inline(always)]
+    pub fn owner(&mut self, owner: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// The account paying for the storage fees
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// The account paying for the storage fees and the DLC cost
+    #[inline(always)]
+    pub fn payer_token_account(
+        &mut self,
+        payer_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.payer_token_account = Some(payer_token_account);
+        self
+    }
+    /// The base game cartridge held by the owner
+    #[inline(always)]
+    pub fn base_cartridge(&mut self, base_cartridge: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.base_cartridge = Some(base_cartridge);
+        self
+    }
+    /// `[optional account, default to 'BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump']`
+    /// The payment mint
+    #[inline(always)]
+    pub fn payment_mint(&mut self, payment_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payment_mint = Some(payment_mint);
+        self
+    }
+    /// `[optional account, default to 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d']`
+    /// The mpl core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// The token program
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The counter of cartridges printed to the owner, when the game limits prints per wallet
+    #[inline(always)]
+    pub fn mint_counter(
+        &mut self,
+        mint_counter: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.mint_counter = mint_counter;
+        self
+    }
+    /// `[optional account]`
+    /// The token account of the wallet that referred the print
+    #[inline(always)]
+    pub fn referrer_token_account(
+        &mut self,
+        referrer_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.referrer_token_account = referrer_token_account;
+        self
+    }
+    /// The receipt of the DLC printed for the base cartridge
+    #[inline(always)]
+    pub fn dlc_receipt(&mut self, dlc_receipt: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.dlc_receipt = Some(dlc_receipt);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_nonce(&mut self, collection_nonce: u8) -> &mut Self {
+        self.collection_nonce = Some(collection_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_bump(&mut self, collection_bump: u8) -> &mut Self {
+        self.collection_bump = Some(collection_bump);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = PrintDlcV1 {
+            cartridge: self.cartridge.expect("cartridge is not set"),
+            game: self.game.expect("game is not set"),
+            game_token_account: self
+                .game_token_account
+                .expect("game_token_account is not set"),
+            owner: self.owner.expect("owner is not set"),
+            payer: self.payer.expect("payer is not set"),
+            payer_token_account: self
+                .payer_token_account
+                .expect("payer_token_account is not set"),
+            base_cartridge: self.base_cartridge.expect("base_cartridge is not set"),
+            payment_mint: self.payment_mint.unwrap_or(solana_program::pubkey!(
+                "BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump"
+            )),
+            mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
+                "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+            )),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            mint_counter: self.mint_counter,
+            referrer_token_account: self.referrer_token_account,
+            dlc_receipt: self.dlc_receipt.expect("dlc_receipt is not set"),
+        };
+        let args = PrintDlcV1InstructionArgs {
+            collection_nonce: self
+                .collection_nonce
+                .clone()
+                .expect("collection_nonce is not set"),
+            collection_bump: self
+                .collection_bump
+                .clone()
+                .expect("collection_bump is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `print_dlc_v1` CPI accounts.
+pub struct PrintDlcV1CpiAccounts<'a, 'b> {
+    /// The new DLC asset account
+    pub cartridge: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The DLC game Collection account
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account receiving the payment for the DLC
+    pub game_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the base cartridge and the new DLC
+    pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account paying for the storage fees
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account paying for the storage fees and the DLC cost
+    pub payer_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The base game cartridge held by the owner
+    pub base_cartridge: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payment mint
+    pub payment_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The counter of cartridges printed to the owner, when the game limits prints per wallet
+    pub mint_counter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token account of the wallet that referred the print
+    pub referrer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The receipt of the DLC printed for the base cartridge
+    pub dlc_receipt: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `print_dlc_v1` CPI instruction.
+pub struct PrintDlcV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The new DLC asset account
+    pub cartridge: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The DLC game Collection account
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account receiving the payment for the DLC
+    pub game_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the base cartridge and the new DLC
+    pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account paying for the storage fees
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account paying for the storage fees and the DLC cost
+    pub payer_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The base game cartridge held by the owner
+    pub base_cartridge: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payment mint
+    pub payment_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The counter of cartridges printed to the owner, when the game limits prints per wallet
+    pub mint_counter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token account of the wallet that referred the print
+    pub referrer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The receipt of the DLC printed for the base cartridge
+    pub dlc_receipt: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: PrintDlcV1InstructionArgs,
+}
+
+impl<'a, 'b> PrintDlcV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: PrintDlcV1CpiAccounts<'a, 'b>,
+        args: PrintDlcV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            cartridge: accounts.cartridge,
+            game: accounts.game,
+            game_token_account: accounts.game_token_account,
+            owner: accounts.owner,
+            payer: accounts.payer,
+            payer_token_account: accounts.payer_token_account,
+            base_cartridge: accounts.base_cartridge,
+            payment_mint: accounts.payment_mint,
+            mpl_core_program: accounts.mpl_core_program,
+            token_program: accounts.token_program,
+            system_program: accounts.system_program,
+            mint_counter: accounts.mint_counter,
+            referrer_token_account: accounts.referrer_token_account,
+            dlc_receipt: accounts.dlc_receipt,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.cartridge.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.game.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.game_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.owner.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.base_cartridge.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payment_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mpl_core_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(mint_counter) = self.mint_counter {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *mint_counter.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        if let Some(referrer_token_account) = self.referrer_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *referrer_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.dlc_receipt.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = PrintDlcV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.cartridge.clone());
+        account_infos.push(self.game.clone());
+        account_infos.push(self.game_token_account.clone());
+        account_infos.push(self.owner.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.payer_token_account.clone());
+        account_infos.push(self.base_cartridge.clone());
+        account_infos.push(self.payment_mint.clone());
+        account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.system_program.clone());
+        if let Some(mint_counter) = self.mint_counter {
+            account_infos.push(mint_counter.clone());
+        }
+        if let Some(referrer_token_account) = self.referrer_token_account {
+            account_infos.push(referrer_token_account.clone());
+        }
+        account_infos.push(self.dlc_receipt.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `PrintDlcV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` cartridge
+///   1. `[writable]` game
+///   2. `[writable]` game_token_account
+///   3. `[]` owner
+///   4. `[writable, signer]` payer
+///   5. `[writable]` payer_token_account
+///   6. `[]` base_cartridge
+///   7. `[writable]` payment_mint
+///   8. `[]` mpl_core_program
+///   9. `[]` token_program
+///   10. `[]` system_program
+///   11. `[writable, optional]` mint_counter
+///   12. `[writable, optional]` referrer_token_account
+///   13. `[writable]` dlc_receipt
+pub struct PrintDlcV1CpiBuilder<'a, 'b> {
+    instruction: Box<PrintDlcV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> PrintDlcV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(PrintDlcV1CpiBuilderInstruction {
+            __program: program,
+            cartridge: None,
+            game: None,
+            game_token_account: None,
+            owner: None,
+            payer: None,
+            payer_token_account: None,
+            base_cartridge: None,
+            payment_mint: None,
+            mpl_core_program: None,
+            token_program: None,
+            system_program: None,
+            mint_counter: None,
+            referrer_token_account: None,
+            dlc_receipt: None,
+            collection_nonce: None,
+            collection_bump: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The new DLC asset account
+    #[inline(always)]
+    pub fn cartridge(
+        &mut self,
+        cartridge: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.cartridge = Some(cartridge);
+        self
+    }
+    /// The DLC game Collection account
+    #[inline(always)]
+    pub fn game(&mut self, game: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.game = Some(game);
+        self
+    }
+    /// The token account receiving the payment for the DLC
+    #[inline(always)]
+    pub fn game_token_account(
+        &mut self,
+        game_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.game_token_account = Some(game_token_account);
+        self
+    }
+    /// The owner of the base cartridge and the new DLC
+    #[inline(always)]
+    pub fn owner(&mut self, owner: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.owner = Some(owner);
+        self
+    }
+    /// The account paying for the storage fees
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// The account paying for the storage fees and the DLC cost
+    #[inline(always)]
+    pub fn payer_token_account(
+        &mut self,
+        payer_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.payer_token_account = Some(payer_token_account);
+        self
+    }
+    /// The base game cartridge held by the owner
+    #[inline(always)]
+    pub fn base_cartridge(
+        &mut self,
+        base_cartridge: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.base_cartridge = Some(base_cartridge);
+        self
+    }
+    /// The payment mint
+    #[inline(always)]
+    pub fn payment_mint(
+        &mut self,
+        payment_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.payment_mint = Some(payment_mint);
+        self
+    }
+    /// The mpl core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// The token program
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The counter of cartridges printed to the owner, when the game limits prints per wallet
+    #[inline(always)]
+    pub fn mint_counter(
+        &mut self,
+        mint_counter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint_counter = mint_counter;
+        self
+    }
+    /// `[optional account]`
+    /// The token account of the wallet that referred the print
+    #[inline(always)]
+    pub fn referrer_token_account(
+        &mut self,
+        referrer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.referrer_token_account = referrer_token_account;
+        self
+    }
+    /// The receipt of the DLC printed for the base cartridge
+    #[inline(always)]
+    pub fn dlc_receipt(
+        &mut self,
+        dlc_receipt: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.dlc_receipt = Some(dlc_receipt);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_nonce(&mut self, collection_nonce: u8) -> &mut Self {
+        self.instruction.collection_nonce = Some(collection_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_bump(&mut self, collection_bump: u8) -> &mut Self {
+        self.instruction.collection_bump = Some(collection_bump);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = PrintDlcV1InstructionArgs {
+            collection_nonce: self
+                .instruction
+                .collection_nonce
+                .clone()
+                .expect("collection_nonce is not set"),
+            collection_bump: self
+                .instruction
+                .collection_bump
+                .clone()
+                .expect("collection_bump is not set"),
+        };
+        let instruction = PrintDlcV1Cpi {
+            __program: self.instruction.__program,
+
+            cartridge: self.instruction.cartridge.expect("cartridge is not set"),
+
+            game: self.instruction.game.expect("game is not set"),
+
+            game_token_account: self
+                .instruction
+                .game_token_account
+                .expect("game_token_account is not set"),
+
+            owner: self.instruction.owner.expect("owner is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            payer_token_account: self
+                .instruction
+                .payer_token_account
+                .expect("payer_token_account is not set"),
+
+            base_cartridge: self
+                .instruction
+                .base_cartridge
+                .expect("base_cartridge is not set"),
+
+            payment_mint: self
+                .instruction
+                .payment_mint
+                .expect("payment_mint is not set"),
+
+            mpl_core_program: self
+                .instruction
+                .mpl_core_program
+                .expect("mpl_core_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            mint_counter: self.instruction.mint_counter,
+
+            referrer_token_account: self.instruction.referrer_token_account,
+
+            dlc_receipt: self
+                .instruction
+                .dlc_receipt
+                .expect("dlc_receipt is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct PrintDlcV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    cartridge: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    game: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    game_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    base_cartridge: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payment_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint_counter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    referrer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    dlc_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection_nonce: Option<u8>,
+    collection_bump: Option<u8>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -131,6 +131,7 @@ impl PrintGameCartridgeV1InstructionData {
 pub struct PrintGameCartridgeV1InstructionArgs {
     pub collection_nonce: u8,
     pub collection_bump: u8,
+    pub max_price: Option<u64>,
 }
 
 /// Instruction builder for `PrintGameCartridgeV1`.
@@ -163,6 +164,7 @@ pub struct PrintGameCartridgeV1Builder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     collection_nonce: Option<u8>,
     collection_bump: Option<u8>,
+    max_price: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -260,6 +262,12 @@ impl PrintGameCartridgeV1Builder {
         self.collection_bump = Some(collection_bump);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_price(&mut self, max_price: u64) -> &mut Self {
+        self.max_price = Some(max_price);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -314,6 +322,7 @@ impl PrintGameCartridgeV1Builder {
                 .collection_bump
                 .clone()
                 .expect("collection_bump is not set"),
+            max_price: self.max_price.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -564,6 +573,7 @@ impl<'a, 'b> PrintGameCartridgeV1CpiBuilder<'a, 'b> {
             system_program: None,
             collection_nonce: None,
             collection_bump: None,
+            max_price: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -669,6 +679,12 @@ impl<'a, 'b> PrintGameCartridgeV1CpiBuilder<'a, 'b> {
         self.instruction.collection_bump = Some(collection_bump);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_price(&mut self, max_price: u64) -> &mut Self {
+        self.instruction.max_price = Some(max_price);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -721,6 +737,7 @@ impl<'a, 'b> PrintGameCartridgeV1CpiBuilder<'a, 'b> {
                 .collection_bump
                 .clone()
                 .expect("collection_bump is not set"),
+            max_price: self.instruction.max_price.clone(),
         };
         let instruction = PrintGameCartridgeV1Cpi {
             __program: self.instruction.__program,
@@ -788,6 +805,7 @@ struct PrintGameCartridgeV1CpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     collection_nonce: Option<u8>,
     collection_bump: Option<u8>,
+    max_price: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
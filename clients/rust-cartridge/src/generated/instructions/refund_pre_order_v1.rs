@@ -0,0 +1,537 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct RefundPreOrderV1 {
+    /// The crowdfunded game Collection account
+    pub game: solana_program::pubkey::Pubkey,
+    /// The escrow of the game
+    pub escrow: solana_program::pubkey::Pubkey,
+    /// The token account of the escrow
+    pub escrow_token_account: solana_program::pubkey::Pubkey,
+    /// The pre-orders of the backer
+    pub pre_order: solana_program::pubkey::Pubkey,
+    /// The wallet that pre-ordered the cartridges
+    pub backer: solana_program::pubkey::Pubkey,
+    /// The token account of the backer
+    pub backer_token_account: solana_program::pubkey::Pubkey,
+    /// The token program
+    pub token_program: solana_program::pubkey::Pubkey,
+}
+
+impl RefundPreOrderV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.game, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.escrow,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.escrow_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.pre_order,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.backer,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.backer_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = RefundPreOrderV1InstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct RefundPreOrderV1InstructionData {
+    discriminator: u8,
+}
+
+impl RefundPreOrderV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 35 }
+    }
+}
+
+/// Instruction builder for `RefundPreOrderV1`.
+///
+/// ### Accounts:
+///
+///   0. `[]` game
+///   1. `[]` escrow
+///   2. `[writable]` escrow_token_account
+///   3. `[writable]` pre_order
+///   4. `[writable]` backer
+///   5. `[writable]` backer_token_account
+///   6. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+#[derive(Default)]
+pub struct RefundPreOrderV1Builder {
+    game: Option<solana_program::pubkey::Pubkey>,
+    escrow: Option<solana_program::pubkey::Pubkey>,
+    escrow_token_account: Option<solana_program::pubkey::Pubkey>,
+    pre_order: Option<solana_program::pubkey::Pubkey>,
+    backer: Option<solana_program::pubkey::Pubkey>,
+    backer_token_account: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl RefundPreOrderV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The crowdfunded game Collection account
+    #[inline(always)]
+    pub fn game(&mut self, game: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.game = Some(game);
+        self
+    }
+    /// The escrow of the game
+    #[inline(always)]
+    pub fn escrow(&mut self, escrow: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.escrow = Some(escrow);
+        self
+    }
+    /// The token account of the escrow
+    #[inline(always)]
+    pub fn escrow_token_account(
+        &mut self,
+        escrow_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.escrow_token_account = Some(escrow_token_account);
+        self
+    }
+    /// The pre-orders of the backer
+    #[inline(always)]
+    pub fn pre_order(&mut self, pre_order: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.pre_order = Some(pre_order);
+        self
+    }
+    /// The wallet that pre-ordered the cartridges
+    #[inline(always)]
+    pub fn backer(&mut self, backer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.backer = Some(backer);
+        self
+    }
+    /// The token account of the backer
+    #[inline(always)]
+    pub fn backer_token_account(
+        &mut self,
+        backer_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.backer_token_account = Some(backer_token_account);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// The token program
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = RefundPreOrderV1 {
+            game: self.game.expect("game is not set"),
+            escrow: self.escrow.expect("escrow is not set"),
+            escrow_token_account: self
+                .escrow_token_account
+                .expect("escrow_token_account is not set"),
+            pre_order: self.pre_order.expect("pre_order is not set"),
+            backer: self.backer.expect("backer is not set"),
+            backer_token_account: self
+                .backer_token_account
+                .expect("backer_token_account is not set"),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `refund_pre_order_v1` CPI accounts.
+pub struct RefundPreOrderV1CpiAccounts<'a, 'b> {
+    /// The crowdfunded game Collection account
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The escrow of the game
+    pub escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account of the escrow
+    pub escrow_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The pre-orders of the backer
+    pub pre_order: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The wallet that pre-ordered the cartridges
+    pub backer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account of the backer
+    pub backer_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `refund_pre_order_v1` CPI instruction.
+pub struct RefundPreOrderV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The crowdfunded game Collection account
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The escrow of the game
+    pub escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account of the escrow
+    pub escrow_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The pre-orders of the backer
+    pub pre_order: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The wallet that pre-ordered the cartridges
+    pub backer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account of the backer
+    pub backer_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> RefundPreOrderV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: RefundPreOrderV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            game: accounts.game,
+            escrow: accounts.escrow,
+            escrow_token_account: accounts.escrow_token_account,
+            pre_order: accounts.pre_order,
+            backer: accounts.backer,
+            backer_token_account: accounts.backer_token_account,
+            token_program: accounts.token_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.game.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.escrow.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.escrow_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.pre_order.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.backer.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.backer_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = RefundPreOrderV1InstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.game.clone());
+        account_infos.push(self.escrow.clone());
+        account_infos.push(self.escrow_token_account.clone());
+        account_infos.push(self.pre_order.clone());
+        account_infos.push(self.backer.clone());
+        account_infos.push(self.backer_token_account.clone());
+        account_infos.push(self.token_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `RefundPreOrderV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` game
+///   1. `[]` escrow
+///   2. `[writable]` escrow_token_account
+///   3. `[writable]` pre_order
+///   4. `[writable]` backer
+///   5. `[writable]` backer_token_account
+///   6. `[]` token_program
+pub struct RefundPreOrderV1CpiBuilder<'a, 'b> {
+    instruction: Box<RefundPreOrderV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> RefundPreOrderV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(RefundPreOrderV1CpiBuilderInstruction {
+            __program: program,
+            game: None,
+            escrow: None,
+            escrow_token_account: None,
+            pre_order: None,
+            backer: None,
+            backer_token_account: None,
+            token_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The crowdfunded game Collection account
+    #[inline(always)]
+    pub fn game(&mut self, game: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.game = Some(game);
+        self
+    }
+    /// The escrow of the game
+    #[inline(always)]
+    pub fn escrow(
+        &mut self,
+        escrow: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.escrow = Some(escrow);
+        self
+    }
+    /// The token account of the escrow
+    #[inline(always)]
+    pub fn escrow_token_account(
+        &mut self,
+        escrow_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.escrow_token_account = Some(escrow_token_account);
+        self
+    }
+    /// The pre-orders of the backer
+    #[inline(always)]
+    pub fn pre_order(
+        &mut self,
+        pre_order: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pre_order = Some(pre_order);
+        self
+    }
+    /// The wallet that pre-ordered the cartridges
+    #[inline(always)]
+    pub fn backer(
+        &mut self,
+        backer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.backer = Some(backer);
+        self
+    }
+    /// The token account of the backer
+    #[inline(always)]
+    pub fn backer_token_account(
+        &mut self,
+        backer_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.backer_token_account = Some(backer_token_account);
+        self
+    }
+    /// The token program
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = RefundPreOrderV1Cpi {
+            __program: self.instruction.__program,
+
+            game: self.instruction.game.expect("game is not set"),
+
+            escrow: self.instruction.escrow.expect("escrow is not set"),
+
+            escrow_token_account: self
+                .instruction
+                .escrow_token_account
+                .expect("escrow_token_account is not set"),
+
+            pre_order: self.instruction.pre_order.expect("pre_order is not set"),
+
+            backer: self.instruction.backer.expect("backer is not set"),
+
+            backer_token_account: self
+                .instruction
+                .backer_token_account
+                .expect("backer_token_account is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct RefundPreOrderV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    game: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    escrow_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pre_order: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    backer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    backer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::CrowdfundTerms;
 use crate::generated::types::PriceType;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
@@ -122,6 +123,9 @@ pub struct ReleaseGameV1InstructionArgs {
     pub nonce: u8,
     pub price_type: PriceType,
     pub price: u64,
+    pub sale_start: Option<i64>,
+    pub sale_end: Option<i64>,
+    pub crowdfund: Option<CrowdfundTerms>,
 }
 
 /// Instruction builder for `ReleaseGameV1`.
@@ -153,6 +157,9 @@ pub struct ReleaseGameV1Builder {
     nonce: Option<u8>,
     price_type: Option<PriceType>,
     price: Option<u64>,
+    sale_start: Option<i64>,
+    sale_end: Option<i64>,
+    crowdfund: Option<CrowdfundTerms>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -255,6 +262,24 @@ impl ReleaseGameV1Builder {
         self.price = Some(price);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sale_start(&mut self, sale_start: i64) -> &mut Self {
+        self.sale_start = Some(sale_start);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sale_end(&mut self, sale_end: i64) -> &mut Self {
+        self.sale_end = Some(sale_end);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn crowdfund(&mut self, crowdfund: CrowdfundTerms) -> &mut Self {
+        self.crowdfund = Some(crowdfund);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -304,6 +329,9 @@ impl ReleaseGameV1Builder {
             nonce: self.nonce.clone().unwrap_or(0),
             price_type: self.price_type.clone().expect("price_type is not set"),
             price: self.price.clone().expect("price is not set"),
+            sale_start: self.sale_start.clone(),
+            sale_end: self.sale_end.clone(),
+            crowdfund: self.crowdfund.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -531,6 +559,9 @@ impl<'a, 'b> ReleaseGameV1CpiBuilder<'a, 'b> {
             nonce: None,
             price_type: None,
             price: None,
+            sale_start: None,
+            sale_end: None,
+            crowdfund: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -637,6 +668,24 @@ impl<'a, 'b> ReleaseGameV1CpiBuilder<'a, 'b> {
         self.instruction.price = Some(price);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sale_start(&mut self, sale_start: i64) -> &mut Self {
+        self.instruction.sale_start = Some(sale_start);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sale_end(&mut self, sale_end: i64) -> &mut Self {
+        self.instruction.sale_end = Some(sale_end);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn crowdfund(&mut self, crowdfund: CrowdfundTerms) -> &mut Self {
+        self.instruction.crowdfund = Some(crowdfund);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -688,6 +737,9 @@ impl<'a, 'b> ReleaseGameV1CpiBuilder<'a, 'b> {
                 .clone()
                 .expect("price_type is not set"),
             price: self.instruction.price.clone().expect("price is not set"),
+            sale_start: self.instruction.sale_start.clone(),
+            sale_end: self.instruction.sale_end.clone(),
+            crowdfund: self.instruction.crowdfund.clone(),
         };
         let instruction = ReleaseGameV1Cpi {
             __program: self.instruction.__program,
@@ -752,6 +804,9 @@ struct ReleaseGameV1CpiBuilderInstruction<'a, 'b> {
     nonce: Option<u8>,
     price_type: Option<PriceType>,
     price: Option<u64>,
+    sale_start: Option<i64>,
+    sale_end: Option<i64>,
+    crowdfund: Option<CrowdfundTerms>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
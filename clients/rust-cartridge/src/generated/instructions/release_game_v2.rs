@@ -0,0 +1,1066 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::CrowdfundTerms;
+use crate::generated::types::PriceType;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct ReleaseGameV2 {
+    /// The new game Collection account
+    pub game: solana_program::pubkey::Pubkey,
+    /// The token account receiving the payment for the game
+    pub game_token_account: solana_program::pubkey::Pubkey,
+    /// The account paying for the storage fees
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The authority signing for account creation
+    pub authority: Option<solana_program::pubkey::Pubkey>,
+    /// The Mint address for the payment token
+    pub payment_mint: solana_program::pubkey::Pubkey,
+    /// The mpl core program
+    pub mpl_core_program: solana_program::pubkey::Pubkey,
+    /// The token program
+    pub token_program: solana_program::pubkey::Pubkey,
+    /// The associated token program
+    pub associated_token_program: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// The escrow of pre-order payments, when crowdfunding
+    pub escrow: Option<solana_program::pubkey::Pubkey>,
+    /// The token account of the escrow, when crowdfunding
+    pub escrow_token_account: Option<solana_program::pubkey::Pubkey>,
+    /// The publisher team releasing the game, approved by its members
+    pub team: Option<solana_program::pubkey::Pubkey>,
+    /// The registry record of the game name
+    pub name_record: solana_program::pubkey::Pubkey,
+    /// The catalog of the publisher
+    pub catalog: solana_program::pubkey::Pubkey,
+}
+
+impl ReleaseGameV2 {
+    pub fn instruction(
+        &self,
+        args: ReleaseGameV2InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: ReleaseGameV2InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.game, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.game_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authority, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.payment_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mpl_core_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.associated_token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        if let Some(escrow) = self.escrow {
+            accounts.push(solana_program::instruction::AccountMeta::new(escrow, false));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        if let Some(escrow_token_account) = self.escrow_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                escrow_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        if let Some(team) = self.team {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                team, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.name_record,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.catalog,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = ReleaseGameV2InstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct ReleaseGameV2InstructionData {
+    discriminator: u8,
+}
+
+impl ReleaseGameV2InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 47 }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseGameV2InstructionArgs {
+    pub name: String,
+    pub uri: String,
+    pub nonce: u8,
+    pub price_type: PriceType,
+    pub price: u64,
+    pub sale_start: Option<i64>,
+    pub sale_end: Option<i64>,
+    pub crowdfund: Option<CrowdfundTerms>,
+}
+
+/// Instruction builder for `ReleaseGameV2`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` game
+///   1. `[writable]` game_token_account
+///   2. `[writable, signer]` payer
+///   3. `[signer, optional]` authority
+///   4. `[optional]` payment_mint (default to `BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump`)
+///   5. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   6. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   7. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   9. `[writable, optional]` escrow
+///   10. `[writable, optional]` escrow_token_account
+///   11. `[optional]` team
+///   12. `[writable]` name_record
+///   13. `[writable]` catalog
+#[derive(Default)]
+pub struct ReleaseGameV2Builder {
+    game: Option<solana_program::pubkey::Pubkey>,
+    game_token_account: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    payment_mint: Option<solana_program::pubkey::Pubkey>,
+    mpl_core_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    escrow: Option<solana_program::pubkey::Pubkey>,
+    escrow_token_account: Option<solana_program::pubkey::Pubkey>,
+    team: Option<solana_program::pubkey::Pubkey>,
+    name_record: Option<solana_program::pubkey::Pubkey>,
+    catalog: Option<solana_program::pubkey::Pubkey>,
+    name: Option<String>,
+    uri: Option<String>,
+    nonce: Option<u8>,
+    price_type: Option<PriceType>,
+    price: Option<u64>,
+    sale_start: Option<i64>,
+    sale_end: Option<i64>,
+    crowdfund: Option<CrowdfundTerms>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl ReleaseGameV2Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The new game Collection account
+    #[inline(always)]
+    pub fn game(&mut self, game: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.game = Some(game);
+        self
+    }
+    /// The token account receiving the payment for the game
+    #[inline(always)]
+    pub fn game_token_account(
+        &mut self,
+        game_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.game_token_account = Some(game_token_account);
+        self
+    }
+    /// The account paying for the storage fees
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account]`
+    /// The authority signing for account creation
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.authority = authority;
+        self
+    }
+    /// `[optional account, default to 'BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump']`
+    /// The Mint address for the payment token
+    #[inline(always)]
+    pub fn payment_mint(&mut self, payment_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payment_mint = Some(payment_mint);
+        self
+    }
+    /// `[optional account, default to 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d']`
+    /// The mpl core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// The token program
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL']`
+    /// The associated token program
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.associated_token_program = Some(associated_token_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The escrow of pre-order payments, when crowdfunding
+    #[inline(always)]
+    pub fn escrow(&mut self, escrow: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.escrow = escrow;
+        self
+    }
+    /// `[optional account]`
+    /// The token account of the escrow, when crowdfunding
+    #[inline(always)]
+    pub fn escrow_token_account(
+        &mut self,
+        escrow_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.escrow_token_account = escrow_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The publisher team releasing the game, approved by its members
+    #[inline(always)]
+    pub fn team(&mut self, team: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.team = team;
+        self
+    }
+    /// The registry record of the game name
+    #[inline(always)]
+    pub fn name_record(&mut self, name_record: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.name_record = Some(name_record);
+        self
+    }
+    /// The catalog of the publisher
+    #[inline(always)]
+    pub fn catalog(&mut self, catalog: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.catalog = Some(catalog);
+        self
+    }
+    #[inline(always)]
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+    #[inline(always)]
+    pub fn uri(&mut self, uri: String) -> &mut Self {
+        self.uri = Some(uri);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn nonce(&mut self, nonce: u8) -> &mut Self {
+        self.nonce = Some(nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn price_type(&mut self, price_type: PriceType) -> &mut Self {
+        self.price_type = Some(price_type);
+        self
+    }
+    #[inline(always)]
+    pub fn price(&mut self, price: u64) -> &mut Self {
+        self.price = Some(price);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sale_start(&mut self, sale_start: i64) -> &mut Self {
+        self.sale_start = Some(sale_start);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sale_end(&mut self, sale_end: i64) -> &mut Self {
+        self.sale_end = Some(sale_end);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn crowdfund(&mut self, crowdfund: CrowdfundTerms) -> &mut Self {
+        self.crowdfund = Some(crowdfund);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = ReleaseGameV2 {
+            game: self.game.expect("game is not set"),
+            game_token_account: self
+                .game_token_account
+                .expect("game_token_account is not set"),
+            payer: self.payer.expect("payer is not set"),
+            authority: self.authority,
+            payment_mint: self.payment_mint.unwrap_or(solana_program::pubkey!(
+                "BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump"
+            )),
+            mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
+                "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+            )),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            associated_token_program: self.associated_token_program.unwrap_or(
+                solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+            ),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            escrow: self.escrow,
+            escrow_token_account: self.escrow_token_account,
+            team: self.team,
+            name_record: self.name_record.expect("name_record is not set"),
+            catalog: self.catalog.expect("catalog is not set"),
+        };
+        let args = ReleaseGameV2InstructionArgs {
+            name: self.name.clone().expect("name is not set"),
+            uri: self.uri.clone().expect("uri is not set"),
+            nonce: self.nonce.clone().unwrap_or(0),
+            price_type: self.price_type.clone().expect("price_type is not set"),
+            price: self.price.clone().expect("price is not set"),
+            sale_start: self.sale_start.clone(),
+            sale_end: self.sale_end.clone(),
+            crowdfund: self.crowdfund.clone(),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `release_game_v2` CPI accounts.
+pub struct ReleaseGameV2CpiAccounts<'a, 'b> {
+    /// The new game Collection account
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account receiving the payment for the game
+    pub game_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account paying for the storage fees
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The authority signing for account creation
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The Mint address for the payment token
+    pub payment_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The associated token program
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The escrow of pre-order payments, when crowdfunding
+    pub escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token account of the escrow, when crowdfunding
+    pub escrow_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The publisher team releasing the game, approved by its members
+    pub team: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The registry record of the game name
+    pub name_record: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The catalog of the publisher
+    pub catalog: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `release_game_v2` CPI instruction.
+pub struct ReleaseGameV2Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The new game Collection account
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account receiving the payment for the game
+    pub game_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account paying for the storage fees
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The authority signing for account creation
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The Mint address for the payment token
+    pub payment_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The associated token program
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The escrow of pre-order payments, when crowdfunding
+    pub escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token account of the escrow, when crowdfunding
+    pub escrow_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The publisher team releasing the game, approved by its members
+    pub team: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The registry record of the game name
+    pub name_record: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The catalog of the publisher
+    pub catalog: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: ReleaseGameV2InstructionArgs,
+}
+
+impl<'a, 'b> ReleaseGameV2Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: ReleaseGameV2CpiAccounts<'a, 'b>,
+        args: ReleaseGameV2InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            game: accounts.game,
+            game_token_account: accounts.game_token_account,
+            payer: accounts.payer,
+            authority: accounts.authority,
+            payment_mint: accounts.payment_mint,
+            mpl_core_program: accounts.mpl_core_program,
+            token_program: accounts.token_program,
+            associated_token_program: accounts.associated_token_program,
+            system_program: accounts.system_program,
+            escrow: accounts.escrow,
+            escrow_token_account: accounts.escrow_token_account,
+            team: accounts.team,
+            name_record: accounts.name_record,
+            catalog: accounts.catalog,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.game.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.game_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authority.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.payment_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mpl_core_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.associated_token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(escrow) = self.escrow {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *escrow.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        if let Some(escrow_token_account) = self.escrow_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *escrow_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        if let Some(team) = self.team {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *team.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.name_record.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.catalog.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = ReleaseGameV2InstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.game.clone());
+        account_infos.push(self.game_token_account.clone());
+        account_infos.push(self.payer.clone());
+        if let Some(authority) = self.authority {
+            account_infos.push(authority.clone());
+        }
+        account_infos.push(self.payment_mint.clone());
+        account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.associated_token_program.clone());
+        account_infos.push(self.system_program.clone());
+        if let Some(escrow) = self.escrow {
+            account_infos.push(escrow.clone());
+        }
+        if let Some(escrow_token_account) = self.escrow_token_account {
+            account_infos.push(escrow_token_account.clone());
+        }
+        if let Some(team) = self.team {
+            account_infos.push(team.clone());
+        }
+        account_infos.push(self.name_record.clone());
+        account_infos.push(self.catalog.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `ReleaseGameV2` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` game
+///   1. `[writable]` game_token_account
+///   2. `[writable, signer]` payer
+///   3. `[signer, optional]` authority
+///   4. `[]` payment_mint
+///   5. `[]` mpl_core_program
+///   6. `[]` token_program
+///   7. `[]` associated_token_program
+///   8. `[]` system_program
+///   9. `[writable, optional]` escrow
+///   10. `[writable, optional]` escrow_token_account
+///   11. `[optional]` team
+///   12. `[writable]` name_record
+///   13. `[writable]` catalog
+pub struct ReleaseGameV2CpiBuilder<'a, 'b> {
+    instruction: Box<ReleaseGameV2CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> ReleaseGameV2CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(ReleaseGameV2CpiBuilderInstruction {
+            __program: program,
+            game: None,
+            game_token_account: None,
+            payer: None,
+            authority: None,
+            payment_mint: None,
+            mpl_core_program: None,
+            token_program: None,
+            associated_token_program: None,
+            system_program: None,
+            escrow: None,
+            escrow_token_account: None,
+            team: None,
+            name_record: None,
+            catalog: None,
+            name: None,
+            uri: None,
+            nonce: None,
+            price_type: None,
+            price: None,
+            sale_start: None,
+            sale_end: None,
+            crowdfund: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The new game Collection account
+    #[inline(always)]
+    pub fn game(&mut self, game: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.game = Some(game);
+        self
+    }
+    /// The token account receiving the payment for the game
+    #[inline(always)]
+    pub fn game_token_account(
+        &mut self,
+        game_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.game_token_account = Some(game_token_account);
+        self
+    }
+    /// The account paying for the storage fees
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// `[optional account]`
+    /// The authority signing for account creation
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authority = authority;
+        self
+    }
+    /// The Mint address for the payment token
+    #[inline(always)]
+    pub fn payment_mint(
+        &mut self,
+        payment_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.payment_mint = Some(payment_mint);
+        self
+    }
+    /// The mpl core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// The token program
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// The associated token program
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.associated_token_program = Some(associated_token_program);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The escrow of pre-order payments, when crowdfunding
+    #[inline(always)]
+    pub fn escrow(
+        &mut self,
+        escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.escrow = escrow;
+        self
+    }
+    /// `[optional account]`
+    /// The token account of the escrow, when crowdfunding
+    #[inline(always)]
+    pub fn escrow_token_account(
+        &mut self,
+        escrow_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.escrow_token_account = escrow_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The publisher team releasing the game, approved by its members
+    #[inline(always)]
+    pub fn team(
+        &mut self,
+        team: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.team = team;
+        self
+    }
+    /// The registry record of the game name
+    #[inline(always)]
+    pub fn name_record(
+        &mut self,
+        name_record: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.name_record = Some(name_record);
+        self
+    }
+    /// The catalog of the publisher
+    #[inline(always)]
+    pub fn catalog(
+        &mut self,
+        catalog: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.catalog = Some(catalog);
+        self
+    }
+    #[inline(always)]
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.instruction.name = Some(name);
+        self
+    }
+    #[inline(always)]
+    pub fn uri(&mut self, uri: String) -> &mut Self {
+        self.instruction.uri = Some(uri);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn nonce(&mut self, nonce: u8) -> &mut Self {
+        self.instruction.nonce = Some(nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn price_type(&mut self, price_type: PriceType) -> &mut Self {
+        self.instruction.price_type = Some(price_type);
+        self
+    }
+    #[inline(always)]
+    pub fn price(&mut self, price: u64) -> &mut Self {
+        self.instruction.price = Some(price);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sale_start(&mut self, sale_start: i64) -> &mut Self {
+        self.instruction.sale_start = Some(sale_start);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sale_end(&mut self, sale_end: i64) -> &mut Self {
+        self.instruction.sale_end = Some(sale_end);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn crowdfund(&mut self, crowdfund: CrowdfundTerms) -> &mut Self {
+        self.instruction.crowdfund = Some(crowdfund);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = ReleaseGameV2InstructionArgs {
+            name: self.instruction.name.clone().expect("name is not set"),
+            uri: self.instruction.uri.clone().expect("uri is not set"),
+            nonce: self.instruction.nonce.clone().unwrap_or(0),
+            price_type: self
+                .instruction
+                .price_type
+                .clone()
+                .expect("price_type is not set"),
+            price: self.instruction.price.clone().expect("price is not set"),
+            sale_start: self.instruction.sale_start.clone(),
+            sale_end: self.instruction.sale_end.clone(),
+            crowdfund: self.instruction.crowdfund.clone(),
+        };
+        let instruction = ReleaseGameV2Cpi {
+            __program: self.instruction.__program,
+
+            game: self.instruction.game.expect("game is not set"),
+
+            game_token_account: self
+                .instruction
+                .game_token_account
+                .expect("game_token_account is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            authority: self.instruction.authority,
+
+            payment_mint: self
+                .instruction
+                .payment_mint
+                .expect("payment_mint is not set"),
+
+            mpl_core_program: self
+                .instruction
+                .mpl_core_program
+                .expect("mpl_core_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            associated_token_program: self
+                .instruction
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            escrow: self.instruction.escrow,
+
+            escrow_token_account: self.instruction.escrow_token_account,
+
+            team: self.instruction.team,
+
+            name_record: self
+                .instruction
+                .name_record
+                .expect("name_record is not set"),
+
+            catalog: self.instruction.catalog.expect("catalog is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct ReleaseGameV2CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    game: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    game_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payment_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    escrow: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    escrow_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    team: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    name_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    catalog: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    name: Option<String>,
+    uri: Option<String>,
+    nonce: Option<u8>,
+    price_type: Option<PriceType>,
+    price: Option<u64>,
+    sale_start: Option<i64>,
+    sale_end: Option<i64>,
+    crowdfund: Option<CrowdfundTerms>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
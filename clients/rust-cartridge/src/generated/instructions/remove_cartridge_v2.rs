@@ -0,0 +1,723 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct RemoveCartridgeV2 {
+    /// The game cartridge account
+    pub cartridge: solana_program::pubkey::Pubkey,
+    /// The game Collection account
+    pub game: solana_program::pubkey::Pubkey,
+    /// The owner of the game cartridge
+    pub cartridge_owner: (solana_program::pubkey::Pubkey, bool),
+    /// The machine asset account
+    pub machine: solana_program::pubkey::Pubkey,
+    /// The Core machine collection
+    pub machine_collection: solana_program::pubkey::Pubkey,
+    /// The owner of the machine, co-signing while the session is locked
+    pub machine_owner: (solana_program::pubkey::Pubkey, bool),
+    /// The mpl core program
+    pub mpl_core_program: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// The machine operator delegate signing in place of the cartridge owner
+    pub operator: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl RemoveCartridgeV2 {
+    pub fn instruction(
+        &self,
+        args: RemoveCartridgeV2InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: RemoveCartridgeV2InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.cartridge,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.game, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.cartridge_owner.0,
+            self.cartridge_owner.1,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.machine,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.machine_collection,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.machine_owner.0,
+            self.machine_owner.1,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mpl_core_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        if let Some(operator) = self.operator {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                operator, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = RemoveCartridgeV2InstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct RemoveCartridgeV2InstructionData {
+    discriminator: u8,
+}
+
+impl RemoveCartridgeV2InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 53 }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemoveCartridgeV2InstructionArgs {
+    pub collection_nonce: u8,
+    pub collection_bump: u8,
+}
+
+/// Instruction builder for `RemoveCartridgeV2`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` cartridge
+///   1. `[writable]` game
+///   2. `[writable, signer]` cartridge_owner
+///   3. `[writable]` machine
+///   4. `[writable]` machine_collection
+///   5. `[signer]` machine_owner
+///   6. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[writable, signer, optional]` operator
+#[derive(Default)]
+pub struct RemoveCartridgeV2Builder {
+    cartridge: Option<solana_program::pubkey::Pubkey>,
+    game: Option<solana_program::pubkey::Pubkey>,
+    cartridge_owner: Option<(solana_program::pubkey::Pubkey, bool)>,
+    machine: Option<solana_program::pubkey::Pubkey>,
+    machine_collection: Option<solana_program::pubkey::Pubkey>,
+    machine_owner: Option<(solana_program::pubkey::Pubkey, bool)>,
+    mpl_core_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    operator: Option<solana_program::pubkey::Pubkey>,
+    collection_nonce: Option<u8>,
+    collection_bump: Option<u8>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl RemoveCartridgeV2Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The game cartridge account
+    #[inline(always)]
+    pub fn cartridge(&mut self, cartridge: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.cartridge = Some(cartridge);
+        self
+    }
+    /// The game Collection account
+    #[inline(always)]
+    pub fn game(&mut self, game: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.game = Some(game);
+        self
+    }
+    /// The owner of the game cartridge
+    #[inline(always)]
+    pub fn cartridge_owner(
+        &mut self,
+        cartridge_owner: solana_program::pubkey::Pubkey,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.cartridge_owner = Some((cartridge_owner, as_signer));
+        self
+    }
+    /// The machine asset account
+    #[inline(always)]
+    pub fn machine(&mut self, machine: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.machine = Some(machine);
+        self
+    }
+    /// The Core machine collection
+    #[inline(always)]
+    pub fn machine_collection(
+        &mut self,
+        machine_collection: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.machine_collection = Some(machine_collection);
+        self
+    }
+    /// The owner of the machine, co-signing while the session is locked
+    #[inline(always)]
+    pub fn machine_owner(
+        &mut self,
+        machine_owner: solana_program::pubkey::Pubkey,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.machine_owner = Some((machine_owner, as_signer));
+        self
+    }
+    /// `[optional account, default to 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d']`
+    /// The mpl core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The machine operator delegate signing in place of the cartridge owner
+    #[inline(always)]
+    pub fn operator(&mut self, operator: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.operator = operator;
+        self
+    }
+    #[inline(always)]
+    pub fn collection_nonce(&mut self, collection_nonce: u8) -> &mut Self {
+        self.collection_nonce = Some(collection_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_bump(&mut self, collection_bump: u8) -> &mut Self {
+        self.collection_bump = Some(collection_bump);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = RemoveCartridgeV2 {
+            cartridge: self.cartridge.expect("cartridge is not set"),
+            game: self.game.expect("game is not set"),
+            cartridge_owner: self.cartridge_owner.expect("cartridge_owner is not set"),
+            machine: self.machine.expect("machine is not set"),
+            machine_collection: self
+                .machine_collection
+                .expect("machine_collection is not set"),
+            machine_owner: self.machine_owner.expect("machine_owner is not set"),
+            mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
+                "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            operator: self.operator,
+        };
+        let args = RemoveCartridgeV2InstructionArgs {
+            collection_nonce: self
+                .collection_nonce
+                .clone()
+                .expect("collection_nonce is not set"),
+            collection_bump: self
+                .collection_bump
+                .clone()
+                .expect("collection_bump is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `remove_cartridge_v2` CPI accounts.
+pub struct RemoveCartridgeV2CpiAccounts<'a, 'b> {
+    /// The game cartridge account
+    pub cartridge: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The game Collection account
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the game cartridge
+    pub cartridge_owner: (&'b solana_program::account_info::AccountInfo<'a>, bool),
+    /// The machine asset account
+    pub machine: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The Core machine collection
+    pub machine_collection: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the machine, co-signing while the session is locked
+    pub machine_owner: (&'b solana_program::account_info::AccountInfo<'a>, bool),
+    /// The mpl core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The machine operator delegate signing in place of the cartridge owner
+    pub operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `remove_cartridge_v2` CPI instruction.
+pub struct RemoveCartridgeV2Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The game cartridge account
+    pub cartridge: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The game Collection account
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the game cartridge
+    pub cartridge_owner: (&'b solana_program::account_info::AccountInfo<'a>, bool),
+    /// The machine asset account
+    pub machine: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The Core machine collection
+    pub machine_collection: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the machine, co-signing while the session is locked
+    pub machine_owner: (&'b solana_program::account_info::AccountInfo<'a>, bool),
+    /// The mpl core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The machine operator delegate signing in place of the cartridge owner
+    pub operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: RemoveCartridgeV2InstructionArgs,
+}
+
+impl<'a, 'b> RemoveCartridgeV2Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: RemoveCartridgeV2CpiAccounts<'a, 'b>,
+        args: RemoveCartridgeV2InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            cartridge: accounts.cartridge,
+            game: accounts.game,
+            cartridge_owner: accounts.cartridge_owner,
+            machine: accounts.machine,
+            machine_collection: accounts.machine_collection,
+            machine_owner: accounts.machine_owner,
+            mpl_core_program: accounts.mpl_core_program,
+            system_program: accounts.system_program,
+            operator: accounts.operator,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.cartridge.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.game.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.cartridge_owner.0.key,
+            self.cartridge_owner.1,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.machine.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.machine_collection.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.machine_owner.0.key,
+            self.machine_owner.1,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mpl_core_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(operator) = self.operator {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *operator.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = RemoveCartridgeV2InstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.cartridge.clone());
+        account_infos.push(self.game.clone());
+        account_infos.push(self.cartridge_owner.0.clone());
+        account_infos.push(self.machine.clone());
+        account_infos.push(self.machine_collection.clone());
+        account_infos.push(self.machine_owner.0.clone());
+        account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.system_program.clone());
+        if let Some(operator) = self.operator {
+            account_infos.push(operator.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `RemoveCartridgeV2` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` cartridge
+///   1. `[writable]` game
+///   2. `[writable, signer]` cartridge_owner
+///   3. `[writable]` machine
+///   4. `[writable]` machine_collection
+///   5. `[signer]` machine_owner
+///   6. `[]` mpl_core_program
+///   7. `[]` system_program
+///   8. `[writable, signer, optional]` operator
+pub struct RemoveCartridgeV2CpiBuilder<'a, 'b> {
+    instruction: Box<RemoveCartridgeV2CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> RemoveCartridgeV2CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(RemoveCartridgeV2CpiBuilderInstruction {
+            __program: program,
+            cartridge: None,
+            game: None,
+            cartridge_owner: None,
+            machine: None,
+            machine_collection: None,
+            machine_owner: None,
+            mpl_core_program: None,
+            system_program: None,
+            operator: None,
+            collection_nonce: None,
+            collection_bump: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The game cartridge account
+    #[inline(always)]
+    pub fn cartridge(
+        &mut self,
+        cartridge: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.cartridge = Some(cartridge);
+        self
+    }
+    /// The game Collection account
+    #[inline(always)]
+    pub fn game(&mut self, game: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.game = Some(game);
+        self
+    }
+    /// The owner of the game cartridge
+    #[inline(always)]
+    pub fn cartridge_owner(
+        &mut self,
+        cartridge_owner: &'b solana_program::account_info::AccountInfo<'a>,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.instruction.cartridge_owner = Some((cartridge_owner, as_signer));
+        self
+    }
+    /// The machine asset account
+    #[inline(always)]
+    pub fn machine(
+        &mut self,
+        machine: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.machine = Some(machine);
+        self
+    }
+    /// The Core machine collection
+    #[inline(always)]
+    pub fn machine_collection(
+        &mut self,
+        machine_collection: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.machine_collection = Some(machine_collection);
+        self
+    }
+    /// The owner of the machine, co-signing while the session is locked
+    #[inline(always)]
+    pub fn machine_owner(
+        &mut self,
+        machine_owner: &'b solana_program::account_info::AccountInfo<'a>,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.instruction.machine_owner = Some((machine_owner, as_signer));
+        self
+    }
+    /// The mpl core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The machine operator delegate signing in place of the cartridge owner
+    #[inline(always)]
+    pub fn operator(
+        &mut self,
+        operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.operator = operator;
+        self
+    }
+    #[inline(always)]
+    pub fn collection_nonce(&mut self, collection_nonce: u8) -> &mut Self {
+        self.instruction.collection_nonce = Some(collection_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_bump(&mut self, collection_bump: u8) -> &mut Self {
+        self.instruction.collection_bump = Some(collection_bump);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = RemoveCartridgeV2InstructionArgs {
+            collection_nonce: self
+                .instruction
+                .collection_nonce
+                .clone()
+                .expect("collection_nonce is not set"),
+            collection_bump: self
+                .instruction
+                .collection_bump
+                .clone()
+                .expect("collection_bump is not set"),
+        };
+        let instruction = RemoveCartridgeV2Cpi {
+            __program: self.instruction.__program,
+
+            cartridge: self.instruction.cartridge.expect("cartridge is not set"),
+
+            game: self.instruction.game.expect("game is not set"),
+
+            cartridge_owner: self
+                .instruction
+                .cartridge_owner
+                .expect("cartridge_owner is not set"),
+
+            machine: self.instruction.machine.expect("machine is not set"),
+
+            machine_collection: self
+                .instruction
+                .machine_collection
+                .expect("machine_collection is not set"),
+
+            machine_owner: self
+                .instruction
+                .machine_owner
+                .expect("machine_owner is not set"),
+
+            mpl_core_program: self
+                .instruction
+                .mpl_core_program
+                .expect("mpl_core_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            operator: self.instruction.operator,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct RemoveCartridgeV2CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    cartridge: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    game: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cartridge_owner: Option<(&'b solana_program::account_info::AccountInfo<'a>, bool)>,
+    machine: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    machine_collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    machine_owner: Option<(&'b solana_program::account_info::AccountInfo<'a>, bool)>,
+    mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection_nonce: Option<u8>,
+    collection_bump: Option<u8>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
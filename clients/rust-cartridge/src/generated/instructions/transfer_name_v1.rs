@@ -0,0 +1,367 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct TransferNameV1 {
+    /// The registry record of the name
+    pub name_record: solana_program::pubkey::Pubkey,
+    /// The publisher holding the name, or its team
+    pub owner: (solana_program::pubkey::Pubkey, bool),
+    /// The publisher, or team, receiving the name
+    pub new_owner: solana_program::pubkey::Pubkey,
+}
+
+impl TransferNameV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.name_record,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.owner.0,
+            self.owner.1,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.new_owner,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = TransferNameV1InstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct TransferNameV1InstructionData {
+    discriminator: u8,
+}
+
+impl TransferNameV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 41 }
+    }
+}
+
+/// Instruction builder for `TransferNameV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` name_record
+///   1. `[signer]` owner
+///   2. `[]` new_owner
+#[derive(Default)]
+pub struct TransferNameV1Builder {
+    name_record: Option<solana_program::pubkey::Pubkey>,
+    owner: Option<(solana_program::pubkey::Pubkey, bool)>,
+    new_owner: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl TransferNameV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The registry record of the name
+    #[inline(always)]
+    pub fn name_record(&mut self, name_record: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.name_record = Some(name_record);
+        self
+    }
+    /// The publisher holding the name, or its team
+    #[inline(always)]
+    pub fn owner(&mut self, owner: solana_program::pubkey::Pubkey, as_signer: bool) -> &mut Self {
+        self.owner = Some((owner, as_signer));
+        self
+    }
+    /// The publisher, or team, receiving the name
+    #[inline(always)]
+    pub fn new_owner(&mut self, new_owner: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.new_owner = Some(new_owner);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = TransferNameV1 {
+            name_record: self.name_record.expect("name_record is not set"),
+            owner: self.owner.expect("owner is not set"),
+            new_owner: self.new_owner.expect("new_owner is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `transfer_name_v1` CPI accounts.
+pub struct TransferNameV1CpiAccounts<'a, 'b> {
+    /// The registry record of the name
+    pub name_record: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The publisher holding the name, or its team
+    pub owner: (&'b solana_program::account_info::AccountInfo<'a>, bool),
+    /// The publisher, or team, receiving the name
+    pub new_owner: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `transfer_name_v1` CPI instruction.
+pub struct TransferNameV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The registry record of the name
+    pub name_record: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The publisher holding the name, or its team
+    pub owner: (&'b solana_program::account_info::AccountInfo<'a>, bool),
+    /// The publisher, or team, receiving the name
+    pub new_owner: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> TransferNameV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: TransferNameV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            name_record: accounts.name_record,
+            owner: accounts.owner,
+            new_owner: accounts.new_owner,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.name_record.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.owner.0.key,
+            self.owner.1,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.new_owner.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = TransferNameV1InstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.name_record.clone());
+        account_infos.push(self.owner.0.clone());
+        account_infos.push(self.new_owner.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `TransferNameV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` name_record
+///   1. `[signer]` owner
+///   2. `[]` new_owner
+pub struct TransferNameV1CpiBuilder<'a, 'b> {
+    instruction: Box<TransferNameV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> TransferNameV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(TransferNameV1CpiBuilderInstruction {
+            __program: program,
+            name_record: None,
+            owner: None,
+            new_owner: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The registry record of the name
+    #[inline(always)]
+    pub fn name_record(
+        &mut self,
+        name_record: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.name_record = Some(name_record);
+        self
+    }
+    /// The publisher holding the name, or its team
+    #[inline(always)]
+    pub fn owner(
+        &mut self,
+        owner: &'b solana_program::account_info::AccountInfo<'a>,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.instruction.owner = Some((owner, as_signer));
+        self
+    }
+    /// The publisher, or team, receiving the name
+    #[inline(always)]
+    pub fn new_owner(
+        &mut self,
+        new_owner: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.new_owner = Some(new_owner);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = TransferNameV1Cpi {
+            __program: self.instruction.__program,
+
+            name_record: self
+                .instruction
+                .name_record
+                .expect("name_record is not set"),
+
+            owner: self.instruction.owner.expect("owner is not set"),
+
+            new_owner: self.instruction.new_owner.expect("new_owner is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct TransferNameV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    name_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    owner: Option<(&'b solana_program::account_info::AccountInfo<'a>, bool)>,
+    new_owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,852 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::Allowlist;
+use crate::generated::types::PricingModel;
+use crate::generated::types::RentalTerms;
+use crate::generated::types::SaleWindow;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Accounts.
+pub struct UpdateGameV1 {
+    /// The game Collection account
+    pub game: solana_program::pubkey::Pubkey,
+    /// The publisher of the game, or its team
+    pub publisher: (solana_program::pubkey::Pubkey, bool),
+    /// The account paying for the storage fees
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The mpl core program
+    pub mpl_core_program: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// The pass collection the game joins
+    pub pass_collection: Option<solana_program::pubkey::Pubkey>,
+    /// The authority of the pass collection the game joins
+    pub pass_authority: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl UpdateGameV1 {
+    pub fn instruction(
+        &self,
+        args: UpdateGameV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: UpdateGameV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.game, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.publisher.0,
+            self.publisher.1,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mpl_core_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        if let Some(pass_collection) = self.pass_collection {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                pass_collection,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        if let Some(pass_authority) = self.pass_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                pass_authority,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = UpdateGameV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct UpdateGameV1InstructionData {
+    discriminator: u8,
+}
+
+impl UpdateGameV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 8 }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpdateGameV1InstructionArgs {
+    pub collection_nonce: u8,
+    pub collection_bump: u8,
+    pub compatible_machine_collections: Option<Vec<Pubkey>>,
+    pub sale_window: Option<SaleWindow>,
+    pub pricing: Option<PricingModel>,
+    pub allowlist: Option<Allowlist>,
+    pub max_per_wallet: Option<u32>,
+    pub voucher_signer: Option<Pubkey>,
+    pub referral_bps: Option<u16>,
+    pub save_authority: Option<Pubkey>,
+    pub refund_window: Option<i64>,
+    pub rental: Option<RentalTerms>,
+    pub pass: Option<Pubkey>,
+}
+
+/// Instruction builder for `UpdateGameV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` game
+///   1. `[signer]` publisher
+///   2. `[writable, signer]` payer
+///   3. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` pass_collection
+///   6. `[signer, optional]` pass_authority
+#[derive(Default)]
+pub struct UpdateGameV1Builder {
+    game: Option<solana_program::pubkey::Pubkey>,
+    publisher: Option<(solana_program::pubkey::Pubkey, bool)>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    mpl_core_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    pass_collection: Option<solana_program::pubkey::Pubkey>,
+    pass_authority: Option<solana_program::pubkey::Pubkey>,
+    collection_nonce: Option<u8>,
+    collection_bump: Option<u8>,
+    compatible_machine_collections: Option<Vec<Pubkey>>,
+    sale_window: Option<SaleWindow>,
+    pricing: Option<PricingModel>,
+    allowlist: Option<Allowlist>,
+    max_per_wallet: Option<u32>,
+    voucher_signer: Option<Pubkey>,
+    referral_bps: Option<u16>,
+    save_authority: Option<Pubkey>,
+    refund_window: Option<i64>,
+    rental: Option<RentalTerms>,
+    pass: Option<Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl UpdateGameV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The game Collection account
+    #[inline(always)]
+    pub fn game(&mut self, game: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.game = Some(game);
+        self
+    }
+    /// The publisher of the game, or its team
+    #[inline(always)]
+    pub fn publisher(
+        &mut self,
+        publisher: solana_program::pubkey::Pubkey,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.publisher = Some((publisher, as_signer));
+        self
+    }
+    /// The account paying for the storage fees
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d']`
+    /// The mpl core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The pass collection the game joins
+    #[inline(always)]
+    pub fn pass_collection(
+        &mut self,
+        pass_collection: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.pass_collection = pass_collection;
+        self
+    }
+    /// `[optional account]`
+    /// The authority of the pass collection the game joins
+    #[inline(always)]
+    pub fn pass_authority(
+        &mut self,
+        pass_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.pass_authority = pass_authority;
+        self
+    }
+    #[inline(always)]
+    pub fn collection_nonce(&mut self, collection_nonce: u8) -> &mut Self {
+        self.collection_nonce = Some(collection_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_bump(&mut self, collection_bump: u8) -> &mut Self {
+        self.collection_bump = Some(collection_bump);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn compatible_machine_collections(
+        &mut self,
+        compatible_machine_collections: Vec<Pubkey>,
+    ) -> &mut Self {
+        self.compatible_machine_collections = Some(compatible_machine_collections);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sale_window(&mut self, sale_window: SaleWindow) -> &mut Self {
+        self.sale_window = Some(sale_window);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn pricing(&mut self, pricing: PricingModel) -> &mut Self {
+        self.pricing = Some(pricing);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn allowlist(&mut self, allowlist: Allowlist) -> &mut Self {
+        self.allowlist = Some(allowlist);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_per_wallet(&mut self, max_per_wallet: u32) -> &mut Self {
+        self.max_per_wallet = Some(max_per_wallet);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn voucher_signer(&mut self, voucher_signer: Pubkey) -> &mut Self {
+        self.voucher_signer = Some(voucher_signer);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn referral_bps(&mut self, referral_bps: u16) -> &mut Self {
+        self.referral_bps = Some(referral_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn save_authority(&mut self, save_authority: Pubkey) -> &mut Self {
+        self.save_authority = Some(save_authority);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn refund_window(&mut self, refund_window: i64) -> &mut Self {
+        self.refund_window = Some(refund_window);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn rental(&mut self, rental: RentalTerms) -> &mut Self {
+        self.rental = Some(rental);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn pass(&mut self, pass: Pubkey) -> &mut Self {
+        self.pass = Some(pass);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = UpdateGameV1 {
+            game: self.game.expect("game is not set"),
+            publisher: self.publisher.expect("publisher is not set"),
+            payer: self.payer.expect("payer is not set"),
+            mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
+                "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            pass_collection: self.pass_collection,
+            pass_authority: self.pass_authority,
+        };
+        let args = UpdateGameV1InstructionArgs {
+            collection_nonce: self
+                .collection_nonce
+                .clone()
+                .expect("collection_nonce is not set"),
+            collection_bump: self
+                .collection_bump
+                .clone()
+                .expect("collection_bump is not set"),
+            compatible_machine_collections: self.compatible_machine_collections.clone(),
+            sale_window: self.sale_window.clone(),
+            pricing: self.pricing.clone(),
+            allowlist: self.allowlist.clone(),
+            max_per_wallet: self.max_per_wallet.clone(),
+            voucher_signer: self.voucher_signer.clone(),
+            referral_bps: self.referral_bps.clone(),
+            save_authority: self.save_authority.clone(),
+            refund_window: self.refund_window.clone(),
+            rental: self.rental.clone(),
+            pass: self.pass.clone(),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `update_game_v1` CPI accounts.
+pub struct UpdateGameV1CpiAccounts<'a, 'b> {
+    /// The game Collection account
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The publisher of the game, or its team
+    pub publisher: (&'b solana_program::account_info::AccountInfo<'a>, bool),
+    /// The account paying for the storage fees
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The pass collection the game joins
+    pub pass_collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The authority of the pass collection the game joins
+    pub pass_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `update_game_v1` CPI instruction.
+pub struct UpdateGameV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The game Collection account
+    pub game: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The publisher of the game, or its team
+    pub publisher: (&'b solana_program::account_info::AccountInfo<'a>, bool),
+    /// The account paying for the storage fees
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The pass collection the game joins
+    pub pass_collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The authority of the pass collection the game joins
+    pub pass_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: UpdateGameV1InstructionArgs,
+}
+
+impl<'a, 'b> UpdateGameV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: UpdateGameV1CpiAccounts<'a, 'b>,
+        args: UpdateGameV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            game: accounts.game,
+            publisher: accounts.publisher,
+            payer: accounts.payer,
+            mpl_core_program: accounts.mpl_core_program,
+            system_program: accounts.system_program,
+            pass_collection: accounts.pass_collection,
+            pass_authority: accounts.pass_authority,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.game.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.publisher.0.key,
+            self.publisher.1,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mpl_core_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(pass_collection) = self.pass_collection {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *pass_collection.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        if let Some(pass_authority) = self.pass_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *pass_authority.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BGL_CARTRIDGE_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = UpdateGameV1InstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::BGL_CARTRIDGE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.game.clone());
+        account_infos.push(self.publisher.0.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.system_program.clone());
+        if let Some(pass_collection) = self.pass_collection {
+            account_infos.push(pass_collection.clone());
+        }
+        if let Some(pass_authority) = self.pass_authority {
+            account_infos.push(pass_authority.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `UpdateGameV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` game
+///   1. `[signer]` publisher
+///   2. `[writable, signer]` payer
+///   3. `[]` mpl_core_program
+///   4. `[]` system_program
+///   5. `[optional]` pass_collection
+///   6. `[signer, optional]` pass_authority
+pub struct UpdateGameV1CpiBuilder<'a, 'b> {
+    instruction: Box<UpdateGameV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> UpdateGameV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(UpdateGameV1CpiBuilderInstruction {
+            __program: program,
+            game: None,
+            publisher: None,
+            payer: None,
+            mpl_core_program: None,
+            system_program: None,
+            pass_collection: None,
+            pass_authority: None,
+            collection_nonce: None,
+            collection_bump: None,
+            compatible_machine_collections: None,
+            sale_window: None,
+            pricing: None,
+            allowlist: None,
+            max_per_wallet: None,
+            voucher_signer: None,
+            referral_bps: None,
+            save_authority: None,
+            refund_window: None,
+            rental: None,
+            pass: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The game Collection account
+    #[inline(always)]
+    pub fn game(&mut self, game: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.game = Some(game);
+        self
+    }
+    /// The publisher of the game, or its team
+    #[inline(always)]
+    pub fn publisher(
+        &mut self,
+        publisher: &'b solana_program::account_info::AccountInfo<'a>,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.instruction.publisher = Some((publisher, as_signer));
+        self
+    }
+    /// The account paying for the storage fees
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// The mpl core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The pass collection the game joins
+    #[inline(always)]
+    pub fn pass_collection(
+        &mut self,
+        pass_collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.pass_collection = pass_collection;
+        self
+    }
+    /// `[optional account]`
+    /// The authority of the pass collection the game joins
+    #[inline(always)]
+    pub fn pass_authority(
+        &mut self,
+        pass_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.pass_authority = pass_authority;
+        self
+    }
+    #[inline(always)]
+    pub fn collection_nonce(&mut self, collection_nonce: u8) -> &mut Self {
+        self.instruction.collection_nonce = Some(collection_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn collection_bump(&mut self, collection_bump: u8) -> &mut Self {
+        self.instruction.collection_bump = Some(collection_bump);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn compatible_machine_collections(
+        &mut self,
+        compatible_machine_collections: Vec<Pubkey>,
+    ) -> &mut Self {
+        self.instruction.compatible_machine_collections = Some(compatible_machine_collections);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sale_window(&mut self, sale_window: SaleWindow) -> &mut Self {
+        self.instruction.sale_window = Some(sale_window);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn pricing(&mut self, pricing: PricingModel) -> &mut Self {
+        self.instruction.pricing = Some(pricing);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn allowlist(&mut self, allowlist: Allowlist) -> &mut Self {
+        self.instruction.allowlist = Some(allowlist);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_per_wallet(&mut self, max_per_wallet: u32) -> &mut Self {
+        self.instruction.max_per_wallet = Some(max_per_wallet);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn voucher_signer(&mut self, voucher_signer: Pubkey) -> &mut Self {
+        self.instruction.voucher_signer = Some(voucher_signer);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn referral_bps(&mut self, referral_bps: u16) -> &mut Self {
+        self.instruction.referral_bps = Some(referral_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn save_authority(&mut self, save_authority: Pubkey) -> &mut Self {
+        self.instruction.save_authority = Some(save_authority);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn refund_window(&mut self, refund_window: i64) -> &mut Self {
+        self.instruction.refund_window = Some(refund_window);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn rental(&mut self, rental: RentalTerms) -> &mut Self {
+        self.instruction.rental = Some(rental);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn pass(&mut self, pass: Pubkey) -> &mut Self {
+        self.instruction.pass = Some(pass);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = UpdateGameV1InstructionArgs {
+            collection_nonce: self
+                .instruction
+                .collection_nonce
+                .clone()
+                .expect("collection_nonce is not set"),
+            collection_bump: self
+                .instruction
+                .collection_bump
+                .clone()
+                .expect("collection_bump is not set"),
+            compatible_machine_collections: self.instruction.compatible_machine_collections.clone(),
+            sale_window: self.instruction.sale_window.clone(),
+            pricing: self.instruction.pricing.clone(),
+            allowlist: self.instruction.allowlist.clone(),
+            max_per_wallet: self.instruction.max_per_wallet.clone(),
+            voucher_signer: self.instruction.voucher_signer.clone(),
+            referral_bps: self.instruction.referral_bps.clone(),
+            save_authority: self.instruction.save_authority.clone(),
+            refund_window: self.instruction.refund_window.clone(),
+            rental: self.instruction.rental.clone(),
+            pass: self.instruction.pass.clone(),
+        };
+        let instruction = UpdateGameV1Cpi {
+            __program: self.instruction.__program,
+
+            game: self.instruction.game.expect("game is not set"),
+
+            publisher: self.instruction.publisher.expect("publisher is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            mpl_core_program: self
+                .instruction
+                .mpl_core_program
+                .expect("mpl_core_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            pass_collection: self.instruction.pass_collection,
+
+            pass_authority: self.instruction.pass_authority,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct UpdateGameV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    game: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    publisher: Option<(&'b solana_program::account_info::AccountInfo<'a>, bool)>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pass_collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pass_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection_nonce: Option<u8>,
+    collection_bump: Option<u8>,
+    compatible_machine_collections: Option<Vec<Pubkey>>,
+    sale_window: Option<SaleWindow>,
+    pricing: Option<PricingModel>,
+    allowlist: Option<Allowlist>,
+    max_per_wallet: Option<u32>,
+    voucher_signer: Option<Pubkey>,
+    referral_bps: Option<u16>,
+    save_authority: Option<Pubkey>,
+    refund_window: Option<i64>,
+    rental: Option<RentalTerms>,
+    pass: Option<Pubkey>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
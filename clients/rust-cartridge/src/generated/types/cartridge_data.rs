@@ -10,6 +10,7 @@ use crate::generated::types::Source;
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
@@ -18,4 +19,13 @@ use borsh::{BorshDeserialize, BorshSerialize};
 pub struct CartridgeData {
     pub version: u8,
     pub source: Source,
+    pub referrer: Option<Pubkey>,
+    pub base_cartridge: Option<Pubkey>,
+    pub purchase_price: u64,
+    pub printed_at: i64,
+    pub lender: Option<Pubkey>,
+    pub expires_at: Option<i64>,
+    pub rent_payer: Option<Pubkey>,
+    pub refundable_until: Option<i64>,
+    pub rent_paid: u64,
 }
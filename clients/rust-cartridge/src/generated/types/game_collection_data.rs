@@ -5,7 +5,10 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::Allowlist;
 use crate::generated::types::PriceType;
+use crate::generated::types::PricingModel;
+use crate::generated::types::RentalTerms;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -25,4 +28,35 @@ pub struct GameCollectionData {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub publisher: Pubkey,
+    pub compatible_machine_collections: Vec<Pubkey>,
+    pub sale_start: Option<i64>,
+    pub sale_end: Option<i64>,
+    pub pricing: PricingModel,
+    pub allowlist: Allowlist,
+    pub max_per_wallet: Option<u32>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub voucher_signer: Pubkey,
+    pub referral_bps: u16,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub base_game: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub save_authority: Pubkey,
+    pub refund_window: Option<i64>,
+    pub rental: Option<RentalTerms>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pass: Pubkey,
+    pub crowdfund_deadline: Option<i64>,
+    pub delisted: bool,
 }
@@ -5,12 +5,72 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#allowlist;
+pub(crate) mod r#allowlist_receipt;
+pub(crate) mod r#bundle;
+pub(crate) mod r#bundle_game_args;
+pub(crate) mod r#bundle_member;
 pub(crate) mod r#cartridge_data;
+pub(crate) mod r#catalog;
+pub(crate) mod r#catalog_entry;
+pub(crate) mod r#crowdfund_terms;
+pub(crate) mod r#dlc_receipt;
+pub(crate) mod r#escrow;
 pub(crate) mod r#game_collection_data;
+pub(crate) mod r#insertion_record;
+pub(crate) mod r#listing;
+pub(crate) mod r#loan;
+pub(crate) mod r#machine_data;
+pub(crate) mod r#mint_counter;
+pub(crate) mod r#name_record;
+pub(crate) mod r#pass_collection_data;
+pub(crate) mod r#pass_data;
+pub(crate) mod r#pre_order;
 pub(crate) mod r#price_type;
+pub(crate) mod r#pricing_model;
+pub(crate) mod r#publisher_team;
+pub(crate) mod r#rental_terms;
+pub(crate) mod r#sale_window;
+pub(crate) mod r#save_data;
+pub(crate) mod r#session_lock;
 pub(crate) mod r#source;
+pub(crate) mod r#team_member;
+pub(crate) mod r#team_member_args;
+pub(crate) mod r#verification;
+pub(crate) mod r#verification_status;
+pub(crate) mod r#voucher_record;
 
+pub use self::r#allowlist::*;
+pub use self::r#allowlist_receipt::*;
+pub use self::r#bundle::*;
+pub use self::r#bundle_game_args::*;
+pub use self::r#bundle_member::*;
 pub use self::r#cartridge_data::*;
+pub use self::r#catalog::*;
+pub use self::r#catalog_entry::*;
+pub use self::r#crowdfund_terms::*;
+pub use self::r#dlc_receipt::*;
+pub use self::r#escrow::*;
 pub use self::r#game_collection_data::*;
+pub use self::r#insertion_record::*;
+pub use self::r#listing::*;
+pub use self::r#loan::*;
+pub use self::r#machine_data::*;
+pub use self::r#mint_counter::*;
+pub use self::r#name_record::*;
+pub use self::r#pass_collection_data::*;
+pub use self::r#pass_data::*;
+pub use self::r#pre_order::*;
 pub use self::r#price_type::*;
+pub use self::r#pricing_model::*;
+pub use self::r#publisher_team::*;
+pub use self::r#rental_terms::*;
+pub use self::r#sale_window::*;
+pub use self::r#save_data::*;
+pub use self::r#session_lock::*;
 pub use self::r#source::*;
+pub use self::r#team_member::*;
+pub use self::r#team_member_args::*;
+pub use self::r#verification::*;
+pub use self::r#verification_status::*;
+pub use self::r#voucher_record::*;
@@ -19,4 +19,5 @@ pub enum Source {
     Unknown,
     Crypto,
     Stripe,
+    PublisherGrant,
 }
@@ -30,6 +30,18 @@ kinobi.update(
   })
 );
 
+// The V1 and V2 release, print, insert and remove instructions share their
+// arguments, inline them.
+kinobi.update(
+  k.unwrapDefinedTypesVisitor([
+    "releaseGameV1Args",
+    "printGameCartridgeV1Args",
+    "insertCartridgeV1Args",
+    "removeCartridgeV1Args",
+  ])
+);
+kinobi.update(k.flattenInstructionDataArgumentsVisitor());
+
 // Update instructions.
 kinobi.update(
   new k.updateInstructionsVisitor({
@@ -68,6 +80,62 @@ kinobi.update(
         nonce: {
           defaultValue: k.numberValueNode(0),
         },
+        saleStart: {
+          defaultValue: k.noneValueNode(),
+        },
+        saleEnd: {
+          defaultValue: k.noneValueNode(),
+        },
+        crowdfund: {
+          defaultValue: k.noneValueNode(),
+        },
+      },
+    },
+    releaseGameV2: {
+      accounts: {
+        game: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("game", "hooked"), [
+            k.pdaSeedValueNode("name", k.argumentValueNode("name"), "The name of the game"),
+            k.pdaSeedValueNode("nonce", k.argumentValueNode("nonce"), "The nonce of the game"),
+          ]),
+        },
+        gameTokenAccount: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("associatedToken", "mplToolbox"), [
+            k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+            k.pdaSeedValueNode("owner", k.accountValueNode("game")),
+          ]),
+        },
+        paymentMint: {
+          defaultValue: k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump"),
+        },
+        associatedTokenProgram: {
+          defaultValue: k.publicKeyValueNode("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+        },
+        nameRecord: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("nameRecord", "hooked"), [
+            k.pdaSeedValueNode("name", k.argumentValueNode("name")),
+          ]),
+        },
+        // Pass the catalog explicitly when an authority or a team publishes.
+        catalog: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("catalog", "hooked"), [
+            k.pdaSeedValueNode("publisher", k.accountValueNode("payer")),
+          ]),
+        },
+      },
+      arguments: {
+        nonce: {
+          defaultValue: k.numberValueNode(0),
+        },
+        saleStart: {
+          defaultValue: k.noneValueNode(),
+        },
+        saleEnd: {
+          defaultValue: k.noneValueNode(),
+        },
+        crowdfund: {
+          defaultValue: k.noneValueNode(),
+        },
       },
     },
     printGameCartridgeV1: {
@@ -91,10 +159,607 @@ kinobi.update(
         paymentMint: {
           defaultValue: k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump"),
         },
-      }
+      },
+      arguments: {
+        maxPrice: {
+          defaultValue: k.noneValueNode(),
+        },
+      },
+    },
+    printGameCartridgeV2: {
+      accounts: {
+        gameTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("game"))
+            ]
+          ),
+        },
+        payerTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("payer"))
+            ]
+          ),
+        },
+        mintCounter: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("mintCounter", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+            k.pdaSeedValueNode("owner", k.accountValueNode("owner")),
+          ]),
+        },
+        paymentMint: {
+          defaultValue: k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump"),
+        },
+      },
+      arguments: {
+        maxPrice: {
+          defaultValue: k.noneValueNode(),
+        },
+      },
+    },
+    printGameCartridgeAllowlistV1: {
+      accounts: {
+        gameTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("game"))
+            ]
+          ),
+        },
+        payerTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("payer"))
+            ]
+          ),
+        },
+        allowlistReceipt: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("allowlistReceipt", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+            k.pdaSeedValueNode("wallet", k.accountValueNode("payer")),
+          ]),
+        },
+        mintCounter: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("mintCounter", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+            k.pdaSeedValueNode("owner", k.accountValueNode("owner")),
+          ]),
+        },
+        paymentMint: {
+          defaultValue: k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump"),
+        },
+      },
+    },
+    printGameCartridgeVoucherV1: {
+      accounts: {
+        gameTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("game"))
+            ]
+          ),
+        },
+        payerTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("payer"))
+            ]
+          ),
+        },
+        voucherRecord: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("voucherRecord", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+            k.pdaSeedValueNode("voucherNonce", k.argumentValueNode("voucherNonce")),
+          ]),
+        },
+        mintCounter: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("mintCounter", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+            k.pdaSeedValueNode("owner", k.accountValueNode("owner")),
+          ]),
+        },
+        paymentMint: {
+          defaultValue: k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump"),
+        },
+        sysvarInstructions: {
+          defaultValue: k.publicKeyValueNode("Sysvar1nstructions1111111111111111111111111"),
+        },
+      },
+    },
+    createBundleV1: {
+      accounts: {
+        bundle: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("bundle", "hooked"), [
+            k.pdaSeedValueNode("publisher", k.accountValueNode("publisher")),
+            k.pdaSeedValueNode("name", k.argumentValueNode("name")),
+          ]),
+        },
+      },
+    },
+    printBundleV1: {
+      accounts: {
+        payerTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("payer"))
+            ]
+          ),
+        },
+        paymentMint: {
+          defaultValue: k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump"),
+        },
+      },
+    },
+    printDlcV1: {
+      accounts: {
+        gameTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("game"))
+            ]
+          ),
+        },
+        payerTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("payer"))
+            ]
+          ),
+        },
+        mintCounter: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("mintCounter", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+            k.pdaSeedValueNode("owner", k.accountValueNode("owner")),
+          ]),
+        },
+        dlcReceipt: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("dlcReceipt", "hooked"), [
+            k.pdaSeedValueNode("dlc", k.accountValueNode("game")),
+            k.pdaSeedValueNode("baseCartridge", k.accountValueNode("baseCartridge")),
+          ]),
+        },
+        paymentMint: {
+          defaultValue: k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump"),
+        },
+      },
+    },
+    refundCartridgeV1: {
+      accounts: {
+        gameTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("game"))
+            ]
+          ),
+        },
+        ownerTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("owner"))
+            ]
+          ),
+        },
+      },
+    },
+    listCartridgeV1: {
+      accounts: {
+        listing: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("listing", "hooked"), [
+            k.pdaSeedValueNode("cartridge", k.accountValueNode("cartridge")),
+          ]),
+        },
+      },
+    },
+    buyCartridgeV1: {
+      accounts: {
+        listing: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("listing", "hooked"), [
+            k.pdaSeedValueNode("cartridge", k.accountValueNode("cartridge")),
+          ]),
+        },
+        sellerTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("seller"))
+            ]
+          ),
+        },
+        buyerTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("buyer"))
+            ]
+          ),
+        },
+      },
+    },
+    lendCartridgeV1: {
+      accounts: {
+        loan: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("loan", "hooked"), [
+            k.pdaSeedValueNode("cartridge", k.accountValueNode("cartridge")),
+          ]),
+        },
+      },
+    },
+    returnCartridgeV1: {
+      accounts: {
+        loan: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("loan", "hooked"), [
+            k.pdaSeedValueNode("cartridge", k.accountValueNode("cartridge")),
+          ]),
+        },
+      },
+    },
+    printRentalCartridgeV1: {
+      accounts: {
+        gameTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("game"))
+            ]
+          ),
+        },
+        payerTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("payer"))
+            ]
+          ),
+        },
+        mintCounter: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("mintCounter", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+            k.pdaSeedValueNode("owner", k.accountValueNode("owner")),
+          ]),
+        },
+        paymentMint: {
+          defaultValue: k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump"),
+        },
+      },
+    },
+    reclaimExpiredCartridgeV1: {
+      accounts: {
+        rentalAuthority: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("rentalAuthority", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+          ]),
+        },
+      },
+    },
+    createPassV1: {
+      accounts: {
+        passCollection: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("pass", "hooked"), [
+            k.pdaSeedValueNode("authority", k.accountValueNode("authority")),
+            k.pdaSeedValueNode("name", k.argumentValueNode("name"), "The name of the pass"),
+          ]),
+        },
+        passTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("passCollection"))
+            ]
+          ),
+        },
+        paymentMint: {
+          defaultValue: k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump"),
+        },
+        associatedTokenProgram: {
+          defaultValue: k.publicKeyValueNode("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+        },
+      },
+    },
+    purchasePassV1: {
+      accounts: {
+        passTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("passCollection"))
+            ]
+          ),
+        },
+        payerTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("payer"))
+            ]
+          ),
+        },
+      },
+    },
+    renewPassV1: {
+      accounts: {
+        passTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("passCollection"))
+            ]
+          ),
+        },
+        payerTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("payer"))
+            ]
+          ),
+        },
+      },
+    },
+    withdrawPassRevenueV1: {
+      accounts: {
+        passTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("passCollection"))
+            ]
+          ),
+        },
+        authorityTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("authority"))
+            ]
+          ),
+        },
+      },
+    },
+    preOrderV1: {
+      accounts: {
+        escrow: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("escrow", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+          ]),
+        },
+        escrowTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("escrow"))
+            ]
+          ),
+        },
+        preOrder: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("preOrder", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+            k.pdaSeedValueNode("backer", k.accountValueNode("backer")),
+          ]),
+        },
+        backerTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("backer"))
+            ]
+          ),
+        },
+      },
+    },
+    claimEscrowV1: {
+      accounts: {
+        escrow: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("escrow", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+          ]),
+        },
+        escrowTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("escrow"))
+            ]
+          ),
+        },
+        gameTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("game"))
+            ]
+          ),
+        },
+        paymentMint: {
+          defaultValue: k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump"),
+        },
+      },
+    },
+    redeemPreOrderV1: {
+      accounts: {
+        escrow: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("escrow", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+          ]),
+        },
+        preOrder: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("preOrder", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+            k.pdaSeedValueNode("backer", k.accountValueNode("backer")),
+          ]),
+        },
+      },
+    },
+    refundPreOrderV1: {
+      accounts: {
+        escrow: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("escrow", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+          ]),
+        },
+        escrowTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("escrow"))
+            ]
+          ),
+        },
+        preOrder: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("preOrder", "hooked"), [
+            k.pdaSeedValueNode("game", k.accountValueNode("game")),
+            k.pdaSeedValueNode("backer", k.accountValueNode("backer")),
+          ]),
+        },
+        backerTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("backer"))
+            ]
+          ),
+        },
+      },
+    },
+    createPublisherTeamV1: {
+      accounts: {
+        team: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("publisherTeam", "hooked"), [
+            k.pdaSeedValueNode("creator", k.accountValueNode("creator")),
+            k.pdaSeedValueNode("name", k.argumentValueNode("name")),
+          ]),
+        },
+      },
+    },
+    withdrawGameRevenueV1: {
+      accounts: {
+        gameTokenAccount: {
+          defaultValue: k.pdaValueNode(
+            k.pdaLinkNode("associatedToken", "mplToolbox"), [
+              k.pdaSeedValueNode("mint", k.publicKeyValueNode("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump")),
+              k.pdaSeedValueNode("owner", k.accountValueNode("game"))
+            ]
+          ),
+        },
+      },
+    },
+    setVerificationV1: {
+      accounts: {
+        verification: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("verification", "hooked"), [
+            k.pdaSeedValueNode("subject", k.accountValueNode("subject")),
+          ]),
+        },
+      },
+      // Only games need their nonce and bump, with the mpl core program.
+      arguments: {
+        collectionNonce: {
+          defaultValue: k.numberValueNode(0),
+        },
+        collectionBump: {
+          defaultValue: k.numberValueNode(0),
+        },
+      },
+    },
+    reserveNameV1: {
+      accounts: {
+        nameRecord: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("nameRecord", "hooked"), [
+            k.pdaSeedValueNode("name", k.argumentValueNode("name")),
+          ]),
+        },
+      },
+    },
+    retireCatalogEntryV1: {
+      accounts: {
+        catalog: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("catalog", "hooked"), [
+            k.pdaSeedValueNode("publisher", k.accountValueNode("publisher")),
+          ]),
+        },
+      },
+    },
+    restoreCatalogEntryV1: {
+      accounts: {
+        catalog: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("catalog", "hooked"), [
+            k.pdaSeedValueNode("publisher", k.accountValueNode("publisher")),
+          ]),
+        },
+      },
+    },
+    addCatalogEntryV1: {
+      accounts: {
+        catalog: {
+          defaultValue: k.pdaValueNode(k.pdaLinkNode("catalog", "hooked"), [
+            k.pdaSeedValueNode("publisher", k.accountValueNode("publisher")),
+          ]),
+        },
+      },
+      arguments: {
+        collectionNonce: {
+          defaultValue: k.numberValueNode(0),
+        },
+      },
+    },
+    airdropCartridgesV1: {
+      // Only team airdrops lead the remaining accounts with approvers.
+      arguments: {
+        numApprovers: {
+          defaultValue: k.numberValueNode(0),
+        },
+      },
     },
     insertCartridgeV1: {},
     removeCartridgeV1: {},
+    updateGameV1: {
+      arguments: {
+        compatibleMachineCollections: {
+          defaultValue: k.noneValueNode(),
+        },
+        saleWindow: {
+          defaultValue: k.noneValueNode(),
+        },
+        pricing: {
+          defaultValue: k.noneValueNode(),
+        },
+        allowlist: {
+          defaultValue: k.noneValueNode(),
+        },
+        maxPerWallet: {
+          defaultValue: k.noneValueNode(),
+        },
+        voucherSigner: {
+          defaultValue: k.noneValueNode(),
+        },
+        referralBps: {
+          defaultValue: k.noneValueNode(),
+        },
+        saveAuthority: {
+          defaultValue: k.noneValueNode(),
+        },
+        refundWindow: {
+          defaultValue: k.noneValueNode(),
+        },
+        rental: {
+          defaultValue: k.noneValueNode(),
+        },
+        pass: {
+          defaultValue: k.noneValueNode(),
+        },
+      },
+    },
   })
 );
 
@@ -47,6 +47,12 @@ generateIdl({
     GameCollectionData: {
       priceType: { defined: "PriceType" },
     },
+    Verification: {
+      status: { defined: "VerificationStatus" },
+    },
+    SetVerificationV1Args: {
+      status: { defined: "VerificationStatus" },
+    },
     CartridgeData: {
       source: { defined: "Source" },
     },
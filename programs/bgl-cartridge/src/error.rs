@@ -107,6 +107,418 @@ pub enum BglCartridgeError {
     /// 24 - Source Already Set
     #[error("Cartridge source has already been set and cannot be changed")]
     SourceAlreadySet,
+
+    /// 25 - Machine Owner must sign
+    #[error("Machine Owner must sign")]
+    MachineOwnerMustSign,
+
+    /// 26 - Invalid Machine Operator
+    #[error("Signer is not the operator delegate of the machine")]
+    InvalidMachineOperator,
+
+    /// 27 - Cartridge Not In Machine Owner Custody
+    #[error("Operators can only handle cartridges owned by the machine owner")]
+    CartridgeNotInMachineOwnerCustody,
+
+    /// 28 - Machine Cartridge Mismatch
+    #[error("The machine does not hold the provided cartridge")]
+    MachineCartridgeMismatch,
+
+    /// 29 - Cartridge Already Linked
+    #[error("The cartridge is already inserted into a machine")]
+    CartridgeAlreadyLinked,
+
+    /// 30 - Cartridge Machine Mismatch
+    #[error("The cartridge is not linked to the provided machine")]
+    CartridgeMachineMismatch,
+
+    /// 31 - Incompatible Machine
+    #[error("The game cannot be played on machines of this collection")]
+    IncompatibleMachine,
+
+    /// 32 - Publisher Must Sign
+    #[error("The game publisher must sign")]
+    PublisherMustSign,
+
+    /// 33 - Too Many Machine Collections
+    #[error("Too many compatible machine collections")]
+    TooManyMachineCollections,
+
+    /// 34 - Session Locked
+    #[error("The machine session is locked, the machine owner must co-sign the removal")]
+    SessionLocked,
+
+    /// 35 - Invalid Session Expiry
+    #[error("The session lock expiry must be in the future")]
+    InvalidSessionExpiry,
+
+    /// 36 - Sale Not Started
+    #[error("The game is not on sale yet")]
+    SaleNotStarted,
+
+    /// 37 - Sale Ended
+    #[error("The game is no longer on sale")]
+    SaleEnded,
+
+    /// 38 - Invalid Sale Window
+    #[error("The sale must end after it starts and cannot start in the past")]
+    InvalidSaleWindow,
+
+    /// 39 - Sale Already Started
+    #[error("The sale window cannot be changed once the sale has started")]
+    SaleAlreadyStarted,
+
+    /// 40 - Invalid Pricing Model
+    #[error("Invalid pricing model parameters")]
+    InvalidPricingModel,
+
+    /// 41 - Numerical Overflow
+    #[error("Numerical overflow")]
+    NumericalOverflow,
+
+    /// 42 - Not Allowlisted
+    #[error("The wallet is not on the game allowlist")]
+    NotAllowlisted,
+
+    /// 43 - Allowlist Quota Exceeded
+    #[error("The wallet has used up its allowlist quota")]
+    AllowlistQuotaExceeded,
+
+    /// 44 - Invalid Allowlist Receipt
+    #[error("Invalid allowlist receipt account")]
+    InvalidAllowlistReceipt,
+
+    /// 45 - Wallet Limit Reached
+    #[error("The owner has reached the per-wallet print limit of this game")]
+    WalletLimitReached,
+
+    /// 46 - Invalid Mint Counter
+    #[error("Invalid mint counter account")]
+    InvalidMintCounter,
+
+    /// 47 - Vouchers Disabled
+    #[error("The game does not accept vouchers")]
+    VouchersDisabled,
+
+    /// 48 - Invalid Voucher Signature
+    #[error("The voucher is not signed by the game voucher signer")]
+    InvalidVoucherSignature,
+
+    /// 49 - Voucher Expired
+    #[error("The voucher has expired")]
+    VoucherExpired,
+
+    /// 50 - Voucher Already Used
+    #[error("The voucher has already been used")]
+    VoucherAlreadyUsed,
+
+    /// 51 - Invalid Voucher Record
+    #[error("Invalid voucher record account")]
+    InvalidVoucherRecord,
+
+    /// 52 - Invalid Instructions Sysvar
+    #[error("Invalid instructions sysvar")]
+    InvalidInstructionsSysvar,
+
+    /// 53 - Invalid Referral Rate
+    #[error("Referral rate cannot exceed 2000 basis points")]
+    InvalidReferralRate,
+
+    /// 54 - Invalid Referrer Token Account Program Owner
+    #[error("Invalid referrer token account program owner")]
+    InvalidReferrerTokenAccountProgramOwner,
+
+    /// 55 - Invalid Referrer Token Account Mint
+    #[error("Invalid referrer token account mint")]
+    InvalidReferrerTokenAccountMint,
+
+    /// 56 - Self Referral
+    #[error("A print cannot be referred by its payer or owner")]
+    SelfReferral,
+
+    /// 57 - Invalid Bundle
+    #[error("Invalid bundle account")]
+    InvalidBundle,
+
+    /// 58 - Invalid Bundle Games
+    #[error("A bundle needs between 2 and 8 distinct games")]
+    InvalidBundleGames,
+
+    /// 59 - Invalid Bundle Shares
+    #[error("Bundle shares must add up to 10000 basis points")]
+    InvalidBundleShares,
+
+    /// 60 - Bundle Game Mismatch
+    #[error("The game accounts do not match the bundle")]
+    BundleGameMismatch,
+
+    /// 61 - Base Cartridge Required
+    #[error("Cartridges of a DLC can only be printed against a base cartridge")]
+    BaseCartridgeRequired,
+
+    /// 62 - Invalid Base Game
+    #[error("A DLC must extend another released game of the same publisher")]
+    InvalidBaseGame,
+
+    /// 63 - DLC Already Registered
+    #[error("The game is already registered as a DLC")]
+    DlcAlreadyRegistered,
+
+    /// 64 - Not A DLC
+    #[error("The game is not a DLC")]
+    NotADlc,
+
+    /// 65 - Invalid Base Cartridge
+    #[error("The base cartridge is not a cartridge of the base game held by the owner")]
+    InvalidBaseCartridge,
+
+    /// 66 - Save Data Disabled
+    #[error("The game has no save authority")]
+    SaveDataDisabled,
+
+    /// 67 - Invalid Save Authority
+    #[error("Only the save authority of the game can write save data")]
+    InvalidSaveAuthority,
+
+    /// 68 - Save Data Too Large
+    #[error("Save data exceeds the maximum size")]
+    SaveDataTooLarge,
+
+    /// 69 - Stale Save Revision
+    #[error("The save revision must follow the current one")]
+    StaleSaveRevision,
+
+    /// 70 - Invalid Cartridge
+    #[error("The cartridge does not belong to the game")]
+    InvalidCartridge,
+
+    /// 71 - Invalid Refund Window
+    #[error("Refund window cannot be negative")]
+    InvalidRefundWindow,
+
+    /// 72 - Refunds Disabled
+    #[error("The game does not offer refunds")]
+    RefundsDisabled,
+
+    /// 73 - Refund Window Closed
+    #[error("The refund window of the cartridge has closed")]
+    RefundWindowClosed,
+
+    /// 74 - Cartridge Already Played
+    #[error("Cartridges that have been inserted into a machine cannot be refunded")]
+    CartridgeAlreadyPlayed,
+
+    /// 75 - Invalid Listing
+    #[error("Invalid listing account")]
+    InvalidListing,
+
+    /// 76 - Invalid Seller Token Account
+    #[error("The token account does not hold the payment token of the seller")]
+    InvalidSellerTokenAccount,
+
+    /// 77 - Invalid Creator Token Account
+    #[error("The token accounts do not hold the payment token of the game creators")]
+    InvalidCreatorTokenAccount,
+
+    /// 78 - Invalid Listing Price
+    #[error("Listing price must be greater than zero")]
+    InvalidListingPrice,
+
+    /// 79 - Listing Price Changed
+    #[error("The listing price differs from the price the buyer agreed to")]
+    ListingPriceChanged,
+
+    /// 80 - Invalid Loan
+    #[error("Invalid loan account")]
+    InvalidLoan,
+
+    /// 81 - Invalid Loan Duration
+    #[error("Loan duration must be greater than zero")]
+    InvalidLoanDuration,
+
+    /// 82 - Invalid Borrower
+    #[error("A cartridge cannot be lent to its owner")]
+    InvalidBorrower,
+
+    /// 83 - Cartridge On Loan
+    #[error("The cartridge is on loan")]
+    CartridgeOnLoan,
+
+    /// 84 - Loan Not Expired
+    #[error("Only the borrower can return a cartridge before the loan expires")]
+    LoanNotExpired,
+
+    /// 85 - Invalid Rental Terms
+    #[error("Rental duration cannot be negative")]
+    InvalidRentalTerms,
+
+    /// 86 - Rentals Disabled
+    #[error("The game does not offer rentals")]
+    RentalsDisabled,
+
+    /// 87 - Cartridge Expired
+    #[error("The rental cartridge has expired")]
+    CartridgeExpired,
+
+    /// 88 - Cartridge Not Expired
+    #[error("Only expired rental cartridges can be reclaimed")]
+    CartridgeNotExpired,
+
+    /// 89 - Invalid Rental Authority
+    #[error("Invalid rental authority PDA derivation")]
+    InvalidRentalAuthority,
+
+    /// 90 - Invalid Rent Payer
+    #[error("The rent payer does not match the one recorded on the cartridge")]
+    InvalidRentPayer,
+
+    /// 91 - Rental Cartridge
+    #[error("Rental cartridges cannot be lent or sold")]
+    RentalCartridge,
+
+    /// 92 - Invalid Pass Collection
+    #[error("The collection is not a pass collection of this program")]
+    InvalidPassCollection,
+
+    /// 93 - Invalid Pass Terms
+    #[error("A pass must last for a positive duration")]
+    InvalidPassTerms,
+
+    /// 94 - Invalid Pass
+    #[error("The asset is not a pass of the pass collection")]
+    InvalidPass,
+
+    /// 95 - Pass Expired
+    #[error("The pass has expired")]
+    PassExpired,
+
+    /// 96 - Game Not In Pass
+    #[error("The game does not accept passes of this collection")]
+    GameNotInPass,
+
+    /// 97 - Invalid Crowdfund Terms
+    #[error("A crowdfund needs a goal and a deadline in the future")]
+    InvalidCrowdfundTerms,
+
+    /// 98 - Invalid Escrow
+    #[error("Invalid escrow PDA derivation")]
+    InvalidEscrow,
+
+    /// 99 - Invalid Pre-Order
+    #[error("Invalid pre-order PDA derivation")]
+    InvalidPreOrder,
+
+    /// 100 - Invalid Pre-Order Quantity
+    #[error("At least one cartridge must be pre-ordered")]
+    InvalidPreOrderQuantity,
+
+    /// 101 - Crowdfund Closed
+    #[error("The crowdfund deadline has passed")]
+    CrowdfundClosed,
+
+    /// 102 - Crowdfund Not Funded
+    #[error("The crowdfund has not met its goal")]
+    CrowdfundNotFunded,
+
+    /// 103 - Crowdfund Not Failed
+    #[error("Pre-orders can only be refunded once the crowdfund has missed its goal")]
+    CrowdfundNotFailed,
+
+    /// 104 - Invalid Publisher Team
+    #[error("Invalid publisher team account")]
+    InvalidPublisherTeam,
+
+    /// 105 - Invalid Team Members
+    #[error("A publisher team needs between 1 and 8 distinct members")]
+    InvalidTeamMembers,
+
+    /// 106 - Invalid Team Shares
+    #[error("Team shares must add up to 10000 basis points")]
+    InvalidTeamShares,
+
+    /// 107 - Invalid Team Threshold
+    #[error("The team threshold must be between 1 and the number of members")]
+    InvalidTeamThreshold,
+
+    /// 108 - Team Threshold Not Met
+    #[error("Not enough team members approved the action")]
+    TeamThresholdNotMet,
+
+    /// 109 - Invalid Revenue Token Account
+    #[error("The token account does not belong to the revenue recipient")]
+    InvalidRevenueTokenAccount,
+
+    /// 110 - Invalid Verifier Authority
+    #[error("Only the verifier authority can manage the verification and name registries")]
+    InvalidVerifierAuthority,
+
+    /// 111 - Invalid Verification
+    #[error("Invalid verification PDA derivation")]
+    InvalidVerification,
+
+    /// 112 - Invalid Verification Status
+    #[error("Invalid verification status")]
+    InvalidVerificationStatus,
+
+    /// 113 - Game Delisted
+    #[error("The game has been delisted")]
+    GameDelisted,
+
+    /// 114 - Invalid Name Record
+    #[error("Invalid name record account")]
+    InvalidNameRecord,
+
+    /// 115 - Name Taken
+    #[error("The game name is held by another publisher")]
+    NameTaken,
+
+    /// 116 - Invalid Catalog
+    #[error("Invalid catalog PDA derivation")]
+    InvalidCatalog,
+
+    /// 117 - Catalog Entry Not Found
+    #[error("The game is not in the catalog")]
+    CatalogEntryNotFound,
+
+    /// 118 - Price Above Max
+    #[error("The cartridge price is above the most the payer accepts")]
+    PriceAboveMax,
+
+    /// 119 - Mint Counter Required
+    #[error("The game limits prints per wallet and needs the mint counter of the owner")]
+    MintCounterRequired,
+
+    /// 120 - Invalid DLC Receipt
+    #[error("Invalid DLC receipt PDA derivation")]
+    InvalidDlcReceipt,
+
+    /// 121 - DLC Already Printed
+    #[error("The base cartridge already has this DLC")]
+    DlcAlreadyPrinted,
+
+    /// 122 - DLC Not Playable
+    #[error("DLC cartridges extend a base cartridge and cannot be inserted on their own")]
+    DlcNotPlayable,
+
+    /// 123 - Game Already Printed
+    #[error("A game with printed cartridges cannot become a DLC")]
+    GameAlreadyPrinted,
+
+    /// 124 - Pass Authority Must Sign
+    #[error("The pass authority must approve games joining the pass")]
+    PassAuthorityMustSign,
+
+    /// 125 - Crowdfund Active
+    #[error("The crowdfund of the game is still open")]
+    CrowdfundActive,
+
+    /// 126 - Catalog Entry Exists
+    #[error("The game is already listed in the catalog")]
+    CatalogEntryExists,
+
+    /// 127 - Catalog Publisher Mismatch
+    #[error("The game is not published by the publisher of the catalog")]
+    CatalogPublisherMismatch,
 }
 
 impl PrintProgramError for BglCartridgeError {
@@ -2,8 +2,16 @@ use shank::{ShankContext, ShankInstruction};
 use strum_macros::{EnumDiscriminants, FromRepr};
 
 use crate::processor::{
-    CommissionMachineV1Args, InsertCartridgeV1Args, PrintGameCartridgeV1Args, ReleaseGameV1Args,
-    RemoveCartridgeV1Args, SetCartridgeSourceV1Args,
+    AddCatalogEntryV1Args, AirdropCartridgesV1Args, BuyCartridgeV1Args, ClaimNameV1Args,
+    CommissionMachineV1Args, CreateBundleV1Args, CreatePassV1Args, CreatePublisherTeamV1Args,
+    EjectCartridgeV1Args, InsertCartridgeV1Args, InsertPassV1Args, LendCartridgeV1Args,
+    ListCartridgeV1Args, PreOrderV1Args, PrintBundleV1Args, PrintDlcV1Args,
+    PrintGameCartridgeAllowlistV1Args, PrintGameCartridgeV1Args, PrintGameCartridgeVoucherV1Args,
+    PrintRentalCartridgeV1Args, ReclaimExpiredCartridgeV1Args, RedeemPreOrderV1Args,
+    RefundCartridgeV1Args, RegisterDlcV1Args, ReleaseGameV1Args, RemoveCartridgeV1Args,
+    ReserveNameV1Args, ReturnCartridgeV1Args, SetCartridgeSourceV1Args, SetSessionLockV1Args,
+    SetVerificationV1Args, UpdateBundleV1Args, UpdateGameV1Args, UpdatePublisherTeamV1Args,
+    WithdrawGameRevenueV1Args, WithdrawPassRevenueV1Args, WriteSaveDataV1Args,
 };
 
 #[derive(Clone, Debug, ShankContext, ShankInstruction, EnumDiscriminants)]
@@ -24,6 +32,9 @@ pub enum BglCartridgeInstruction {
     /// Create a game.
     /// Create a Core collection to represent the game.
     /// The actual games will be represented by Core NFTs in the game collection.
+    /// The name record of the game name and the catalog of the publisher
+    /// trail the accounts. The release fails when another publisher holds the
+    /// name, and lists the game in the catalog.
     #[account(0, writable, name = "game", desc = "The new game Collection account")]
     #[account(1, writable, name = "game_token_account", desc = "The token account receiving the payment for the game")]
     #[account(2, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
@@ -85,4 +96,598 @@ pub enum BglCartridgeInstruction {
     #[account(4, name = "mpl_core_program", desc = "The mpl core program")]
     #[account(5, name = "system_program", desc = "The system program")]
     SetCartridgeSourceV1(SetCartridgeSourceV1Args),
+
+    /// Set machine operator.
+    /// Appoints an operator delegate that can insert and remove cartridges the
+    /// machine owner has custody of, and force-eject any cartridge. Omitting
+    /// the operator account clears the delegate.
+    #[account(0, writable, name = "machine", desc = "The machine asset account")]
+    #[account(1, writable, name = "machine_collection", desc = "The Core machine collection")]
+    #[account(2, signer, name = "machine_owner", desc = "The owner of the machine")]
+    #[account(3, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(4, optional, name = "operator", desc = "The new operator delegate")]
+    #[account(5, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(6, name = "system_program", desc = "The system program")]
+    SetMachineOperatorV1,
+
+    /// Eject cartridge
+    /// Force-eject a game cartridge from a machine, regardless of who owns it.
+    /// Signed by the machine owner or its operator delegate.
+    #[account(0, writable, name = "cartridge", desc = "The game cartridge account")]
+    #[account(1, writable, name = "game", desc = "The game Collection account")]
+    #[account(2, writable, name = "machine", desc = "The machine asset account")]
+    #[account(3, writable, name = "machine_collection", desc = "The Core machine collection")]
+    #[account(4, writable, signer, name = "authority", desc = "The machine owner or operator delegate")]
+    #[account(5, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(6, name = "system_program", desc = "The system program")]
+    EjectCartridgeV1(EjectCartridgeV1Args),
+
+    /// Update game.
+    /// Updates the settings stored in the game data. Only the provided fields
+    /// are changed. Signed by the game publisher, and by the pass authority
+    /// when the game joins a pass.
+    #[account(0, writable, name = "game", desc = "The game Collection account")]
+    #[account(1, optional_signer, name = "publisher", desc = "The publisher of the game, or its team")]
+    #[account(2, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(3, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(4, name = "system_program", desc = "The system program")]
+    #[account(5, optional, name = "pass_collection", desc = "The pass collection the game joins")]
+    #[account(6, optional, signer, name = "pass_authority", desc = "The authority of the pass collection the game joins")]
+    UpdateGameV1(UpdateGameV1Args),
+
+    /// Set session lock.
+    /// Locks or unlocks cartridge removal on a machine. While the lock is
+    /// active, removing the cartridge requires the machine owner to co-sign.
+    /// Signed by the machine owner or its operator delegate.
+    #[account(0, writable, name = "machine", desc = "The machine asset account")]
+    #[account(1, writable, name = "machine_collection", desc = "The Core machine collection")]
+    #[account(2, signer, name = "authority", desc = "The machine owner or operator delegate")]
+    #[account(3, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(4, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(5, name = "system_program", desc = "The system program")]
+    SetSessionLockV1(SetSessionLockV1Args),
+
+    /// Get price.
+    /// Read-only. Returns the price of the next cartridge of a game as a
+    /// little-endian u64 in the return data.
+    #[account(0, name = "game", desc = "The game Collection account")]
+    GetPriceV1,
+
+    /// Print a game cartridge through the allowlist.
+    /// The payer proves its allowlist leaf with a merkle proof. Its prints are
+    /// counted in a receipt so the quota of the leaf cannot be exceeded.
+    #[account(0, writable, signer, name = "cartridge", desc = "The new game asset account")]
+    #[account(1, writable, name = "game", desc = "The game Collection account")]
+    #[account(2, writable, name = "game_token_account", desc = "The token account receiving the payment for the game")]
+    #[account(3, name = "owner", desc = "The owner of the game")]
+    #[account(4, writable, signer, name = "payer", desc = "The allowlisted wallet paying for the game")]
+    #[account(5, writable, name = "payer_token_account", desc = "The account paying for the storage fees and the game cost")]
+    #[account(6, writable, name = "allowlist_receipt", desc = "The allowlist receipt of the payer")]
+    #[account(7, writable, name = "payment_mint", desc = "The payment mint")]
+    #[account(8, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(9, name = "token_program", desc = "The token program")]
+    #[account(10, name = "system_program", desc = "The system program")]
+    #[account(11, optional, writable, name = "mint_counter", desc = "The counter of cartridges printed to the owner, when the game limits prints per wallet")]
+    #[account(12, optional, writable, name = "referrer_token_account", desc = "The token account of the wallet that referred the print")]
+    PrintGameCartridgeAllowlistV1(PrintGameCartridgeAllowlistV1Args),
+
+    /// Print a game cartridge with a promo voucher.
+    /// The voucher is signed off-chain by the game's voucher signer and
+    /// verified by an Ed25519 program instruction placed right before this
+    /// one. Each voucher nonce can only be used once.
+    #[account(0, writable, signer, name = "cartridge", desc = "The new game asset account")]
+    #[account(1, writable, name = "game", desc = "The game Collection account")]
+    #[account(2, writable, name = "game_token_account", desc = "The token account receiving the payment for the game")]
+    #[account(3, name = "owner", desc = "The owner of the game")]
+    #[account(4, writable, signer, name = "payer", desc = "The wallet the voucher was issued to")]
+    #[account(5, writable, name = "payer_token_account", desc = "The account paying for the storage fees and the game cost")]
+    #[account(6, writable, name = "voucher_record", desc = "The record marking the voucher nonce as used")]
+    #[account(7, writable, name = "payment_mint", desc = "The payment mint")]
+    #[account(8, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(9, name = "token_program", desc = "The token program")]
+    #[account(10, name = "system_program", desc = "The system program")]
+    #[account(11, optional, writable, name = "mint_counter", desc = "The counter of cartridges printed to the owner, when the game limits prints per wallet")]
+    #[account(12, name = "sysvar_instructions", desc = "The instructions sysvar")]
+    #[account(13, optional, writable, name = "referrer_token_account", desc = "The token account of the wallet that referred the print")]
+    PrintGameCartridgeVoucherV1(PrintGameCartridgeVoucherV1Args),
+
+    /// Airdrop cartridges.
+    /// Mints cartridges for free to several wallets, flagged as publisher
+    /// grants. The owners and new cartridge accounts are passed as
+    /// (owner, cartridge) pairs in the remaining accounts, after the team
+    /// members approving the airdrop when a team publishes the game.
+    #[account(0, writable, name = "game", desc = "The game Collection account")]
+    #[account(1, optional_signer, name = "publisher", desc = "The publisher of the game, or its team")]
+    #[account(2, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(3, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(4, name = "system_program", desc = "The system program")]
+    AirdropCartridgesV1(AirdropCartridgesV1Args),
+
+    /// Create a bundle.
+    /// Sells several games of a publisher together at a single price, split
+    /// between the games in configured shares. The member game collections
+    /// are passed in the remaining accounts in the order of the args.
+    #[account(0, writable, name = "bundle", desc = "The new bundle account")]
+    #[account(1, optional_signer, name = "publisher", desc = "The publisher of every member game, or its team")]
+    #[account(2, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(3, name = "system_program", desc = "The system program")]
+    CreateBundleV1(CreateBundleV1Args),
+
+    /// Print a bundle.
+    /// Charges the bundle price once and prints one cartridge of every member
+    /// game. The accounts of each member are passed as (game, game token
+    /// account, cartridge, mint counter) in the remaining accounts, the
+    /// program id standing in for the mint counter of games without a
+    /// per-wallet limit. The print fails when the bundle price moved above
+    /// the most the payer accepts.
+    #[account(0, name = "bundle", desc = "The bundle account")]
+    #[account(1, name = "owner", desc = "The owner of the new cartridges")]
+    #[account(2, writable, signer, name = "payer", desc = "The account paying for the bundle and the storage fees")]
+    #[account(3, writable, name = "payer_token_account", desc = "The token account paying for the bundle")]
+    #[account(4, writable, name = "payment_mint", desc = "The payment mint")]
+    #[account(5, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(6, name = "token_program", desc = "The token program")]
+    #[account(7, name = "system_program", desc = "The system program")]
+    PrintBundleV1(PrintBundleV1Args),
+
+    /// Register a DLC.
+    /// Links a game to the base game it is an add-on for. Its cartridges can
+    /// then only be printed through PrintDlcV1 by owners of a base cartridge.
+    #[account(0, writable, name = "game", desc = "The DLC game Collection account")]
+    #[account(1, name = "base_game", desc = "The base game Collection account")]
+    #[account(2, optional_signer, name = "publisher", desc = "The publisher of both games, or its team")]
+    #[account(3, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(4, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(5, name = "system_program", desc = "The system program")]
+    RegisterDlcV1(RegisterDlcV1Args),
+
+    /// Print a DLC.
+    /// Print a cartridge of a DLC for the owner of a base game cartridge. The
+    /// new cartridge records the base cartridge it is bound to, and each base
+    /// cartridge gets the DLC once.
+    #[account(0, writable, signer, name = "cartridge", desc = "The new DLC asset account")]
+    #[account(1, writable, name = "game", desc = "The DLC game Collection account")]
+    #[account(2, writable, name = "game_token_account", desc = "The token account receiving the payment for the DLC")]
+    #[account(3, name = "owner", desc = "The owner of the base cartridge and the new DLC")]
+    #[account(4, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(5, writable, name = "payer_token_account", desc = "The account paying for the storage fees and the DLC cost")]
+    #[account(6, name = "base_cartridge", desc = "The base game cartridge held by the owner")]
+    #[account(7, writable, name = "payment_mint", desc = "The payment mint")]
+    #[account(8, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(9, name = "token_program", desc = "The token program")]
+    #[account(10, name = "system_program", desc = "The system program")]
+    #[account(11, optional, writable, name = "mint_counter", desc = "The counter of cartridges printed to the owner, when the game limits prints per wallet")]
+    #[account(12, optional, writable, name = "referrer_token_account", desc = "The token account of the wallet that referred the print")]
+    #[account(13, writable, name = "dlc_receipt", desc = "The receipt of the DLC printed for the base cartridge")]
+    PrintDlcV1(PrintDlcV1Args),
+
+    /// Write save data.
+    /// Stores a game state blob on a cartridge, in an AppData plugin added by
+    /// the game and written by its save authority. Only the save authority
+    /// registered for the game can write, and every write must bump the save
+    /// revision.
+    #[account(0, writable, name = "cartridge", desc = "The game cartridge asset account")]
+    #[account(1, writable, name = "game", desc = "The game Collection account")]
+    #[account(2, signer, name = "save_authority", desc = "The game server key registered as save authority, owning the save data")]
+    #[account(3, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(4, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(5, name = "system_program", desc = "The system program")]
+    WriteSaveDataV1(WriteSaveDataV1Args),
+
+    /// Refund a cartridge.
+    /// Burns a cartridge that was never inserted into a machine and pays its
+    /// recorded purchase price back from the game token account, within the
+    /// refund window of the game.
+    #[account(0, writable, name = "cartridge", desc = "The game cartridge asset account")]
+    #[account(1, writable, name = "game", desc = "The game Collection account")]
+    #[account(2, writable, name = "game_token_account", desc = "The token account paying the refund")]
+    #[account(3, writable, signer, name = "owner", desc = "The owner of the cartridge")]
+    #[account(4, writable, name = "owner_token_account", desc = "The token account receiving the refund")]
+    #[account(5, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(6, name = "token_program", desc = "The token program")]
+    #[account(7, name = "system_program", desc = "The system program")]
+    RefundCartridgeV1(RefundCartridgeV1Args),
+
+    /// List a cartridge for sale.
+    /// Records an ask price for a cartridge held outside of any machine. The
+    /// cartridge stays frozen in the seller's wallet until it is bought.
+    #[account(0, name = "cartridge", desc = "The game cartridge asset account")]
+    #[account(1, name = "game", desc = "The game Collection account")]
+    #[account(2, writable, name = "listing", desc = "The new listing of the cartridge")]
+    #[account(3, writable, signer, name = "seller", desc = "The owner of the cartridge")]
+    #[account(4, name = "system_program", desc = "The system program")]
+    ListCartridgeV1(ListCartridgeV1Args),
+
+    /// Buy a listed cartridge.
+    /// Pays the game creators their royalties and the seller the rest, then
+    /// thaws the cartridge, transfers it to the buyer and freezes it again as
+    /// the game. The remaining accounts are the token accounts of the game's
+    /// royalties creators, in order.
+    #[account(0, writable, name = "cartridge", desc = "The game cartridge asset account")]
+    #[account(1, writable, name = "game", desc = "The game Collection account")]
+    #[account(2, writable, name = "listing", desc = "The listing of the cartridge")]
+    #[account(3, writable, name = "seller", desc = "The seller of the cartridge")]
+    #[account(4, writable, name = "seller_token_account", desc = "The token account receiving the sale proceeds")]
+    #[account(5, writable, signer, name = "buyer", desc = "The buyer of the cartridge")]
+    #[account(6, writable, name = "buyer_token_account", desc = "The token account paying for the cartridge")]
+    #[account(7, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(8, name = "token_program", desc = "The token program")]
+    #[account(9, name = "system_program", desc = "The system program")]
+    BuyCartridgeV1(BuyCartridgeV1Args),
+
+    /// Cancel a listing.
+    /// Closes the listing of a cartridge, returning its rent to the seller.
+    #[account(0, writable, name = "listing", desc = "The listing of the cartridge")]
+    #[account(1, writable, signer, name = "seller", desc = "The seller of the cartridge")]
+    CancelListingV1,
+
+    /// Lend a cartridge.
+    /// Moves a cartridge held outside of any machine to the borrower as the
+    /// game and records the loan. The borrower can play the cartridge but not
+    /// sell or refund it.
+    #[account(0, writable, name = "cartridge", desc = "The game cartridge asset account")]
+    #[account(1, writable, name = "game", desc = "The game Collection account")]
+    #[account(2, writable, name = "loan", desc = "The new loan of the cartridge")]
+    #[account(3, writable, signer, name = "lender", desc = "The owner of the cartridge")]
+    #[account(4, name = "borrower", desc = "The wallet borrowing the cartridge")]
+    #[account(5, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(6, name = "system_program", desc = "The system program")]
+    LendCartridgeV1(LendCartridgeV1Args),
+
+    /// Return a lent cartridge.
+    /// Ejects the cartridge from the machine it is inserted in, if any, then
+    /// moves it back to the lender and closes the loan. Signed by the borrower
+    /// at any time, or by anyone once the loan has expired.
+    #[account(0, writable, name = "cartridge", desc = "The game cartridge asset account")]
+    #[account(1, writable, name = "game", desc = "The game Collection account")]
+    #[account(2, writable, name = "loan", desc = "The loan of the cartridge")]
+    #[account(3, writable, name = "lender", desc = "The lender the cartridge returns to")]
+    #[account(4, writable, signer, name = "authority", desc = "The borrower, or anyone once the loan has expired")]
+    #[account(5, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(6, name = "system_program", desc = "The system program")]
+    #[account(7, optional, writable, name = "machine", desc = "The machine the cartridge is inserted in")]
+    #[account(8, optional, writable, name = "machine_collection", desc = "The Core collection of the machine")]
+    ReturnCartridgeV1(ReturnCartridgeV1Args),
+
+    /// Print a rental cartridge.
+    /// Prints a cartridge at the rental price of the game that stops working
+    /// once the rental duration has passed.
+    #[account(0, writable, signer, name = "cartridge", desc = "The new game asset account")]
+    #[account(1, writable, name = "game", desc = "The game Collection account")]
+    #[account(2, writable, name = "game_token_account", desc = "The token account receiving the payment for the game")]
+    #[account(3, name = "owner", desc = "The owner of the game")]
+    #[account(4, writable, signer, name = "payer", desc = "The account paying for the storage fees and the rental")]
+    #[account(5, writable, name = "payer_token_account", desc = "The token account paying for the rental")]
+    #[account(6, writable, name = "payment_mint", desc = "The payment mint")]
+    #[account(7, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(8, name = "token_program", desc = "The token program")]
+    #[account(9, name = "system_program", desc = "The system program")]
+    #[account(10, optional, writable, name = "mint_counter", desc = "The counter of cartridges printed to the owner, when the game limits prints per wallet")]
+    #[account(11, optional, writable, name = "referrer_token_account", desc = "The token account of the wallet that referred the print")]
+    PrintRentalCartridgeV1(PrintRentalCartridgeV1Args),
+
+    /// Reclaim an expired rental cartridge.
+    /// Permissionless. Ejects the expired rental from its machine, if any,
+    /// burns it and returns the rent paid for its print to the wallet that
+    /// paid it.
+    #[account(0, writable, name = "cartridge", desc = "The expired rental cartridge")]
+    #[account(1, writable, name = "game", desc = "The game Collection account")]
+    #[account(2, writable, name = "rental_authority", desc = "The rental authority PDA of the game, collecting the rent of the burn")]
+    #[account(3, writable, name = "rent_payer", desc = "The wallet that paid the rent of the cartridge")]
+    #[account(4, writable, signer, name = "payer", desc = "The account cranking the reclaim")]
+    #[account(5, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(6, name = "system_program", desc = "The system program")]
+    #[account(7, optional, writable, name = "machine", desc = "The machine the cartridge is inserted in")]
+    #[account(8, optional, writable, name = "machine_collection", desc = "The Core collection of the machine")]
+    ReclaimExpiredCartridgeV1(ReclaimExpiredCartridgeV1Args),
+
+    /// Create a pass.
+    /// Creates a pass collection whose passes grant access to every game that
+    /// opts into it for as long as they are renewed.
+    #[account(0, writable, name = "pass_collection", desc = "The new pass collection account")]
+    #[account(1, writable, name = "pass_token_account", desc = "The token account receiving pass payments")]
+    #[account(2, signer, name = "authority", desc = "The wallet creating the pass")]
+    #[account(3, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(4, name = "payment_mint", desc = "The payment mint")]
+    #[account(5, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(6, name = "token_program", desc = "The token program")]
+    #[account(7, name = "associated_token_program", desc = "The associated token program")]
+    #[account(8, name = "system_program", desc = "The system program")]
+    CreatePassV1(CreatePassV1Args),
+
+    /// Purchase a pass.
+    /// Mints a pass valid for one period of the pass collection.
+    #[account(0, writable, signer, name = "pass", desc = "The new pass asset account")]
+    #[account(1, writable, name = "pass_collection", desc = "The pass collection account")]
+    #[account(2, writable, name = "pass_token_account", desc = "The token account receiving the payment for the pass")]
+    #[account(3, name = "owner", desc = "The owner of the new pass")]
+    #[account(4, writable, signer, name = "payer", desc = "The account paying for the storage fees and the pass")]
+    #[account(5, writable, name = "payer_token_account", desc = "The token account paying for the pass")]
+    #[account(6, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(7, name = "token_program", desc = "The token program")]
+    #[account(8, name = "system_program", desc = "The system program")]
+    PurchasePassV1,
+
+    /// Renew a pass.
+    /// Extends a pass by one period of the pass collection.
+    #[account(0, writable, name = "pass", desc = "The pass asset account")]
+    #[account(1, writable, name = "pass_collection", desc = "The pass collection account")]
+    #[account(2, writable, name = "pass_token_account", desc = "The token account receiving the payment for the pass")]
+    #[account(3, writable, signer, name = "payer", desc = "The account paying for the renewal")]
+    #[account(4, writable, name = "payer_token_account", desc = "The token account paying for the renewal")]
+    #[account(5, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(6, name = "token_program", desc = "The token program")]
+    #[account(7, name = "system_program", desc = "The system program")]
+    RenewPassV1,
+
+    /// Insert a pass.
+    /// Inserts a valid pass into a machine in place of a cartridge of a game
+    /// that opted into the pass.
+    #[account(0, writable, name = "pass", desc = "The pass asset account")]
+    #[account(1, writable, name = "pass_collection", desc = "The pass collection account")]
+    #[account(2, name = "game", desc = "The game Collection account to play")]
+    #[account(3, writable, signer, name = "pass_owner", desc = "The owner of the pass")]
+    #[account(4, writable, name = "machine", desc = "The machine asset account")]
+    #[account(5, writable, name = "machine_collection", desc = "The Core collection of the machine")]
+    #[account(6, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(7, name = "system_program", desc = "The system program")]
+    InsertPassV1(InsertPassV1Args),
+
+    /// Remove a pass.
+    /// Takes a pass out of a machine, by its owner outside of a locked
+    /// session or by the machine owner or operator at any time.
+    #[account(0, writable, name = "pass", desc = "The pass asset account")]
+    #[account(1, writable, name = "pass_collection", desc = "The pass collection account")]
+    #[account(2, writable, signer, name = "authority", desc = "The pass owner, machine owner or machine operator")]
+    #[account(3, writable, name = "machine", desc = "The machine asset account")]
+    #[account(4, writable, name = "machine_collection", desc = "The Core collection of the machine")]
+    #[account(5, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(6, name = "system_program", desc = "The system program")]
+    RemovePassV1,
+
+    /// Pre-order cartridges.
+    /// Pays for cartridges of a crowdfunded game into its escrow until the
+    /// deadline.
+    #[account(0, name = "game", desc = "The crowdfunded game Collection account")]
+    #[account(1, writable, name = "escrow", desc = "The escrow of the game")]
+    #[account(2, writable, name = "escrow_token_account", desc = "The token account of the escrow")]
+    #[account(3, writable, name = "pre_order", desc = "The pre-orders of the backer")]
+    #[account(4, writable, signer, name = "backer", desc = "The wallet pre-ordering the cartridges")]
+    #[account(5, writable, name = "backer_token_account", desc = "The token account paying for the pre-orders")]
+    #[account(6, name = "token_program", desc = "The token program")]
+    #[account(7, name = "system_program", desc = "The system program")]
+    PreOrderV1(PreOrderV1Args),
+
+    /// Claim an escrow.
+    /// Moves the pre-order payments of a funded game to the game once its
+    /// pre-orders close, or burns them when the game is paid by burning.
+    #[account(0, name = "game", desc = "The crowdfunded game Collection account")]
+    #[account(1, name = "escrow", desc = "The escrow of the game")]
+    #[account(2, writable, name = "escrow_token_account", desc = "The token account of the escrow")]
+    #[account(3, writable, name = "game_token_account", desc = "The token account receiving the payments for the game")]
+    #[account(4, optional_signer, name = "publisher", desc = "The publisher of the game, or its team")]
+    #[account(5, name = "token_program", desc = "The token program")]
+    #[account(6, writable, name = "payment_mint", desc = "The payment token mint")]
+    ClaimEscrowV1,
+
+    /// Redeem a pre-order.
+    /// Prints one pre-ordered cartridge of a funded game to its backer.
+    #[account(0, writable, signer, name = "cartridge", desc = "The new game asset account")]
+    #[account(1, writable, name = "game", desc = "The crowdfunded game Collection account")]
+    #[account(2, name = "escrow", desc = "The escrow of the game")]
+    #[account(3, writable, name = "pre_order", desc = "The pre-orders of the backer")]
+    #[account(4, writable, signer, name = "backer", desc = "The wallet that pre-ordered the cartridge")]
+    #[account(5, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(6, name = "system_program", desc = "The system program")]
+    RedeemPreOrderV1(RedeemPreOrderV1Args),
+
+    /// Refund a pre-order.
+    /// Permissionless. Pays the pre-orders of a game that missed its goal
+    /// back to the backer.
+    #[account(0, name = "game", desc = "The crowdfunded game Collection account")]
+    #[account(1, name = "escrow", desc = "The escrow of the game")]
+    #[account(2, writable, name = "escrow_token_account", desc = "The token account of the escrow")]
+    #[account(3, writable, name = "pre_order", desc = "The pre-orders of the backer")]
+    #[account(4, writable, name = "backer", desc = "The wallet that pre-ordered the cartridges")]
+    #[account(5, writable, name = "backer_token_account", desc = "The token account of the backer")]
+    #[account(6, name = "token_program", desc = "The token program")]
+    RefundPreOrderV1,
+
+    /// Create a publisher team.
+    /// Records the members of a team, their revenue shares and how many of
+    /// them must approve administrative actions.
+    #[account(0, writable, name = "team", desc = "The new publisher team")]
+    #[account(1, signer, name = "creator", desc = "The wallet creating the team")]
+    #[account(2, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(3, name = "system_program", desc = "The system program")]
+    CreatePublisherTeamV1(CreatePublisherTeamV1Args),
+
+    /// Update a publisher team.
+    /// Replaces the members and threshold, approved by the current members
+    /// passed as remaining signers.
+    #[account(0, writable, name = "team", desc = "The publisher team")]
+    UpdatePublisherTeamV1(UpdatePublisherTeamV1Args),
+
+    /// Withdraw game revenue.
+    /// Pays from the game token account to the publisher, split between the
+    /// members by share when a team publishes the game. Nothing is reserved
+    /// for refunds, which fail once the balance runs out, so publishers
+    /// offering refunds leave enough to cover the open refund windows.
+    /// Pre-order payments stay in the escrow until claimed.
+    #[account(0, name = "game", desc = "The game Collection account")]
+    #[account(1, writable, name = "game_token_account", desc = "The token account holding the game revenue")]
+    #[account(2, optional_signer, name = "publisher", desc = "The publisher of the game, or its team")]
+    #[account(3, name = "token_program", desc = "The token program")]
+    WithdrawGameRevenueV1(WithdrawGameRevenueV1Args),
+
+    /// Set the verification of a publisher or a game.
+    /// Records whether a publisher key or a game is verified, or delists a
+    /// game so it can no longer be printed. Signed by the verifier authority.
+    /// The delisting of a game is mirrored into its game data, which needs
+    /// the mpl core program.
+    #[account(0, writable, name = "subject", desc = "The publisher key or game Collection account")]
+    #[account(1, writable, name = "verification", desc = "The verification flags of the subject")]
+    #[account(2, signer, name = "authority", desc = "The verifier authority")]
+    #[account(3, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(4, name = "system_program", desc = "The system program")]
+    #[account(5, optional, name = "mpl_core_program", desc = "The mpl core program, when the subject is a game")]
+    SetVerificationV1(SetVerificationV1Args),
+
+    /// Reserve a game name.
+    /// Records the publisher holding a game name no one holds yet. Signed by
+    /// the verifier authority.
+    #[account(0, writable, name = "name_record", desc = "The registry record of the name")]
+    #[account(1, name = "owner", desc = "The publisher, or team, to hold the name")]
+    #[account(2, signer, name = "authority", desc = "The verifier authority")]
+    #[account(3, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(4, name = "system_program", desc = "The system program")]
+    ReserveNameV1(ReserveNameV1Args),
+
+    /// Transfer a game name.
+    /// Hands a held game name over to another publisher, who can then release
+    /// games under it.
+    #[account(0, writable, name = "name_record", desc = "The registry record of the name")]
+    #[account(1, optional_signer, name = "owner", desc = "The publisher holding the name, or its team")]
+    #[account(2, name = "new_owner", desc = "The publisher, or team, receiving the name")]
+    TransferNameV1,
+
+    /// Retire a game from a catalog.
+    /// Marks a game of the publisher catalog as retired. The entry stays in
+    /// the catalog to keep its release history.
+    #[account(0, writable, name = "catalog", desc = "The catalog of the publisher")]
+    #[account(1, optional_signer, name = "publisher", desc = "The publisher of the catalog, or its team")]
+    #[account(2, name = "game", desc = "The game Collection account to retire")]
+    RetireCatalogEntryV1,
+
+    /// Print a game cartridge.
+    /// Print a Core NFT in the game collection to represent a new game cartridge,
+    /// counting it against the per-wallet limit and paying the referrer.
+    #[account(0, writable, signer, name = "cartridge", desc = "The new game asset account")]
+    #[account(1, writable, name = "game", desc = "The game Collection account")]
+    #[account(2, writable, name = "game_token_account", desc = "The token account receiving the payment for the game")]
+    #[account(3, name = "owner", desc = "The owner of the game")]
+    #[account(4, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(5, writable, name = "payer_token_account", desc = "The account paying for the storage fees and the game cost")]
+    #[account(6, optional, signer, name = "authority", desc = "The authority signing for account creation")]
+    #[account(7, writable, name = "payment_mint", desc = "The payment mint")]
+    #[account(8, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(9, name = "token_program", desc = "The token program")]
+    #[account(10, name = "system_program", desc = "The system program")]
+    #[account(11, optional, writable, name = "mint_counter", desc = "The counter of cartridges printed to the owner, when the game limits prints per wallet")]
+    #[account(12, optional, writable, name = "referrer_token_account", desc = "The token account of the wallet that referred the print")]
+    PrintGameCartridgeV2(PrintGameCartridgeV1Args),
+
+    /// Update a bundle.
+    /// Changes the price of a bundle or how it is split between the member
+    /// games. The team members approving the update are passed in the
+    /// remaining accounts when a team publishes the bundle.
+    #[account(0, writable, name = "bundle", desc = "The bundle account")]
+    #[account(1, optional_signer, name = "publisher", desc = "The publisher of the bundle, or its team")]
+    UpdateBundleV1(UpdateBundleV1Args),
+
+    /// Close a bundle.
+    /// Takes a bundle off sale, returning its rent to the destination. The
+    /// team members approving the close are passed in the remaining accounts
+    /// when a team publishes the bundle.
+    #[account(0, writable, name = "bundle", desc = "The bundle account")]
+    #[account(1, optional_signer, name = "publisher", desc = "The publisher of the bundle, or its team")]
+    #[account(2, writable, name = "destination", desc = "The account receiving the rent of the bundle")]
+    CloseBundleV1,
+
+    /// Withdraw pass revenue.
+    /// Transfers payments collected by a pass collection to its authority.
+    #[account(0, name = "pass_collection", desc = "The pass collection account")]
+    #[account(1, writable, name = "pass_token_account", desc = "The token account holding the pass revenue")]
+    #[account(2, signer, name = "authority", desc = "The authority of the pass collection")]
+    #[account(3, writable, name = "authority_token_account", desc = "The token account of the authority receiving the revenue")]
+    #[account(4, name = "token_program", desc = "The token program")]
+    WithdrawPassRevenueV1(WithdrawPassRevenueV1Args),
+
+    /// Create a game.
+    /// Create a Core collection to represent the game, claiming its name in
+    /// the registry and listing it in the catalog of the publisher. Games can
+    /// be crowdfunded and published by a team, approved by its members passed
+    /// as remaining signers. Games released through V1 are not registered.
+    #[account(0, writable, name = "game", desc = "The new game Collection account")]
+    #[account(1, writable, name = "game_token_account", desc = "The token account receiving the payment for the game")]
+    #[account(2, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name = "authority", desc = "The authority signing for account creation")]
+    #[account(4, name = "payment_mint", desc = "The Mint address for the payment token")]
+    #[account(5, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(6, name = "token_program", desc = "The token program")]
+    #[account(7, name = "associated_token_program", desc = "The associated token program")]
+    #[account(8, name = "system_program", desc = "The system program")]
+    #[account(9, optional, writable, name = "escrow", desc = "The escrow of pre-order payments, when crowdfunding")]
+    #[account(10, optional, writable, name = "escrow_token_account", desc = "The token account of the escrow, when crowdfunding")]
+    #[account(11, optional, name = "team", desc = "The publisher team releasing the game, approved by its members")]
+    #[account(12, writable, name = "name_record", desc = "The registry record of the game name")]
+    #[account(13, writable, name = "catalog", desc = "The catalog of the publisher")]
+    ReleaseGameV2(ReleaseGameV1Args),
+
+    /// Claim a game name.
+    /// Registers the name of a game released before the registry, or through
+    /// V1, for its publisher while no one holds it.
+    #[account(0, writable, name = "name_record", desc = "The registry record of the game name")]
+    #[account(1, name = "game", desc = "The game Collection account")]
+    #[account(2, optional_signer, name = "publisher", desc = "The publisher of the game, or its team")]
+    #[account(3, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(4, name = "system_program", desc = "The system program")]
+    ClaimNameV1(ClaimNameV1Args),
+
+    /// Reassign a game name.
+    /// Takes a held game name from its publisher and hands it to another,
+    /// such as the rightful owner of a trademark. Signed by the verifier
+    /// authority.
+    #[account(0, writable, name = "name_record", desc = "The registry record of the name")]
+    #[account(1, name = "new_owner", desc = "The publisher, or team, receiving the name")]
+    #[account(2, signer, name = "authority", desc = "The verifier authority")]
+    ReassignNameV1,
+
+    /// Add a game to a catalog.
+    /// Lists a game released before the catalog at the end of the catalog of
+    /// its publisher.
+    #[account(0, writable, name = "catalog", desc = "The catalog of the publisher")]
+    #[account(1, optional_signer, name = "publisher", desc = "The publisher of the game, or its team")]
+    #[account(2, name = "game", desc = "The game Collection account to list")]
+    #[account(3, writable, signer, name = "payer", desc = "The account paying for the storage fees")]
+    #[account(4, name = "system_program", desc = "The system program")]
+    AddCatalogEntryV1(AddCatalogEntryV1Args),
+
+    /// Restore a game to a catalog.
+    /// Clears the retirement of a game of the publisher catalog.
+    #[account(0, writable, name = "catalog", desc = "The catalog of the publisher")]
+    #[account(1, optional_signer, name = "publisher", desc = "The publisher of the catalog, or its team")]
+    #[account(2, name = "game", desc = "The game Collection account to restore")]
+    RestoreCatalogEntryV1,
+
+    /// Insert cartridge
+    /// Insert a game cartridge into a machine.
+    /// The machine operator may sign in place of the cartridge owner for
+    /// cartridges owned by the machine owner.
+    #[account(0, writable, name = "cartridge", desc = "The game cartridge account")]
+    #[account(1, writable, name = "game", desc = "The game Collection account")]
+    #[account(2, writable, optional_signer, name = "cartridge_owner", desc = "The owner of the game cartridge")]
+    #[account(3, writable, name = "machine", desc = "The machine asset account")]
+    #[account(4, writable, name = "machine_collection", desc = "The Core machine collection")]
+    #[account(5, name = "machine_owner", desc = "The owner of the machine")]
+    #[account(6, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(7, name = "system_program", desc = "The system program")]
+    #[account(8, optional, writable, signer, name = "operator", desc = "The machine operator delegate signing in place of the cartridge owner")]
+    InsertCartridgeV2(InsertCartridgeV1Args),
+
+    /// Remove cartridge
+    /// Remove a game cartridge from a machine.
+    /// The machine operator may sign in place of the cartridge owner for
+    /// cartridges owned by the machine owner. While the session is locked the
+    /// machine owner must co-sign.
+    #[account(0, writable, name = "cartridge", desc = "The game cartridge account")]
+    #[account(1, writable, name = "game", desc = "The game Collection account")]
+    #[account(2, writable, optional_signer, name = "cartridge_owner", desc = "The owner of the game cartridge")]
+    #[account(3, writable, name = "machine", desc = "The machine asset account")]
+    #[account(4, writable, name = "machine_collection", desc = "The Core machine collection")]
+    #[account(5, optional_signer, name = "machine_owner", desc = "The owner of the machine, co-signing while the session is locked")]
+    #[account(6, name = "mpl_core_program", desc = "The mpl core program")]
+    #[account(7, name = "system_program", desc = "The system program")]
+    #[account(8, optional, writable, signer, name = "operator", desc = "The machine operator delegate signing in place of the cartridge owner")]
+    RemoveCartridgeV2(RemoveCartridgeV1Args),
 }
@@ -0,0 +1,149 @@
+use bytemuck::{from_bytes, from_bytes_mut, Pod, Zeroable};
+use mpl_utils::{
+    assert_signer, cmp_pubkeys, create_or_allocate_account_raw, resize_or_reallocate_account_raw,
+};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::AddCatalogEntryV1Accounts,
+    state::{
+        assert_catalog, assert_game_derivation, assert_publisher, Catalog, CatalogEntry,
+        GameCollectionData, CATALOG_PREFIX,
+    },
+};
+
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub struct AddCatalogEntryV1Args {
+    #[skip]
+    /// The discriminator for the instruction
+    discriminator: u8,
+    /// The nonce for the game collection
+    collection_nonce: u8,
+    /// The bump for the game collection
+    collection_bump: u8,
+}
+
+impl AddCatalogEntryV1Accounts<'_> {
+    /// Returns the bump of the catalog.
+    pub fn check(
+        &self,
+        args: &AddCatalogEntryV1Args,
+        approvers: &[AccountInfo],
+    ) -> Result<u8, ProgramError> {
+        let Self {
+            catalog,
+            publisher,
+            game,
+            payer,
+            system_program,
+        } = self;
+
+        // Catalog
+        let bump = assert_catalog(catalog, publisher.key)?;
+        if !catalog.data_is_empty()
+            && Catalog::entry_mut(&mut catalog.try_borrow_mut_data()?, game.key).is_some()
+        {
+            return Err(BglCartridgeError::CatalogEntryExists.into());
+        }
+
+        // Publisher
+        // Only the publisher lists its games, a team with the approval of its
+        // members.
+        assert_publisher(publisher.key, publisher, approvers)?;
+
+        // Game
+        // Games released before the catalog are listed by their publisher.
+        assert_game_derivation(game, args.collection_nonce, args.collection_bump)?;
+        if GameCollectionData::fetch(game)?.publisher != *publisher.key {
+            return Err(BglCartridgeError::CatalogPublisherMismatch.into());
+        }
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(bump)
+    }
+}
+
+pub fn add_catalog_entry<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = AddCatalogEntryV1Accounts::context(accounts)?;
+    let args: &AddCatalogEntryV1Args = from_bytes(args);
+
+    // All account guards and validations happen here. The remaining accounts
+    // are the team members approving the listing when a team publishes.
+    let bump = ctx.accounts.check(args, ctx.remaining_accounts)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    append_catalog_entry(
+        ctx.accounts.catalog,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.publisher.key,
+        bump,
+        ctx.accounts.game.key,
+    )
+}
+
+/// Lists a game at the end of the catalog of the publisher, creating the
+/// catalog on its first game and growing it by one entry.
+pub(crate) fn append_catalog_entry<'a>(
+    catalog: &'a AccountInfo<'a>,
+    payer: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    publisher: &Pubkey,
+    bump: u8,
+    game: &Pubkey,
+) -> ProgramResult {
+    if catalog.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::ID,
+            catalog,
+            system_program,
+            payer,
+            Catalog::size(0),
+            &[CATALOG_PREFIX, publisher.as_ref(), &[bump]],
+        )?;
+
+        let mut catalog_data = catalog.try_borrow_mut_data()?;
+        *from_bytes_mut::<Catalog>(&mut catalog_data) = Catalog {
+            publisher: *publisher,
+            num_entries: 0,
+            _padding: [0; 4],
+        };
+    }
+
+    let num_entries = Catalog::split_mut(&mut catalog.try_borrow_mut_data()?)
+        .0
+        .num_entries;
+    resize_or_reallocate_account_raw(
+        catalog,
+        payer,
+        system_program,
+        Catalog::size(num_entries as usize + 1),
+    )?;
+
+    let mut catalog_data = catalog.try_borrow_mut_data()?;
+    let (header, entries) = Catalog::split_mut(&mut catalog_data);
+    entries[num_entries as usize] = CatalogEntry {
+        game: *game,
+        released_at: Clock::get()?.unix_timestamp,
+        retired: 0,
+        _padding: [0; 7],
+    };
+    header.num_entries = num_entries + 1;
+
+    Ok(())
+}
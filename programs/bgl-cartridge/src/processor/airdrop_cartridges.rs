@@ -0,0 +1,112 @@
+use bytemuck::{from_bytes, Pod, Zeroable};
+use mpl_utils::{assert_signer, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::AirdropCartridgesV1Accounts,
+    processor::CartridgeMinter,
+    state::{assert_publisher, CartridgeData, GameCollectionData, Source},
+};
+
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub struct AirdropCartridgesV1Args {
+    #[skip]
+    /// The discriminator for the instruction
+    discriminator: u8,
+    /// The nonce for the collection
+    collection_nonce: u8,
+    /// The bump for the collection
+    collection_bump: u8,
+    /// The number of team members approving the airdrop
+    num_approvers: u8,
+}
+
+impl AirdropCartridgesV1Accounts<'_> {
+    pub fn check(&self, approvers: &[AccountInfo]) -> ProgramResult {
+        let Self {
+            game,
+            publisher,
+            payer,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Game
+        // SAFE: Checked by Core
+
+        // Publisher
+        // Only the publisher recorded at release can give cartridges away, a
+        // team with the approval of its members.
+        assert_publisher(
+            &GameCollectionData::fetch(game)?.publisher,
+            publisher,
+            approvers,
+        )?;
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+pub fn airdrop_cartridges<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = AirdropCartridgesV1Accounts::context(accounts)?;
+    let args: &AirdropCartridgesV1Args = from_bytes(args);
+
+    // Remaining Accounts
+    // The team members approving the airdrop when a team publishes the game,
+    // followed by (owner, cartridge) pairs, the cartridges being new signers.
+    if ctx.remaining_accounts.len() < args.num_approvers as usize {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (approvers, pairs) = ctx.remaining_accounts.split_at(args.num_approvers as usize);
+    if pairs.is_empty() || pairs.len() % 2 != 0 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // All account guards and validations happen here.
+    ctx.accounts.check(approvers)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Mint one cartridge per pair without payment. Each mint reads the
+    // collection's count so editions stay sequential.
+    let minter = CartridgeMinter {
+        game: ctx.accounts.game,
+        payer: ctx.accounts.payer,
+        mpl_core_program: ctx.accounts.mpl_core_program,
+        system_program: ctx.accounts.system_program,
+    };
+    let cartridge_data =
+        CartridgeData::new(Source::PublisherGrant, 0, Clock::get()?.unix_timestamp);
+    for pair in pairs.chunks_exact(2) {
+        let (owner, cartridge) = (&pair[0], &pair[1]);
+        minter.mint(
+            cartridge,
+            owner,
+            args.collection_nonce,
+            args.collection_bump,
+            &cartridge_data,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,338 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::from_bytes;
+use mpl_core::{
+    accounts::BaseCollectionV1,
+    fetch_plugin,
+    instructions::{
+        TransferV1Cpi, TransferV1InstructionArgs, UpdatePluginV1Cpi, UpdatePluginV1InstructionArgs,
+        WriteExternalPluginAdapterDataV1Cpi, WriteExternalPluginAdapterDataV1InstructionArgs,
+    },
+    types::{
+        ExternalPluginAdapterKey, PermanentFreezeDelegate, Plugin, PluginAuthority, PluginType,
+        Royalties,
+    },
+};
+use mpl_utils::{assert_owned_by, assert_signer, close_account_raw, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke,
+    program_error::ProgramError, program_pack::Pack, pubkey::Pubkey, system_program,
+};
+use spl_token::state::Account as SplTokenAccount;
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::BuyCartridgeV1Accounts,
+    processor::assert_payer_token_account,
+    state::{
+        assert_cartridge_of_game, fetch_cartridge_link, CartridgeData, Listing,
+        CARTRIDGE_DATA_VERSION, GAME_PREFIX, MAX_BPS, PAYMENT_TOKEN_MINT,
+    },
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct BuyCartridgeV1Args {
+    /// The nonce for the game collection
+    pub collection_nonce: u8,
+    /// The bump for the game collection
+    pub collection_bump: u8,
+    /// The listing price the buyer agreed to
+    pub price: u64,
+}
+
+impl BuyCartridgeV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+/// Checks that the token account holds the payment token of the wallet.
+pub(crate) fn assert_wallet_token_account(
+    wallet: &Pubkey,
+    token_account: &AccountInfo,
+    error: BglCartridgeError,
+) -> ProgramResult {
+    assert_owned_by(token_account, &spl_token::ID, error.clone())?;
+
+    let account = SplTokenAccount::unpack(token_account.try_borrow_data()?.as_ref())?;
+    if account.owner != *wallet || account.mint != PAYMENT_TOKEN_MINT {
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+impl BuyCartridgeV1Accounts<'_> {
+    /// Returns the listing.
+    pub fn check(&self) -> Result<Listing, ProgramError> {
+        let Self {
+            cartridge,
+            game,
+            listing,
+            seller,
+            seller_token_account,
+            buyer,
+            buyer_token_account,
+            mpl_core_program,
+            token_program,
+            system_program,
+        } = self;
+
+        // Listing
+        assert_owned_by(listing, &crate::ID, BglCartridgeError::InvalidListing)?;
+        if listing.data_len() != core::mem::size_of::<Listing>() {
+            return Err(BglCartridgeError::InvalidListing.into());
+        }
+        let listing_data = *from_bytes::<Listing>(&listing.try_borrow_data()?);
+
+        // Cartridge
+        // Must be the listed cartridge, still held by the seller and sitting
+        // outside of any machine.
+        if !cmp_pubkeys(cartridge.key, &listing_data.cartridge) {
+            return Err(BglCartridgeError::InvalidListing.into());
+        }
+        let asset = assert_cartridge_of_game(cartridge, game.key)?;
+        if asset.owner != listing_data.seller {
+            return Err(BglCartridgeError::InvalidListing.into());
+        }
+        if fetch_cartridge_link(cartridge)?.is_some() {
+            return Err(BglCartridgeError::CartridgeAlreadyInserted.into());
+        }
+
+        // Game
+        // SAFE: Checked against the cartridge, and by Core when transferring
+        // as the game PDA.
+
+        // Seller
+        if !cmp_pubkeys(seller.key, &listing_data.seller) {
+            return Err(BglCartridgeError::InvalidListing.into());
+        }
+
+        // Seller Token Account
+        assert_wallet_token_account(
+            seller.key,
+            seller_token_account,
+            BglCartridgeError::InvalidSellerTokenAccount,
+        )?;
+
+        // Buyer
+        assert_signer(buyer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // Buyer Token Account
+        assert_payer_token_account(buyer, buyer_token_account)?;
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // Token Program
+        if !cmp_pubkeys(token_program.key, &spl_token::ID) {
+            return Err(BglCartridgeError::InvalidTokenProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(listing_data)
+    }
+}
+
+/// Splits the price between the creators of the royalties and the seller,
+/// the seller getting what the creators' shares leave.
+fn split_royalties(price: u64, royalties: &Royalties) -> Result<(Vec<u64>, u64), ProgramError> {
+    let royalty = (price as u128)
+        .checked_mul(royalties.basis_points as u128)
+        .ok_or(BglCartridgeError::NumericalOverflow)?
+        / MAX_BPS as u128;
+
+    let shares = royalties
+        .creators
+        .iter()
+        .map(|creator| (royalty * creator.percentage as u128 / 100) as u64)
+        .collect::<Vec<_>>();
+    let seller_share = shares
+        .iter()
+        .try_fold(price, |rest, share| rest.checked_sub(*share))
+        .ok_or(BglCartridgeError::NumericalOverflow)?;
+
+    Ok((shares, seller_share))
+}
+
+pub fn buy_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = BuyCartridgeV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    let listing = ctx.accounts.check()?;
+
+    let args = BuyCartridgeV1Args::unpack(args)?;
+
+    // The seller could have relisted at another price in the meantime.
+    if listing.price != args.price {
+        return Err(BglCartridgeError::ListingPriceChanged.into());
+    }
+
+    // Remaining Accounts
+    // One token account per royalties creator of the game, in order.
+    let (_, royalties, _) =
+        fetch_plugin::<BaseCollectionV1, Royalties>(ctx.accounts.game, PluginType::Royalties)?;
+    if ctx.remaining_accounts.len() != royalties.creators.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    for (creator, creator_token_account) in
+        royalties.creators.iter().zip(ctx.remaining_accounts.iter())
+    {
+        assert_wallet_token_account(
+            &creator.address,
+            creator_token_account,
+            BglCartridgeError::InvalidCreatorTokenAccount,
+        )?;
+    }
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Pay the creators their royalties and the seller the rest.
+    let (creator_shares, seller_share) = split_royalties(listing.price, &royalties)?;
+    let payouts = creator_shares
+        .into_iter()
+        .zip(ctx.remaining_accounts.iter())
+        .chain([(seller_share, ctx.accounts.seller_token_account)]);
+    for (amount, destination) in payouts {
+        if amount == 0 {
+            continue;
+        }
+
+        invoke(
+            &spl_token::instruction::transfer(
+                ctx.accounts.token_program.key,
+                ctx.accounts.buyer_token_account.key,
+                destination.key,
+                ctx.accounts.buyer.key,
+                &[],
+                amount,
+            )?,
+            &[
+                ctx.accounts.buyer_token_account.clone(),
+                destination.clone(),
+                ctx.accounts.buyer.clone(),
+            ],
+        )?;
+    }
+
+    // Thaw the cartridge, move it to the buyer through the permanent transfer
+    // delegate and freeze it again, all as the game.
+    CartridgeMover {
+        game: ctx.accounts.game,
+        payer: ctx.accounts.buyer,
+        mpl_core_program: ctx.accounts.mpl_core_program,
+        system_program: ctx.accounts.system_program,
+    }
+    .transfer(
+        ctx.accounts.cartridge,
+        ctx.accounts.buyer,
+        args.collection_nonce,
+        args.collection_bump,
+    )?;
+
+    // Close the listing, returning its rent to the seller.
+    close_account_raw(ctx.accounts.seller, ctx.accounts.listing)
+}
+
+/// The accounts needed to move cartridges between wallets and rewrite their
+/// data, signing as the game.
+pub(crate) struct CartridgeMover<'a> {
+    pub game: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub mpl_core_program: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl<'a> CartridgeMover<'a> {
+    /// Thaws the cartridge, transfers it to the new owner through the
+    /// permanent transfer delegate and freezes it again.
+    pub fn transfer(
+        &self,
+        cartridge: &'a AccountInfo<'a>,
+        new_owner: &'a AccountInfo<'a>,
+        collection_nonce: u8,
+        collection_bump: u8,
+    ) -> ProgramResult {
+        let collection = BaseCollectionV1::from_bytes(self.game.try_borrow_data()?.as_ref())?;
+        let game_seeds: &[&[u8]] = &[
+            GAME_PREFIX,
+            collection.name.as_bytes(),
+            &[collection_nonce],
+            &[collection_bump],
+        ];
+        let set_frozen = |frozen: bool| {
+            UpdatePluginV1Cpi {
+                __program: self.mpl_core_program,
+                asset: cartridge,
+                collection: Some(self.game),
+                payer: self.payer,
+                authority: Some(self.game),
+                system_program: self.system_program,
+                log_wrapper: None,
+                __args: UpdatePluginV1InstructionArgs {
+                    plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen }),
+                },
+            }
+            .invoke_signed(&[game_seeds])
+        };
+
+        set_frozen(false)?;
+        TransferV1Cpi {
+            __program: self.mpl_core_program,
+            asset: cartridge,
+            collection: Some(self.game),
+            payer: self.payer,
+            authority: Some(self.game),
+            new_owner,
+            system_program: Some(self.system_program),
+            log_wrapper: None,
+            __args: TransferV1InstructionArgs {
+                compression_proof: None,
+            },
+        }
+        .invoke_signed(&[game_seeds])?;
+        set_frozen(true)
+    }
+
+    /// Rewrites the cartridge data, upgrading it to the latest layout.
+    pub fn write_data(
+        &self,
+        cartridge: &'a AccountInfo<'a>,
+        collection_nonce: u8,
+        collection_bump: u8,
+        cartridge_data: &mut CartridgeData,
+    ) -> ProgramResult {
+        cartridge_data.version = CARTRIDGE_DATA_VERSION;
+
+        let collection = BaseCollectionV1::from_bytes(self.game.try_borrow_data()?.as_ref())?;
+        WriteExternalPluginAdapterDataV1Cpi {
+            __program: self.mpl_core_program,
+            asset: cartridge,
+            collection: Some(self.game),
+            payer: self.payer,
+            authority: Some(self.game),
+            buffer: None,
+            system_program: self.system_program,
+            log_wrapper: None,
+            __args: WriteExternalPluginAdapterDataV1InstructionArgs {
+                key: ExternalPluginAdapterKey::AppData(PluginAuthority::UpdateAuthority),
+                data: Some(borsh::to_vec(cartridge_data)?),
+            },
+        }
+        .invoke_signed(&[&[
+            GAME_PREFIX,
+            collection.name.as_bytes(),
+            &[collection_nonce],
+            &[collection_bump],
+        ]])
+    }
+}
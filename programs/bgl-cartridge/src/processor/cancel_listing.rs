@@ -0,0 +1,41 @@
+use bytemuck::from_bytes;
+use mpl_utils::{assert_owned_by, assert_signer, close_account_raw};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use crate::{
+    error::BglCartridgeError, instruction::accounts::CancelListingV1Accounts, state::Listing,
+};
+
+impl CancelListingV1Accounts<'_> {
+    pub fn check(&self) -> ProgramResult {
+        let Self { listing, seller } = self;
+
+        // Listing
+        assert_owned_by(listing, &crate::ID, BglCartridgeError::InvalidListing)?;
+        if listing.data_len() != core::mem::size_of::<Listing>() {
+            return Err(BglCartridgeError::InvalidListing.into());
+        }
+
+        // Seller
+        // Only the seller who listed the cartridge can take it off the market.
+        assert_signer(seller).map_err(|_| BglCartridgeError::CartridgeOwnerMustSign)?;
+        if from_bytes::<Listing>(&listing.try_borrow_data()?).seller != *seller.key {
+            return Err(BglCartridgeError::CartridgeOwnerMustSign.into());
+        }
+
+        Ok(())
+    }
+}
+
+pub fn cancel_listing<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = CancelListingV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    ctx.accounts.check()?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Close the listing, returning its rent to the seller.
+    close_account_raw(ctx.accounts.seller, ctx.accounts.listing)
+}
@@ -0,0 +1,124 @@
+use bytemuck::from_bytes;
+use mpl_utils::cmp_pubkeys;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program::invoke_signed,
+    program_error::ProgramError, program_pack::Pack, sysvar::Sysvar,
+};
+use spl_token::state::Account as SplTokenAccount;
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::ClaimEscrowV1Accounts,
+    processor::assert_game_token_account,
+    state::{
+        assert_escrow, assert_publisher, Escrow, GameCollectionData, PriceType, ESCROW_PREFIX,
+        PAYMENT_TOKEN_MINT,
+    },
+};
+
+impl ClaimEscrowV1Accounts<'_> {
+    /// Returns the game data and the bump of the escrow.
+    pub fn check(
+        &self,
+        approvers: &[AccountInfo],
+    ) -> Result<(GameCollectionData, u8), ProgramError> {
+        let Self {
+            game,
+            escrow,
+            escrow_token_account,
+            game_token_account,
+            publisher,
+            token_program,
+            payment_mint,
+        } = self;
+
+        // Game
+        let game_data = GameCollectionData::fetch(game)?;
+
+        // Escrow
+        // Released once the goal is met and the pre-orders closed, so every
+        // payment is claimed at once.
+        let bump = assert_escrow(escrow, game.key)?;
+        let escrow_state = *from_bytes::<Escrow>(&escrow.try_borrow_data()?);
+        if !escrow_state.is_funded() {
+            return Err(BglCartridgeError::CrowdfundNotFunded.into());
+        }
+
+        if Clock::get()?.unix_timestamp < escrow_state.deadline {
+            return Err(BglCartridgeError::CrowdfundActive.into());
+        }
+
+        // Escrow Token Account
+        assert_game_token_account(escrow, escrow_token_account)?;
+
+        // Game Token Account
+        // The pre-order payments join the rest of the game revenue, unless the
+        // game burns its payments.
+        assert_game_token_account(game, game_token_account)?;
+
+        // Publisher
+        assert_publisher(&game_data.publisher, publisher, approvers)?;
+
+        // Token Program
+        if !cmp_pubkeys(token_program.key, &spl_token::ID) {
+            return Err(BglCartridgeError::InvalidTokenProgram.into());
+        }
+
+        // Payment Mint
+        if !cmp_pubkeys(payment_mint.key, &PAYMENT_TOKEN_MINT) {
+            return Err(BglCartridgeError::InvalidPaymentMint.into());
+        }
+
+        Ok((game_data, bump))
+    }
+}
+
+pub fn claim_escrow<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = ClaimEscrowV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here. The remaining accounts
+    // are the team members approving the claim when a team publishes the game.
+    let (game_data, bump) = ctx.accounts.check(ctx.remaining_accounts)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Pre-orders are paid like prints: into the game, or burned.
+    let escrow_token_account = ctx.accounts.escrow_token_account;
+    let balance = SplTokenAccount::unpack(&escrow_token_account.try_borrow_data()?)?.amount;
+    let escrow_seeds: &[&[u8]] = &[ESCROW_PREFIX, ctx.accounts.game.key.as_ref(), &[bump]];
+    match PriceType::from(game_data.price_type) {
+        PriceType::Transfer => invoke_signed(
+            &spl_token::instruction::transfer(
+                ctx.accounts.token_program.key,
+                escrow_token_account.key,
+                ctx.accounts.game_token_account.key,
+                ctx.accounts.escrow.key,
+                &[],
+                balance,
+            )?,
+            &[
+                escrow_token_account.clone(),
+                ctx.accounts.game_token_account.clone(),
+                ctx.accounts.escrow.clone(),
+            ],
+            &[escrow_seeds],
+        ),
+        PriceType::Burn => invoke_signed(
+            &spl_token::instruction::burn(
+                ctx.accounts.token_program.key,
+                escrow_token_account.key,
+                ctx.accounts.payment_mint.key,
+                ctx.accounts.escrow.key,
+                &[],
+                balance,
+            )?,
+            &[
+                escrow_token_account.clone(),
+                ctx.accounts.payment_mint.clone(),
+                ctx.accounts.escrow.clone(),
+            ],
+            &[escrow_seeds],
+        ),
+    }
+}
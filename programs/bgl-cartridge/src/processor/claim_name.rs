@@ -0,0 +1,98 @@
+use bytemuck::{from_bytes, Pod, Zeroable};
+use mpl_core::accounts::BaseCollectionV1;
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    system_program,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::ClaimNameV1Accounts,
+    processor::create_name_record,
+    state::{
+        assert_game_derivation, assert_publisher, normalize_name, GameCollectionData, NAME_PREFIX,
+    },
+};
+
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub struct ClaimNameV1Args {
+    #[skip]
+    /// The discriminator for the instruction
+    discriminator: u8,
+    /// The nonce for the game collection
+    collection_nonce: u8,
+    /// The bump for the game collection
+    collection_bump: u8,
+}
+
+impl ClaimNameV1Accounts<'_> {
+    /// Returns the game name, its publisher and the bump of the name record.
+    pub fn check(
+        &self,
+        args: &ClaimNameV1Args,
+        approvers: &[AccountInfo],
+    ) -> Result<(String, GameCollectionData, u8), ProgramError> {
+        let Self {
+            name_record,
+            game,
+            publisher,
+            payer,
+            system_program,
+        } = self;
+
+        // Game
+        assert_game_derivation(game, args.collection_nonce, args.collection_bump)?;
+        let name = BaseCollectionV1::from_bytes(game.try_borrow_data()?.as_ref())?.name;
+        let game_data = GameCollectionData::fetch(game)?;
+
+        // Name Record
+        // Unregistered games only claim names no one holds yet.
+        let bump = assert_derivation(
+            &crate::ID,
+            name_record,
+            &[NAME_PREFIX, normalize_name(&name).as_bytes()],
+            BglCartridgeError::InvalidNameRecord,
+        )?;
+
+        if !name_record.data_is_empty() {
+            return Err(BglCartridgeError::NameTaken.into());
+        }
+
+        // Publisher
+        assert_publisher(&game_data.publisher, publisher, approvers)?;
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok((name, game_data, bump))
+    }
+}
+
+pub fn claim_name<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = ClaimNameV1Accounts::context(accounts)?;
+    let args: &ClaimNameV1Args = from_bytes(args);
+
+    // All account guards and validations happen here. The remaining accounts
+    // are the team members approving the claim when a team publishes the game.
+    let (name, game_data, bump) = ctx.accounts.check(args, ctx.remaining_accounts)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    create_name_record(
+        ctx.accounts.name_record,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        &name,
+        bump,
+        &game_data.publisher,
+    )
+}
@@ -0,0 +1,50 @@
+use bytemuck::from_bytes;
+use mpl_utils::{assert_owned_by, close_account_raw};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::CloseBundleV1Accounts,
+    state::{assert_publisher, Bundle},
+};
+
+impl CloseBundleV1Accounts<'_> {
+    pub fn check(&self, approvers: &[AccountInfo]) -> ProgramResult {
+        let Self {
+            bundle,
+            publisher,
+            destination: _destination,
+        } = self;
+
+        // Bundle
+        assert_owned_by(bundle, &crate::ID, BglCartridgeError::InvalidBundle)?;
+        if bundle.data_len() != core::mem::size_of::<Bundle>() {
+            return Err(BglCartridgeError::InvalidBundle.into());
+        }
+
+        // Publisher
+        // A wallet signs, a team needs its members to approve.
+        let expected = from_bytes::<Bundle>(&bundle.try_borrow_data()?).publisher;
+        assert_publisher(&expected, publisher, approvers)?;
+
+        // Destination
+        // SAFE: Chosen by the publisher.
+
+        Ok(())
+    }
+}
+
+pub fn close_bundle<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = CloseBundleV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    // Remaining Accounts
+    // The team members approving the close when a team publishes the bundle.
+    ctx.accounts.check(ctx.remaining_accounts)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Take the bundle off sale, sending its rent to the destination.
+    close_account_raw(ctx.accounts.destination, ctx.accounts.bundle)
+}
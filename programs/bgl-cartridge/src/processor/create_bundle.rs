@@ -0,0 +1,172 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{from_bytes_mut, Zeroable};
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys, create_or_allocate_account_raw};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    system_program,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::CreateBundleV1Accounts,
+    state::{
+        assert_bundle_shares, assert_game_derivation, assert_publisher, Bundle, BundleMember,
+        GameCollectionData, BUNDLE_PREFIX, MAX_BUNDLE_GAMES,
+    },
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct BundleGameArgs {
+    /// The nonce for the game collection
+    pub collection_nonce: u8,
+    /// The bump for the game collection
+    pub collection_bump: u8,
+    /// The share of the bundle price paid to the game, in basis points
+    pub share_bps: u16,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct CreateBundleV1Args {
+    /// The name of the bundle, unique per publisher
+    pub name: String,
+    /// The price of the bundle in the payment token
+    pub price: u64,
+    /// The member games, in the order of the remaining accounts
+    pub games: Vec<BundleGameArgs>,
+}
+
+impl CreateBundleV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+
+    pub fn check(&self) -> ProgramResult {
+        // Name
+        // Used as a PDA seed.
+        if self.name.is_empty() || self.name.len() > 32 {
+            return Err(BglCartridgeError::InvalidName.into());
+        }
+
+        // Games
+        if self.games.len() < 2 || self.games.len() > MAX_BUNDLE_GAMES {
+            return Err(BglCartridgeError::InvalidBundleGames.into());
+        }
+
+        // Shares
+        assert_bundle_shares(self.games.iter().map(|game| game.share_bps))
+    }
+}
+
+impl CreateBundleV1Accounts<'_> {
+    /// Returns the bump of the bundle.
+    pub fn check(
+        &self,
+        args: &CreateBundleV1Args,
+        approvers: &[AccountInfo],
+    ) -> Result<u8, ProgramError> {
+        let Self {
+            bundle,
+            publisher,
+            payer,
+            system_program,
+        } = self;
+
+        // Bundle
+        let bump = assert_derivation(
+            &crate::ID,
+            bundle,
+            &[BUNDLE_PREFIX, publisher.key.as_ref(), args.name.as_bytes()],
+            BglCartridgeError::InvalidBundle,
+        )?;
+
+        if !bundle.data_is_empty() {
+            return Err(BglCartridgeError::InvalidBundle.into());
+        }
+
+        // Publisher
+        // A wallet signs, a team needs its members to approve.
+        assert_publisher(publisher.key, publisher, approvers)?;
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(bump)
+    }
+}
+
+pub fn create_bundle<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = CreateBundleV1Accounts::context(accounts)?;
+    let args = CreateBundleV1Args::unpack(args)?;
+    args.check()?;
+
+    // Remaining Accounts
+    // The member game collections, one per game in the args, followed by the
+    // team members approving the bundle when a team publishes the games.
+    if ctx.remaining_accounts.len() < args.games.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (games, approvers) = ctx.remaining_accounts.split_at(args.games.len());
+
+    // All account guards and validations happen here.
+    let bump = ctx.accounts.check(&args, approvers)?;
+
+    // Every member must be a distinct game of the publisher.
+    let mut members = [BundleMember::zeroed(); MAX_BUNDLE_GAMES];
+    for (i, (game, game_args)) in games.iter().zip(&args.games).enumerate() {
+        assert_game_derivation(game, game_args.collection_nonce, game_args.collection_bump)?;
+
+        if GameCollectionData::fetch(game)?.publisher != *ctx.accounts.publisher.key {
+            return Err(BglCartridgeError::PublisherMustSign.into());
+        }
+
+        if members[..i].iter().any(|member| member.game == *game.key) {
+            return Err(BglCartridgeError::InvalidBundleGames.into());
+        }
+
+        members[i] = BundleMember {
+            game: *game.key,
+            share_bps: game_args.share_bps,
+            collection_nonce: game_args.collection_nonce,
+            collection_bump: game_args.collection_bump,
+            _padding: [0; 4],
+        };
+    }
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    create_or_allocate_account_raw(
+        crate::ID,
+        ctx.accounts.bundle,
+        ctx.accounts.system_program,
+        ctx.accounts.payer,
+        core::mem::size_of::<Bundle>(),
+        &[
+            BUNDLE_PREFIX,
+            ctx.accounts.publisher.key.as_ref(),
+            args.name.as_bytes(),
+            &[bump],
+        ],
+    )?;
+
+    let mut bundle_data = ctx.accounts.bundle.try_borrow_mut_data()?;
+    let bundle: &mut Bundle = from_bytes_mut(&mut bundle_data);
+    *bundle = Bundle {
+        publisher: *ctx.accounts.publisher.key,
+        price: args.price,
+        games: members,
+        num_games: args.games.len() as u8,
+        _padding: [0; 7],
+    };
+
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_core::{
+    instructions::{
+        CreateCollectionV2Cpi, CreateCollectionV2InstructionArgs,
+        WriteCollectionExternalPluginAdapterDataV1Cpi,
+        WriteCollectionExternalPluginAdapterDataV1InstructionArgs,
+    },
+    types::{
+        ExternalPluginAdapterInitInfo, ExternalPluginAdapterKey, LinkedAppDataInitInfo,
+        PluginAuthority,
+    },
+};
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke,
+    program_error::ProgramError, system_program,
+};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::CreatePassV1Accounts,
+    state::{PassCollectionData, PASS_COLLECTION_DATA_VERSION, PASS_PREFIX, PAYMENT_TOKEN_MINT},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct CreatePassV1Args {
+    /// The name of the pass collection, unique per authority
+    pub name: String,
+    /// The URI of the pass collection metadata
+    pub uri: String,
+    /// The price of a pass period in the payment token
+    pub price: u64,
+    /// How long a purchase or renewal extends a pass, in seconds
+    pub duration: i64,
+}
+
+impl CreatePassV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+
+    pub fn check(&self) -> ProgramResult {
+        // Name
+        // Used as a PDA seed.
+        if self.name.is_empty() || self.name.len() > 32 {
+            return Err(BglCartridgeError::InvalidName.into());
+        }
+
+        // URI
+        if self.uri.is_empty() {
+            return Err(BglCartridgeError::InvalidUri.into());
+        }
+
+        // Duration
+        if self.duration <= 0 {
+            return Err(BglCartridgeError::InvalidPassTerms.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl CreatePassV1Accounts<'_> {
+    /// Returns the bump of the pass collection.
+    pub fn check(&self, args: &CreatePassV1Args) -> Result<u8, ProgramError> {
+        let Self {
+            pass_collection,
+            pass_token_account: _pass_token_account,
+            authority,
+            payer,
+            payment_mint,
+            mpl_core_program,
+            token_program,
+            associated_token_program,
+            system_program,
+        } = self;
+
+        // Pass Collection
+        let bump = assert_derivation(
+            &crate::ID,
+            pass_collection,
+            &[PASS_PREFIX, authority.key.as_ref(), args.name.as_bytes()],
+            BglCartridgeError::InvalidPassCollection,
+        )?;
+
+        // Pass Token Account
+        // SAFE: Checked by CreateAssociatedTokenAccountIdempotent
+
+        // Authority
+        assert_signer(authority).map_err(|_| BglCartridgeError::AuthorityMustSign)?;
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // Payment Mint
+        if !cmp_pubkeys(payment_mint.key, &PAYMENT_TOKEN_MINT) {
+            return Err(BglCartridgeError::InvalidPaymentMint.into());
+        }
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // Token Program
+        if !cmp_pubkeys(token_program.key, &spl_token::ID) {
+            return Err(BglCartridgeError::InvalidTokenProgram.into());
+        }
+
+        // Associated Token Program
+        if !cmp_pubkeys(
+            associated_token_program.key,
+            &spl_associated_token_account::ID,
+        ) {
+            return Err(BglCartridgeError::InvalidAssociatedTokenProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(bump)
+    }
+}
+
+pub fn create_pass<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = CreatePassV1Accounts::context(accounts)?;
+
+    let args = CreatePassV1Args::unpack(args)?;
+    args.check()?;
+
+    // All account guards and validations happen here.
+    let bump = ctx.accounts.check(&args)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Create the pass collection. Like games, its LinkedAppData holds the pass
+    // terms on the collection and the machine link on each pass.
+    let pass_seeds: &[&[u8]] = &[
+        PASS_PREFIX,
+        ctx.accounts.authority.key.as_ref(),
+        args.name.as_bytes(),
+        &[bump],
+    ];
+    CreateCollectionV2Cpi {
+        __program: ctx.accounts.mpl_core_program,
+        collection: ctx.accounts.pass_collection,
+        update_authority: Some(ctx.accounts.pass_collection),
+        payer: ctx.accounts.payer,
+        system_program: ctx.accounts.system_program,
+        __args: CreateCollectionV2InstructionArgs {
+            name: args.name.clone(),
+            uri: args.uri,
+            plugins: None,
+            external_plugin_adapters: Some(vec![ExternalPluginAdapterInitInfo::LinkedAppData(
+                LinkedAppDataInitInfo {
+                    data_authority: PluginAuthority::UpdateAuthority,
+                    init_plugin_authority: None,
+                    schema: None,
+                },
+            )]),
+        },
+    }
+    .invoke_signed(&[pass_seeds])?;
+
+    let data = PassCollectionData {
+        version: PASS_COLLECTION_DATA_VERSION,
+        authority: *ctx.accounts.authority.key,
+        price: args.price,
+        duration: args.duration,
+    };
+    WriteCollectionExternalPluginAdapterDataV1Cpi {
+        __program: ctx.accounts.mpl_core_program,
+        collection: ctx.accounts.pass_collection,
+        payer: ctx.accounts.payer,
+        authority: Some(ctx.accounts.pass_collection),
+        buffer: None,
+        system_program: ctx.accounts.system_program,
+        log_wrapper: None,
+        __args: WriteCollectionExternalPluginAdapterDataV1InstructionArgs {
+            key: ExternalPluginAdapterKey::LinkedAppData(PluginAuthority::UpdateAuthority),
+            data: Some(borsh::to_vec(&data)?),
+        },
+    }
+    .invoke_signed(&[pass_seeds])?;
+
+    // Create the token account collecting pass payments.
+    invoke(
+        &create_associated_token_account_idempotent(
+            ctx.accounts.payer.key,
+            ctx.accounts.pass_collection.key,
+            &PAYMENT_TOKEN_MINT,
+            ctx.accounts.token_program.key,
+        ),
+        &[
+            ctx.accounts.payer.clone(),
+            ctx.accounts.pass_collection.clone(),
+            ctx.accounts.pass_token_account.clone(),
+            ctx.accounts.payment_mint.clone(),
+            ctx.accounts.token_program.clone(),
+            ctx.accounts.system_program.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{from_bytes_mut, Zeroable};
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys, create_or_allocate_account_raw};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey, system_program,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::CreatePublisherTeamV1Accounts,
+    state::{PublisherTeam, TeamMember, MAX_BPS, MAX_TEAM_MEMBERS, TEAM_PREFIX},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct TeamMemberArgs {
+    /// The wallet of the member
+    pub address: Pubkey,
+    /// The share of the game revenue paid to the member, in basis points
+    pub share_bps: u16,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct CreatePublisherTeamV1Args {
+    /// The name of the team, unique per creator
+    pub name: String,
+    /// How many members must sign administrative actions
+    pub threshold: u8,
+    /// The members of the team
+    pub members: Vec<TeamMemberArgs>,
+}
+
+impl CreatePublisherTeamV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+
+    pub fn check(&self) -> ProgramResult {
+        // Name
+        // Used as a PDA seed.
+        if self.name.is_empty() || self.name.len() > 32 {
+            return Err(BglCartridgeError::InvalidName.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Builds a team from its members and threshold, checking the members are
+/// distinct, their shares add up and the threshold can be met.
+pub(crate) fn build_team(
+    threshold: u8,
+    members: &[TeamMemberArgs],
+) -> Result<PublisherTeam, ProgramError> {
+    // Members
+    if members.is_empty() || members.len() > MAX_TEAM_MEMBERS {
+        return Err(BglCartridgeError::InvalidTeamMembers.into());
+    }
+
+    // Shares
+    let total = members
+        .iter()
+        .try_fold(0u16, |total, member| total.checked_add(member.share_bps))
+        .ok_or(BglCartridgeError::InvalidTeamShares)?;
+    if total != MAX_BPS {
+        return Err(BglCartridgeError::InvalidTeamShares.into());
+    }
+
+    // Threshold
+    if threshold == 0 || threshold as usize > members.len() {
+        return Err(BglCartridgeError::InvalidTeamThreshold.into());
+    }
+
+    let mut team = PublisherTeam::zeroed();
+    for (i, member) in members.iter().enumerate() {
+        if team.members[..i]
+            .iter()
+            .any(|existing| existing.address == member.address)
+        {
+            return Err(BglCartridgeError::InvalidTeamMembers.into());
+        }
+
+        team.members[i] = TeamMember {
+            address: member.address,
+            share_bps: member.share_bps,
+            _padding: [0; 6],
+        };
+    }
+    team.num_members = members.len() as u8;
+    team.threshold = threshold;
+
+    Ok(team)
+}
+
+impl CreatePublisherTeamV1Accounts<'_> {
+    /// Returns the bump of the team.
+    pub fn check(&self, args: &CreatePublisherTeamV1Args) -> Result<u8, ProgramError> {
+        let Self {
+            team,
+            creator,
+            payer,
+            system_program,
+        } = self;
+
+        // Team
+        let bump = assert_derivation(
+            &crate::ID,
+            team,
+            &[TEAM_PREFIX, creator.key.as_ref(), args.name.as_bytes()],
+            BglCartridgeError::InvalidPublisherTeam,
+        )?;
+
+        if !team.data_is_empty() {
+            return Err(BglCartridgeError::InvalidPublisherTeam.into());
+        }
+
+        // Creator
+        assert_signer(creator).map_err(|_| BglCartridgeError::AuthorityMustSign)?;
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(bump)
+    }
+}
+
+pub fn create_publisher_team<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = CreatePublisherTeamV1Accounts::context(accounts)?;
+    let args = CreatePublisherTeamV1Args::unpack(args)?;
+    args.check()?;
+
+    // All account guards and validations happen here.
+    let bump = ctx.accounts.check(&args)?;
+
+    let team = build_team(args.threshold, &args.members)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    create_or_allocate_account_raw(
+        crate::ID,
+        ctx.accounts.team,
+        ctx.accounts.system_program,
+        ctx.accounts.payer,
+        core::mem::size_of::<PublisherTeam>(),
+        &[
+            TEAM_PREFIX,
+            ctx.accounts.creator.key.as_ref(),
+            args.name.as_bytes(),
+            &[bump],
+        ],
+    )?;
+
+    let mut team_data = ctx.accounts.team.try_borrow_mut_data()?;
+    *from_bytes_mut::<PublisherTeam>(&mut team_data) = team;
+
+    Ok(())
+}
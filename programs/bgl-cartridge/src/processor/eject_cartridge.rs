@@ -0,0 +1,250 @@
+use bytemuck::{from_bytes, Pod, Zeroable};
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    instructions::{
+        WriteExternalPluginAdapterDataV1Cpi, WriteExternalPluginAdapterDataV1InstructionArgs,
+    },
+    types::{ExternalPluginAdapterKey, PluginAuthority},
+};
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::EjectCartridgeV1Accounts,
+    state::{fetch_cartridge_link, MachineData, GAME_PREFIX, MACHINE_PREFIX},
+};
+
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub struct EjectCartridgeV1Args {
+    #[skip]
+    /// The discriminator for the instruction
+    discriminator: u8,
+    /// The nonce for the collection
+    collection_nonce: u8,
+    /// The bump for the collection
+    collection_bump: u8,
+}
+
+impl<'a> EjectCartridgeV1Accounts<'a> {
+    pub(crate) fn ejector(&self) -> CartridgeEjector<'a> {
+        CartridgeEjector {
+            cartridge: self.cartridge,
+            game: self.game,
+            machine: self.machine,
+            machine_collection: self.machine_collection,
+            payer: self.authority,
+            mpl_core_program: self.mpl_core_program,
+            system_program: self.system_program,
+        }
+    }
+
+    pub fn check(&self) -> Result<(u8, String, MachineData), ProgramError> {
+        let Self {
+            authority,
+            mpl_core_program,
+            system_program,
+            ..
+        } = self;
+
+        // Cartridge, Game, Machine and Machine Collection
+        let (bump, machine_asset, machine_data) = self.ejector().check()?;
+
+        // Authority
+        // Either the machine owner or its operator delegate.
+        assert_signer(authority).map_err(|_| BglCartridgeError::AuthorityMustSign)?;
+        if machine_asset.owner != *authority.key && !machine_data.is_operator(authority.key) {
+            return Err(BglCartridgeError::InvalidMachineOperator.into());
+        }
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok((bump, machine_asset.name, machine_data))
+    }
+}
+
+pub fn eject_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = EjectCartridgeV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    let (machine_bump, machine_name, machine_data) = ctx.accounts.check()?;
+
+    let args: &EjectCartridgeV1Args = from_bytes(args);
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Force-eject the cartridge, cleaning up the links on both sides the same
+    // way remove_cartridge does.
+    ctx.accounts.ejector().eject(
+        args.collection_nonce,
+        args.collection_bump,
+        machine_bump,
+        &machine_name,
+        machine_data,
+    )
+}
+
+/// The accounts needed to force a cartridge out of the machine it is
+/// inserted in.
+pub(crate) struct CartridgeEjector<'a> {
+    pub cartridge: &'a AccountInfo<'a>,
+    pub game: &'a AccountInfo<'a>,
+    pub machine: &'a AccountInfo<'a>,
+    pub machine_collection: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub mpl_core_program: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl<'a> CartridgeEjector<'a> {
+    /// The ejector of the machine the cartridge is inserted in, if any. The
+    /// machine accounts are only required while the cartridge is inserted.
+    pub fn if_inserted(
+        cartridge: &'a AccountInfo<'a>,
+        game: &'a AccountInfo<'a>,
+        machine: Option<&'a AccountInfo<'a>>,
+        machine_collection: Option<&'a AccountInfo<'a>>,
+        payer: &'a AccountInfo<'a>,
+        mpl_core_program: &'a AccountInfo<'a>,
+        system_program: &'a AccountInfo<'a>,
+    ) -> Result<Option<Self>, ProgramError> {
+        if fetch_cartridge_link(cartridge)?.is_none() {
+            return Ok(None);
+        }
+
+        match (machine, machine_collection) {
+            (Some(machine), Some(machine_collection)) => Ok(Some(Self {
+                cartridge,
+                game,
+                machine,
+                machine_collection,
+                payer,
+                mpl_core_program,
+                system_program,
+            })),
+            _ => Err(BglCartridgeError::CartridgeMachineMismatch.into()),
+        }
+    }
+
+    /// Returns the machine bump, asset and data.
+    pub fn check(&self) -> Result<(u8, BaseAssetV1, MachineData), ProgramError> {
+        let Self {
+            cartridge,
+            machine,
+            machine_collection,
+            payer,
+            ..
+        } = self;
+
+        // Cartridge
+        // SAFE: Must be linked to the machine, checked below.
+
+        // Game Collection
+        // SAFE: Checked by Core
+
+        // Machine
+        let machine_asset = BaseAssetV1::from_bytes(machine.try_borrow_data()?.as_ref())?;
+        let bump = assert_derivation(
+            &crate::ID,
+            machine,
+            &[
+                MACHINE_PREFIX,
+                machine_collection.key.as_ref(),
+                machine_asset.name.as_bytes(),
+            ],
+            BglCartridgeError::InvalidMachinePdaDerivation,
+        )?;
+
+        // Check that the machine and the cartridge point at each other.
+        let machine_data = MachineData::fetch(machine)?;
+        machine_data.assert_inserted(machine, cartridge)?;
+
+        // Machine Collection
+        // SAFE: Checked by Core
+
+        // Payer
+        // A locked session can only be broken by the machine owner.
+        if machine_data
+            .session_lock
+            .is_active(Clock::get()?.unix_timestamp)
+            && (!payer.is_signer || machine_asset.owner != *payer.key)
+        {
+            return Err(BglCartridgeError::SessionLocked.into());
+        }
+
+        Ok((bump, machine_asset, machine_data))
+    }
+
+    /// Clears the link on both sides, moving the insertion to the machine's
+    /// history.
+    pub fn eject(
+        &self,
+        collection_nonce: u8,
+        collection_bump: u8,
+        machine_bump: u8,
+        machine_name: &str,
+        mut machine_data: MachineData,
+    ) -> ProgramResult {
+        // Clear the cartridge AppData.
+        let collection = BaseCollectionV1::from_bytes(self.game.try_borrow_data()?.as_ref())?;
+        WriteExternalPluginAdapterDataV1Cpi {
+            __program: self.mpl_core_program,
+            asset: self.cartridge,
+            collection: Some(self.game),
+            payer: self.payer,
+            authority: Some(self.game),
+            buffer: None,
+            system_program: self.system_program,
+            log_wrapper: None,
+            __args: WriteExternalPluginAdapterDataV1InstructionArgs {
+                key: ExternalPluginAdapterKey::LinkedAppData(PluginAuthority::UpdateAuthority),
+                data: Some(vec![]),
+            },
+        }
+        .invoke_signed(&[&[
+            GAME_PREFIX,
+            collection.name.as_bytes(),
+            &[collection_nonce],
+            &[collection_bump],
+        ]])?;
+
+        // Move the insertion from the machine's current slot to its history.
+        machine_data.remove(Clock::get()?.unix_timestamp);
+        WriteExternalPluginAdapterDataV1Cpi {
+            __program: self.mpl_core_program,
+            asset: self.machine,
+            collection: Some(self.machine_collection),
+            payer: self.payer,
+            authority: Some(self.machine),
+            buffer: None,
+            system_program: self.system_program,
+            log_wrapper: None,
+            __args: WriteExternalPluginAdapterDataV1InstructionArgs {
+                key: ExternalPluginAdapterKey::AppData(PluginAuthority::Address {
+                    address: *self.machine.key,
+                }),
+                data: Some(borsh::to_vec(&machine_data)?),
+            },
+        }
+        .invoke_signed(&[&[
+            MACHINE_PREFIX,
+            self.machine_collection.key.as_ref(),
+            machine_name.as_bytes(),
+            &[machine_bump],
+        ]])
+    }
+}
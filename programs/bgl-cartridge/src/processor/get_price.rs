@@ -0,0 +1,25 @@
+use mpl_core::accounts::BaseCollectionV1;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program::set_return_data,
+    sysvar::Sysvar,
+};
+
+use crate::{instruction::accounts::GetPriceV1Accounts, state::GameCollectionData};
+
+pub fn get_price<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = GetPriceV1Accounts::context(accounts)?;
+
+    // Game
+    // SAFE: Read-only, a fake game only fools the caller.
+    let collection = BaseCollectionV1::from_bytes(ctx.accounts.game.try_borrow_data()?.as_ref())?;
+    let game_data = GameCollectionData::fetch(ctx.accounts.game)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Return the price of the next cartridge as a little-endian u64.
+    let price = game_data.current_price(Clock::get()?.unix_timestamp, collection.num_minted)?;
+    set_return_data(&price.to_le_bytes());
+
+    Ok(())
+}
@@ -1,7 +1,6 @@
 use bytemuck::{from_bytes, Pod, Zeroable};
 use mpl_core::{
     accounts::{BaseAssetV1, BaseCollectionV1},
-    fetch_external_plugin_adapter_data_info,
     instructions::{
         WriteExternalPluginAdapterDataV1Cpi, WriteExternalPluginAdapterDataV1InstructionArgs,
     },
@@ -10,14 +9,17 @@ use mpl_core::{
 use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys};
 use shank::ShankType;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
-    system_program,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, system_program, sysvar::Sysvar,
 };
 
 use crate::{
     error::BglCartridgeError,
-    instruction::accounts::InsertCartridgeV1Accounts,
-    state::{GAME_PREFIX, MACHINE_PREFIX},
+    instruction::accounts::{InsertCartridgeV1Accounts, InsertCartridgeV2Accounts},
+    state::{
+        fetch_cartridge_link, CartridgeData, GameCollectionData, MachineData, GAME_PREFIX,
+        MACHINE_PREFIX,
+    },
 };
 
 #[repr(C)]
@@ -32,8 +34,39 @@ pub struct InsertCartridgeV1Args {
     collection_bump: u8,
 }
 
-impl InsertCartridgeV1Accounts<'_> {
-    pub fn check(&self) -> Result<(u8, String), ProgramError> {
+/// Builds the `CartridgeInserter` of an insert instruction.
+macro_rules! cartridge_inserter {
+    ($accounts:expr, operator: $operator:expr) => {
+        CartridgeInserter {
+            cartridge: $accounts.cartridge,
+            game: $accounts.game,
+            cartridge_owner: $accounts.cartridge_owner,
+            machine: $accounts.machine,
+            machine_collection: $accounts.machine_collection,
+            mpl_core_program: $accounts.mpl_core_program,
+            system_program: $accounts.system_program,
+            operator: $operator,
+        }
+    };
+}
+
+/// The accounts every insert instruction uses to link the cartridge and the
+/// machine.
+struct CartridgeInserter<'a> {
+    cartridge: &'a AccountInfo<'a>,
+    game: &'a AccountInfo<'a>,
+    cartridge_owner: &'a AccountInfo<'a>,
+    machine: &'a AccountInfo<'a>,
+    machine_collection: &'a AccountInfo<'a>,
+    mpl_core_program: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    /// Only set when the machine operator signs in place of the cartridge
+    /// owner.
+    operator: Option<&'a AccountInfo<'a>>,
+}
+
+impl CartridgeInserter<'_> {
+    fn check(&self) -> Result<(u8, String, MachineData), ProgramError> {
         // Cartridge
         // The cartridge owner must sign AND actually own the asset; with the
         // permanent freeze delegate gating transfers, no mpl-core CPI in this
@@ -44,38 +77,53 @@ impl InsertCartridgeV1Accounts<'_> {
         }
 
         // Game Collection
-        // SAFE: Checked by Core
+        // Membership checked by Core, but the game decides which machines it
+        // can be played on. DLCs are only played through their base game.
+        let game_data = GameCollectionData::fetch(self.game)?;
+        if game_data.is_dlc() {
+            return Err(BglCartridgeError::DlcNotPlayable.into());
+        }
+        if !game_data.is_compatible(self.machine_collection.key) {
+            return Err(BglCartridgeError::IncompatibleMachine.into());
+        }
 
         // Cartridge Owner
-        assert_signer(self.cartridge_owner)
-            .map_err(|_| BglCartridgeError::CartridgeOwnerMustSign)?;
+        // The cartridge owner must sign unless the machine operator signs in
+        // their place.
+        if self.operator.is_none() {
+            assert_signer(self.cartridge_owner)
+                .map_err(|_| BglCartridgeError::CartridgeOwnerMustSign)?;
+        }
 
         // Machine
-        let machine_name = BaseAssetV1::from_bytes(self.machine.try_borrow_data()?.as_ref())?.name;
+        let machine_asset = BaseAssetV1::from_bytes(self.machine.try_borrow_data()?.as_ref())?;
         let bump = assert_derivation(
             &crate::ID,
             self.machine,
             &[
                 MACHINE_PREFIX,
                 self.machine_collection.key.as_ref(),
-                machine_name.as_bytes(),
+                machine_asset.name.as_bytes(),
             ],
             BglCartridgeError::InvalidMachinePdaDerivation,
         )?;
 
         // Check if a cartridge is already inserted into the machine.
-        let (_, length) = fetch_external_plugin_adapter_data_info::<BaseAssetV1>(
-            self.machine,
-            None,
-            &ExternalPluginAdapterKey::AppData(PluginAuthority::Address {
-                address: *self.machine.key,
-            }),
-        )?;
-
-        if length > 0 {
+        let machine_data = MachineData::fetch(self.machine)?;
+        if !machine_data.is_empty() {
             return Err(BglCartridgeError::CartridgeAlreadyInserted.into());
         }
 
+        // Check if the cartridge is already inserted into a machine.
+        if fetch_cartridge_link(self.cartridge)?.is_some() {
+            return Err(BglCartridgeError::CartridgeAlreadyLinked.into());
+        }
+
+        // Rental cartridges stop working once they expire.
+        if CartridgeData::fetch(self.cartridge)?.is_expired(Clock::get()?.unix_timestamp) {
+            return Err(BglCartridgeError::CartridgeExpired.into());
+        }
+
         // Machine Collection
         // SAFE: Checked by Core
 
@@ -92,15 +140,43 @@ impl InsertCartridgeV1Accounts<'_> {
             return Err(BglCartridgeError::InvalidSystemProgram.into());
         }
 
-        Ok((bump, machine_name))
+        // Operator
+        // The operator can only insert cartridges the machine owner has
+        // custody of.
+        if let Some(operator) = self.operator {
+            assert_signer(operator).map_err(|_| BglCartridgeError::InvalidMachineOperator)?;
+            if !machine_data.is_operator(operator.key) {
+                return Err(BglCartridgeError::InvalidMachineOperator.into());
+            }
+            if cartridge_asset.owner != machine_asset.owner {
+                return Err(BglCartridgeError::CartridgeNotInMachineOwnerCustody.into());
+            }
+        }
+
+        Ok((bump, machine_asset.name, machine_data))
     }
 }
 
 pub fn insert_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
     let ctx = InsertCartridgeV1Accounts::context(accounts)?;
 
+    // The cartridge owner signs V1 inserts.
+    insert(&cartridge_inserter!(ctx.accounts, operator: None), args)
+}
+
+pub fn insert_cartridge_v2<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = InsertCartridgeV2Accounts::context(accounts)?;
+
+    insert(
+        &cartridge_inserter!(ctx.accounts, operator: ctx.accounts.operator),
+        args,
+    )
+}
+
+/// Inserts the cartridge into the machine.
+fn insert(inserter: &CartridgeInserter, args: &[u8]) -> ProgramResult {
     // All account guards and validations happen here.
-    let (machine_bump, machine_name) = ctx.accounts.check()?;
+    let (machine_bump, machine_name, mut machine_data) = inserter.check()?;
 
     let args: &InsertCartridgeV1Args = from_bytes(args);
 
@@ -112,21 +188,24 @@ pub fn insert_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> Pro
     // 2. Add the cartridge to the Machine's AppData
     // Freeze state is managed independently via the PermanentFreezeDelegate
     // by the game operator.
+    // Whoever signed (the cartridge owner or the machine operator) pays for
+    // any storage growth.
+    let signer = inserter.operator.unwrap_or(inserter.cartridge_owner);
 
     // Add the machine to the Cartridge's AppData
-    let collection = BaseCollectionV1::from_bytes(ctx.accounts.game.try_borrow_data()?.as_ref())?;
+    let collection = BaseCollectionV1::from_bytes(inserter.game.try_borrow_data()?.as_ref())?;
     WriteExternalPluginAdapterDataV1Cpi {
-        __program: ctx.accounts.mpl_core_program,
-        asset: ctx.accounts.cartridge,
-        collection: Some(ctx.accounts.game),
-        payer: ctx.accounts.cartridge_owner,
-        authority: Some(ctx.accounts.game),
+        __program: inserter.mpl_core_program,
+        asset: inserter.cartridge,
+        collection: Some(inserter.game),
+        payer: signer,
+        authority: Some(inserter.game),
         buffer: None,
-        system_program: ctx.accounts.system_program,
+        system_program: inserter.system_program,
         log_wrapper: None,
         __args: WriteExternalPluginAdapterDataV1InstructionArgs {
             key: ExternalPluginAdapterKey::LinkedAppData(PluginAuthority::UpdateAuthority),
-            data: Some(ctx.accounts.cartridge.key.to_bytes().into()),
+            data: Some(inserter.machine.key.to_bytes().into()),
         },
     }
     .invoke_signed(&[&[
@@ -137,25 +216,31 @@ pub fn insert_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> Pro
     ]])?;
 
     // Add the cartridge to the Machine's AppData
+    machine_data.insert(
+        *inserter.cartridge.key,
+        *inserter.game.key,
+        *signer.key,
+        Clock::get()?.unix_timestamp,
+    );
     WriteExternalPluginAdapterDataV1Cpi {
-        __program: ctx.accounts.mpl_core_program,
-        asset: ctx.accounts.machine,
-        collection: Some(ctx.accounts.machine_collection),
-        payer: ctx.accounts.machine_owner,
-        authority: Some(ctx.accounts.machine),
+        __program: inserter.mpl_core_program,
+        asset: inserter.machine,
+        collection: Some(inserter.machine_collection),
+        payer: signer,
+        authority: Some(inserter.machine),
         buffer: None,
-        system_program: ctx.accounts.system_program,
+        system_program: inserter.system_program,
         log_wrapper: None,
         __args: WriteExternalPluginAdapterDataV1InstructionArgs {
             key: ExternalPluginAdapterKey::AppData(PluginAuthority::Address {
-                address: *ctx.accounts.machine.key,
+                address: *inserter.machine.key,
             }),
-            data: Some(ctx.accounts.cartridge.key.to_bytes().into()),
+            data: Some(borsh::to_vec(&machine_data)?),
         },
     }
     .invoke_signed(&[&[
         MACHINE_PREFIX,
-        ctx.accounts.machine_collection.key.as_ref(),
+        inserter.machine_collection.key.as_ref(),
         machine_name.as_bytes(),
         &[machine_bump],
     ]])?;
@@ -0,0 +1,216 @@
+use bytemuck::{from_bytes, Pod, Zeroable};
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    instructions::{
+        WriteExternalPluginAdapterDataV1Cpi, WriteExternalPluginAdapterDataV1InstructionArgs,
+    },
+    types::{ExternalPluginAdapterKey, PluginAuthority},
+};
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::InsertPassV1Accounts,
+    state::{
+        assert_game_derivation, assert_pass_collection_derivation, assert_pass_of_collection,
+        fetch_cartridge_link, GameCollectionData, MachineData, PassCollectionData, PassData,
+        MACHINE_PREFIX, PASS_PREFIX,
+    },
+};
+
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub struct InsertPassV1Args {
+    #[skip]
+    /// The discriminator for the instruction
+    discriminator: u8,
+    /// The nonce for the game collection
+    collection_nonce: u8,
+    /// The bump for the game collection
+    collection_bump: u8,
+}
+
+impl InsertPassV1Accounts<'_> {
+    /// Returns the pass collection bump, the machine bump, name and data.
+    pub fn check(
+        &self,
+        args: &InsertPassV1Args,
+    ) -> Result<(u8, u8, String, MachineData), ProgramError> {
+        let Self {
+            pass,
+            pass_collection,
+            game,
+            pass_owner,
+            machine,
+            machine_collection,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Pass
+        // Must be a pass of the collection held by the signer that has not
+        // expired yet.
+        let pass_asset = assert_pass_of_collection(pass, pass_collection.key)?;
+        if !PassData::fetch(pass)?.is_active(Clock::get()?.unix_timestamp) {
+            return Err(BglCartridgeError::PassExpired.into());
+        }
+
+        if fetch_cartridge_link(pass)?.is_some() {
+            return Err(BglCartridgeError::CartridgeAlreadyLinked.into());
+        }
+
+        // Pass Collection
+        let pass_collection_bump = assert_pass_collection_derivation(pass_collection)?;
+
+        // Game
+        // Must be a game of this program that opted into the pass and runs on
+        // the machine.
+        assert_game_derivation(game, args.collection_nonce, args.collection_bump)?;
+        let game_data = GameCollectionData::fetch(game)?;
+        if !game_data.accepts_pass(pass_collection.key) {
+            return Err(BglCartridgeError::GameNotInPass.into());
+        }
+
+        if !game_data.is_compatible(machine_collection.key) {
+            return Err(BglCartridgeError::IncompatibleMachine.into());
+        }
+
+        // Pass Owner
+        assert_signer(pass_owner).map_err(|_| BglCartridgeError::CartridgeOwnerMustSign)?;
+        if pass_asset.owner != *pass_owner.key {
+            return Err(BglCartridgeError::CartridgeOwnerMustSign.into());
+        }
+
+        // Machine
+        let machine_asset = BaseAssetV1::from_bytes(machine.try_borrow_data()?.as_ref())?;
+        let machine_bump = assert_derivation(
+            &crate::ID,
+            machine,
+            &[
+                MACHINE_PREFIX,
+                machine_collection.key.as_ref(),
+                machine_asset.name.as_bytes(),
+            ],
+            BglCartridgeError::InvalidMachinePdaDerivation,
+        )?;
+
+        let machine_data = MachineData::fetch(machine)?;
+        if !machine_data.is_empty() {
+            return Err(BglCartridgeError::CartridgeAlreadyInserted.into());
+        }
+
+        // Machine Collection
+        // SAFE: Checked by Core
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok((
+            pass_collection_bump,
+            machine_bump,
+            machine_asset.name,
+            machine_data,
+        ))
+    }
+}
+
+pub fn insert_pass<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = InsertPassV1Accounts::context(accounts)?;
+
+    let args: &InsertPassV1Args = from_bytes(args);
+
+    // All account guards and validations happen here.
+    let (pass_collection_bump, machine_bump, machine_name, mut machine_data) =
+        ctx.accounts.check(args)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Insert the pass in place of a cartridge, recording the game it is
+    // played as on the machine.
+    write_pass_link(
+        ctx.accounts.pass,
+        ctx.accounts.pass_collection,
+        ctx.accounts.pass_owner,
+        ctx.accounts.mpl_core_program,
+        ctx.accounts.system_program,
+        pass_collection_bump,
+        ctx.accounts.machine.key.to_bytes().into(),
+    )?;
+
+    machine_data.insert(
+        *ctx.accounts.pass.key,
+        *ctx.accounts.game.key,
+        *ctx.accounts.pass_owner.key,
+        Clock::get()?.unix_timestamp,
+    );
+    WriteExternalPluginAdapterDataV1Cpi {
+        __program: ctx.accounts.mpl_core_program,
+        asset: ctx.accounts.machine,
+        collection: Some(ctx.accounts.machine_collection),
+        payer: ctx.accounts.pass_owner,
+        authority: Some(ctx.accounts.machine),
+        buffer: None,
+        system_program: ctx.accounts.system_program,
+        log_wrapper: None,
+        __args: WriteExternalPluginAdapterDataV1InstructionArgs {
+            key: ExternalPluginAdapterKey::AppData(PluginAuthority::Address {
+                address: *ctx.accounts.machine.key,
+            }),
+            data: Some(borsh::to_vec(&machine_data)?),
+        },
+    }
+    .invoke_signed(&[&[
+        MACHINE_PREFIX,
+        ctx.accounts.machine_collection.key.as_ref(),
+        machine_name.as_bytes(),
+        &[machine_bump],
+    ]])
+}
+
+/// Writes the machine link of a pass, signing as the pass collection. An
+/// empty link means the pass is not inserted.
+pub(crate) fn write_pass_link<'a>(
+    pass: &'a AccountInfo<'a>,
+    pass_collection: &'a AccountInfo<'a>,
+    payer: &'a AccountInfo<'a>,
+    mpl_core_program: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    pass_collection_bump: u8,
+    link: Vec<u8>,
+) -> ProgramResult {
+    let collection = BaseCollectionV1::from_bytes(pass_collection.try_borrow_data()?.as_ref())?;
+    let authority = PassCollectionData::fetch(pass_collection)?.authority;
+    WriteExternalPluginAdapterDataV1Cpi {
+        __program: mpl_core_program,
+        asset: pass,
+        collection: Some(pass_collection),
+        payer,
+        authority: Some(pass_collection),
+        buffer: None,
+        system_program,
+        log_wrapper: None,
+        __args: WriteExternalPluginAdapterDataV1InstructionArgs {
+            key: ExternalPluginAdapterKey::LinkedAppData(PluginAuthority::UpdateAuthority),
+            data: Some(link),
+        },
+    }
+    .invoke_signed(&[&[
+        PASS_PREFIX,
+        authority.as_ref(),
+        collection.name.as_bytes(),
+        &[pass_collection_bump],
+    ]])
+}
@@ -0,0 +1,164 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::from_bytes_mut;
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys, create_or_allocate_account_raw};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::LendCartridgeV1Accounts,
+    processor::CartridgeMover,
+    state::{assert_cartridge_of_game, fetch_cartridge_link, CartridgeData, Loan, LOAN_PREFIX},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct LendCartridgeV1Args {
+    /// The nonce for the game collection
+    pub collection_nonce: u8,
+    /// The bump for the game collection
+    pub collection_bump: u8,
+    /// How long the borrower keeps the cartridge, in seconds
+    pub duration: i64,
+}
+
+impl LendCartridgeV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+impl LendCartridgeV1Accounts<'_> {
+    /// Returns the bump of the loan.
+    pub fn check(&self) -> Result<u8, ProgramError> {
+        let Self {
+            cartridge,
+            game,
+            loan,
+            lender,
+            borrower,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Cartridge
+        // Must be a cartridge of the game held by the lender, sitting outside
+        // of any machine and not rented.
+        let asset = assert_cartridge_of_game(cartridge, game.key)?;
+        if fetch_cartridge_link(cartridge)?.is_some() {
+            return Err(BglCartridgeError::CartridgeAlreadyInserted.into());
+        }
+        CartridgeData::fetch(cartridge)?.assert_not_rental()?;
+
+        // Game
+        // SAFE: Checked against the cartridge, and by Core when transferring
+        // as the game PDA.
+
+        // Loan
+        // One loan per cartridge, which must not exist yet.
+        let bump = assert_derivation(
+            &crate::ID,
+            loan,
+            &[LOAN_PREFIX, cartridge.key.as_ref()],
+            BglCartridgeError::InvalidLoan,
+        )?;
+
+        if !loan.data_is_empty() {
+            return Err(BglCartridgeError::CartridgeOnLoan.into());
+        }
+
+        // Lender
+        assert_signer(lender).map_err(|_| BglCartridgeError::CartridgeOwnerMustSign)?;
+        if asset.owner != *lender.key {
+            return Err(BglCartridgeError::CartridgeOwnerMustSign.into());
+        }
+
+        // Borrower
+        if cmp_pubkeys(borrower.key, lender.key) {
+            return Err(BglCartridgeError::InvalidBorrower.into());
+        }
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(bump)
+    }
+}
+
+pub fn lend_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = LendCartridgeV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    let bump = ctx.accounts.check()?;
+
+    let args = LendCartridgeV1Args::unpack(args)?;
+
+    if args.duration <= 0 {
+        return Err(BglCartridgeError::InvalidLoanDuration.into());
+    }
+    let expires_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(args.duration)
+        .ok_or(BglCartridgeError::NumericalOverflow)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Record the loan.
+    create_or_allocate_account_raw(
+        crate::ID,
+        ctx.accounts.loan,
+        ctx.accounts.system_program,
+        ctx.accounts.lender,
+        core::mem::size_of::<Loan>(),
+        &[LOAN_PREFIX, ctx.accounts.cartridge.key.as_ref(), &[bump]],
+    )?;
+
+    let mut loan_data = ctx.accounts.loan.try_borrow_mut_data()?;
+    let loan: &mut Loan = from_bytes_mut(&mut loan_data);
+    *loan = Loan {
+        cartridge: *ctx.accounts.cartridge.key,
+        game: *ctx.accounts.game.key,
+        lender: *ctx.accounts.lender.key,
+        borrower: *ctx.accounts.borrower.key,
+        expires_at,
+    };
+    drop(loan_data);
+
+    // Move the cartridge to the borrower and mark it as lent, so the borrower
+    // can play it but not sell or refund it.
+    let mover = CartridgeMover {
+        game: ctx.accounts.game,
+        payer: ctx.accounts.lender,
+        mpl_core_program: ctx.accounts.mpl_core_program,
+        system_program: ctx.accounts.system_program,
+    };
+    mover.transfer(
+        ctx.accounts.cartridge,
+        ctx.accounts.borrower,
+        args.collection_nonce,
+        args.collection_bump,
+    )?;
+
+    let mut cartridge_data = CartridgeData {
+        lender: Some(*ctx.accounts.lender.key),
+        ..CartridgeData::fetch(ctx.accounts.cartridge)?
+    };
+    mover.write_data(
+        ctx.accounts.cartridge,
+        args.collection_nonce,
+        args.collection_bump,
+        &mut cartridge_data,
+    )
+}
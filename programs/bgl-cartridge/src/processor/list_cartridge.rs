@@ -0,0 +1,124 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::from_bytes_mut;
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys, create_or_allocate_account_raw};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    system_program,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::ListCartridgeV1Accounts,
+    state::{
+        assert_cartridge_of_game, fetch_cartridge_link, CartridgeData, Listing, LISTING_PREFIX,
+    },
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct ListCartridgeV1Args {
+    /// The ask price in the payment token
+    pub price: u64,
+}
+
+impl ListCartridgeV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+impl ListCartridgeV1Accounts<'_> {
+    /// Returns the bump of the listing.
+    pub fn check(&self) -> Result<u8, ProgramError> {
+        let Self {
+            cartridge,
+            game,
+            listing,
+            seller,
+            system_program,
+        } = self;
+
+        // Cartridge
+        // Must be a cartridge of the game held by the seller, sitting outside
+        // of any machine, neither borrowed nor rented.
+        let asset = assert_cartridge_of_game(cartridge, game.key)?;
+        if fetch_cartridge_link(cartridge)?.is_some() {
+            return Err(BglCartridgeError::CartridgeAlreadyInserted.into());
+        }
+        let cartridge_data = CartridgeData::fetch(cartridge)?;
+        if cartridge_data.lender.is_some() {
+            return Err(BglCartridgeError::CartridgeOnLoan.into());
+        }
+        cartridge_data.assert_not_rental()?;
+
+        // Game
+        // SAFE: Checked against the cartridge.
+
+        // Listing
+        // One listing per cartridge, which must not exist yet.
+        let bump = assert_derivation(
+            &crate::ID,
+            listing,
+            &[LISTING_PREFIX, cartridge.key.as_ref()],
+            BglCartridgeError::InvalidListing,
+        )?;
+
+        if !listing.data_is_empty() {
+            return Err(BglCartridgeError::InvalidListing.into());
+        }
+
+        // Seller
+        assert_signer(seller).map_err(|_| BglCartridgeError::CartridgeOwnerMustSign)?;
+        if asset.owner != *seller.key {
+            return Err(BglCartridgeError::CartridgeOwnerMustSign.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(bump)
+    }
+}
+
+pub fn list_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = ListCartridgeV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    let bump = ctx.accounts.check()?;
+
+    let args = ListCartridgeV1Args::unpack(args)?;
+
+    // A free listing would let cartridges change hands without royalties.
+    if args.price == 0 {
+        return Err(BglCartridgeError::InvalidListingPrice.into());
+    }
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Record the ask. The cartridge stays frozen in the seller's wallet until
+    // it is bought.
+    create_or_allocate_account_raw(
+        crate::ID,
+        ctx.accounts.listing,
+        ctx.accounts.system_program,
+        ctx.accounts.seller,
+        core::mem::size_of::<Listing>(),
+        &[LISTING_PREFIX, ctx.accounts.cartridge.key.as_ref(), &[bump]],
+    )?;
+
+    let mut listing_data = ctx.accounts.listing.try_borrow_mut_data()?;
+    let listing: &mut Listing = from_bytes_mut(&mut listing_data);
+    *listing = Listing {
+        cartridge: *ctx.accounts.cartridge.key,
+        game: *ctx.accounts.game.key,
+        seller: *ctx.accounts.seller.key,
+        price: args.price,
+    };
+
+    Ok(())
+}
@@ -1,16 +1,104 @@
+pub mod add_catalog_entry;
+pub mod airdrop_cartridges;
+pub mod buy_cartridge;
+pub mod cancel_listing;
+pub mod claim_escrow;
+pub mod claim_name;
+pub mod close_bundle;
 pub mod commission_machine;
+pub mod create_bundle;
+pub mod create_pass;
+pub mod create_publisher_team;
+pub mod eject_cartridge;
+pub mod get_price;
 pub mod insert_cartridge;
+pub mod insert_pass;
+pub mod lend_cartridge;
+pub mod list_cartridge;
+pub mod pre_order;
+pub mod print_bundle;
+pub mod print_dlc;
 pub mod print_game_cartridge;
+pub mod print_game_cartridge_allowlist;
+pub mod print_game_cartridge_voucher;
+pub mod print_rental_cartridge;
+pub mod purchase_pass;
+pub mod reassign_name;
+pub mod reclaim_expired_cartridge;
+pub mod redeem_pre_order;
+pub mod refund_cartridge;
+pub mod refund_pre_order;
+pub mod register_dlc;
 pub mod release_game;
 pub mod remove_cartridge;
+pub mod remove_pass;
+pub mod renew_pass;
+pub mod reserve_name;
+pub mod restore_catalog_entry;
+pub mod retire_catalog_entry;
+pub mod return_cartridge;
 pub mod set_cartridge_source;
+pub mod set_machine_operator;
+pub mod set_session_lock;
+pub mod set_verification;
+pub mod transfer_name;
+pub mod update_bundle;
+pub mod update_game;
+pub mod update_publisher_team;
+pub mod withdraw_game_revenue;
+pub mod withdraw_pass_revenue;
+pub mod write_save_data;
 
+pub use add_catalog_entry::*;
+pub use airdrop_cartridges::*;
+pub use buy_cartridge::*;
+pub use cancel_listing::*;
+pub use claim_escrow::*;
+pub use claim_name::*;
+pub use close_bundle::*;
 pub use commission_machine::*;
+pub use create_bundle::*;
+pub use create_pass::*;
+pub use create_publisher_team::*;
+pub use eject_cartridge::*;
+pub use get_price::*;
 pub use insert_cartridge::*;
+pub use insert_pass::*;
+pub use lend_cartridge::*;
+pub use list_cartridge::*;
+pub use pre_order::*;
+pub use print_bundle::*;
+pub use print_dlc::*;
 pub use print_game_cartridge::*;
+pub use print_game_cartridge_allowlist::*;
+pub use print_game_cartridge_voucher::*;
+pub use print_rental_cartridge::*;
+pub use purchase_pass::*;
+pub use reassign_name::*;
+pub use reclaim_expired_cartridge::*;
+pub use redeem_pre_order::*;
+pub use refund_cartridge::*;
+pub use refund_pre_order::*;
+pub use register_dlc::*;
 pub use release_game::*;
 pub use remove_cartridge::*;
+pub use remove_pass::*;
+pub use renew_pass::*;
+pub use reserve_name::*;
+pub use restore_catalog_entry::*;
+pub use retire_catalog_entry::*;
+pub use return_cartridge::*;
 pub use set_cartridge_source::*;
+pub use set_machine_operator::*;
+pub use set_session_lock::*;
+pub use set_verification::*;
+pub use transfer_name::*;
+pub use update_bundle::*;
+pub use update_game::*;
+pub use update_publisher_team::*;
+pub use withdraw_game_revenue::*;
+pub use withdraw_pass_revenue::*;
+pub use write_save_data::*;
 
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
@@ -51,5 +139,197 @@ pub fn process_instruction<'a>(
             msg!("Instruction: Set Cartridge Source");
             set_cartridge_source(accounts, instruction_data)
         }
+        BglCartridgeInstructionDiscriminants::SetMachineOperatorV1 => {
+            msg!("Instruction: Set Machine Operator");
+            set_machine_operator(accounts)
+        }
+        BglCartridgeInstructionDiscriminants::EjectCartridgeV1 => {
+            msg!("Instruction: Eject Cartridge");
+            eject_cartridge(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::UpdateGameV1 => {
+            msg!("Instruction: Update Game");
+            update_game(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::SetSessionLockV1 => {
+            msg!("Instruction: Set Session Lock");
+            set_session_lock(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::GetPriceV1 => {
+            msg!("Instruction: Get Price");
+            get_price(accounts)
+        }
+        BglCartridgeInstructionDiscriminants::PrintGameCartridgeAllowlistV1 => {
+            msg!("Instruction: Print Game Cartridge Allowlist");
+            print_game_cartridge_allowlist(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::PrintGameCartridgeVoucherV1 => {
+            msg!("Instruction: Print Game Cartridge Voucher");
+            print_game_cartridge_voucher(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::AirdropCartridgesV1 => {
+            msg!("Instruction: Airdrop Cartridges");
+            airdrop_cartridges(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::CreateBundleV1 => {
+            msg!("Instruction: Create Bundle");
+            create_bundle(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::PrintBundleV1 => {
+            msg!("Instruction: Print Bundle");
+            print_bundle(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::RegisterDlcV1 => {
+            msg!("Instruction: Register DLC");
+            register_dlc(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::PrintDlcV1 => {
+            msg!("Instruction: Print DLC");
+            print_dlc(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::WriteSaveDataV1 => {
+            msg!("Instruction: Write Save Data");
+            write_save_data(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::RefundCartridgeV1 => {
+            msg!("Instruction: Refund Cartridge");
+            refund_cartridge(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::ListCartridgeV1 => {
+            msg!("Instruction: List Cartridge");
+            list_cartridge(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::BuyCartridgeV1 => {
+            msg!("Instruction: Buy Cartridge");
+            buy_cartridge(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::CancelListingV1 => {
+            msg!("Instruction: Cancel Listing");
+            cancel_listing(accounts)
+        }
+        BglCartridgeInstructionDiscriminants::LendCartridgeV1 => {
+            msg!("Instruction: Lend Cartridge");
+            lend_cartridge(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::ReturnCartridgeV1 => {
+            msg!("Instruction: Return Cartridge");
+            return_cartridge(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::PrintRentalCartridgeV1 => {
+            msg!("Instruction: Print Rental Cartridge");
+            print_rental_cartridge(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::ReclaimExpiredCartridgeV1 => {
+            msg!("Instruction: Reclaim Expired Cartridge");
+            reclaim_expired_cartridge(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::CreatePassV1 => {
+            msg!("Instruction: Create Pass");
+            create_pass(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::PurchasePassV1 => {
+            msg!("Instruction: Purchase Pass");
+            purchase_pass(accounts)
+        }
+        BglCartridgeInstructionDiscriminants::RenewPassV1 => {
+            msg!("Instruction: Renew Pass");
+            renew_pass(accounts)
+        }
+        BglCartridgeInstructionDiscriminants::InsertPassV1 => {
+            msg!("Instruction: Insert Pass");
+            insert_pass(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::RemovePassV1 => {
+            msg!("Instruction: Remove Pass");
+            remove_pass(accounts)
+        }
+        BglCartridgeInstructionDiscriminants::PreOrderV1 => {
+            msg!("Instruction: Pre-Order");
+            pre_order(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::ClaimEscrowV1 => {
+            msg!("Instruction: Claim Escrow");
+            claim_escrow(accounts)
+        }
+        BglCartridgeInstructionDiscriminants::RedeemPreOrderV1 => {
+            msg!("Instruction: Redeem Pre-Order");
+            redeem_pre_order(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::RefundPreOrderV1 => {
+            msg!("Instruction: Refund Pre-Order");
+            refund_pre_order(accounts)
+        }
+        BglCartridgeInstructionDiscriminants::CreatePublisherTeamV1 => {
+            msg!("Instruction: Create Publisher Team");
+            create_publisher_team(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::UpdatePublisherTeamV1 => {
+            msg!("Instruction: Update Publisher Team");
+            update_publisher_team(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::WithdrawGameRevenueV1 => {
+            msg!("Instruction: Withdraw Game Revenue");
+            withdraw_game_revenue(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::SetVerificationV1 => {
+            msg!("Instruction: Set Verification");
+            set_verification(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::ReserveNameV1 => {
+            msg!("Instruction: Reserve Name");
+            reserve_name(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::TransferNameV1 => {
+            msg!("Instruction: Transfer Name");
+            transfer_name(accounts)
+        }
+        BglCartridgeInstructionDiscriminants::RetireCatalogEntryV1 => {
+            msg!("Instruction: Retire Catalog Entry");
+            retire_catalog_entry(accounts)
+        }
+        BglCartridgeInstructionDiscriminants::PrintGameCartridgeV2 => {
+            msg!("Instruction: Print Game Cartridge V2");
+            print_game_cartridge_v2(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::UpdateBundleV1 => {
+            msg!("Instruction: Update Bundle");
+            update_bundle(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::CloseBundleV1 => {
+            msg!("Instruction: Close Bundle");
+            close_bundle(accounts)
+        }
+        BglCartridgeInstructionDiscriminants::WithdrawPassRevenueV1 => {
+            msg!("Instruction: Withdraw Pass Revenue");
+            withdraw_pass_revenue(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::ReleaseGameV2 => {
+            msg!("Instruction: Release Game V2");
+            release_game_v2(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::ClaimNameV1 => {
+            msg!("Instruction: Claim Name");
+            claim_name(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::ReassignNameV1 => {
+            msg!("Instruction: Reassign Name");
+            reassign_name(accounts)
+        }
+        BglCartridgeInstructionDiscriminants::AddCatalogEntryV1 => {
+            msg!("Instruction: Add Catalog Entry");
+            add_catalog_entry(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::RestoreCatalogEntryV1 => {
+            msg!("Instruction: Restore Catalog Entry");
+            restore_catalog_entry(accounts)
+        }
+        BglCartridgeInstructionDiscriminants::InsertCartridgeV2 => {
+            msg!("Instruction: Insert Cartridge V2");
+            insert_cartridge_v2(accounts, instruction_data)
+        }
+        BglCartridgeInstructionDiscriminants::RemoveCartridgeV2 => {
+            msg!("Instruction: Remove Cartridge V2");
+            remove_cartridge_v2(accounts, instruction_data)
+        }
     }
 }
@@ -0,0 +1,182 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{from_bytes, from_bytes_mut};
+use mpl_core::accounts::BaseCollectionV1;
+use mpl_utils::{
+    assert_derivation, assert_owned_by, assert_signer, cmp_pubkeys, create_or_allocate_account_raw,
+};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program::invoke,
+    program_error::ProgramError, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::PreOrderV1Accounts,
+    processor::{assert_game_token_account, assert_payer_token_account},
+    state::{assert_escrow, Escrow, GameCollectionData, PreOrder, PRE_ORDER_PREFIX},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct PreOrderV1Args {
+    /// How many cartridges to pre-order
+    pub quantity: u32,
+}
+
+impl PreOrderV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+impl PreOrderV1Accounts<'_> {
+    /// Returns the bump of the pre-order.
+    pub fn check(&self) -> Result<u8, ProgramError> {
+        let Self {
+            game,
+            escrow,
+            escrow_token_account,
+            pre_order,
+            backer,
+            backer_token_account,
+            token_program,
+            system_program,
+        } = self;
+
+        // Game
+        // SAFE: The escrow is derived from it and only created by release_game.
+
+        // Escrow
+        assert_escrow(escrow, game.key)?;
+
+        // Escrow Token Account
+        assert_game_token_account(escrow, escrow_token_account)?;
+
+        // Pre-Order
+        // One pre-order per backer, topped up by later pre-orders.
+        let bump = assert_derivation(
+            &crate::ID,
+            pre_order,
+            &[PRE_ORDER_PREFIX, game.key.as_ref(), backer.key.as_ref()],
+            BglCartridgeError::InvalidPreOrder,
+        )?;
+
+        if !pre_order.data_is_empty() {
+            assert_owned_by(pre_order, &crate::ID, BglCartridgeError::InvalidPreOrder)?;
+        }
+
+        // Backer
+        assert_signer(backer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // Backer Token Account
+        assert_payer_token_account(backer, backer_token_account)?;
+
+        // Token Program
+        if !cmp_pubkeys(token_program.key, &spl_token::ID) {
+            return Err(BglCartridgeError::InvalidTokenProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(bump)
+    }
+}
+
+pub fn pre_order<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = PreOrderV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    let bump = ctx.accounts.check()?;
+
+    let args = PreOrderV1Args::unpack(args)?;
+
+    if args.quantity == 0 {
+        return Err(BglCartridgeError::InvalidPreOrderQuantity.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let escrow = *from_bytes::<Escrow>(&ctx.accounts.escrow.try_borrow_data()?);
+    if now >= escrow.deadline {
+        return Err(BglCartridgeError::CrowdfundClosed.into());
+    }
+
+    // Pre-orders are paid at the price of the game when they are placed.
+    let game_data = GameCollectionData::fetch(ctx.accounts.game)?;
+    let collection = BaseCollectionV1::from_bytes(ctx.accounts.game.try_borrow_data()?.as_ref())?;
+    let amount = game_data
+        .current_price(now, collection.num_minted)?
+        .checked_mul(args.quantity as u64)
+        .ok_or(BglCartridgeError::NumericalOverflow)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    invoke(
+        &spl_token::instruction::transfer(
+            ctx.accounts.token_program.key,
+            ctx.accounts.backer_token_account.key,
+            ctx.accounts.escrow_token_account.key,
+            ctx.accounts.backer.key,
+            &[],
+            amount,
+        )?,
+        &[
+            ctx.accounts.backer_token_account.clone(),
+            ctx.accounts.escrow_token_account.clone(),
+            ctx.accounts.backer.clone(),
+        ],
+    )?;
+
+    let mut escrow_data = ctx.accounts.escrow.try_borrow_mut_data()?;
+    let escrow: &mut Escrow = from_bytes_mut(&mut escrow_data);
+    escrow.raised = escrow
+        .raised
+        .checked_add(amount)
+        .ok_or(BglCartridgeError::NumericalOverflow)?;
+    drop(escrow_data);
+
+    // Record the position of the backer.
+    if ctx.accounts.pre_order.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::ID,
+            ctx.accounts.pre_order,
+            ctx.accounts.system_program,
+            ctx.accounts.backer,
+            core::mem::size_of::<PreOrder>(),
+            &[
+                PRE_ORDER_PREFIX,
+                ctx.accounts.game.key.as_ref(),
+                ctx.accounts.backer.key.as_ref(),
+                &[bump],
+            ],
+        )?;
+
+        let mut pre_order_data = ctx.accounts.pre_order.try_borrow_mut_data()?;
+        let pre_order: &mut PreOrder = from_bytes_mut(&mut pre_order_data);
+        *pre_order = PreOrder {
+            game: *ctx.accounts.game.key,
+            backer: *ctx.accounts.backer.key,
+            amount: 0,
+            quantity: 0,
+            redeemed: 0,
+        };
+    }
+
+    let mut pre_order_data = ctx.accounts.pre_order.try_borrow_mut_data()?;
+    let pre_order: &mut PreOrder = from_bytes_mut(&mut pre_order_data);
+    pre_order.amount = pre_order
+        .amount
+        .checked_add(amount)
+        .ok_or(BglCartridgeError::NumericalOverflow)?;
+    pre_order.quantity = pre_order
+        .quantity
+        .checked_add(args.quantity)
+        .ok_or(BglCartridgeError::NumericalOverflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::from_bytes;
+use mpl_utils::{assert_owned_by, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::PrintBundleV1Accounts,
+    processor::CartridgePrinter,
+    state::{Bundle, CartridgeData, PriceType, Source},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct PrintBundleV1Args {
+    /// The most the payer accepts to pay for the bundle
+    pub max_price: u64,
+}
+
+impl PrintBundleV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+impl<'a> PrintBundleV1Accounts<'a> {
+    pub fn check(&self) -> ProgramResult {
+        // Bundle
+        assert_owned_by(self.bundle, &crate::ID, BglCartridgeError::InvalidBundle)?;
+        if self.bundle.data_len() != core::mem::size_of::<Bundle>() {
+            return Err(BglCartridgeError::InvalidBundle.into());
+        }
+
+        // Owner, Payer, Token Accounts and Programs
+        // Checked by the printer of every member.
+
+        Ok(())
+    }
+
+    /// The printer of a member game, from its (game, game token account,
+    /// cartridge, mint counter) accounts. The program id stands in for the
+    /// mint counter of games without a per-wallet limit.
+    fn printer(&self, member_accounts: &'a [AccountInfo<'a>]) -> CartridgePrinter<'a> {
+        let mint_counter = &member_accounts[3];
+        CartridgePrinter {
+            cartridge: &member_accounts[2],
+            game: &member_accounts[0],
+            game_token_account: &member_accounts[1],
+            owner: self.owner,
+            payer: self.payer,
+            payer_token_account: self.payer_token_account,
+            mint_counter: (!cmp_pubkeys(mint_counter.key, &crate::ID)).then_some(mint_counter),
+            referrer_token_account: None,
+            payment_mint: self.payment_mint,
+            mpl_core_program: self.mpl_core_program,
+            token_program: self.token_program,
+            system_program: self.system_program,
+        }
+    }
+}
+
+pub fn print_bundle<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = PrintBundleV1Accounts::context(accounts)?;
+    solana_program::msg!("Printing bundle");
+
+    // All account guards and validations happen here.
+    ctx.accounts.check()?;
+
+    let args = PrintBundleV1Args::unpack(args)?;
+    let bundle = *from_bytes::<Bundle>(&ctx.accounts.bundle.try_borrow_data()?);
+
+    // The price may have moved since the payer quoted it.
+    if bundle.price > args.max_price {
+        return Err(BglCartridgeError::PriceAboveMax.into());
+    }
+
+    // Remaining Accounts
+    // (game, game token account, cartridge, mint counter) accounts in the
+    // order of the bundle members, the cartridges being new signers.
+    if ctx.remaining_accounts.len() != bundle.num_games as usize * 4 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let mut members = Vec::with_capacity(bundle.num_games as usize);
+    for (member, member_accounts) in bundle
+        .members()
+        .iter()
+        .zip(ctx.remaining_accounts.chunks_exact(4))
+    {
+        let printer = ctx.accounts.printer(member_accounts);
+        if !cmp_pubkeys(printer.game.key, &member.game) {
+            return Err(BglCartridgeError::BundleGameMismatch.into());
+        }
+
+        let (game_data, mint_counter_bump, _) = printer.check(now)?;
+        game_data.assert_not_dlc()?;
+        members.push((member, printer, game_data, mint_counter_bump));
+    }
+    solana_program::msg!("Account checks passed");
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Charge the bundle price once, split between the member games, and print
+    // one cartridge of every member game recording its share of the price.
+    // Every share is paid the way its game takes payments.
+    let amounts = bundle.split_price()?;
+    for ((member, printer, game_data, mint_counter_bump), amount) in members.iter().zip(amounts) {
+        printer.count_print(*mint_counter_bump, game_data.max_per_wallet)?;
+        printer.pay(PriceType::from(game_data.price_type), amount)?;
+        printer.mint(
+            member.collection_nonce,
+            member.collection_bump,
+            &CartridgeData {
+                refundable_until: game_data.refund_deadline(now),
+                ..CartridgeData::new(Source::Unknown, amount, now)
+            },
+        )?;
+    }
+
+    Ok(())
+}
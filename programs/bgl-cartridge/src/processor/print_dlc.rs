@@ -0,0 +1,156 @@
+use bytemuck::{from_bytes, from_bytes_mut, Pod, Zeroable};
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    types::UpdateAuthority,
+};
+use mpl_utils::{assert_derivation, assert_owned_by, create_or_allocate_account_raw};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::PrintDlcV1Accounts,
+    processor::{cartridge_printer, CartridgePrinter},
+    state::{CartridgeData, DlcReceipt, GameCollectionData, Source, DLC_PREFIX},
+};
+
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub struct PrintDlcV1Args {
+    #[skip]
+    /// The discriminator for the instruction
+    discriminator: u8,
+    /// The nonce for the DLC collection
+    collection_nonce: u8,
+    /// The bump for the DLC collection
+    collection_bump: u8,
+}
+
+impl<'a> PrintDlcV1Accounts<'a> {
+    pub(crate) fn printer(&self) -> CartridgePrinter<'a> {
+        cartridge_printer!(self)
+    }
+
+    /// Returns the game data, the bump of the mint counter, the referrer of
+    /// the print and the bump of the DLC receipt.
+    pub fn check(
+        &self,
+        now: i64,
+    ) -> Result<(GameCollectionData, Option<u8>, Option<Pubkey>, u8), ProgramError> {
+        // Cartridge, Game, Owner, Payer, Token Accounts, Mint Counter,
+        // Referrer Token Account and Programs
+        let (game_data, mint_counter_bump, referrer) = self.printer().check(now)?;
+
+        // Base Cartridge
+        // Must be a Core asset, its collection is checked against the DLC once
+        // the game data is read.
+        assert_owned_by(
+            self.base_cartridge,
+            &mpl_core::ID,
+            BglCartridgeError::InvalidBaseCartridge,
+        )?;
+
+        // Only cartridges owned outright get DLCs, rentals and borrowed
+        // cartridges go back to another wallet.
+        let base_cartridge_data = CartridgeData::fetch(self.base_cartridge)?;
+        base_cartridge_data.assert_not_rental()?;
+        if base_cartridge_data.lender.is_some() {
+            return Err(BglCartridgeError::CartridgeOnLoan.into());
+        }
+
+        // DLC Receipt
+        // One per DLC and base cartridge, which must not exist yet.
+        let dlc_receipt_bump = assert_derivation(
+            &crate::ID,
+            self.dlc_receipt,
+            &[
+                DLC_PREFIX,
+                self.game.key.as_ref(),
+                self.base_cartridge.key.as_ref(),
+            ],
+            BglCartridgeError::InvalidDlcReceipt,
+        )?;
+
+        if !self.dlc_receipt.data_is_empty() {
+            return Err(BglCartridgeError::DlcAlreadyPrinted.into());
+        }
+
+        Ok((game_data, mint_counter_bump, referrer, dlc_receipt_bump))
+    }
+}
+
+pub fn print_dlc<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = PrintDlcV1Accounts::context(accounts)?;
+    solana_program::msg!("Printing DLC");
+
+    // All account guards and validations happen here.
+    let now = Clock::get()?.unix_timestamp;
+    let (game_data, mint_counter_bump, referrer, dlc_receipt_bump) = ctx.accounts.check(now)?;
+    solana_program::msg!("Account checks passed");
+
+    let args: &PrintDlcV1Args = from_bytes(args);
+
+    if !game_data.is_dlc() {
+        return Err(BglCartridgeError::NotADlc.into());
+    }
+
+    // The owner of the new DLC must hold a cartridge of the base game.
+    let base_cartridge =
+        BaseAssetV1::from_bytes(ctx.accounts.base_cartridge.try_borrow_data()?.as_ref())?;
+    if base_cartridge.owner != *ctx.accounts.owner.key
+        || base_cartridge.update_authority != UpdateAuthority::Collection(game_data.base_game)
+    {
+        return Err(BglCartridgeError::InvalidBaseCartridge.into());
+    }
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    let printer = ctx.accounts.printer();
+
+    let collection = BaseCollectionV1::from_bytes(ctx.accounts.game.try_borrow_data()?.as_ref())?;
+    let price = game_data.current_price(now, collection.num_minted)?;
+
+    printer.count_print(mint_counter_bump, game_data.max_per_wallet)?;
+    let purchase_price = printer.pay_print(&game_data, price)?;
+
+    // Bind the DLC to the base cartridge it was bought for.
+    printer.mint(
+        args.collection_nonce,
+        args.collection_bump,
+        &CartridgeData {
+            base_cartridge: Some(*ctx.accounts.base_cartridge.key),
+            referrer,
+            refundable_until: game_data.refund_deadline(now),
+            ..CartridgeData::new(Source::Unknown, purchase_price, now)
+        },
+    )?;
+
+    // Record the DLC against the base cartridge so it cannot get it again.
+    create_or_allocate_account_raw(
+        crate::ID,
+        ctx.accounts.dlc_receipt,
+        ctx.accounts.system_program,
+        ctx.accounts.payer,
+        core::mem::size_of::<DlcReceipt>(),
+        &[
+            DLC_PREFIX,
+            ctx.accounts.game.key.as_ref(),
+            ctx.accounts.base_cartridge.key.as_ref(),
+            &[dlc_receipt_bump],
+        ],
+    )?;
+
+    let mut receipt_data = ctx.accounts.dlc_receipt.try_borrow_mut_data()?;
+    let receipt: &mut DlcReceipt = from_bytes_mut(&mut receipt_data);
+    *receipt = DlcReceipt {
+        dlc: *ctx.accounts.game.key,
+        base_cartridge: *ctx.accounts.base_cartridge.key,
+        cartridge: *ctx.accounts.cartridge.key,
+    };
+
+    Ok(())
+}
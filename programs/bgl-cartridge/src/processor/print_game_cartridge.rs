@@ -1,56 +1,190 @@
 use borsh::BorshDeserialize;
-use bytemuck::{from_bytes, Pod, Zeroable};
+use bytemuck::from_bytes_mut;
 use mpl_core::{
     accounts::BaseCollectionV1,
-    fetch_external_plugin_adapter_data_info,
     instructions::{
         CreateV2Cpi, CreateV2InstructionArgs, WriteExternalPluginAdapterDataV1Cpi,
         WriteExternalPluginAdapterDataV1InstructionArgs,
     },
     types::{
         AppDataInitInfo, DataState, Edition, ExternalPluginAdapterInitInfo,
-        ExternalPluginAdapterKey, LinkedDataKey, PermanentFreezeDelegate, Plugin, PluginAuthority,
+        ExternalPluginAdapterKey, PermanentFreezeDelegate, Plugin, PluginAuthority,
         PluginAuthorityPair,
     },
 };
-use mpl_utils::{assert_owned_by, assert_signer, cmp_pubkeys};
+use mpl_utils::{
+    assert_derivation, assert_owned_by, assert_signer, cmp_pubkeys, create_or_allocate_account_raw,
+};
 use shank::ShankType;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke, program_pack::Pack,
-    system_program,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program::invoke,
+    program_error::ProgramError, program_pack::Pack, pubkey::Pubkey, system_program,
+    sysvar::Sysvar,
 };
 use spl_token::state::Account as SplTokenAccount;
 
 use crate::{
     error::BglCartridgeError,
-    instruction::accounts::PrintGameCartridgeV1Accounts,
+    instruction::accounts::{PrintGameCartridgeV1Accounts, PrintGameCartridgeV2Accounts},
     state::{
-        CartridgeData, GameCollectionData, PriceType, Source, GAME_PREFIX, PAYMENT_TOKEN_MINT,
+        CartridgeData, GameCollectionData, MintCounter, PriceType, Source, GAME_PREFIX,
+        MINT_COUNT_PREFIX, PAYMENT_TOKEN_MINT,
     },
 };
 
 #[repr(C)]
-#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone, ShankType)]
 pub struct PrintGameCartridgeV1Args {
-    #[skip]
-    /// The discriminator for the instruction
-    discriminator: u8,
     /// The nonce for the collection
     collection_nonce: u8,
     /// The bump for the collection
     collection_bump: u8,
+    /// The most the payer accepts to pay, any price when none
+    max_price: Option<u64>,
 }
 
-impl PrintGameCartridgeV1Accounts<'_> {
-    pub fn check(&self) -> ProgramResult {
+impl PrintGameCartridgeV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        let mut reader = input.get(1..).ok_or(ProgramError::InvalidInstructionData)?;
+
+        let collection_nonce =
+            u8::deserialize(&mut reader).map_err(|_| ProgramError::InvalidInstructionData)?;
+        let collection_bump =
+            u8::deserialize(&mut reader).map_err(|_| ProgramError::InvalidInstructionData)?;
+
+        // Read the price limit, older clients don't send it.
+        let mut max_price = None;
+        if !reader.is_empty() {
+            max_price = Option::<u64>::deserialize(&mut reader)
+                .map_err(|_| ProgramError::InvalidInstructionData)?;
+        }
+
+        Ok(Self {
+            collection_nonce,
+            collection_bump,
+            max_price,
+        })
+    }
+}
+
+/// Checks that the token account receives the payment token for the game.
+pub(crate) fn assert_game_token_account(
+    game: &AccountInfo,
+    game_token_account: &AccountInfo,
+) -> ProgramResult {
+    assert_owned_by(
+        game_token_account,
+        &spl_token::ID,
+        BglCartridgeError::InvalidGameTokenAccountProgramOwner,
+    )?;
+
+    let token_account = SplTokenAccount::unpack(game_token_account.try_borrow_data()?.as_ref())?;
+
+    if token_account.owner != *game.key {
+        return Err(BglCartridgeError::InvalidGameTokenAccountOwner.into());
+    }
+
+    if token_account.mint != PAYMENT_TOKEN_MINT {
+        return Err(BglCartridgeError::InvalidGameTokenAccountMint.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that the token account holds the payment token of the payer.
+pub(crate) fn assert_payer_token_account(
+    payer: &AccountInfo,
+    payer_token_account: &AccountInfo,
+) -> ProgramResult {
+    assert_owned_by(
+        payer_token_account,
+        &spl_token::ID,
+        BglCartridgeError::InvalidPayerTokenAccountProgramOwner,
+    )?;
+
+    let token_account = SplTokenAccount::unpack(payer_token_account.try_borrow_data()?.as_ref())?;
+
+    if token_account.owner != *payer.key {
+        return Err(BglCartridgeError::InvalidPayerTokenAccountOwner.into());
+    }
+
+    if token_account.mint != PAYMENT_TOKEN_MINT {
+        return Err(BglCartridgeError::InvalidPayerTokenAccountMint.into());
+    }
+
+    Ok(())
+}
+
+/// Builds the `CartridgePrinter` of a print instruction whose accounts share
+/// the names of the printer fields.
+macro_rules! cartridge_printer {
+    ($accounts:expr) => {
+        cartridge_printer!(
+            $accounts,
+            mint_counter: $accounts.mint_counter,
+            referrer_token_account: $accounts.referrer_token_account
+        )
+    };
+    (
+        $accounts:expr,
+        mint_counter: $mint_counter:expr,
+        referrer_token_account: $referrer_token_account:expr
+    ) => {
+        $crate::processor::CartridgePrinter {
+            cartridge: $accounts.cartridge,
+            game: $accounts.game,
+            game_token_account: $accounts.game_token_account,
+            owner: $accounts.owner,
+            payer: $accounts.payer,
+            payer_token_account: $accounts.payer_token_account,
+            mint_counter: $mint_counter,
+            referrer_token_account: $referrer_token_account,
+            payment_mint: $accounts.payment_mint,
+            mpl_core_program: $accounts.mpl_core_program,
+            token_program: $accounts.token_program,
+            system_program: $accounts.system_program,
+        }
+    };
+}
+pub(crate) use cartridge_printer;
+
+/// The accounts every print instruction uses to take the payment and mint the
+/// cartridge.
+pub(crate) struct CartridgePrinter<'a> {
+    pub cartridge: &'a AccountInfo<'a>,
+    pub game: &'a AccountInfo<'a>,
+    pub game_token_account: &'a AccountInfo<'a>,
+    pub owner: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub payer_token_account: &'a AccountInfo<'a>,
+    /// Only needed for games limiting prints per wallet.
+    pub mint_counter: Option<&'a AccountInfo<'a>>,
+    /// Only set when a referrer gets a share of the print.
+    pub referrer_token_account: Option<&'a AccountInfo<'a>>,
+    pub payment_mint: &'a AccountInfo<'a>,
+    pub mpl_core_program: &'a AccountInfo<'a>,
+    pub token_program: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl<'a> CartridgePrinter<'a> {
+    /// Returns the game data, the bump of the mint counter, when passed, and
+    /// the referrer of the print. Every print follows the sale window of the
+    /// game.
+    pub fn check(
+        &self,
+        now: i64,
+    ) -> Result<(GameCollectionData, Option<u8>, Option<Pubkey>), ProgramError> {
         let Self {
             cartridge: _cartridge,
             game,
             game_token_account,
-            owner: _owner,
+            owner,
             payer,
             payer_token_account,
-            authority,
+            mint_counter,
+            referrer_token_account,
             payment_mint,
             mpl_core_program,
             token_program,
@@ -64,22 +198,7 @@ impl PrintGameCartridgeV1Accounts<'_> {
         // SAFE: Checked by Core
 
         // Game Token Account
-        assert_owned_by(
-            game_token_account,
-            &spl_token::ID,
-            BglCartridgeError::InvalidGameTokenAccountProgramOwner,
-        )?;
-
-        let token_account =
-            SplTokenAccount::unpack(game_token_account.try_borrow_data()?.as_ref())?;
-
-        if token_account.owner != *game.key {
-            return Err(BglCartridgeError::InvalidGameTokenAccountOwner.into());
-        }
-
-        if token_account.mint != PAYMENT_TOKEN_MINT {
-            return Err(BglCartridgeError::InvalidGameTokenAccountMint.into());
-        }
+        assert_game_token_account(game, game_token_account)?;
 
         // Owner
         // SAFE: Can be anything.
@@ -88,27 +207,60 @@ impl PrintGameCartridgeV1Accounts<'_> {
         assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
 
         // Payer Token Account
-        assert_owned_by(
-            payer_token_account,
-            &spl_token::ID,
-            BglCartridgeError::InvalidPayerTokenAccountProgramOwner,
-        )?;
-
-        let token_account =
-            SplTokenAccount::unpack(payer_token_account.try_borrow_data()?.as_ref())?;
-
-        if token_account.owner != *payer.key {
-            return Err(BglCartridgeError::InvalidPayerTokenAccountOwner.into());
-        }
-
-        if token_account.mint != PAYMENT_TOKEN_MINT {
-            return Err(BglCartridgeError::InvalidPayerTokenAccountMint.into());
-        }
-
-        // Authority
-        if let Some(authority) = authority {
-            assert_signer(authority).map_err(|_| BglCartridgeError::AuthorityMustSign)?;
-        }
+        assert_payer_token_account(payer, payer_token_account)?;
+
+        // Mint Counter
+        // Keyed on the owner so a wallet cannot dodge the limit by having
+        // someone else pay.
+        let mint_counter_bump = match mint_counter {
+            Some(mint_counter) => {
+                let bump = assert_derivation(
+                    &crate::ID,
+                    mint_counter,
+                    &[MINT_COUNT_PREFIX, game.key.as_ref(), owner.key.as_ref()],
+                    BglCartridgeError::InvalidMintCounter,
+                )?;
+
+                if !mint_counter.data_is_empty() {
+                    assert_owned_by(
+                        mint_counter,
+                        &crate::ID,
+                        BglCartridgeError::InvalidMintCounter,
+                    )?;
+                }
+
+                Some(bump)
+            }
+            None => None,
+        };
+
+        // Referrer Token Account
+        // The referrer is the owner of the token account receiving the share.
+        let referrer = match referrer_token_account {
+            Some(referrer_token_account) => {
+                assert_owned_by(
+                    referrer_token_account,
+                    &spl_token::ID,
+                    BglCartridgeError::InvalidReferrerTokenAccountProgramOwner,
+                )?;
+
+                let token_account =
+                    SplTokenAccount::unpack(referrer_token_account.try_borrow_data()?.as_ref())?;
+
+                if token_account.mint != PAYMENT_TOKEN_MINT {
+                    return Err(BglCartridgeError::InvalidReferrerTokenAccountMint.into());
+                }
+
+                if cmp_pubkeys(&token_account.owner, payer.key)
+                    || cmp_pubkeys(&token_account.owner, owner.key)
+                {
+                    return Err(BglCartridgeError::SelfReferral.into());
+                }
+
+                Some(token_account.owner)
+            }
+            None => None,
+        };
 
         // Payment Mint
         if !cmp_pubkeys(payment_mint.key, &PAYMENT_TOKEN_MINT) {
@@ -130,8 +282,345 @@ impl PrintGameCartridgeV1Accounts<'_> {
             return Err(BglCartridgeError::InvalidSystemProgram.into());
         }
 
+        let game_data = GameCollectionData::fetch(game)?;
+        game_data.assert_on_sale(now)?;
+
+        // Games limiting prints per wallet count them in the mint counter.
+        if game_data.max_per_wallet.is_some() && mint_counter.is_none() {
+            return Err(BglCartridgeError::MintCounterRequired.into());
+        }
+
+        Ok((game_data, mint_counter_bump, referrer))
+    }
+
+    /// Counts the print in the owner's mint counter, creating it on the first
+    /// print, and enforces the per-wallet limit of the game. Prints are only
+    /// counted while the game has a limit.
+    pub fn count_print(
+        &self,
+        mint_counter_bump: Option<u8>,
+        max_per_wallet: Option<u32>,
+    ) -> ProgramResult {
+        let (Some(max_per_wallet), Some(mint_counter), Some(mint_counter_bump)) =
+            (max_per_wallet, self.mint_counter, mint_counter_bump)
+        else {
+            return Ok(());
+        };
+
+        if mint_counter.data_is_empty() {
+            create_or_allocate_account_raw(
+                crate::ID,
+                mint_counter,
+                self.system_program,
+                self.payer,
+                core::mem::size_of::<MintCounter>(),
+                &[
+                    MINT_COUNT_PREFIX,
+                    self.game.key.as_ref(),
+                    self.owner.key.as_ref(),
+                    &[mint_counter_bump],
+                ],
+            )?;
+
+            let mut counter_data = mint_counter.try_borrow_mut_data()?;
+            let counter: &mut MintCounter = from_bytes_mut(&mut counter_data);
+            *counter = MintCounter {
+                game: *self.game.key,
+                owner: *self.owner.key,
+                count: 0,
+                _padding: [0; 4],
+            };
+        }
+
+        let mut counter_data = mint_counter.try_borrow_mut_data()?;
+        let counter: &mut MintCounter = from_bytes_mut(&mut counter_data);
+        counter.count = counter
+            .count
+            .checked_add(1)
+            .ok_or(BglCartridgeError::NumericalOverflow)?;
+
+        if counter.count > max_per_wallet {
+            return Err(BglCartridgeError::WalletLimitReached.into());
+        }
+
         Ok(())
     }
+
+    /// Transfers the payment to the game or burns it.
+    pub fn pay(&self, price_type: PriceType, price: u64) -> ProgramResult {
+        match price_type {
+            PriceType::Transfer => invoke(
+                &spl_token::instruction::transfer(
+                    self.token_program.key,
+                    self.payer_token_account.key,
+                    self.game_token_account.key,
+                    self.payer.key,
+                    &[],
+                    price,
+                )?,
+                &[
+                    self.payer_token_account.clone(),
+                    self.game_token_account.clone(),
+                    self.payer.clone(),
+                ],
+            ),
+            PriceType::Burn => invoke(
+                &spl_token::instruction::burn(
+                    self.token_program.key,
+                    self.payer_token_account.key,
+                    self.payment_mint.key,
+                    self.payer.key,
+                    &[],
+                    price,
+                )?,
+                &[
+                    self.payer_token_account.clone(),
+                    self.payment_mint.clone(),
+                    self.payer.clone(),
+                ],
+            ),
+        }
+    }
+
+    /// Takes the payment of a print, paying the referrer its share first.
+    /// Burned payments have no referral share. Returns the part of the price
+    /// left to the game.
+    pub fn pay_print(
+        &self,
+        game_data: &GameCollectionData,
+        price: u64,
+    ) -> Result<u64, ProgramError> {
+        let price_type = PriceType::from(game_data.price_type);
+        let mut game_share = price;
+        if let (Some(referrer_token_account), PriceType::Transfer) =
+            (self.referrer_token_account, price_type)
+        {
+            let share = game_data.referral_share(price)?;
+            if share > 0 {
+                invoke(
+                    &spl_token::instruction::transfer(
+                        self.token_program.key,
+                        self.payer_token_account.key,
+                        referrer_token_account.key,
+                        self.payer.key,
+                        &[],
+                        share,
+                    )?,
+                    &[
+                        self.payer_token_account.clone(),
+                        referrer_token_account.clone(),
+                        self.payer.clone(),
+                    ],
+                )?;
+                game_share -= share;
+            }
+        }
+
+        self.pay(price_type, game_share)?;
+
+        Ok(game_share)
+    }
+
+    /// Creates the cartridge asset in the game collection and writes its
+    /// AppData.
+    pub fn mint(
+        &self,
+        collection_nonce: u8,
+        collection_bump: u8,
+        cartridge_data: &CartridgeData,
+    ) -> ProgramResult {
+        CartridgeMinter {
+            game: self.game,
+            payer: self.payer,
+            mpl_core_program: self.mpl_core_program,
+            system_program: self.system_program,
+        }
+        .mint(
+            self.cartridge,
+            self.owner,
+            collection_nonce,
+            collection_bump,
+            cartridge_data,
+        )
+    }
+
+    /// Rewrites the AppData of the printed cartridge.
+    pub fn write(
+        &self,
+        collection_nonce: u8,
+        collection_bump: u8,
+        cartridge_data: &CartridgeData,
+    ) -> ProgramResult {
+        CartridgeMinter {
+            game: self.game,
+            payer: self.payer,
+            mpl_core_program: self.mpl_core_program,
+            system_program: self.system_program,
+        }
+        .write(
+            self.cartridge,
+            collection_nonce,
+            collection_bump,
+            cartridge_data,
+        )
+    }
+}
+
+/// The accounts needed to mint cartridges of a game, signing as the game.
+pub(crate) struct CartridgeMinter<'a> {
+    pub game: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub mpl_core_program: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl<'a> CartridgeMinter<'a> {
+    /// Creates the cartridge asset in the game collection and writes its
+    /// AppData. The edition number follows the collection's mint count.
+    /// Delisted games are never minted, however the cartridge is paid for.
+    pub fn mint(
+        &self,
+        cartridge: &'a AccountInfo<'a>,
+        owner: &'a AccountInfo<'a>,
+        collection_nonce: u8,
+        collection_bump: u8,
+        cartridge_data: &CartridgeData,
+    ) -> ProgramResult {
+        if GameCollectionData::fetch(self.game)?.delisted {
+            return Err(BglCartridgeError::GameDelisted.into());
+        }
+
+        // Fetch the collection data so we can derive the PDA signer
+        let collection = BaseCollectionV1::from_bytes(self.game.try_borrow_data()?.as_ref())?;
+
+        let plugins = vec![
+            PluginAuthorityPair {
+                plugin: Plugin::Edition(Edition {
+                    number: collection.num_minted + 1,
+                }),
+                authority: None,
+            },
+            PluginAuthorityPair {
+                plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: true }),
+                authority: Some(PluginAuthority::UpdateAuthority),
+            },
+        ];
+
+        // Create the Game Cartridge
+        // We need to fetch the count to use as the edition number
+        let name = format!("{} {}", collection.name, collection.num_minted + 1);
+        CreateV2Cpi {
+            __program: self.mpl_core_program,
+            asset: cartridge,
+            collection: Some(self.game),
+            owner: Some(owner),
+            authority: Some(self.game),
+            update_authority: None,
+            payer: self.payer,
+            system_program: self.system_program,
+            log_wrapper: None,
+            __args: CreateV2InstructionArgs {
+                name,
+                uri: collection.uri,
+                data_state: DataState::AccountState,
+                plugins: Some(plugins),
+                external_plugin_adapters: Some(vec![ExternalPluginAdapterInitInfo::AppData(
+                    AppDataInitInfo {
+                        data_authority: PluginAuthority::UpdateAuthority,
+                        init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
+                        schema: None,
+                    },
+                )]),
+            },
+        }
+        .invoke_signed(&[&[
+            GAME_PREFIX,
+            collection.name.as_bytes(),
+            &[collection_nonce],
+            &[collection_bump],
+        ]])?;
+        solana_program::msg!("Game cartridge printed");
+
+        self.write(cartridge, collection_nonce, collection_bump, cartridge_data)?;
+        solana_program::msg!("Cartridge data recorded");
+
+        Ok(())
+    }
+
+    /// Writes the AppData of a cartridge of the game.
+    pub fn write(
+        &self,
+        cartridge: &'a AccountInfo<'a>,
+        collection_nonce: u8,
+        collection_bump: u8,
+        cartridge_data: &CartridgeData,
+    ) -> ProgramResult {
+        let collection = BaseCollectionV1::from_bytes(self.game.try_borrow_data()?.as_ref())?;
+        WriteExternalPluginAdapterDataV1Cpi {
+            __program: self.mpl_core_program,
+            asset: cartridge,
+            collection: Some(self.game),
+            payer: self.payer,
+            authority: Some(self.game),
+            buffer: None,
+            system_program: self.system_program,
+            log_wrapper: None,
+            __args: WriteExternalPluginAdapterDataV1InstructionArgs {
+                key: ExternalPluginAdapterKey::AppData(PluginAuthority::UpdateAuthority),
+                data: Some(borsh::to_vec(cartridge_data)?),
+            },
+        }
+        .invoke_signed(&[&[
+            GAME_PREFIX,
+            collection.name.as_bytes(),
+            &[collection_nonce],
+            &[collection_bump],
+        ]])
+    }
+}
+
+impl<'a> PrintGameCartridgeV1Accounts<'a> {
+    pub(crate) fn printer(&self) -> CartridgePrinter<'a> {
+        // Games limiting prints per wallet need the mint counter of V2.
+        cartridge_printer!(self, mint_counter: None, referrer_token_account: None)
+    }
+
+    /// Returns the game data.
+    pub fn check(&self, now: i64) -> Result<GameCollectionData, ProgramError> {
+        // Cartridge, Game, Owner, Payer, Token Accounts and Programs
+        let (game_data, _, _) = self.printer().check(now)?;
+
+        // Authority
+        if let Some(authority) = self.authority {
+            assert_signer(authority).map_err(|_| BglCartridgeError::AuthorityMustSign)?;
+        }
+
+        Ok(game_data)
+    }
+}
+
+impl<'a> PrintGameCartridgeV2Accounts<'a> {
+    pub(crate) fn printer(&self) -> CartridgePrinter<'a> {
+        cartridge_printer!(self)
+    }
+
+    /// Returns the game data, the bump of the mint counter and the referrer of
+    /// the print.
+    pub fn check(
+        &self,
+        now: i64,
+    ) -> Result<(GameCollectionData, Option<u8>, Option<Pubkey>), ProgramError> {
+        // Cartridge, Game, Owner, Payer, Token Accounts, Mint Counter,
+        // Referrer Token Account and Programs
+        let (game_data, mint_counter_bump, referrer) = self.printer().check(now)?;
+
+        // Authority
+        if let Some(authority) = self.authority {
+            assert_signer(authority).map_err(|_| BglCartridgeError::AuthorityMustSign)?;
+        }
+
+        Ok((game_data, mint_counter_bump, referrer))
+    }
 }
 
 pub fn print_game_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
@@ -139,145 +628,87 @@ pub fn print_game_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) ->
     solana_program::msg!("Printing game cartridge");
 
     // All account guards and validations happen here.
-    ctx.accounts.check()?;
+    let now = Clock::get()?.unix_timestamp;
+    let game_collection_data = ctx.accounts.check(now)?;
+    solana_program::msg!("Account checks passed");
+
+    let args = PrintGameCartridgeV1Args::unpack(args)?;
+    solana_program::msg!("Args parsed");
+
+    print(
+        &ctx.accounts.printer(),
+        &game_collection_data,
+        None,
+        None,
+        &args,
+        now,
+    )
+}
+
+pub fn print_game_cartridge_v2<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = PrintGameCartridgeV2Accounts::context(accounts)?;
+    solana_program::msg!("Printing game cartridge");
+
+    // All account guards and validations happen here.
+    let now = Clock::get()?.unix_timestamp;
+    let (game_collection_data, mint_counter_bump, referrer) = ctx.accounts.check(now)?;
     solana_program::msg!("Account checks passed");
 
-    let args: &PrintGameCartridgeV1Args = from_bytes(args);
+    let args = PrintGameCartridgeV1Args::unpack(args)?;
     solana_program::msg!("Args parsed");
 
+    print(
+        &ctx.accounts.printer(),
+        &game_collection_data,
+        mint_counter_bump,
+        referrer,
+        &args,
+        now,
+    )
+}
+
+/// Prints a cartridge at the current price of the game.
+fn print(
+    printer: &CartridgePrinter,
+    game_collection_data: &GameCollectionData,
+    mint_counter_bump: Option<u8>,
+    referrer: Option<Pubkey>,
+    args: &PrintGameCartridgeV1Args,
+    now: i64,
+) -> ProgramResult {
     /*********************************************/
     /****************** Actions ******************/
     /*********************************************/
-    // Fetch the collection data so we can derive the PDA signer
-    let collection = BaseCollectionV1::from_bytes(ctx.accounts.game.try_borrow_data()?.as_ref())?;
-    solana_program::msg!("Collection fetched");
-
     // Handle payment for the game.
-    // Fetch the game data.
-    let (offset, length) = fetch_external_plugin_adapter_data_info::<BaseCollectionV1>(
-        ctx.accounts.game,
-        None,
-        &ExternalPluginAdapterKey::DataSection(LinkedDataKey::LinkedAppData(
-            PluginAuthority::UpdateAuthority,
-        )),
-    )?;
+    let collection = BaseCollectionV1::from_bytes(printer.game.try_borrow_data()?.as_ref())?;
+    game_collection_data.assert_not_dlc()?;
+    let price = game_collection_data.current_price(now, collection.num_minted)?;
 
-    let game_collection_data = GameCollectionData::try_from_slice(
-        ctx.accounts.game.try_borrow_data()?[offset..offset + length].as_ref(),
-    )?;
+    // The price may have moved since the payer quoted it.
+    if matches!(args.max_price, Some(max_price) if price > max_price) {
+        return Err(BglCartridgeError::PriceAboveMax.into());
+    }
 
     solana_program::msg!("Game collection data fetched: {:?}", game_collection_data);
 
-    // Transfer or burn the payment.
-    match PriceType::from(game_collection_data.price_type) {
-        PriceType::Transfer => invoke(
-            &spl_token::instruction::transfer(
-                ctx.accounts.token_program.key,
-                ctx.accounts.payer_token_account.key,
-                ctx.accounts.game_token_account.key,
-                ctx.accounts.payer.key,
-                &[],
-                game_collection_data.price,
-            )?,
-            &[
-                ctx.accounts.payer_token_account.clone(),
-                ctx.accounts.game_token_account.clone(),
-                ctx.accounts.payer.clone(),
-            ],
-        ),
-        PriceType::Burn => invoke(
-            &spl_token::instruction::burn(
-                ctx.accounts.token_program.key,
-                ctx.accounts.payer_token_account.key,
-                ctx.accounts.payment_mint.key,
-                ctx.accounts.payer.key,
-                &[],
-                game_collection_data.price,
-            )?,
-            &[
-                ctx.accounts.payer_token_account.clone(),
-                ctx.accounts.payment_mint.clone(),
-                ctx.accounts.payer.clone(),
-            ],
-        ),
-    }?;
-
-    // Create the Game Cartridge
-    // We need to fetch the count to use as the edition number
-    let name = format!("{} {}", collection.name, collection.num_minted + 1);
-    solana_program::msg!("Name formatted");
-    CreateV2Cpi {
-        __program: ctx.accounts.mpl_core_program,
-        asset: ctx.accounts.cartridge,
-        collection: Some(ctx.accounts.game),
-        owner: Some(ctx.accounts.owner),
-        authority: Some(ctx.accounts.game),
-        update_authority: None,
-        payer: ctx.accounts.payer,
-        system_program: ctx.accounts.system_program,
-        log_wrapper: None,
-        __args: CreateV2InstructionArgs {
-            name,
-            uri: collection.uri,
-            data_state: DataState::AccountState,
-            plugins: Some(vec![
-                PluginAuthorityPair {
-                    plugin: Plugin::Edition(Edition {
-                        number: collection.num_minted + 1,
-                    }),
-                    authority: None,
-                },
-                PluginAuthorityPair {
-                    plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate {
-                        frozen: true,
-                    }),
-                    authority: Some(PluginAuthority::UpdateAuthority),
-                },
-            ]),
-            external_plugin_adapters: Some(vec![ExternalPluginAdapterInitInfo::AppData(
-                AppDataInitInfo {
-                    data_authority: PluginAuthority::UpdateAuthority,
-                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
-                    schema: None,
-                },
-            )]),
-        },
-    }
-    .invoke_signed(&[&[
-        GAME_PREFIX,
-        collection.name.as_bytes(),
-        &[args.collection_nonce],
-        &[args.collection_bump],
-    ]])?;
-    solana_program::msg!("Game cartridge printed");
-
-    // Initialize the AppData with source = Unknown. Only the AML authority
-    // can later upgrade this via set_cartridge_source.
-    let cartridge_data = CartridgeData {
-        version: 0,
-        source: Source::Unknown as u8,
-    };
-    WriteExternalPluginAdapterDataV1Cpi {
-        __program: ctx.accounts.mpl_core_program,
-        asset: ctx.accounts.cartridge,
-        collection: Some(ctx.accounts.game),
-        payer: ctx.accounts.payer,
-        authority: Some(ctx.accounts.game),
-        buffer: None,
-        system_program: ctx.accounts.system_program,
-        log_wrapper: None,
-        __args: WriteExternalPluginAdapterDataV1InstructionArgs {
-            key: ExternalPluginAdapterKey::AppData(PluginAuthority::UpdateAuthority),
-            data: Some(borsh::to_vec(&cartridge_data)?),
+    // Enforce the per-wallet limit.
+    printer.count_print(mint_counter_bump, game_collection_data.max_per_wallet)?;
+
+    // Pay the referrer their share, the rest goes to the game as usual.
+    let purchase_price = printer.pay_print(game_collection_data, price)?;
+
+    // Create the Game Cartridge and initialize the AppData with
+    // source = Unknown. Only the AML authority can later upgrade this via
+    // set_cartridge_source.
+    printer.mint(
+        args.collection_nonce,
+        args.collection_bump,
+        &CartridgeData {
+            referrer,
+            refundable_until: game_collection_data.refund_deadline(now),
+            ..CartridgeData::new(Source::Unknown, purchase_price, now)
         },
-    }
-    .invoke_signed(&[&[
-        GAME_PREFIX,
-        collection.name.as_bytes(),
-        &[args.collection_nonce],
-        &[args.collection_bump],
-    ]])?;
-    solana_program::msg!("Cartridge source recorded");
+    )?;
 
     Ok(())
 }
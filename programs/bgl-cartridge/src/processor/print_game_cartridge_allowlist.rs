@@ -0,0 +1,165 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::from_bytes_mut;
+use mpl_core::accounts::BaseCollectionV1;
+use mpl_utils::{assert_derivation, assert_owned_by, create_or_allocate_account_raw};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::PrintGameCartridgeAllowlistV1Accounts,
+    processor::{cartridge_printer, CartridgePrinter},
+    state::{AllowlistReceipt, CartridgeData, GameCollectionData, Source, ALLOWLIST_PREFIX},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct PrintGameCartridgeAllowlistV1Args {
+    /// The nonce for the collection
+    pub collection_nonce: u8,
+    /// The bump for the collection
+    pub collection_bump: u8,
+    /// The quota of the payer in the allowlist, zero for none
+    pub quota: u32,
+    /// The merkle proof of the payer's allowlist leaf
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl PrintGameCartridgeAllowlistV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+impl<'a> PrintGameCartridgeAllowlistV1Accounts<'a> {
+    pub(crate) fn printer(&self) -> CartridgePrinter<'a> {
+        cartridge_printer!(self)
+    }
+
+    /// Returns the game data, the bumps of the allowlist receipt and the mint
+    /// counter, and the referrer of the print.
+    pub fn check(
+        &self,
+        now: i64,
+    ) -> Result<(GameCollectionData, u8, Option<u8>, Option<Pubkey>), ProgramError> {
+        // Cartridge, Game, Owner, Payer, Token Accounts, Mint Counter,
+        // Referrer Token Account and Programs
+        let (game_data, mint_counter_bump, referrer) = self.printer().check(now)?;
+
+        // Allowlist Receipt
+        // One receipt per allowlisted wallet, created on its first print.
+        let bump = assert_derivation(
+            &crate::ID,
+            self.allowlist_receipt,
+            &[
+                ALLOWLIST_PREFIX,
+                self.game.key.as_ref(),
+                self.payer.key.as_ref(),
+            ],
+            BglCartridgeError::InvalidAllowlistReceipt,
+        )?;
+
+        if !self.allowlist_receipt.data_is_empty() {
+            assert_owned_by(
+                self.allowlist_receipt,
+                &crate::ID,
+                BglCartridgeError::InvalidAllowlistReceipt,
+            )?;
+        }
+
+        Ok((game_data, bump, mint_counter_bump, referrer))
+    }
+}
+
+pub fn print_game_cartridge_allowlist<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &[u8],
+) -> ProgramResult {
+    let ctx = PrintGameCartridgeAllowlistV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    let now = Clock::get()?.unix_timestamp;
+    let (game_data, receipt_bump, mint_counter_bump, referrer) = ctx.accounts.check(now)?;
+
+    let args = PrintGameCartridgeAllowlistV1Args::unpack(args)?;
+
+    // The payer must be on the allowlist with the claimed quota.
+    if !game_data
+        .allowlist
+        .contains(ctx.accounts.payer.key, args.quota, &args.proof)
+    {
+        return Err(BglCartridgeError::NotAllowlisted.into());
+    }
+
+    game_data.assert_not_dlc()?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Create the receipt on the first print.
+    if ctx.accounts.allowlist_receipt.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::ID,
+            ctx.accounts.allowlist_receipt,
+            ctx.accounts.system_program,
+            ctx.accounts.payer,
+            core::mem::size_of::<AllowlistReceipt>(),
+            &[
+                ALLOWLIST_PREFIX,
+                ctx.accounts.game.key.as_ref(),
+                ctx.accounts.payer.key.as_ref(),
+                &[receipt_bump],
+            ],
+        )?;
+
+        let mut receipt_data = ctx.accounts.allowlist_receipt.try_borrow_mut_data()?;
+        let receipt: &mut AllowlistReceipt = from_bytes_mut(&mut receipt_data);
+        *receipt = AllowlistReceipt {
+            game: *ctx.accounts.game.key,
+            wallet: *ctx.accounts.payer.key,
+            printed: 0,
+            _padding: [0; 4],
+        };
+    }
+
+    // Count the print against the quota.
+    let mut receipt_data = ctx.accounts.allowlist_receipt.try_borrow_mut_data()?;
+    let receipt: &mut AllowlistReceipt = from_bytes_mut(&mut receipt_data);
+    receipt.printed = receipt
+        .printed
+        .checked_add(1)
+        .ok_or(BglCartridgeError::NumericalOverflow)?;
+    if args.quota != 0 && receipt.printed > args.quota {
+        return Err(BglCartridgeError::AllowlistQuotaExceeded.into());
+    }
+    drop(receipt_data);
+
+    // Allowlisted wallets pay the allowlist price when there is one.
+    let price = match game_data.allowlist.price {
+        Some(price) => price,
+        None => {
+            let collection =
+                BaseCollectionV1::from_bytes(ctx.accounts.game.try_borrow_data()?.as_ref())?;
+            game_data.current_price(now, collection.num_minted)?
+        }
+    };
+
+    let printer = ctx.accounts.printer();
+    printer.count_print(mint_counter_bump, game_data.max_per_wallet)?;
+    let purchase_price = printer.pay_print(&game_data, price)?;
+    printer.mint(
+        args.collection_nonce,
+        args.collection_bump,
+        &CartridgeData {
+            referrer,
+            refundable_until: game_data.refund_deadline(now),
+            ..CartridgeData::new(Source::Unknown, purchase_price, now)
+        },
+    )?;
+
+    Ok(())
+}
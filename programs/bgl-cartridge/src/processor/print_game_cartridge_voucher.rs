@@ -0,0 +1,237 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::from_bytes_mut;
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    ed25519_program,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+    sysvar::{
+        self,
+        instructions::{load_current_index_checked, load_instruction_at_checked},
+        Sysvar,
+    },
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::PrintGameCartridgeVoucherV1Accounts,
+    processor::{cartridge_printer, CartridgePrinter},
+    state::{CartridgeData, GameCollectionData, Source, VoucherRecord, VOUCHER_PREFIX},
+};
+
+// Layout of the Ed25519 program instruction data: a signature count, a
+// padding byte, then one 14-byte offsets entry per signature.
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_SIZE: usize = 14;
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct PrintGameCartridgeVoucherV1Args {
+    /// The nonce for the collection
+    pub collection_nonce: u8,
+    /// The bump for the collection
+    pub collection_bump: u8,
+    /// The price granted by the voucher
+    pub price: u64,
+    /// When the voucher expires
+    pub expires_at: i64,
+    /// The nonce of the voucher, unique per game
+    pub voucher_nonce: u64,
+}
+
+impl PrintGameCartridgeVoucherV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+
+    /// The message signed by the voucher signer:
+    /// `game || wallet || price || expires_at || voucher_nonce`, with the
+    /// integers little-endian.
+    pub fn message(&self, game: &Pubkey, wallet: &Pubkey) -> Vec<u8> {
+        [
+            game.as_ref(),
+            wallet.as_ref(),
+            &self.price.to_le_bytes(),
+            &self.expires_at.to_le_bytes(),
+            &self.voucher_nonce.to_le_bytes(),
+        ]
+        .concat()
+    }
+}
+
+impl<'a> PrintGameCartridgeVoucherV1Accounts<'a> {
+    pub(crate) fn printer(&self) -> CartridgePrinter<'a> {
+        cartridge_printer!(self)
+    }
+
+    /// Returns the game data, the bumps of the voucher record and the mint
+    /// counter, and the referrer of the print.
+    pub fn check(
+        &self,
+        args: &PrintGameCartridgeVoucherV1Args,
+        now: i64,
+    ) -> Result<(GameCollectionData, u8, Option<u8>, Option<Pubkey>), ProgramError> {
+        // Cartridge, Game, Owner, Payer, Token Accounts, Mint Counter,
+        // Referrer Token Account and Programs
+        let (game_data, mint_counter_bump, referrer) = self.printer().check(now)?;
+
+        // Voucher Record
+        // Must not exist yet, which is what prevents replays.
+        let bump = assert_derivation(
+            &crate::ID,
+            self.voucher_record,
+            &[
+                VOUCHER_PREFIX,
+                self.game.key.as_ref(),
+                &args.voucher_nonce.to_le_bytes(),
+            ],
+            BglCartridgeError::InvalidVoucherRecord,
+        )?;
+
+        if !self.voucher_record.data_is_empty() {
+            return Err(BglCartridgeError::VoucherAlreadyUsed.into());
+        }
+
+        // Instructions Sysvar
+        if self.sysvar_instructions.key != &sysvar::instructions::ID {
+            return Err(BglCartridgeError::InvalidInstructionsSysvar.into());
+        }
+
+        Ok((game_data, bump, mint_counter_bump, referrer))
+    }
+}
+
+/// Checks that the instruction right before this one is an Ed25519 program
+/// instruction verifying `message` signed by `signer`. The precompile fails
+/// the whole transaction on a bad signature, so only what it verified needs
+/// to be checked here.
+fn assert_ed25519_signature(
+    sysvar_instructions: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> ProgramResult {
+    let current_index = load_current_index_checked(sysvar_instructions)? as usize;
+    if current_index == 0 {
+        return Err(BglCartridgeError::InvalidVoucherSignature.into());
+    }
+
+    let instruction = load_instruction_at_checked(current_index - 1, sysvar_instructions)?;
+    let data = &instruction.data;
+    if instruction.program_id != ed25519_program::ID
+        || data.len() < ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE
+        || data[0] != 1
+    {
+        return Err(BglCartridgeError::InvalidVoucherSignature.into());
+    }
+
+    let read_u16 = |offset: usize| {
+        u16::from_le_bytes([
+            data[ED25519_OFFSETS_START + offset],
+            data[ED25519_OFFSETS_START + offset + 1],
+        ])
+    };
+    let signature_instruction_index = read_u16(2);
+    let public_key_offset = read_u16(4) as usize;
+    let public_key_instruction_index = read_u16(6);
+    let message_offset = read_u16(8) as usize;
+    let message_size = read_u16(10) as usize;
+    let message_instruction_index = read_u16(12);
+
+    // Everything must live in the Ed25519 instruction itself, otherwise the
+    // verified data could come from another instruction.
+    if [
+        signature_instruction_index,
+        public_key_instruction_index,
+        message_instruction_index,
+    ]
+    .iter()
+    .any(|index| *index != u16::MAX)
+    {
+        return Err(BglCartridgeError::InvalidVoucherSignature.into());
+    }
+
+    let verified_signer = data.get(public_key_offset..public_key_offset + PUBKEY_BYTES);
+    let verified_message = data.get(message_offset..message_offset + message_size);
+    if verified_signer != Some(signer.as_ref()) || verified_message != Some(message) {
+        return Err(BglCartridgeError::InvalidVoucherSignature.into());
+    }
+
+    Ok(())
+}
+
+pub fn print_game_cartridge_voucher<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &[u8],
+) -> ProgramResult {
+    let ctx = PrintGameCartridgeVoucherV1Accounts::context(accounts)?;
+
+    let args = PrintGameCartridgeVoucherV1Args::unpack(args)?;
+
+    // All account guards and validations happen here.
+    let now = Clock::get()?.unix_timestamp;
+    let (game_data, record_bump, mint_counter_bump, referrer) = ctx.accounts.check(&args, now)?;
+
+    // The voucher must be signed by the game's voucher signer for this payer.
+    if game_data.voucher_signer == Pubkey::default() {
+        return Err(BglCartridgeError::VouchersDisabled.into());
+    }
+
+    assert_ed25519_signature(
+        ctx.accounts.sysvar_instructions,
+        &game_data.voucher_signer,
+        &args.message(ctx.accounts.game.key, ctx.accounts.payer.key),
+    )?;
+
+    if now >= args.expires_at {
+        return Err(BglCartridgeError::VoucherExpired.into());
+    }
+    game_data.assert_not_dlc()?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Burn the voucher nonce.
+    create_or_allocate_account_raw(
+        crate::ID,
+        ctx.accounts.voucher_record,
+        ctx.accounts.system_program,
+        ctx.accounts.payer,
+        core::mem::size_of::<VoucherRecord>(),
+        &[
+            VOUCHER_PREFIX,
+            ctx.accounts.game.key.as_ref(),
+            &args.voucher_nonce.to_le_bytes(),
+            &[record_bump],
+        ],
+    )?;
+
+    let mut record_data = ctx.accounts.voucher_record.try_borrow_mut_data()?;
+    let record: &mut VoucherRecord = from_bytes_mut(&mut record_data);
+    *record = VoucherRecord {
+        game: *ctx.accounts.game.key,
+        wallet: *ctx.accounts.payer.key,
+        nonce: args.voucher_nonce,
+    };
+    drop(record_data);
+
+    // Print at the voucher price.
+    let printer = ctx.accounts.printer();
+    printer.count_print(mint_counter_bump, game_data.max_per_wallet)?;
+    let purchase_price = printer.pay_print(&game_data, args.price)?;
+    printer.mint(
+        args.collection_nonce,
+        args.collection_bump,
+        &CartridgeData {
+            referrer,
+            refundable_until: game_data.refund_deadline(now),
+            ..CartridgeData::new(Source::Unknown, purchase_price, now)
+        },
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use bytemuck::{from_bytes, Pod, Zeroable};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::PrintRentalCartridgeV1Accounts,
+    processor::{cartridge_printer, CartridgePrinter},
+    state::{CartridgeData, GameCollectionData, Source},
+};
+
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub struct PrintRentalCartridgeV1Args {
+    #[skip]
+    /// The discriminator for the instruction
+    discriminator: u8,
+    /// The nonce for the collection
+    collection_nonce: u8,
+    /// The bump for the collection
+    collection_bump: u8,
+}
+
+impl<'a> PrintRentalCartridgeV1Accounts<'a> {
+    pub(crate) fn printer(&self) -> CartridgePrinter<'a> {
+        cartridge_printer!(self)
+    }
+
+    /// Returns the game data, the bump of the mint counter and the referrer of
+    /// the print.
+    pub fn check(
+        &self,
+        now: i64,
+    ) -> Result<(GameCollectionData, Option<u8>, Option<Pubkey>), ProgramError> {
+        // Cartridge, Game, Owner, Payer, Token Accounts, Mint Counter,
+        // Referrer Token Account and Programs
+        self.printer().check(now)
+    }
+}
+
+pub fn print_rental_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = PrintRentalCartridgeV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    let now = Clock::get()?.unix_timestamp;
+    let (game_data, mint_counter_bump, referrer) = ctx.accounts.check(now)?;
+
+    let args: &PrintRentalCartridgeV1Args = from_bytes(args);
+
+    // Rentals follow the sale of the game at the price of its rental terms.
+    let rental = game_data.rental.ok_or(BglCartridgeError::RentalsDisabled)?;
+    game_data.assert_not_dlc()?;
+    let expires_at = now
+        .checked_add(rental.duration)
+        .ok_or(BglCartridgeError::NumericalOverflow)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    let printer = ctx.accounts.printer();
+    printer.count_print(mint_counter_bump, game_data.max_per_wallet)?;
+    let purchase_price = printer.pay_print(&game_data, rental.price)?;
+
+    // The payer gets the storage of the cartridge back once it expires and is
+    // reclaimed.
+    let mut cartridge_data = CartridgeData {
+        expires_at: Some(expires_at),
+        rent_payer: Some(*ctx.accounts.payer.key),
+        referrer,
+        ..CartridgeData::new(Source::Unknown, purchase_price, now)
+    };
+    printer.mint(args.collection_nonce, args.collection_bump, &cartridge_data)?;
+
+    // Record the storage the payer funded, plugins added later are paid by
+    // others.
+    cartridge_data.rent_paid = ctx.accounts.cartridge.lamports();
+    printer.write(args.collection_nonce, args.collection_bump, &cartridge_data)?;
+
+    Ok(())
+}
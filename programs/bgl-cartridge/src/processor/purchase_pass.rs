@@ -0,0 +1,211 @@
+use mpl_core::{
+    accounts::BaseCollectionV1,
+    instructions::{
+        CreateV2Cpi, CreateV2InstructionArgs, WriteExternalPluginAdapterDataV1Cpi,
+        WriteExternalPluginAdapterDataV1InstructionArgs,
+    },
+    types::{
+        AppDataInitInfo, DataState, ExternalPluginAdapterInitInfo, ExternalPluginAdapterKey,
+        PermanentFreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair,
+    },
+};
+use mpl_utils::{assert_signer, cmp_pubkeys};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program::invoke,
+    program_error::ProgramError, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::PurchasePassV1Accounts,
+    processor::{assert_game_token_account, assert_payer_token_account},
+    state::{assert_pass_collection_derivation, PassCollectionData, PassData, PASS_PREFIX},
+};
+
+/// The accounts every pass sale uses to take the payment and write the pass.
+pub(crate) struct PassSeller<'a> {
+    pub pass: &'a AccountInfo<'a>,
+    pub pass_collection: &'a AccountInfo<'a>,
+    pub pass_token_account: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub payer_token_account: &'a AccountInfo<'a>,
+    pub mpl_core_program: &'a AccountInfo<'a>,
+    pub token_program: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl<'a> PassSeller<'a> {
+    /// Returns the bump of the pass collection.
+    pub fn check(&self) -> Result<u8, ProgramError> {
+        let Self {
+            pass: _pass,
+            pass_collection,
+            pass_token_account,
+            payer,
+            payer_token_account,
+            mpl_core_program,
+            token_program,
+            system_program,
+        } = self;
+
+        // Pass Collection
+        let bump = assert_pass_collection_derivation(pass_collection)?;
+
+        // Pass Token Account
+        assert_game_token_account(pass_collection, pass_token_account)?;
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // Payer Token Account
+        assert_payer_token_account(payer, payer_token_account)?;
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // Token Program
+        if !cmp_pubkeys(token_program.key, &spl_token::ID) {
+            return Err(BglCartridgeError::InvalidTokenProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(bump)
+    }
+
+    /// Transfers the price of a pass period to the pass collection.
+    pub fn pay(&self, price: u64) -> ProgramResult {
+        invoke(
+            &spl_token::instruction::transfer(
+                self.token_program.key,
+                self.payer_token_account.key,
+                self.pass_token_account.key,
+                self.payer.key,
+                &[],
+                price,
+            )?,
+            &[
+                self.payer_token_account.clone(),
+                self.pass_token_account.clone(),
+                self.payer.clone(),
+            ],
+        )
+    }
+
+    /// Writes the pass AppData, signing as the pass collection.
+    pub fn write_data(&self, bump: u8, pass_data: &PassData) -> ProgramResult {
+        let collection =
+            BaseCollectionV1::from_bytes(self.pass_collection.try_borrow_data()?.as_ref())?;
+        let authority = PassCollectionData::fetch(self.pass_collection)?.authority;
+        WriteExternalPluginAdapterDataV1Cpi {
+            __program: self.mpl_core_program,
+            asset: self.pass,
+            collection: Some(self.pass_collection),
+            payer: self.payer,
+            authority: Some(self.pass_collection),
+            buffer: None,
+            system_program: self.system_program,
+            log_wrapper: None,
+            __args: WriteExternalPluginAdapterDataV1InstructionArgs {
+                key: ExternalPluginAdapterKey::AppData(PluginAuthority::UpdateAuthority),
+                data: Some(borsh::to_vec(pass_data)?),
+            },
+        }
+        .invoke_signed(&[&[
+            PASS_PREFIX,
+            authority.as_ref(),
+            collection.name.as_bytes(),
+            &[bump],
+        ]])
+    }
+}
+
+impl<'a> PurchasePassV1Accounts<'a> {
+    pub(crate) fn seller(&self) -> PassSeller<'a> {
+        PassSeller {
+            pass: self.pass,
+            pass_collection: self.pass_collection,
+            pass_token_account: self.pass_token_account,
+            payer: self.payer,
+            payer_token_account: self.payer_token_account,
+            mpl_core_program: self.mpl_core_program,
+            token_program: self.token_program,
+            system_program: self.system_program,
+        }
+    }
+
+    /// Returns the bump of the pass collection.
+    pub fn check(&self) -> Result<u8, ProgramError> {
+        // Pass
+        // SAFE: New mint so it can be anything.
+
+        // Owner
+        // SAFE: Can be anything.
+
+        // Pass Collection, Token Accounts, Payer and Programs
+        self.seller().check()
+    }
+}
+
+pub fn purchase_pass<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = PurchasePassV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    let bump = ctx.accounts.check()?;
+
+    let pass_collection_data = PassCollectionData::fetch(ctx.accounts.pass_collection)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    let seller = ctx.accounts.seller();
+    seller.pay(pass_collection_data.price)?;
+
+    // Mint the pass. Passes are frozen like cartridges so the machine link
+    // always follows its holder.
+    let collection =
+        BaseCollectionV1::from_bytes(ctx.accounts.pass_collection.try_borrow_data()?.as_ref())?;
+    let name = format!("{} {}", collection.name, collection.num_minted + 1);
+    CreateV2Cpi {
+        __program: ctx.accounts.mpl_core_program,
+        asset: ctx.accounts.pass,
+        collection: Some(ctx.accounts.pass_collection),
+        owner: Some(ctx.accounts.owner),
+        authority: Some(ctx.accounts.pass_collection),
+        update_authority: None,
+        payer: ctx.accounts.payer,
+        system_program: ctx.accounts.system_program,
+        log_wrapper: None,
+        __args: CreateV2InstructionArgs {
+            name,
+            uri: collection.uri,
+            data_state: DataState::AccountState,
+            plugins: Some(vec![PluginAuthorityPair {
+                plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: true }),
+                authority: Some(PluginAuthority::UpdateAuthority),
+            }]),
+            external_plugin_adapters: Some(vec![ExternalPluginAdapterInitInfo::AppData(
+                AppDataInitInfo {
+                    data_authority: PluginAuthority::UpdateAuthority,
+                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
+                    schema: None,
+                },
+            )]),
+        },
+    }
+    .invoke_signed(&[&[
+        PASS_PREFIX,
+        pass_collection_data.authority.as_ref(),
+        collection.name.as_bytes(),
+        &[bump],
+    ]])?;
+
+    let mut pass_data = PassData::default();
+    pass_data.extend(Clock::get()?.unix_timestamp, pass_collection_data.duration)?;
+    seller.write_data(bump, &pass_data)
+}
@@ -0,0 +1,58 @@
+use bytemuck::from_bytes_mut;
+use mpl_utils::{assert_signer, cmp_pubkeys};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::ReassignNameV1Accounts,
+    state::{fetch_name_record, NameRecord, LOCALNET_AUTHORITY, VERIFIER_AUTHORITY},
+};
+
+impl ReassignNameV1Accounts<'_> {
+    pub fn check(&self) -> ProgramResult {
+        let Self {
+            name_record,
+            new_owner: _new_owner,
+            authority,
+        } = self;
+
+        // Name Record
+        // Only held names are reassigned, free names are reserved instead.
+        fetch_name_record(name_record)?;
+
+        // New Owner
+        // SAFE: Any publisher key or team can hold a name.
+
+        // Authority — must be the configured protocol authority and must sign.
+        // If it's epoch zero, then we can assume it's a localnet for testing.
+        assert_signer(authority).map_err(|_| BglCartridgeError::AuthorityMustSign)?;
+        if !cmp_pubkeys(authority.key, &VERIFIER_AUTHORITY)
+            && (Clock::get()?.epoch != 0 || !cmp_pubkeys(authority.key, &LOCALNET_AUTHORITY))
+        {
+            return Err(BglCartridgeError::InvalidVerifierAuthority.into());
+        }
+
+        Ok(())
+    }
+}
+
+pub fn reassign_name<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = ReassignNameV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    ctx.accounts.check()?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // The protocol overrides the holder, which is why this is kept apart from
+    // reserving free names.
+    let mut name_record_data = ctx.accounts.name_record.try_borrow_mut_data()?;
+    *from_bytes_mut::<NameRecord>(&mut name_record_data) = NameRecord {
+        owner: *ctx.accounts.new_owner.key,
+    };
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+use bytemuck::{from_bytes, Pod, Zeroable};
+use mpl_core::{
+    accounts::BaseCollectionV1,
+    instructions::{BurnV1Cpi, BurnV1InstructionArgs},
+};
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program::invoke_signed,
+    program_error::ProgramError, system_instruction, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::ReclaimExpiredCartridgeV1Accounts,
+    processor::CartridgeEjector,
+    state::{assert_cartridge_of_game, CartridgeData, GAME_PREFIX, RENTAL_PREFIX},
+};
+
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub struct ReclaimExpiredCartridgeV1Args {
+    #[skip]
+    /// The discriminator for the instruction
+    discriminator: u8,
+    /// The nonce for the game collection
+    collection_nonce: u8,
+    /// The bump for the game collection
+    collection_bump: u8,
+}
+
+impl<'a> ReclaimExpiredCartridgeV1Accounts<'a> {
+    /// The ejector of the machine the cartridge is inserted in, if any.
+    pub(crate) fn ejector(&self) -> Result<Option<CartridgeEjector<'a>>, ProgramError> {
+        CartridgeEjector::if_inserted(
+            self.cartridge,
+            self.game,
+            self.machine,
+            self.machine_collection,
+            self.payer,
+            self.mpl_core_program,
+            self.system_program,
+        )
+    }
+
+    /// Returns the cartridge data and the bump of the rental authority.
+    pub fn check(&self) -> Result<(CartridgeData, u8), ProgramError> {
+        let Self {
+            cartridge,
+            game,
+            rental_authority,
+            rent_payer,
+            payer,
+            mpl_core_program,
+            system_program,
+            ..
+        } = self;
+
+        // Cartridge
+        // Must be a rental cartridge of the game past its expiry.
+        assert_cartridge_of_game(cartridge, game.key)?;
+        let cartridge_data = CartridgeData::fetch(cartridge)?;
+        if !cartridge_data.is_expired(Clock::get()?.unix_timestamp) {
+            return Err(BglCartridgeError::CartridgeNotExpired.into());
+        }
+
+        // Burning a lent cartridge would leave its loan open forever.
+        if cartridge_data.lender.is_some() {
+            return Err(BglCartridgeError::CartridgeOnLoan.into());
+        }
+
+        // Game
+        // SAFE: Checked against the cartridge.
+
+        // Rental Authority
+        let bump = assert_derivation(
+            &crate::ID,
+            rental_authority,
+            &[RENTAL_PREFIX, game.key.as_ref()],
+            BglCartridgeError::InvalidRentalAuthority,
+        )?;
+
+        // Rent Payer
+        if cartridge_data.rent_payer != Some(*rent_payer.key) {
+            return Err(BglCartridgeError::InvalidRentPayer.into());
+        }
+
+        // Payer
+        // Anyone can crank expired rentals.
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok((cartridge_data, bump))
+    }
+}
+
+pub fn reclaim_expired_cartridge<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &[u8],
+) -> ProgramResult {
+    let ctx = ReclaimExpiredCartridgeV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    let (cartridge_data, rental_authority_bump) = ctx.accounts.check()?;
+
+    // Machine and Machine Collection
+    // Only needed when the rental was left in a machine.
+    let ejection = match ctx.accounts.ejector()? {
+        Some(ejector) => {
+            let machine = ejector.check()?;
+            Some((ejector, machine))
+        }
+        None => None,
+    };
+
+    let args: &ReclaimExpiredCartridgeV1Args = from_bytes(args);
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Free the machine slot before the cartridge disappears.
+    if let Some((ejector, (machine_bump, machine_asset, machine_data))) = ejection {
+        ejector.eject(
+            args.collection_nonce,
+            args.collection_bump,
+            machine_bump,
+            &machine_asset.name,
+            machine_data,
+        )?;
+    }
+
+    // Burn the rental through the game's permanent burn delegate, with the
+    // rental authority collecting its rent.
+    let collection = BaseCollectionV1::from_bytes(ctx.accounts.game.try_borrow_data()?.as_ref())?;
+    let rental_authority_seeds: &[&[u8]] = &[
+        RENTAL_PREFIX,
+        ctx.accounts.game.key.as_ref(),
+        &[rental_authority_bump],
+    ];
+    let lamports_before = ctx.accounts.rental_authority.lamports();
+    BurnV1Cpi {
+        __program: ctx.accounts.mpl_core_program,
+        asset: ctx.accounts.cartridge,
+        collection: Some(ctx.accounts.game),
+        payer: ctx.accounts.rental_authority,
+        authority: Some(ctx.accounts.game),
+        system_program: Some(ctx.accounts.system_program),
+        log_wrapper: None,
+        __args: BurnV1InstructionArgs {
+            compression_proof: None,
+        },
+    }
+    .invoke_signed(&[
+        &[
+            GAME_PREFIX,
+            collection.name.as_bytes(),
+            &[args.collection_nonce],
+            &[args.collection_bump],
+        ],
+        rental_authority_seeds,
+    ])?;
+
+    // The rent payer gets back what it paid for the print. Storage added
+    // since, like save data, goes to the account cranking the reclaim.
+    let rent = ctx
+        .accounts
+        .rental_authority
+        .lamports()
+        .saturating_sub(lamports_before);
+    let refund = rent.min(cartridge_data.rent_paid);
+    for (recipient, amount) in [
+        (ctx.accounts.rent_payer, refund),
+        (ctx.accounts.payer, rent - refund),
+    ] {
+        if amount > 0 {
+            invoke_signed(
+                &system_instruction::transfer(
+                    ctx.accounts.rental_authority.key,
+                    recipient.key,
+                    amount,
+                ),
+                &[
+                    ctx.accounts.rental_authority.clone(),
+                    recipient.clone(),
+                    ctx.accounts.system_program.clone(),
+                ],
+                &[rental_authority_seeds],
+            )?;
+        }
+    }
+
+    Ok(())
+}
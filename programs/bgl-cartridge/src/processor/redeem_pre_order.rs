@@ -0,0 +1,138 @@
+use bytemuck::{from_bytes, from_bytes_mut, Pod, Zeroable};
+use mpl_utils::{
+    assert_derivation, assert_owned_by, assert_signer, close_account_raw, cmp_pubkeys,
+};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, system_program,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::RedeemPreOrderV1Accounts,
+    processor::CartridgeMinter,
+    state::{assert_escrow, CartridgeData, Escrow, PreOrder, Source, PRE_ORDER_PREFIX},
+};
+
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub struct RedeemPreOrderV1Args {
+    #[skip]
+    /// The discriminator for the instruction
+    discriminator: u8,
+    /// The nonce for the game collection
+    collection_nonce: u8,
+    /// The bump for the game collection
+    collection_bump: u8,
+}
+
+impl RedeemPreOrderV1Accounts<'_> {
+    pub fn check(&self) -> ProgramResult {
+        let Self {
+            cartridge: _cartridge,
+            game,
+            escrow,
+            pre_order,
+            backer,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Cartridge
+        // SAFE: New mint so it can be anything.
+
+        // Game
+        // SAFE: Checked by Core when minting as the game PDA.
+
+        // Escrow
+        // Pre-orders become cartridges once the goal is met.
+        assert_escrow(escrow, game.key)?;
+        if !from_bytes::<Escrow>(&escrow.try_borrow_data()?).is_funded() {
+            return Err(BglCartridgeError::CrowdfundNotFunded.into());
+        }
+
+        // Pre-Order
+        assert_owned_by(pre_order, &crate::ID, BglCartridgeError::InvalidPreOrder)?;
+        assert_derivation(
+            &crate::ID,
+            pre_order,
+            &[PRE_ORDER_PREFIX, game.key.as_ref(), backer.key.as_ref()],
+            BglCartridgeError::InvalidPreOrder,
+        )?;
+
+        // Backer
+        assert_signer(backer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+pub fn redeem_pre_order<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = RedeemPreOrderV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    ctx.accounts.check()?;
+
+    let args: &RedeemPreOrderV1Args = from_bytes(args);
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Redeem one pre-order at a time, each for a new cartridge.
+    let mut pre_order_data = ctx.accounts.pre_order.try_borrow_mut_data()?;
+    let pre_order: &mut PreOrder = from_bytes_mut(&mut pre_order_data);
+    if pre_order.redeemed >= pre_order.quantity {
+        return Err(BglCartridgeError::InvalidPreOrder.into());
+    }
+    pre_order.redeemed += 1;
+    let PreOrder {
+        amount,
+        quantity,
+        redeemed,
+        ..
+    } = *pre_order;
+    drop(pre_order_data);
+
+    // Every cartridge is worth an equal part of the payment, the last one
+    // also takes what the split leaves over.
+    let mut purchase_price = amount / quantity as u64;
+    if redeemed == quantity {
+        purchase_price += amount % quantity as u64;
+    }
+
+    CartridgeMinter {
+        game: ctx.accounts.game,
+        payer: ctx.accounts.backer,
+        mpl_core_program: ctx.accounts.mpl_core_program,
+        system_program: ctx.accounts.system_program,
+    }
+    .mint(
+        ctx.accounts.cartridge,
+        ctx.accounts.backer,
+        args.collection_nonce,
+        args.collection_bump,
+        &CartridgeData::new(
+            Source::Unknown,
+            purchase_price,
+            Clock::get()?.unix_timestamp,
+        ),
+    )?;
+
+    // The position is settled once every pre-order has been redeemed.
+    if redeemed == quantity {
+        close_account_raw(ctx.accounts.backer, ctx.accounts.pre_order)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use bytemuck::{from_bytes, Pod, Zeroable};
+use mpl_core::{
+    accounts::BaseCollectionV1,
+    instructions::{BurnV1Cpi, BurnV1InstructionArgs},
+};
+use mpl_utils::{assert_signer, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program::invoke_signed,
+    system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::RefundCartridgeV1Accounts,
+    processor::{assert_game_token_account, assert_payer_token_account},
+    state::{assert_cartridge_of_game, has_been_inserted, CartridgeData, GAME_PREFIX},
+};
+
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub struct RefundCartridgeV1Args {
+    #[skip]
+    /// The discriminator for the instruction
+    discriminator: u8,
+    /// The nonce for the game collection
+    collection_nonce: u8,
+    /// The bump for the game collection
+    collection_bump: u8,
+}
+
+impl RefundCartridgeV1Accounts<'_> {
+    pub fn check(&self) -> ProgramResult {
+        let Self {
+            cartridge,
+            game,
+            game_token_account,
+            owner,
+            owner_token_account,
+            mpl_core_program,
+            token_program,
+            system_program,
+        } = self;
+
+        // Cartridge
+        // Must be a cartridge of the game held by the owner.
+        let asset = assert_cartridge_of_game(cartridge, game.key)?;
+
+        // Game
+        // SAFE: Checked by Core when burning as the game PDA.
+
+        // Game Token Account
+        assert_game_token_account(game, game_token_account)?;
+
+        // Owner
+        assert_signer(owner).map_err(|_| BglCartridgeError::CartridgeOwnerMustSign)?;
+        if asset.owner != *owner.key {
+            return Err(BglCartridgeError::CartridgeOwnerMustSign.into());
+        }
+
+        // Owner Token Account
+        assert_payer_token_account(owner, owner_token_account)?;
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // Token Program
+        if !cmp_pubkeys(token_program.key, &spl_token::ID) {
+            return Err(BglCartridgeError::InvalidTokenProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+pub fn refund_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = RefundCartridgeV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    ctx.accounts.check()?;
+
+    let args: &RefundCartridgeV1Args = from_bytes(args);
+
+    // Refunds follow the terms of the game when the cartridge was printed,
+    // later changes to its price type or refund window do not apply.
+    let cartridge_data = CartridgeData::fetch(ctx.accounts.cartridge)?;
+    cartridge_data.assert_not_rental()?;
+    let Some(refundable_until) = cartridge_data.refundable_until else {
+        return Err(BglCartridgeError::RefundsDisabled.into());
+    };
+
+    // Burning a lent cartridge would leave its loan open forever, it must be
+    // returned first.
+    if cartridge_data.lender.is_some() {
+        return Err(BglCartridgeError::CartridgeOnLoan.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if now >= refundable_until {
+        return Err(BglCartridgeError::RefundWindowClosed.into());
+    }
+
+    if has_been_inserted(ctx.accounts.cartridge)? {
+        return Err(BglCartridgeError::CartridgeAlreadyPlayed.into());
+    }
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    let collection = BaseCollectionV1::from_bytes(ctx.accounts.game.try_borrow_data()?.as_ref())?;
+    let signer_seeds: &[&[u8]] = &[
+        GAME_PREFIX,
+        collection.name.as_bytes(),
+        &[args.collection_nonce],
+        &[args.collection_bump],
+    ];
+
+    // Burn the cartridge through the game's permanent burn delegate, which
+    // overrides the permanent freeze. The rent goes back to the owner.
+    BurnV1Cpi {
+        __program: ctx.accounts.mpl_core_program,
+        asset: ctx.accounts.cartridge,
+        collection: Some(ctx.accounts.game),
+        payer: ctx.accounts.owner,
+        authority: Some(ctx.accounts.game),
+        system_program: Some(ctx.accounts.system_program),
+        log_wrapper: None,
+        __args: BurnV1InstructionArgs {
+            compression_proof: None,
+        },
+    }
+    .invoke_signed(&[signer_seeds])?;
+
+    // Pay back what the game received for the cartridge from the game token
+    // account.
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            ctx.accounts.token_program.key,
+            ctx.accounts.game_token_account.key,
+            ctx.accounts.owner_token_account.key,
+            ctx.accounts.game.key,
+            &[],
+            cartridge_data.purchase_price,
+        )?,
+        &[
+            ctx.accounts.game_token_account.clone(),
+            ctx.accounts.owner_token_account.clone(),
+            ctx.accounts.game.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    Ok(())
+}
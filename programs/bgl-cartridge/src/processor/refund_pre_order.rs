@@ -0,0 +1,97 @@
+use bytemuck::from_bytes;
+use mpl_utils::{assert_derivation, assert_owned_by, close_account_raw, cmp_pubkeys};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program::invoke_signed,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::RefundPreOrderV1Accounts,
+    processor::{assert_game_token_account, assert_payer_token_account},
+    state::{assert_escrow, Escrow, PreOrder, ESCROW_PREFIX, PRE_ORDER_PREFIX},
+};
+
+impl RefundPreOrderV1Accounts<'_> {
+    /// Returns the bump of the escrow.
+    pub fn check(&self) -> Result<u8, ProgramError> {
+        let Self {
+            game,
+            escrow,
+            escrow_token_account,
+            pre_order,
+            backer,
+            backer_token_account,
+            token_program,
+        } = self;
+
+        // Game
+        // SAFE: The escrow is derived from it.
+
+        // Escrow
+        // Refunds open once the deadline passes without meeting the goal.
+        let bump = assert_escrow(escrow, game.key)?;
+        if !from_bytes::<Escrow>(&escrow.try_borrow_data()?)
+            .has_failed(Clock::get()?.unix_timestamp)
+        {
+            return Err(BglCartridgeError::CrowdfundNotFailed.into());
+        }
+
+        // Escrow Token Account
+        assert_game_token_account(escrow, escrow_token_account)?;
+
+        // Pre-Order
+        assert_owned_by(pre_order, &crate::ID, BglCartridgeError::InvalidPreOrder)?;
+        assert_derivation(
+            &crate::ID,
+            pre_order,
+            &[PRE_ORDER_PREFIX, game.key.as_ref(), backer.key.as_ref()],
+            BglCartridgeError::InvalidPreOrder,
+        )?;
+
+        // Backer
+        // SAFE: Checked by the pre-order derivation. Anyone can crank the
+        // refund, the money only goes back to the backer.
+
+        // Backer Token Account
+        assert_payer_token_account(backer, backer_token_account)?;
+
+        // Token Program
+        if !cmp_pubkeys(token_program.key, &spl_token::ID) {
+            return Err(BglCartridgeError::InvalidTokenProgram.into());
+        }
+
+        Ok(bump)
+    }
+}
+
+pub fn refund_pre_order<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = RefundPreOrderV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    let bump = ctx.accounts.check()?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Pay the backer back in full and close the position.
+    let amount = from_bytes::<PreOrder>(&ctx.accounts.pre_order.try_borrow_data()?).amount;
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            ctx.accounts.token_program.key,
+            ctx.accounts.escrow_token_account.key,
+            ctx.accounts.backer_token_account.key,
+            ctx.accounts.escrow.key,
+            &[],
+            amount,
+        )?,
+        &[
+            ctx.accounts.escrow_token_account.clone(),
+            ctx.accounts.backer_token_account.clone(),
+            ctx.accounts.escrow.clone(),
+        ],
+        &[&[ESCROW_PREFIX, ctx.accounts.game.key.as_ref(), &[bump]]],
+    )?;
+
+    close_account_raw(ctx.accounts.backer, ctx.accounts.pre_order)
+}
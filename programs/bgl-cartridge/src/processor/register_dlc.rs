@@ -0,0 +1,124 @@
+use bytemuck::{from_bytes, Pod, Zeroable};
+use mpl_core::accounts::BaseCollectionV1;
+use mpl_utils::{assert_signer, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    system_program,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::RegisterDlcV1Accounts,
+    processor::write_game_data,
+    state::{assert_game_derivation, assert_publisher, GameCollectionData},
+};
+
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub struct RegisterDlcV1Args {
+    #[skip]
+    /// The discriminator for the instruction
+    discriminator: u8,
+    /// The nonce for the DLC game collection
+    collection_nonce: u8,
+    /// The bump for the DLC game collection
+    collection_bump: u8,
+    /// The nonce for the base game collection
+    base_collection_nonce: u8,
+    /// The bump for the base game collection
+    base_collection_bump: u8,
+}
+
+impl RegisterDlcV1Accounts<'_> {
+    pub fn check(
+        &self,
+        args: &RegisterDlcV1Args,
+        approvers: &[AccountInfo],
+    ) -> Result<GameCollectionData, ProgramError> {
+        let Self {
+            game,
+            base_game,
+            publisher,
+            payer,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Game
+        // SAFE: Checked by Core when writing as the game PDA.
+        let game_data = GameCollectionData::fetch(game)?;
+        if game_data.is_dlc() {
+            return Err(BglCartridgeError::DlcAlreadyRegistered.into());
+        }
+
+        // Cartridges printed before would stay playable on their own.
+        let collection = BaseCollectionV1::from_bytes(game.try_borrow_data()?.as_ref())?;
+        if collection.num_minted > 0 {
+            return Err(BglCartridgeError::GameAlreadyPrinted.into());
+        }
+
+        // Publisher
+        // Only the publisher recorded at release can register the DLC.
+        assert_publisher(&game_data.publisher, publisher, approvers)?;
+
+        // Base Game
+        // A released game of the same publisher, which is not a DLC itself.
+        if cmp_pubkeys(base_game.key, game.key) {
+            return Err(BglCartridgeError::InvalidBaseGame.into());
+        }
+
+        assert_game_derivation(
+            base_game,
+            args.base_collection_nonce,
+            args.base_collection_bump,
+        )?;
+
+        let base_game_data = GameCollectionData::fetch(base_game)?;
+        if base_game_data.publisher != *publisher.key || base_game_data.is_dlc() {
+            return Err(BglCartridgeError::InvalidBaseGame.into());
+        }
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(game_data)
+    }
+}
+
+pub fn register_dlc<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = RegisterDlcV1Accounts::context(accounts)?;
+
+    let args: &RegisterDlcV1Args = from_bytes(args);
+
+    // All account guards and validations happen here. The remaining accounts
+    // are the team members approving the DLC when a team publishes the game.
+    let mut game_data = ctx.accounts.check(args, ctx.remaining_accounts)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Link the game to its base game. From now on its cartridges can only be
+    // printed through PrintDlcV1.
+    game_data.base_game = *ctx.accounts.base_game.key;
+
+    write_game_data(
+        ctx.accounts.game,
+        ctx.accounts.payer,
+        ctx.accounts.mpl_core_program,
+        ctx.accounts.system_program,
+        args.collection_nonce,
+        args.collection_bump,
+        &mut game_data,
+    )
+}
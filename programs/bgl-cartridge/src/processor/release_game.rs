@@ -1,3 +1,5 @@
+use borsh::BorshDeserialize;
+use bytemuck::from_bytes_mut;
 use mpl_core::{
     instructions::{
         CreateCollectionV2Cpi, CreateCollectionV2InstructionArgs,
@@ -10,19 +12,27 @@ use mpl_core::{
         PluginAuthorityPair, Royalties, RuleSet,
     },
 };
-use mpl_utils::{assert_derivation, assert_owned_by, assert_signer, cmp_pubkeys};
+use mpl_utils::{
+    assert_derivation, assert_owned_by, assert_signer, cmp_pubkeys, create_or_allocate_account_raw,
+};
 use shank::ShankType;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke,
-    program_error::ProgramError, program_pack::Pack, pubkey, system_program,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program::invoke,
+    program_error::ProgramError, program_pack::Pack, pubkey, pubkey::Pubkey, system_program,
+    sysvar::Sysvar,
 };
 use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
 use spl_token::state::Account as SplTokenAccount;
 
 use crate::{
     error::BglCartridgeError,
-    instruction::accounts::ReleaseGameV1Accounts,
-    state::{GameCollectionData, PriceType, GAME_PREFIX, PAYMENT_TOKEN_MINT},
+    instruction::accounts::{ReleaseGameV1Accounts, ReleaseGameV2Accounts},
+    processor::{append_catalog_entry, create_name_record},
+    state::{
+        assert_catalog, assert_sale_window, fetch_name_record, fetch_team, normalize_name,
+        CrowdfundTerms, Escrow, GameCollectionData, PriceType, PublisherTeam, ESCROW_PREFIX,
+        GAME_COLLECTION_DATA_VERSION, GAME_PREFIX, NAME_PREFIX, PAYMENT_TOKEN_MINT,
+    },
 };
 
 #[repr(C)]
@@ -33,6 +43,9 @@ pub struct ReleaseGameV1Args {
     nonce: u8,
     price_type: PriceType,
     price: u64,
+    sale_start: Option<i64>,
+    sale_end: Option<i64>,
+    crowdfund: Option<CrowdfundTerms>,
 }
 
 impl ReleaseGameV1Args {
@@ -49,6 +62,16 @@ impl ReleaseGameV1Args {
             return Err(BglCartridgeError::InvalidUri.into());
         }
 
+        // Sale Window
+        assert_sale_window(self.sale_start, self.sale_end)?;
+
+        // Crowdfund
+        if let Some(crowdfund) = self.crowdfund {
+            if crowdfund.goal == 0 || crowdfund.deadline <= Clock::get()?.unix_timestamp {
+                return Err(BglCartridgeError::InvalidCrowdfundTerms.into());
+            }
+        }
+
         Ok(())
     }
 }
@@ -98,6 +121,25 @@ impl ReleaseGameV1Args {
                 .try_into()
                 .map_err(|_| ProgramError::InvalidInstructionData)?,
         );
+        offset += 8;
+
+        // Read the sale window, older clients don't send it.
+        let mut sale_start = None;
+        let mut sale_end = None;
+        let mut reader = &input[offset..];
+        if !reader.is_empty() {
+            sale_start = Option::<i64>::deserialize(&mut reader)
+                .map_err(|_| ProgramError::InvalidInstructionData)?;
+            sale_end = Option::<i64>::deserialize(&mut reader)
+                .map_err(|_| ProgramError::InvalidInstructionData)?;
+        }
+
+        // Read the crowdfund terms, older clients don't send them.
+        let mut crowdfund = None;
+        if !reader.is_empty() {
+            crowdfund = Option::<CrowdfundTerms>::deserialize(&mut reader)
+                .map_err(|_| ProgramError::InvalidInstructionData)?;
+        }
 
         Ok(Self {
             name,
@@ -105,11 +147,45 @@ impl ReleaseGameV1Args {
             nonce,
             price_type,
             price,
+            sale_start,
+            sale_end,
+            crowdfund,
         })
     }
 }
 
-impl ReleaseGameV1Accounts<'_> {
+/// Builds the `GameReleaser` of a release instruction.
+macro_rules! game_releaser {
+    ($accounts:expr) => {
+        GameReleaser {
+            game: $accounts.game,
+            game_token_account: $accounts.game_token_account,
+            payer: $accounts.payer,
+            authority: $accounts.authority,
+            payment_mint: $accounts.payment_mint,
+            mpl_core_program: $accounts.mpl_core_program,
+            token_program: $accounts.token_program,
+            associated_token_program: $accounts.associated_token_program,
+            system_program: $accounts.system_program,
+        }
+    };
+}
+
+/// The accounts every release instruction uses to create the game.
+pub(crate) struct GameReleaser<'a> {
+    pub game: &'a AccountInfo<'a>,
+    pub game_token_account: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub authority: Option<&'a AccountInfo<'a>>,
+    pub payment_mint: &'a AccountInfo<'a>,
+    pub mpl_core_program: &'a AccountInfo<'a>,
+    pub token_program: &'a AccountInfo<'a>,
+    pub associated_token_program: &'a AccountInfo<'a>,
+    pub system_program: &'a AccountInfo<'a>,
+}
+
+impl<'a> GameReleaser<'a> {
+    /// Returns the bump of the game.
     pub fn check(&self, args: &ReleaseGameV1Args) -> Result<u8, ProgramError> {
         let Self {
             game,
@@ -171,6 +247,167 @@ impl ReleaseGameV1Accounts<'_> {
 
         Ok(bump)
     }
+
+    /// Creates the game collection, writes its game data and creates the game
+    /// token account.
+    pub fn release(
+        &self,
+        args: &ReleaseGameV1Args,
+        bump: u8,
+        publisher: &Pubkey,
+        creators: Vec<Creator>,
+    ) -> ProgramResult {
+        // Create the Game
+        CreateCollectionV2Cpi {
+            __program: self.mpl_core_program,
+            collection: self.game,
+            update_authority: Some(self.game),
+            payer: self.payer,
+            system_program: self.system_program,
+            __args: CreateCollectionV2InstructionArgs {
+                name: args.name.clone(),
+                uri: args.uri.clone(),
+                plugins: Some(vec![
+                    PluginAuthorityPair {
+                        plugin: Plugin::MasterEdition(MasterEdition {
+                            max_supply: None,
+                            name: None,
+                            uri: None,
+                        }),
+                        authority: None,
+                    },
+                    PluginAuthorityPair {
+                        plugin: Plugin::Royalties(Royalties {
+                            basis_points: 500, // 5%
+                            creators,
+                            rule_set: RuleSet::None,
+                        }),
+                        authority: None,
+                    },
+                    // Permanent delegates for institutional AML compliance.
+                    PluginAuthorityPair {
+                        plugin: Plugin::PermanentTransferDelegate(PermanentTransferDelegate {}),
+                        authority: Some(PluginAuthority::UpdateAuthority),
+                    },
+                    PluginAuthorityPair {
+                        plugin: Plugin::PermanentBurnDelegate(PermanentBurnDelegate {}),
+                        authority: Some(PluginAuthority::UpdateAuthority),
+                    },
+                ]),
+                external_plugin_adapters: Some(vec![ExternalPluginAdapterInitInfo::LinkedAppData(
+                    LinkedAppDataInitInfo {
+                        data_authority: PluginAuthority::UpdateAuthority,
+                        init_plugin_authority: None,
+                        schema: None,
+                    },
+                )]),
+            },
+        }
+        .invoke_signed(&[&[GAME_PREFIX, args.name.as_bytes(), &[args.nonce], &[bump]]])?;
+
+        // Write basic Game data to the collection.
+        let data = GameCollectionData {
+            version: GAME_COLLECTION_DATA_VERSION,
+            price_type: args.price_type as u8,
+            price: args.price,
+            publisher: *publisher,
+            compatible_machine_collections: vec![],
+            sale_start: args.sale_start,
+            sale_end: args.sale_end,
+            crowdfund_deadline: args.crowdfund.map(|crowdfund| crowdfund.deadline),
+            ..Default::default()
+        };
+        WriteCollectionExternalPluginAdapterDataV1Cpi {
+            __program: self.mpl_core_program,
+            collection: self.game,
+            payer: self.payer,
+            authority: Some(self.game),
+            buffer: None,
+            system_program: self.system_program,
+            log_wrapper: None,
+            __args: WriteCollectionExternalPluginAdapterDataV1InstructionArgs {
+                key: ExternalPluginAdapterKey::LinkedAppData(PluginAuthority::UpdateAuthority),
+                data: Some(borsh::to_vec(&data)?),
+            },
+        }
+        .invoke_signed(&[&[GAME_PREFIX, args.name.as_bytes(), &[args.nonce], &[bump]]])?;
+
+        // Create the game token account
+        invoke(
+            &create_associated_token_account_idempotent(
+                self.payer.key,
+                self.game.key,
+                &PAYMENT_TOKEN_MINT,
+                self.token_program.key,
+            ),
+            &[
+                self.payer.clone(),
+                self.game.clone(),
+                self.game_token_account.clone(),
+                self.payment_mint.clone(),
+                self.token_program.clone(),
+                self.system_program.clone(),
+            ],
+        )
+    }
+}
+
+/// The creators sharing the royalties of a game: the publisher, split between
+/// the members by revenue share when a team publishes the game.
+fn royalty_creators(
+    publisher: &Pubkey,
+    team: Option<&PublisherTeam>,
+) -> Result<Vec<Creator>, ProgramError> {
+    let mut creators = match team {
+        Some(team) => team
+            .members()
+            .iter()
+            .zip(team.split_revenue(90)?)
+            .filter(|(_, percentage)| *percentage > 0)
+            .map(|(member, percentage)| Creator {
+                address: member.address,
+                percentage: percentage as u8,
+            })
+            .collect(),
+        None => vec![Creator {
+            address: *publisher,
+            percentage: 90,
+        }],
+    };
+    creators.push(Creator {
+        address: pubkey!("GmbntHsucposYsgj7TE4GeMCjJAU39YcRcSZgPr6jMh7"),
+        percentage: 10,
+    });
+
+    Ok(creators)
+}
+
+/// Returns the bump of the name record of a release. The name belongs to the
+/// first publisher releasing under it, or to the publisher it was reserved for
+/// or transferred to.
+fn assert_name_record(
+    name_record: &AccountInfo,
+    name: &str,
+    publisher: &Pubkey,
+) -> Result<u8, ProgramError> {
+    let bump = assert_derivation(
+        &crate::ID,
+        name_record,
+        &[NAME_PREFIX, normalize_name(name).as_bytes()],
+        BglCartridgeError::InvalidNameRecord,
+    )?;
+
+    if !name_record.data_is_empty() && fetch_name_record(name_record)?.owner != *publisher {
+        return Err(BglCartridgeError::NameTaken.into());
+    }
+
+    Ok(bump)
+}
+
+impl<'a> ReleaseGameV1Accounts<'a> {
+    pub(crate) fn releaser(&self) -> GameReleaser<'a> {
+        game_releaser!(self)
+    }
 }
 
 pub fn release_game<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
@@ -178,7 +415,8 @@ pub fn release_game<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> Program
 
     let args = ReleaseGameV1Args::unpack(args)?;
     args.check()?;
-    let bump = ctx.accounts.check(&args)?;
+    let releaser = ctx.accounts.releaser();
+    let bump = releaser.check(&args)?;
 
     /*****************************************************/
     /****************** Argument Guards ******************/
@@ -191,105 +429,205 @@ pub fn release_game<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> Program
         return Err(BglCartridgeError::InvalidUri.into());
     }
 
+    // Crowdfunding needs the escrow accounts of V2.
+    if args.crowdfund.is_some() {
+        return Err(BglCartridgeError::InvalidEscrow.into());
+    }
+
+    // Remaining Accounts
+    // The name record of the game name, which V1 cannot release under when
+    // another publisher holds it, then the catalog of the publisher.
+    let publisher = releaser.authority.unwrap_or(releaser.payer).key;
+    let [name_record, catalog, ..] = ctx.remaining_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    assert_name_record(name_record, &args.name, publisher)?;
+    let catalog_bump = assert_catalog(catalog, publisher)?;
+
     /*********************************************/
     /****************** Actions ******************/
     /*********************************************/
-    // Create the Game
-    CreateCollectionV2Cpi {
-        __program: ctx.accounts.mpl_core_program,
-        collection: ctx.accounts.game,
-        update_authority: Some(ctx.accounts.game),
-        payer: ctx.accounts.payer,
-        system_program: ctx.accounts.system_program,
-        __args: CreateCollectionV2InstructionArgs {
-            name: args.name.clone(),
-            uri: args.uri,
-            plugins: Some(vec![
-                PluginAuthorityPair {
-                    plugin: Plugin::MasterEdition(MasterEdition {
-                        max_supply: None,
-                        name: None,
-                        uri: None,
-                    }),
-                    authority: None,
-                },
-                PluginAuthorityPair {
-                    plugin: Plugin::Royalties(Royalties {
-                        basis_points: 500, // 5%
-                        creators: vec![
-                            Creator {
-                                address: *ctx.accounts.authority.unwrap_or(ctx.accounts.payer).key,
-                                percentage: 90,
-                            },
-                            Creator {
-                                address: pubkey!("GmbntHsucposYsgj7TE4GeMCjJAU39YcRcSZgPr6jMh7"),
-                                percentage: 10,
-                            },
-                        ],
-                        rule_set: RuleSet::None,
-                    }),
-                    authority: None,
-                },
-                // Permanent delegates for institutional AML compliance.
-                PluginAuthorityPair {
-                    plugin: Plugin::PermanentTransferDelegate(PermanentTransferDelegate {}),
-                    authority: Some(PluginAuthority::UpdateAuthority),
-                },
-                PluginAuthorityPair {
-                    plugin: Plugin::PermanentBurnDelegate(PermanentBurnDelegate {}),
-                    authority: Some(PluginAuthority::UpdateAuthority),
-                },
-            ]),
-            external_plugin_adapters: Some(vec![ExternalPluginAdapterInitInfo::LinkedAppData(
-                LinkedAppDataInitInfo {
-                    data_authority: PluginAuthority::UpdateAuthority,
-                    init_plugin_authority: None,
-                    schema: None,
-                },
-            )]),
-        },
+    releaser.release(&args, bump, publisher, royalty_creators(publisher, None)?)?;
+
+    // List the game in the catalog of the publisher.
+    append_catalog_entry(
+        catalog,
+        releaser.payer,
+        releaser.system_program,
+        publisher,
+        catalog_bump,
+        releaser.game.key,
+    )
+}
+
+/// The bumps of the accounts created when releasing a game.
+pub struct ReleaseGameBumps {
+    pub game: u8,
+    /// Only set when crowdfunding the game.
+    pub escrow: Option<u8>,
+    pub name_record: u8,
+    pub catalog: u8,
+}
+
+impl<'a> ReleaseGameV2Accounts<'a> {
+    pub(crate) fn releaser(&self) -> GameReleaser<'a> {
+        game_releaser!(self)
     }
-    .invoke_signed(&[&[GAME_PREFIX, args.name.as_bytes(), &[args.nonce], &[bump]]])?;
-
-    // Write basic Game data to the collection.
-    let data = GameCollectionData {
-        version: 0,
-        price_type: args.price_type as u8,
-        price: args.price,
-        publisher: *ctx.accounts.authority.unwrap_or(ctx.accounts.payer).key,
-    };
-    WriteCollectionExternalPluginAdapterDataV1Cpi {
-        __program: ctx.accounts.mpl_core_program,
-        collection: ctx.accounts.game,
-        payer: ctx.accounts.payer,
-        authority: Some(ctx.accounts.game),
-        buffer: None,
-        system_program: ctx.accounts.system_program,
-        log_wrapper: None,
-        __args: WriteCollectionExternalPluginAdapterDataV1InstructionArgs {
-            key: ExternalPluginAdapterKey::LinkedAppData(PluginAuthority::UpdateAuthority),
-            data: Some(borsh::to_vec(&data)?),
-        },
+
+    /// The publisher recorded for the game: the team, else the authority, else
+    /// the payer.
+    fn publisher(&self) -> &'a AccountInfo<'a> {
+        self.team.or(self.authority).unwrap_or(self.payer)
+    }
+
+    pub fn check(
+        &self,
+        args: &ReleaseGameV1Args,
+        approvers: &[AccountInfo],
+    ) -> Result<ReleaseGameBumps, ProgramError> {
+        // Game, Game Token Account, Payer, Authority, Payment Mint and Programs
+        let bump = self.releaser().check(args)?;
+
+        let Self {
+            game,
+            escrow,
+            escrow_token_account,
+            team,
+            name_record,
+            catalog,
+            ..
+        } = self;
+
+        // Escrow and Escrow Token Account
+        // Only needed to crowdfund the game.
+        let escrow_bump = match (args.crowdfund, escrow, escrow_token_account) {
+            (None, _, _) => None,
+            (Some(_), Some(escrow), Some(_escrow_token_account)) => {
+                let escrow_bump = assert_derivation(
+                    &crate::ID,
+                    escrow,
+                    &[ESCROW_PREFIX, game.key.as_ref()],
+                    BglCartridgeError::InvalidEscrow,
+                )?;
+                // SAFE: The escrow token account is checked by
+                // CreateAssociatedTokenAccountIdempotent.
+                Some(escrow_bump)
+            }
+            _ => return Err(BglCartridgeError::InvalidEscrow.into()),
+        };
+
+        // Team
+        // Publishing as a team needs the approval of its members.
+        if let Some(team) = team {
+            fetch_team(team)?.assert_approved(approvers)?;
+        }
+
+        // Name Record
+        let name_bump = assert_name_record(name_record, &args.name, self.publisher().key)?;
+
+        // Catalog
+        let catalog_bump = assert_catalog(catalog, self.publisher().key)?;
+
+        Ok(ReleaseGameBumps {
+            game: bump,
+            escrow: escrow_bump,
+            name_record: name_bump,
+            catalog: catalog_bump,
+        })
+    }
+}
+
+pub fn release_game_v2<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = ReleaseGameV2Accounts::context(accounts)?;
+
+    let args = ReleaseGameV1Args::unpack(args)?;
+    args.check()?;
+    // The remaining accounts are the team members approving the release when
+    // a team publishes the game.
+    let bumps = ctx.accounts.check(&args, ctx.remaining_accounts)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    let publisher = ctx.accounts.publisher().key;
+    let team = ctx.accounts.team.map(fetch_team).transpose()?;
+    ctx.accounts.releaser().release(
+        &args,
+        bumps.game,
+        publisher,
+        royalty_creators(publisher, team.as_ref())?,
+    )?;
+
+    // Claim the name for the publisher on its first release.
+    if ctx.accounts.name_record.data_is_empty() {
+        create_name_record(
+            ctx.accounts.name_record,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            &args.name,
+            bumps.name_record,
+            publisher,
+        )?;
     }
-    .invoke_signed(&[&[GAME_PREFIX, args.name.as_bytes(), &[args.nonce], &[bump]]])?;
-
-    // Create the game token account
-    invoke(
-        &create_associated_token_account_idempotent(
-            ctx.accounts.payer.key,
-            ctx.accounts.game.key,
-            &PAYMENT_TOKEN_MINT,
-            ctx.accounts.token_program.key,
-        ),
-        &[
-            ctx.accounts.payer.clone(),
-            ctx.accounts.game.clone(),
-            ctx.accounts.game_token_account.clone(),
-            ctx.accounts.payment_mint.clone(),
-            ctx.accounts.token_program.clone(),
-            ctx.accounts.system_program.clone(),
-        ],
+
+    // List the game in the catalog of the publisher.
+    append_catalog_entry(
+        ctx.accounts.catalog,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        publisher,
+        bumps.catalog,
+        ctx.accounts.game.key,
     )?;
 
+    // Open the escrow holding pre-order payments until the goal is met or
+    // missed.
+    if let (Some(crowdfund), Some(escrow_bump), Some(escrow), Some(escrow_token_account)) = (
+        args.crowdfund,
+        bumps.escrow,
+        ctx.accounts.escrow,
+        ctx.accounts.escrow_token_account,
+    ) {
+        create_or_allocate_account_raw(
+            crate::ID,
+            escrow,
+            ctx.accounts.system_program,
+            ctx.accounts.payer,
+            core::mem::size_of::<Escrow>(),
+            &[
+                ESCROW_PREFIX,
+                ctx.accounts.game.key.as_ref(),
+                &[escrow_bump],
+            ],
+        )?;
+
+        let mut escrow_data = escrow.try_borrow_mut_data()?;
+        let escrow_state: &mut Escrow = from_bytes_mut(&mut escrow_data);
+        *escrow_state = Escrow {
+            game: *ctx.accounts.game.key,
+            goal: crowdfund.goal,
+            deadline: crowdfund.deadline,
+            raised: 0,
+        };
+        drop(escrow_data);
+
+        invoke(
+            &create_associated_token_account_idempotent(
+                ctx.accounts.payer.key,
+                escrow.key,
+                &PAYMENT_TOKEN_MINT,
+                ctx.accounts.token_program.key,
+            ),
+            &[
+                ctx.accounts.payer.clone(),
+                escrow.clone(),
+                escrow_token_account.clone(),
+                ctx.accounts.payment_mint.clone(),
+                ctx.accounts.token_program.clone(),
+                ctx.accounts.system_program.clone(),
+            ],
+        )?;
+    }
+
     Ok(())
 }
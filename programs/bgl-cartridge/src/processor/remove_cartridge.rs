@@ -1,7 +1,6 @@
 use bytemuck::{from_bytes, Pod, Zeroable};
 use mpl_core::{
     accounts::{BaseAssetV1, BaseCollectionV1},
-    fetch_external_plugin_adapter_data_info,
     instructions::{
         WriteExternalPluginAdapterDataV1Cpi, WriteExternalPluginAdapterDataV1InstructionArgs,
     },
@@ -10,14 +9,14 @@ use mpl_core::{
 use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys};
 use shank::ShankType;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
-    system_program,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, system_program, sysvar::Sysvar,
 };
 
 use crate::{
     error::BglCartridgeError,
-    instruction::accounts::RemoveCartridgeV1Accounts,
-    state::{GAME_PREFIX, MACHINE_PREFIX},
+    instruction::accounts::{RemoveCartridgeV1Accounts, RemoveCartridgeV2Accounts},
+    state::{MachineData, GAME_PREFIX, MACHINE_PREFIX},
 };
 
 #[repr(C)]
@@ -32,8 +31,41 @@ pub struct RemoveCartridgeV1Args {
     collection_bump: u8,
 }
 
-impl RemoveCartridgeV1Accounts<'_> {
-    pub fn check(&self) -> Result<(u8, String), ProgramError> {
+/// Builds the `CartridgeRemover` of a remove instruction.
+macro_rules! cartridge_remover {
+    ($accounts:expr, operator: $operator:expr) => {
+        CartridgeRemover {
+            cartridge: $accounts.cartridge,
+            game: $accounts.game,
+            cartridge_owner: $accounts.cartridge_owner,
+            machine: $accounts.machine,
+            machine_collection: $accounts.machine_collection,
+            machine_owner: $accounts.machine_owner,
+            mpl_core_program: $accounts.mpl_core_program,
+            system_program: $accounts.system_program,
+            operator: $operator,
+        }
+    };
+}
+
+/// The accounts every remove instruction uses to unlink the cartridge and the
+/// machine.
+struct CartridgeRemover<'a> {
+    cartridge: &'a AccountInfo<'a>,
+    game: &'a AccountInfo<'a>,
+    cartridge_owner: &'a AccountInfo<'a>,
+    machine: &'a AccountInfo<'a>,
+    machine_collection: &'a AccountInfo<'a>,
+    machine_owner: &'a AccountInfo<'a>,
+    mpl_core_program: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    /// Only set when the machine operator signs in place of the cartridge
+    /// owner.
+    operator: Option<&'a AccountInfo<'a>>,
+}
+
+impl CartridgeRemover<'_> {
+    fn check(&self) -> Result<(u8, String, MachineData), ProgramError> {
         // Cartridge
         // The cartridge owner must sign AND actually own the asset; with the
         // permanent freeze delegate gating transfers, no mpl-core CPI in this
@@ -47,40 +79,44 @@ impl RemoveCartridgeV1Accounts<'_> {
         // SAFE: Checked by Core
 
         // Cartridge Owner
-        assert_signer(self.cartridge_owner)
-            .map_err(|_| BglCartridgeError::CartridgeOwnerMustSign)?;
+        // The cartridge owner must sign unless the machine operator signs in
+        // their place.
+        if self.operator.is_none() {
+            assert_signer(self.cartridge_owner)
+                .map_err(|_| BglCartridgeError::CartridgeOwnerMustSign)?;
+        }
 
         // Machine
-        let machine_name = BaseAssetV1::from_bytes(self.machine.try_borrow_data()?.as_ref())?.name;
+        let machine_asset = BaseAssetV1::from_bytes(self.machine.try_borrow_data()?.as_ref())?;
         let bump = assert_derivation(
             &crate::ID,
             self.machine,
             &[
                 MACHINE_PREFIX,
                 self.machine_collection.key.as_ref(),
-                machine_name.as_bytes(),
+                machine_asset.name.as_bytes(),
             ],
             BglCartridgeError::InvalidMachinePdaDerivation,
         )?;
 
-        // Check if a cartridge is inserted into the machine.
-        let (_, length) = fetch_external_plugin_adapter_data_info::<BaseAssetV1>(
-            self.machine,
-            None,
-            &ExternalPluginAdapterKey::AppData(PluginAuthority::Address {
-                address: *self.machine.key,
-            }),
-        )?;
-
-        if length == 0 {
-            return Err(BglCartridgeError::CartridgeNotInserted.into());
-        }
+        // Check that the machine and the cartridge point at each other.
+        let machine_data = MachineData::fetch(self.machine)?;
+        machine_data.assert_inserted(self.machine, self.cartridge)?;
 
         // Machine Collection
         // SAFE: Checked by Core
 
         // Machine Owner
-        // SAFE: No need to check
+        // Only needs to co-sign while the session is locked.
+        if machine_data
+            .session_lock
+            .is_active(Clock::get()?.unix_timestamp)
+        {
+            assert_signer(self.machine_owner).map_err(|_| BglCartridgeError::SessionLocked)?;
+            if machine_asset.owner != *self.machine_owner.key {
+                return Err(BglCartridgeError::SessionLocked.into());
+            }
+        }
 
         // MPL Core Program
         if !cmp_pubkeys(self.mpl_core_program.key, &mpl_core::ID) {
@@ -92,15 +128,43 @@ impl RemoveCartridgeV1Accounts<'_> {
             return Err(BglCartridgeError::InvalidSystemProgram.into());
         }
 
-        Ok((bump, machine_name))
+        // Operator
+        // The operator can only remove cartridges the machine owner has
+        // custody of.
+        if let Some(operator) = self.operator {
+            assert_signer(operator).map_err(|_| BglCartridgeError::InvalidMachineOperator)?;
+            if !machine_data.is_operator(operator.key) {
+                return Err(BglCartridgeError::InvalidMachineOperator.into());
+            }
+            if cartridge_asset.owner != machine_asset.owner {
+                return Err(BglCartridgeError::CartridgeNotInMachineOwnerCustody.into());
+            }
+        }
+
+        Ok((bump, machine_asset.name, machine_data))
     }
 }
 
 pub fn remove_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
     let ctx = RemoveCartridgeV1Accounts::context(accounts)?;
 
+    // The cartridge owner signs V1 removes.
+    remove(&cartridge_remover!(ctx.accounts, operator: None), args)
+}
+
+pub fn remove_cartridge_v2<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = RemoveCartridgeV2Accounts::context(accounts)?;
+
+    remove(
+        &cartridge_remover!(ctx.accounts, operator: ctx.accounts.operator),
+        args,
+    )
+}
+
+/// Removes the cartridge from the machine.
+fn remove(remover: &CartridgeRemover, args: &[u8]) -> ProgramResult {
     // All account guards and validations happen here.
-    let (machine_bump, machine_name) = ctx.accounts.check()?;
+    let (machine_bump, machine_name, mut machine_data) = remover.check()?;
 
     let args: &RemoveCartridgeV1Args = from_bytes(args);
 
@@ -113,17 +177,20 @@ pub fn remove_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> Pro
     // The cartridge stays in whatever frozen state it was — the game operator
     // unfreezes individual cartridges on a case-by-case basis via the
     // PermanentFreezeDelegate.
+    // Whoever signed (the cartridge owner or the machine operator) pays for
+    // any storage growth.
+    let signer = remover.operator.unwrap_or(remover.cartridge_owner);
 
     // Clear the cartridge AppData.
-    let collection = BaseCollectionV1::from_bytes(ctx.accounts.game.try_borrow_data()?.as_ref())?;
+    let collection = BaseCollectionV1::from_bytes(remover.game.try_borrow_data()?.as_ref())?;
     WriteExternalPluginAdapterDataV1Cpi {
-        __program: ctx.accounts.mpl_core_program,
-        asset: ctx.accounts.cartridge,
-        collection: Some(ctx.accounts.game),
-        payer: ctx.accounts.cartridge_owner,
-        authority: Some(ctx.accounts.game),
+        __program: remover.mpl_core_program,
+        asset: remover.cartridge,
+        collection: Some(remover.game),
+        payer: signer,
+        authority: Some(remover.game),
         buffer: None,
-        system_program: ctx.accounts.system_program,
+        system_program: remover.system_program,
         log_wrapper: None,
         __args: WriteExternalPluginAdapterDataV1InstructionArgs {
             key: ExternalPluginAdapterKey::LinkedAppData(PluginAuthority::UpdateAuthority),
@@ -137,26 +204,27 @@ pub fn remove_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> Pro
         &[args.collection_bump],
     ]])?;
 
-    // Clear the machine AppData.
+    // Move the insertion from the machine's current slot to its history.
+    machine_data.remove(Clock::get()?.unix_timestamp);
     WriteExternalPluginAdapterDataV1Cpi {
-        __program: ctx.accounts.mpl_core_program,
-        asset: ctx.accounts.machine,
-        collection: Some(ctx.accounts.machine_collection),
-        payer: ctx.accounts.machine_owner,
-        authority: Some(ctx.accounts.machine),
+        __program: remover.mpl_core_program,
+        asset: remover.machine,
+        collection: Some(remover.machine_collection),
+        payer: signer,
+        authority: Some(remover.machine),
         buffer: None,
-        system_program: ctx.accounts.system_program,
+        system_program: remover.system_program,
         log_wrapper: None,
         __args: WriteExternalPluginAdapterDataV1InstructionArgs {
             key: ExternalPluginAdapterKey::AppData(PluginAuthority::Address {
-                address: *ctx.accounts.machine.key,
+                address: *remover.machine.key,
             }),
-            data: Some(vec![]),
+            data: Some(borsh::to_vec(&machine_data)?),
         },
     }
     .invoke_signed(&[&[
         MACHINE_PREFIX,
-        ctx.accounts.machine_collection.key.as_ref(),
+        remover.machine_collection.key.as_ref(),
         machine_name.as_bytes(),
         &[machine_bump],
     ]])?;
@@ -0,0 +1,143 @@
+use mpl_core::{
+    accounts::BaseAssetV1,
+    instructions::{
+        WriteExternalPluginAdapterDataV1Cpi, WriteExternalPluginAdapterDataV1InstructionArgs,
+    },
+    types::{ExternalPluginAdapterKey, PluginAuthority},
+};
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::RemovePassV1Accounts,
+    processor::write_pass_link,
+    state::{
+        assert_pass_collection_derivation, assert_pass_of_collection, MachineData, MACHINE_PREFIX,
+    },
+};
+
+impl RemovePassV1Accounts<'_> {
+    /// Returns the pass collection bump, the machine bump, name and data.
+    pub fn check(&self) -> Result<(u8, u8, String, MachineData), ProgramError> {
+        let Self {
+            pass,
+            pass_collection,
+            authority,
+            machine,
+            machine_collection,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Pass
+        let pass_asset = assert_pass_of_collection(pass, pass_collection.key)?;
+
+        // Pass Collection
+        let pass_collection_bump = assert_pass_collection_derivation(pass_collection)?;
+
+        // Machine
+        let machine_asset = BaseAssetV1::from_bytes(machine.try_borrow_data()?.as_ref())?;
+        let machine_bump = assert_derivation(
+            &crate::ID,
+            machine,
+            &[
+                MACHINE_PREFIX,
+                machine_collection.key.as_ref(),
+                machine_asset.name.as_bytes(),
+            ],
+            BglCartridgeError::InvalidMachinePdaDerivation,
+        )?;
+
+        // Check that the machine and the pass point at each other.
+        let machine_data = MachineData::fetch(machine)?;
+        machine_data.assert_inserted(machine, pass)?;
+
+        // Machine Collection
+        // SAFE: Checked by Core
+
+        // Authority
+        // The machine owner or its operator can always take a pass out, the
+        // pass owner only outside of a locked session.
+        assert_signer(authority).map_err(|_| BglCartridgeError::AuthorityMustSign)?;
+        if machine_asset.owner != *authority.key && !machine_data.is_operator(authority.key) {
+            if pass_asset.owner != *authority.key {
+                return Err(BglCartridgeError::CartridgeOwnerMustSign.into());
+            }
+
+            if machine_data
+                .session_lock
+                .is_active(Clock::get()?.unix_timestamp)
+            {
+                return Err(BglCartridgeError::SessionLocked.into());
+            }
+        }
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok((
+            pass_collection_bump,
+            machine_bump,
+            machine_asset.name,
+            machine_data,
+        ))
+    }
+}
+
+pub fn remove_pass<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = RemovePassV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    let (pass_collection_bump, machine_bump, machine_name, mut machine_data) =
+        ctx.accounts.check()?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Clear the link on both sides, moving the insertion to the machine's
+    // history.
+    write_pass_link(
+        ctx.accounts.pass,
+        ctx.accounts.pass_collection,
+        ctx.accounts.authority,
+        ctx.accounts.mpl_core_program,
+        ctx.accounts.system_program,
+        pass_collection_bump,
+        vec![],
+    )?;
+
+    machine_data.remove(Clock::get()?.unix_timestamp);
+    WriteExternalPluginAdapterDataV1Cpi {
+        __program: ctx.accounts.mpl_core_program,
+        asset: ctx.accounts.machine,
+        collection: Some(ctx.accounts.machine_collection),
+        payer: ctx.accounts.authority,
+        authority: Some(ctx.accounts.machine),
+        buffer: None,
+        system_program: ctx.accounts.system_program,
+        log_wrapper: None,
+        __args: WriteExternalPluginAdapterDataV1InstructionArgs {
+            key: ExternalPluginAdapterKey::AppData(PluginAuthority::Address {
+                address: *ctx.accounts.machine.key,
+            }),
+            data: Some(borsh::to_vec(&machine_data)?),
+        },
+    }
+    .invoke_signed(&[&[
+        MACHINE_PREFIX,
+        ctx.accounts.machine_collection.key.as_ref(),
+        machine_name.as_bytes(),
+        &[machine_bump],
+    ]])
+}
@@ -0,0 +1,56 @@
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::{
+    instruction::accounts::RenewPassV1Accounts,
+    processor::PassSeller,
+    state::{assert_pass_of_collection, PassCollectionData, PassData},
+};
+
+impl<'a> RenewPassV1Accounts<'a> {
+    pub(crate) fn seller(&self) -> PassSeller<'a> {
+        PassSeller {
+            pass: self.pass,
+            pass_collection: self.pass_collection,
+            pass_token_account: self.pass_token_account,
+            payer: self.payer,
+            payer_token_account: self.payer_token_account,
+            mpl_core_program: self.mpl_core_program,
+            token_program: self.token_program,
+            system_program: self.system_program,
+        }
+    }
+
+    /// Returns the bump of the pass collection.
+    pub fn check(&self) -> Result<u8, ProgramError> {
+        // Pass
+        // Anyone can renew a pass, so it can be gifted.
+        assert_pass_of_collection(self.pass, self.pass_collection.key)?;
+
+        // Pass Collection, Token Accounts, Payer and Programs
+        self.seller().check()
+    }
+}
+
+pub fn renew_pass<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = RenewPassV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    let bump = ctx.accounts.check()?;
+
+    let pass_collection_data = PassCollectionData::fetch(ctx.accounts.pass_collection)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Extend the pass by one period, from its expiry or from now if it has
+    // already lapsed.
+    let seller = ctx.accounts.seller();
+    seller.pay(pass_collection_data.price)?;
+
+    let mut pass_data = PassData::fetch(ctx.accounts.pass)?;
+    pass_data.extend(Clock::get()?.unix_timestamp, pass_collection_data.duration)?;
+    seller.write_data(bump, &pass_data)
+}
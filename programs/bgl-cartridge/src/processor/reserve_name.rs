@@ -0,0 +1,131 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::from_bytes_mut;
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys, create_or_allocate_account_raw};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::ReserveNameV1Accounts,
+    state::{normalize_name, NameRecord, LOCALNET_AUTHORITY, NAME_PREFIX, VERIFIER_AUTHORITY},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct ReserveNameV1Args {
+    /// The game name to reserve
+    pub name: String,
+}
+
+impl ReserveNameV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+
+    pub fn check(&self) -> ProgramResult {
+        // Name
+        // Same limits as a released game name.
+        if self.name.is_empty() || self.name.len() > 32 {
+            return Err(BglCartridgeError::InvalidName.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl ReserveNameV1Accounts<'_> {
+    /// Returns the bump of the name record.
+    pub fn check(&self, args: &ReserveNameV1Args) -> Result<u8, ProgramError> {
+        let Self {
+            name_record,
+            owner: _owner,
+            authority,
+            payer,
+            system_program,
+        } = self;
+
+        // Name Record
+        // Only free names are reserved, held names are reassigned instead.
+        let bump = assert_derivation(
+            &crate::ID,
+            name_record,
+            &[NAME_PREFIX, normalize_name(&args.name).as_bytes()],
+            BglCartridgeError::InvalidNameRecord,
+        )?;
+
+        if !name_record.data_is_empty() {
+            return Err(BglCartridgeError::NameTaken.into());
+        }
+
+        // Owner
+        // SAFE: Any publisher key or team can hold a name.
+
+        // Authority — must be the configured protocol authority and must sign.
+        // If it's epoch zero, then we can assume it's a localnet for testing.
+        assert_signer(authority).map_err(|_| BglCartridgeError::AuthorityMustSign)?;
+        if !cmp_pubkeys(authority.key, &VERIFIER_AUTHORITY)
+            && (Clock::get()?.epoch != 0 || !cmp_pubkeys(authority.key, &LOCALNET_AUTHORITY))
+        {
+            return Err(BglCartridgeError::InvalidVerifierAuthority.into());
+        }
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(bump)
+    }
+}
+
+pub fn reserve_name<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = ReserveNameV1Accounts::context(accounts)?;
+    let args = ReserveNameV1Args::unpack(args)?;
+    args.check()?;
+
+    // All account guards and validations happen here.
+    let bump = ctx.accounts.check(&args)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    create_name_record(
+        ctx.accounts.name_record,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        &args.name,
+        bump,
+        ctx.accounts.owner.key,
+    )
+}
+
+/// Creates the registry record of a free game name, held by `owner`.
+pub(crate) fn create_name_record<'a>(
+    name_record: &'a AccountInfo<'a>,
+    payer: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    name: &str,
+    bump: u8,
+    owner: &Pubkey,
+) -> ProgramResult {
+    create_or_allocate_account_raw(
+        crate::ID,
+        name_record,
+        system_program,
+        payer,
+        core::mem::size_of::<NameRecord>(),
+        &[NAME_PREFIX, normalize_name(name).as_bytes(), &[bump]],
+    )?;
+
+    let mut name_record_data = name_record.try_borrow_mut_data()?;
+    *from_bytes_mut::<NameRecord>(&mut name_record_data) = NameRecord { owner: *owner };
+
+    Ok(())
+}
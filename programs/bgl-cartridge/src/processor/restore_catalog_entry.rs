@@ -0,0 +1,51 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::RestoreCatalogEntryV1Accounts,
+    state::{assert_catalog, assert_publisher, Catalog},
+};
+
+impl RestoreCatalogEntryV1Accounts<'_> {
+    pub fn check(&self, approvers: &[AccountInfo]) -> ProgramResult {
+        let Self {
+            catalog,
+            publisher,
+            game: _game,
+        } = self;
+
+        // Catalog
+        assert_catalog(catalog, publisher.key)?;
+        if catalog.data_is_empty() {
+            return Err(BglCartridgeError::InvalidCatalog.into());
+        }
+
+        // Publisher
+        // Only the publisher restores its games, a team with the approval of
+        // its members.
+        assert_publisher(publisher.key, publisher, approvers)?;
+
+        // Game
+        // SAFE: Looked up among the catalog entries.
+
+        Ok(())
+    }
+}
+
+pub fn restore_catalog_entry<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = RestoreCatalogEntryV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here. The remaining accounts
+    // are the team members approving the restoration when a team publishes.
+    ctx.accounts.check(ctx.remaining_accounts)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    let mut catalog_data = ctx.accounts.catalog.try_borrow_mut_data()?;
+    Catalog::entry_mut(&mut catalog_data, ctx.accounts.game.key)
+        .ok_or(BglCartridgeError::CatalogEntryNotFound)?
+        .retired = 0;
+
+    Ok(())
+}
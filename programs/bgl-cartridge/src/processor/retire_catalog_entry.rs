@@ -0,0 +1,51 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::RetireCatalogEntryV1Accounts,
+    state::{assert_catalog, assert_publisher, Catalog},
+};
+
+impl RetireCatalogEntryV1Accounts<'_> {
+    pub fn check(&self, approvers: &[AccountInfo]) -> ProgramResult {
+        let Self {
+            catalog,
+            publisher,
+            game: _game,
+        } = self;
+
+        // Catalog
+        assert_catalog(catalog, publisher.key)?;
+        if catalog.data_is_empty() {
+            return Err(BglCartridgeError::InvalidCatalog.into());
+        }
+
+        // Publisher
+        // Only the publisher retires its games, a team with the approval of its
+        // members.
+        assert_publisher(publisher.key, publisher, approvers)?;
+
+        // Game
+        // SAFE: Looked up among the catalog entries.
+
+        Ok(())
+    }
+}
+
+pub fn retire_catalog_entry<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = RetireCatalogEntryV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here. The remaining accounts
+    // are the team members approving the retirement when a team publishes.
+    ctx.accounts.check(ctx.remaining_accounts)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    let mut catalog_data = ctx.accounts.catalog.try_borrow_mut_data()?;
+    Catalog::entry_mut(&mut catalog_data, ctx.accounts.game.key)
+        .ok_or(BglCartridgeError::CatalogEntryNotFound)?
+        .retired = 1;
+
+    Ok(())
+}
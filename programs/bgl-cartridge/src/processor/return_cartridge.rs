@@ -0,0 +1,159 @@
+use bytemuck::{from_bytes, Pod, Zeroable};
+use mpl_utils::{assert_owned_by, assert_signer, close_account_raw, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::ReturnCartridgeV1Accounts,
+    processor::{CartridgeEjector, CartridgeMover},
+    state::{assert_cartridge_of_game, CartridgeData, Loan},
+};
+
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub struct ReturnCartridgeV1Args {
+    #[skip]
+    /// The discriminator for the instruction
+    discriminator: u8,
+    /// The nonce for the game collection
+    collection_nonce: u8,
+    /// The bump for the game collection
+    collection_bump: u8,
+}
+
+impl<'a> ReturnCartridgeV1Accounts<'a> {
+    /// The ejector of the machine the cartridge is inserted in, if any.
+    pub(crate) fn ejector(&self) -> Result<Option<CartridgeEjector<'a>>, ProgramError> {
+        CartridgeEjector::if_inserted(
+            self.cartridge,
+            self.game,
+            self.machine,
+            self.machine_collection,
+            self.authority,
+            self.mpl_core_program,
+            self.system_program,
+        )
+    }
+
+    pub fn check(&self) -> ProgramResult {
+        let Self {
+            cartridge,
+            game,
+            loan,
+            lender,
+            authority,
+            mpl_core_program,
+            system_program,
+            ..
+        } = self;
+
+        // Loan
+        assert_owned_by(loan, &crate::ID, BglCartridgeError::InvalidLoan)?;
+        if loan.data_len() != core::mem::size_of::<Loan>() {
+            return Err(BglCartridgeError::InvalidLoan.into());
+        }
+        let loan_data = *from_bytes::<Loan>(&loan.try_borrow_data()?);
+
+        // Cartridge
+        if !cmp_pubkeys(cartridge.key, &loan_data.cartridge) {
+            return Err(BglCartridgeError::InvalidLoan.into());
+        }
+        assert_cartridge_of_game(cartridge, game.key)?;
+
+        // Game
+        // SAFE: Checked against the cartridge, and by Core when transferring
+        // as the game PDA.
+
+        // Lender
+        if !cmp_pubkeys(lender.key, &loan_data.lender) {
+            return Err(BglCartridgeError::InvalidLoan.into());
+        }
+
+        // Authority
+        // The borrower can return the cartridge early, anyone else has to
+        // wait for the loan to expire.
+        assert_signer(authority).map_err(|_| BglCartridgeError::AuthorityMustSign)?;
+        if !cmp_pubkeys(authority.key, &loan_data.borrower)
+            && Clock::get()?.unix_timestamp < loan_data.expires_at
+        {
+            return Err(BglCartridgeError::LoanNotExpired.into());
+        }
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+pub fn return_cartridge<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = ReturnCartridgeV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    ctx.accounts.check()?;
+
+    // Machine and Machine Collection
+    // Only needed when the borrower left the cartridge in a machine.
+    let ejection = match ctx.accounts.ejector()? {
+        Some(ejector) => {
+            let machine = ejector.check()?;
+            Some((ejector, machine))
+        }
+        None => None,
+    };
+
+    let args: &ReturnCartridgeV1Args = from_bytes(args);
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Pull the cartridge out of the borrower's machine first.
+    if let Some((ejector, (machine_bump, machine_asset, machine_data))) = ejection {
+        ejector.eject(
+            args.collection_nonce,
+            args.collection_bump,
+            machine_bump,
+            &machine_asset.name,
+            machine_data,
+        )?;
+    }
+
+    // Move the cartridge back to the lender and clear the loan mark.
+    let mover = CartridgeMover {
+        game: ctx.accounts.game,
+        payer: ctx.accounts.authority,
+        mpl_core_program: ctx.accounts.mpl_core_program,
+        system_program: ctx.accounts.system_program,
+    };
+    mover.transfer(
+        ctx.accounts.cartridge,
+        ctx.accounts.lender,
+        args.collection_nonce,
+        args.collection_bump,
+    )?;
+
+    let mut cartridge_data = CartridgeData {
+        lender: None,
+        ..CartridgeData::fetch(ctx.accounts.cartridge)?
+    };
+    mover.write_data(
+        ctx.accounts.cartridge,
+        args.collection_nonce,
+        args.collection_bump,
+        &mut cartridge_data,
+    )?;
+
+    // Close the loan, returning its rent to the lender.
+    close_account_raw(ctx.accounts.lender, ctx.accounts.loan)
+}
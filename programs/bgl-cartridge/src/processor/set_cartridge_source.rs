@@ -1,8 +1,6 @@
-use borsh::BorshDeserialize;
 use bytemuck::{from_bytes, Pod, Zeroable};
 use mpl_core::{
     accounts::BaseCollectionV1,
-    fetch_external_plugin_adapter_data_info,
     instructions::{
         WriteExternalPluginAdapterDataV1Cpi, WriteExternalPluginAdapterDataV1InstructionArgs,
     },
@@ -18,7 +16,10 @@ use solana_program::{
 use crate::{
     error::BglCartridgeError,
     instruction::accounts::SetCartridgeSourceV1Accounts,
-    state::{CartridgeData, Source, GAME_PREFIX, LOCALNET_AUTHORITY, SOURCE_AUTHORITY},
+    state::{
+        CartridgeData, Source, CARTRIDGE_DATA_VERSION, GAME_PREFIX, LOCALNET_AUTHORITY,
+        SOURCE_AUTHORITY,
+    },
 };
 
 #[repr(C)]
@@ -98,24 +99,18 @@ pub fn set_cartridge_source<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) ->
 
     // Read the existing AppData on the cartridge. Write-once: if the source
     // is anything other than Unknown, refuse.
-    let (offset, length) =
-        fetch_external_plugin_adapter_data_info::<mpl_core::accounts::BaseAssetV1>(
-            ctx.accounts.cartridge,
-            None,
-            &ExternalPluginAdapterKey::AppData(PluginAuthority::UpdateAuthority),
-        )?;
-    let existing = CartridgeData::try_from_slice(
-        ctx.accounts.cartridge.try_borrow_data()?[offset..offset + length].as_ref(),
-    )?;
+    let existing = CartridgeData::fetch(ctx.accounts.cartridge)?;
     if existing.source != Source::Unknown as u8 {
         return Err(BglCartridgeError::SourceAlreadySet.into());
     }
 
     // Write the new source value, signing as the game collection PDA (which
-    // is the AppData's data authority via UpdateAuthority).
+    // is the AppData's data authority via UpdateAuthority). The rest of the
+    // cartridge data is carried over.
     let new_data = CartridgeData {
-        version: existing.version,
+        version: CARTRIDGE_DATA_VERSION,
         source: args.source,
+        ..existing
     };
     WriteExternalPluginAdapterDataV1Cpi {
         __program: ctx.accounts.mpl_core_program,
@@ -0,0 +1,113 @@
+use mpl_core::{
+    accounts::BaseAssetV1,
+    instructions::{
+        WriteExternalPluginAdapterDataV1Cpi, WriteExternalPluginAdapterDataV1InstructionArgs,
+    },
+    types::{ExternalPluginAdapterKey, PluginAuthority},
+};
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey, system_program,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::SetMachineOperatorV1Accounts,
+    state::{MachineData, MACHINE_PREFIX},
+};
+
+impl SetMachineOperatorV1Accounts<'_> {
+    pub fn check(&self) -> Result<(u8, String, MachineData), ProgramError> {
+        let Self {
+            machine,
+            machine_collection,
+            machine_owner,
+            payer,
+            operator: _operator,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Machine
+        let machine_asset = BaseAssetV1::from_bytes(machine.try_borrow_data()?.as_ref())?;
+        let bump = assert_derivation(
+            &crate::ID,
+            machine,
+            &[
+                MACHINE_PREFIX,
+                machine_collection.key.as_ref(),
+                machine_asset.name.as_bytes(),
+            ],
+            BglCartridgeError::InvalidMachinePdaDerivation,
+        )?;
+
+        // Machine Collection
+        // SAFE: Checked by Core
+
+        // Machine Owner
+        // Only the owner of the machine can appoint its operator.
+        assert_signer(machine_owner).map_err(|_| BglCartridgeError::MachineOwnerMustSign)?;
+        if machine_asset.owner != *machine_owner.key {
+            return Err(BglCartridgeError::MachineOwnerMustSign.into());
+        }
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // Operator
+        // SAFE: Can be anything, omitting it clears the operator.
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok((bump, machine_asset.name, MachineData::fetch(machine)?))
+    }
+}
+
+pub fn set_machine_operator<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = SetMachineOperatorV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here.
+    let (machine_bump, machine_name, mut machine_data) = ctx.accounts.check()?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Record the operator in the Machine's AppData.
+    machine_data.operator = ctx
+        .accounts
+        .operator
+        .map_or(Pubkey::default(), |operator| *operator.key);
+    WriteExternalPluginAdapterDataV1Cpi {
+        __program: ctx.accounts.mpl_core_program,
+        asset: ctx.accounts.machine,
+        collection: Some(ctx.accounts.machine_collection),
+        payer: ctx.accounts.payer,
+        authority: Some(ctx.accounts.machine),
+        buffer: None,
+        system_program: ctx.accounts.system_program,
+        log_wrapper: None,
+        __args: WriteExternalPluginAdapterDataV1InstructionArgs {
+            key: ExternalPluginAdapterKey::AppData(PluginAuthority::Address {
+                address: *ctx.accounts.machine.key,
+            }),
+            data: Some(borsh::to_vec(&machine_data)?),
+        },
+    }
+    .invoke_signed(&[&[
+        MACHINE_PREFIX,
+        ctx.accounts.machine_collection.key.as_ref(),
+        machine_name.as_bytes(),
+        &[machine_bump],
+    ]])?;
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_core::{
+    accounts::BaseAssetV1,
+    instructions::{
+        WriteExternalPluginAdapterDataV1Cpi, WriteExternalPluginAdapterDataV1InstructionArgs,
+    },
+    types::{ExternalPluginAdapterKey, PluginAuthority},
+};
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::SetSessionLockV1Accounts,
+    state::{MachineData, SessionLock, MACHINE_PREFIX},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct SetSessionLockV1Args {
+    /// Whether to lock or unlock cartridge removal
+    pub locked: bool,
+    /// When the lock lapses on its own, none for no expiry
+    pub expires_at: Option<i64>,
+}
+
+impl SetSessionLockV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+
+    pub fn check(&self, now: i64) -> ProgramResult {
+        // Expires At
+        // A lock that has already lapsed would be a no-op.
+        if let Some(expires_at) = self.expires_at {
+            if self.locked && expires_at <= now {
+                return Err(BglCartridgeError::InvalidSessionExpiry.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl SetSessionLockV1Accounts<'_> {
+    pub fn check(&self) -> Result<(u8, String, MachineData), ProgramError> {
+        let Self {
+            machine,
+            machine_collection,
+            authority,
+            payer,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Machine
+        let machine_asset = BaseAssetV1::from_bytes(machine.try_borrow_data()?.as_ref())?;
+        let bump = assert_derivation(
+            &crate::ID,
+            machine,
+            &[
+                MACHINE_PREFIX,
+                machine_collection.key.as_ref(),
+                machine_asset.name.as_bytes(),
+            ],
+            BglCartridgeError::InvalidMachinePdaDerivation,
+        )?;
+        let machine_data = MachineData::fetch(machine)?;
+
+        // Machine Collection
+        // SAFE: Checked by Core
+
+        // Authority
+        // Either the machine owner or its operator delegate.
+        assert_signer(authority).map_err(|_| BglCartridgeError::AuthorityMustSign)?;
+        if machine_asset.owner != *authority.key && !machine_data.is_operator(authority.key) {
+            return Err(BglCartridgeError::InvalidMachineOperator.into());
+        }
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok((bump, machine_asset.name, machine_data))
+    }
+}
+
+pub fn set_session_lock<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = SetSessionLockV1Accounts::context(accounts)?;
+
+    let args = SetSessionLockV1Args::unpack(args)?;
+    args.check(Clock::get()?.unix_timestamp)?;
+
+    // All account guards and validations happen here.
+    let (machine_bump, machine_name, mut machine_data) = ctx.accounts.check()?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Record the lock in the Machine's AppData.
+    machine_data.session_lock = if args.locked {
+        SessionLock {
+            locked: true,
+            expires_at: args.expires_at.unwrap_or_default(),
+        }
+    } else {
+        SessionLock::default()
+    };
+    WriteExternalPluginAdapterDataV1Cpi {
+        __program: ctx.accounts.mpl_core_program,
+        asset: ctx.accounts.machine,
+        collection: Some(ctx.accounts.machine_collection),
+        payer: ctx.accounts.payer,
+        authority: Some(ctx.accounts.machine),
+        buffer: None,
+        system_program: ctx.accounts.system_program,
+        log_wrapper: None,
+        __args: WriteExternalPluginAdapterDataV1InstructionArgs {
+            key: ExternalPluginAdapterKey::AppData(PluginAuthority::Address {
+                address: *ctx.accounts.machine.key,
+            }),
+            data: Some(borsh::to_vec(&machine_data)?),
+        },
+    }
+    .invoke_signed(&[&[
+        MACHINE_PREFIX,
+        ctx.accounts.machine_collection.key.as_ref(),
+        machine_name.as_bytes(),
+        &[machine_bump],
+    ]])?;
+
+    Ok(())
+}
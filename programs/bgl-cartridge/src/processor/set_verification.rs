@@ -0,0 +1,149 @@
+use bytemuck::{from_bytes, from_bytes_mut, Pod, Zeroable};
+use mpl_utils::{assert_derivation, assert_signer, cmp_pubkeys, create_or_allocate_account_raw};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::SetVerificationV1Accounts,
+    processor::write_game_data,
+    state::{
+        assert_game_derivation, GameCollectionData, Verification, VerificationStatus,
+        LOCALNET_AUTHORITY, VERIFICATION_PREFIX, VERIFIER_AUTHORITY,
+    },
+};
+
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub struct SetVerificationV1Args {
+    #[skip]
+    /// The discriminator for the instruction
+    discriminator: u8,
+    /// The standing to record for the subject
+    #[idl_type(VerificationStatus)]
+    status: u8,
+    /// The nonce for the game collection, ignored for publisher keys
+    collection_nonce: u8,
+    /// The bump for the game collection, ignored for publisher keys
+    collection_bump: u8,
+}
+
+impl SetVerificationV1Accounts<'_> {
+    /// Returns the bump of the verification.
+    pub fn check(&self, args: &SetVerificationV1Args) -> Result<u8, ProgramError> {
+        let Self {
+            subject,
+            verification,
+            authority,
+            payer,
+            system_program,
+            mpl_core_program,
+        } = self;
+
+        // Subject
+        // Any publisher key or game collection can be flagged. Games always
+        // come with the mpl core program so prints see their delisting.
+        match mpl_core_program {
+            Some(_) => {
+                assert_game_derivation(subject, args.collection_nonce, args.collection_bump)?
+            }
+            None if subject.owner == &mpl_core::ID => {
+                return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+            }
+            None => (),
+        }
+
+        // Verification
+        let bump = assert_derivation(
+            &crate::ID,
+            verification,
+            &[VERIFICATION_PREFIX, subject.key.as_ref()],
+            BglCartridgeError::InvalidVerification,
+        )?;
+
+        // Authority — must be the configured verifier authority and must sign.
+        // If it's epoch zero, then we can assume it's a localnet for testing.
+        assert_signer(authority).map_err(|_| BglCartridgeError::AuthorityMustSign)?;
+        if !cmp_pubkeys(authority.key, &VERIFIER_AUTHORITY)
+            && (Clock::get()?.epoch != 0 || !cmp_pubkeys(authority.key, &LOCALNET_AUTHORITY))
+        {
+            return Err(BglCartridgeError::InvalidVerifierAuthority.into());
+        }
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        // MPL Core Program
+        if let Some(mpl_core_program) = mpl_core_program {
+            if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+                return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+            }
+        }
+
+        Ok(bump)
+    }
+}
+
+pub fn set_verification<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = SetVerificationV1Accounts::context(accounts)?;
+
+    let args: &SetVerificationV1Args = from_bytes(args);
+
+    // All account guards and validations happen here.
+    let bump = ctx.accounts.check(args)?;
+
+    let status = VerificationStatus::try_from(args.status)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // The flags are created on the first review and overwritten afterwards.
+    if ctx.accounts.verification.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::ID,
+            ctx.accounts.verification,
+            ctx.accounts.system_program,
+            ctx.accounts.payer,
+            core::mem::size_of::<Verification>(),
+            &[
+                VERIFICATION_PREFIX,
+                ctx.accounts.subject.key.as_ref(),
+                &[bump],
+            ],
+        )?;
+    }
+
+    let mut verification_data = ctx.accounts.verification.try_borrow_mut_data()?;
+    let verification: &mut Verification = from_bytes_mut(&mut verification_data);
+    *verification = Verification {
+        subject: *ctx.accounts.subject.key,
+        status: status as u8,
+        _padding: [0; 7],
+    };
+    drop(verification_data);
+
+    // Prints read the delisting from the game data.
+    if let Some(mpl_core_program) = ctx.accounts.mpl_core_program {
+        let mut game_data = GameCollectionData::fetch(ctx.accounts.subject)?;
+        game_data.delisted = status == VerificationStatus::Delisted;
+        write_game_data(
+            ctx.accounts.subject,
+            ctx.accounts.payer,
+            mpl_core_program,
+            ctx.accounts.system_program,
+            args.collection_nonce,
+            args.collection_bump,
+            &mut game_data,
+        )?;
+    }
+
+    Ok(())
+}
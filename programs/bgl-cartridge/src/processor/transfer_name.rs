@@ -0,0 +1,48 @@
+use bytemuck::from_bytes_mut;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use crate::{
+    instruction::accounts::TransferNameV1Accounts,
+    state::{assert_publisher, fetch_name_record, NameRecord},
+};
+
+impl TransferNameV1Accounts<'_> {
+    pub fn check(&self, approvers: &[AccountInfo]) -> ProgramResult {
+        let Self {
+            name_record,
+            owner,
+            new_owner: _new_owner,
+        } = self;
+
+        // Name Record
+        let record = fetch_name_record(name_record)?;
+
+        // Owner
+        // Only the current holder gives the name away, a team with the
+        // approval of its members.
+        assert_publisher(&record.owner, owner, approvers)?;
+
+        // New Owner
+        // SAFE: Any publisher key or team can hold a name.
+
+        Ok(())
+    }
+}
+
+pub fn transfer_name<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = TransferNameV1Accounts::context(accounts)?;
+
+    // All account guards and validations happen here. The remaining accounts
+    // are the team members approving the transfer when a team holds the name.
+    ctx.accounts.check(ctx.remaining_accounts)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    let mut name_record_data = ctx.accounts.name_record.try_borrow_mut_data()?;
+    *from_bytes_mut::<NameRecord>(&mut name_record_data) = NameRecord {
+        owner: *ctx.accounts.new_owner.key,
+    };
+
+    Ok(())
+}
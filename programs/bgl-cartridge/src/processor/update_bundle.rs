@@ -0,0 +1,86 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{from_bytes, from_bytes_mut};
+use mpl_utils::assert_owned_by;
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::UpdateBundleV1Accounts,
+    state::{assert_bundle_shares, assert_publisher, Bundle},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct UpdateBundleV1Args {
+    /// The price of the bundle in the payment token
+    pub price: Option<u64>,
+    /// The share of the bundle price paid to each member game, in basis
+    /// points and in the order of the members
+    pub shares_bps: Option<Vec<u16>>,
+}
+
+impl UpdateBundleV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+impl UpdateBundleV1Accounts<'_> {
+    pub fn check(&self, approvers: &[AccountInfo]) -> ProgramResult {
+        let Self { bundle, publisher } = self;
+
+        // Bundle
+        assert_owned_by(bundle, &crate::ID, BglCartridgeError::InvalidBundle)?;
+        if bundle.data_len() != core::mem::size_of::<Bundle>() {
+            return Err(BglCartridgeError::InvalidBundle.into());
+        }
+
+        // Publisher
+        // A wallet signs, a team needs its members to approve.
+        let expected = from_bytes::<Bundle>(&bundle.try_borrow_data()?).publisher;
+        assert_publisher(&expected, publisher, approvers)?;
+
+        Ok(())
+    }
+}
+
+pub fn update_bundle<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = UpdateBundleV1Accounts::context(accounts)?;
+    let args = UpdateBundleV1Args::unpack(args)?;
+
+    // All account guards and validations happen here.
+    // Remaining Accounts
+    // The team members approving the update when a team publishes the bundle.
+    ctx.accounts.check(ctx.remaining_accounts)?;
+
+    let mut bundle_data = ctx.accounts.bundle.try_borrow_mut_data()?;
+    let bundle: &mut Bundle = from_bytes_mut(&mut bundle_data);
+
+    // Shares
+    // One per member, still paying out the whole price.
+    if let Some(shares_bps) = &args.shares_bps {
+        if shares_bps.len() != bundle.num_games as usize {
+            return Err(BglCartridgeError::InvalidBundleShares.into());
+        }
+        assert_bundle_shares(shares_bps.iter().copied())?;
+    }
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    if let Some(price) = args.price {
+        bundle.price = price;
+    }
+
+    if let Some(shares_bps) = args.shares_bps {
+        for (member, share_bps) in bundle.games.iter_mut().zip(shares_bps) {
+            member.share_bps = share_bps;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,289 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_core::{
+    accounts::BaseCollectionV1,
+    instructions::{
+        WriteCollectionExternalPluginAdapterDataV1Cpi,
+        WriteCollectionExternalPluginAdapterDataV1InstructionArgs,
+    },
+    types::{ExternalPluginAdapterKey, PluginAuthority},
+};
+use mpl_utils::{assert_signer, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::UpdateGameV1Accounts,
+    state::{
+        assert_pass_collection_derivation, assert_publisher, assert_sale_window, Allowlist,
+        GameCollectionData, PassCollectionData, PricingModel, RentalTerms,
+        GAME_COLLECTION_DATA_VERSION, GAME_PREFIX, MAX_COMPATIBLE_MACHINE_COLLECTIONS,
+        MAX_REFERRAL_BPS,
+    },
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct SaleWindow {
+    /// When cartridges can first be printed, immediately when none
+    pub sale_start: Option<i64>,
+    /// When cartridges can no longer be printed, never when none
+    pub sale_end: Option<i64>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct UpdateGameV1Args {
+    /// The nonce for the game collection
+    pub collection_nonce: u8,
+    /// The bump for the game collection
+    pub collection_bump: u8,
+    /// The machine collections the game can be played on, empty for any.
+    pub compatible_machine_collections: Option<Vec<Pubkey>>,
+    /// The print window of the game, only adjustable before the sale starts.
+    pub sale_window: Option<SaleWindow>,
+    /// How the cartridge price is computed.
+    pub pricing: Option<PricingModel>,
+    /// The allowlist of the game, an all zero root removes it.
+    pub allowlist: Option<Allowlist>,
+    /// How many cartridges can be printed to a single owner, zero for no limit.
+    pub max_per_wallet: Option<u32>,
+    /// The key signing promo vouchers, the default key disables vouchers.
+    pub voucher_signer: Option<Pubkey>,
+    /// The share of the print price paid to referrers, in basis points.
+    pub referral_bps: Option<u16>,
+    /// The game server key writing save data, the default key disables saves.
+    pub save_authority: Option<Pubkey>,
+    /// How long after printing cartridges can be refunded, in seconds, zero
+    /// disables refunds.
+    pub refund_window: Option<i64>,
+    /// The terms of rental cartridges, a zero duration disables rentals.
+    pub rental: Option<RentalTerms>,
+    /// The pass collection granting access to the game, the default key
+    /// leaves the pass.
+    pub pass: Option<Pubkey>,
+}
+
+impl UpdateGameV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+
+    pub fn check(&self) -> ProgramResult {
+        // Compatible Machine Collections
+        if let Some(collections) = &self.compatible_machine_collections {
+            if collections.len() > MAX_COMPATIBLE_MACHINE_COLLECTIONS {
+                return Err(BglCartridgeError::TooManyMachineCollections.into());
+            }
+        }
+
+        // Sale Window
+        if let Some(window) = &self.sale_window {
+            assert_sale_window(window.sale_start, window.sale_end)?;
+        }
+
+        // Pricing
+        if let Some(pricing) = &self.pricing {
+            pricing.check()?;
+        }
+
+        // Referral Rate
+        if matches!(self.referral_bps, Some(referral_bps) if referral_bps > MAX_REFERRAL_BPS) {
+            return Err(BglCartridgeError::InvalidReferralRate.into());
+        }
+
+        // Refund Window
+        if matches!(self.refund_window, Some(refund_window) if refund_window < 0) {
+            return Err(BglCartridgeError::InvalidRefundWindow.into());
+        }
+
+        // Rental
+        if matches!(self.rental, Some(rental) if rental.duration < 0) {
+            return Err(BglCartridgeError::InvalidRentalTerms.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl UpdateGameV1Accounts<'_> {
+    pub fn check(
+        &self,
+        args: &UpdateGameV1Args,
+        approvers: &[AccountInfo],
+    ) -> Result<GameCollectionData, ProgramError> {
+        let Self {
+            game,
+            publisher,
+            payer,
+            mpl_core_program,
+            system_program,
+            pass_collection,
+            pass_authority,
+        } = self;
+
+        // Game
+        // SAFE: Checked by Core when writing as the game PDA.
+        let game_data = GameCollectionData::fetch(game)?;
+
+        // Publisher
+        // Only the publisher recorded at release can update the game.
+        assert_publisher(&game_data.publisher, publisher, approvers)?;
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        // Pass Collection & Pass Authority
+        // A game only joins a pass its authority approves, leaving needs no
+        // approval.
+        if let Some(pass) = args.pass.filter(|pass| *pass != Pubkey::default()) {
+            let (Some(pass_collection), Some(pass_authority)) = (pass_collection, pass_authority)
+            else {
+                return Err(BglCartridgeError::PassAuthorityMustSign.into());
+            };
+
+            if !cmp_pubkeys(pass_collection.key, &pass) {
+                return Err(BglCartridgeError::InvalidPassCollection.into());
+            }
+            assert_pass_collection_derivation(pass_collection)?;
+
+            assert_signer(pass_authority).map_err(|_| BglCartridgeError::PassAuthorityMustSign)?;
+            if PassCollectionData::fetch(pass_collection)?.authority != *pass_authority.key {
+                return Err(BglCartridgeError::PassAuthorityMustSign.into());
+            }
+        }
+
+        Ok(game_data)
+    }
+}
+
+pub fn update_game<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = UpdateGameV1Accounts::context(accounts)?;
+
+    let args = UpdateGameV1Args::unpack(args)?;
+    args.check()?;
+
+    // All account guards and validations happen here. The remaining accounts
+    // are the team members approving the update when a team publishes the game.
+    let mut game_data = ctx.accounts.check(&args, ctx.remaining_accounts)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Apply the provided fields, leaving the others untouched.
+    if let Some(collections) = args.compatible_machine_collections {
+        game_data.compatible_machine_collections = collections;
+    }
+
+    if let Some(window) = args.sale_window {
+        // Once the sale has started the window is part of the promise made to
+        // buyers, so it is frozen.
+        let now = Clock::get()?.unix_timestamp;
+        if game_data.has_sale_started(now) {
+            return Err(BglCartridgeError::SaleAlreadyStarted.into());
+        }
+
+        // A start in the past would open the sale retroactively, clear it to
+        // open the sale now instead.
+        if matches!(window.sale_start, Some(sale_start) if sale_start < now) {
+            return Err(BglCartridgeError::InvalidSaleWindow.into());
+        }
+        game_data.sale_start = window.sale_start;
+        game_data.sale_end = window.sale_end;
+    }
+
+    if let Some(pricing) = args.pricing {
+        game_data.pricing = pricing;
+    }
+
+    if let Some(allowlist) = args.allowlist {
+        game_data.allowlist = allowlist;
+    }
+
+    if let Some(max_per_wallet) = args.max_per_wallet {
+        game_data.max_per_wallet = (max_per_wallet != 0).then_some(max_per_wallet);
+    }
+
+    if let Some(voucher_signer) = args.voucher_signer {
+        game_data.voucher_signer = voucher_signer;
+    }
+
+    if let Some(referral_bps) = args.referral_bps {
+        game_data.referral_bps = referral_bps;
+    }
+
+    if let Some(save_authority) = args.save_authority {
+        game_data.save_authority = save_authority;
+    }
+
+    if let Some(refund_window) = args.refund_window {
+        game_data.refund_window = (refund_window != 0).then_some(refund_window);
+    }
+
+    if let Some(rental) = args.rental {
+        game_data.rental = (rental.duration != 0).then_some(rental);
+    }
+
+    if let Some(pass) = args.pass {
+        game_data.pass = pass;
+    }
+
+    write_game_data(
+        ctx.accounts.game,
+        ctx.accounts.payer,
+        ctx.accounts.mpl_core_program,
+        ctx.accounts.system_program,
+        args.collection_nonce,
+        args.collection_bump,
+        &mut game_data,
+    )
+}
+
+/// Writes the game data section, signing as the game. Rewriting always
+/// upgrades the game data to the latest layout.
+pub(crate) fn write_game_data<'a>(
+    game: &'a AccountInfo<'a>,
+    payer: &'a AccountInfo<'a>,
+    mpl_core_program: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    collection_nonce: u8,
+    collection_bump: u8,
+    game_data: &mut GameCollectionData,
+) -> ProgramResult {
+    game_data.version = GAME_COLLECTION_DATA_VERSION;
+
+    let collection = BaseCollectionV1::from_bytes(game.try_borrow_data()?.as_ref())?;
+    WriteCollectionExternalPluginAdapterDataV1Cpi {
+        __program: mpl_core_program,
+        collection: game,
+        payer,
+        authority: Some(game),
+        buffer: None,
+        system_program,
+        log_wrapper: None,
+        __args: WriteCollectionExternalPluginAdapterDataV1InstructionArgs {
+            key: ExternalPluginAdapterKey::LinkedAppData(PluginAuthority::UpdateAuthority),
+            data: Some(borsh::to_vec(game_data)?),
+        },
+    }
+    .invoke_signed(&[&[
+        GAME_PREFIX,
+        collection.name.as_bytes(),
+        &[collection_nonce],
+        &[collection_bump],
+    ]])
+}
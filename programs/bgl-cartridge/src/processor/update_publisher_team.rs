@@ -0,0 +1,57 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::from_bytes_mut;
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    instruction::accounts::UpdatePublisherTeamV1Accounts,
+    processor::{build_team, TeamMemberArgs},
+    state::{fetch_team, PublisherTeam},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct UpdatePublisherTeamV1Args {
+    /// How many members must sign administrative actions
+    pub threshold: u8,
+    /// The members of the team, replacing the current ones
+    pub members: Vec<TeamMemberArgs>,
+}
+
+impl UpdatePublisherTeamV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+impl UpdatePublisherTeamV1Accounts<'_> {
+    pub fn check(&self, approvers: &[AccountInfo]) -> ProgramResult {
+        let Self { team } = self;
+
+        // Team
+        // Changing the team is approved under its current threshold.
+        fetch_team(team)?.assert_approved(approvers)
+    }
+}
+
+pub fn update_publisher_team<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = UpdatePublisherTeamV1Accounts::context(accounts)?;
+    let args = UpdatePublisherTeamV1Args::unpack(args)?;
+
+    // All account guards and validations happen here. The remaining accounts
+    // are the members approving the update.
+    ctx.accounts.check(ctx.remaining_accounts)?;
+
+    let team = build_team(args.threshold, &args.members)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    let mut team_data = ctx.accounts.team.try_borrow_mut_data()?;
+    *from_bytes_mut::<PublisherTeam>(&mut team_data) = team;
+
+    Ok(())
+}
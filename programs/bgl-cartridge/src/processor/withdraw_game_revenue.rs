@@ -0,0 +1,142 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_core::accounts::BaseCollectionV1;
+use mpl_utils::cmp_pubkeys;
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed,
+    program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::WithdrawGameRevenueV1Accounts,
+    processor::{assert_game_token_account, assert_wallet_token_account},
+    state::{
+        assert_game_derivation, assert_publisher, fetch_team, GameCollectionData, GAME_PREFIX,
+    },
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct WithdrawGameRevenueV1Args {
+    /// The nonce for the game collection
+    pub collection_nonce: u8,
+    /// The bump for the game collection
+    pub collection_bump: u8,
+    /// The amount to withdraw from the game token account
+    pub amount: u64,
+}
+
+impl WithdrawGameRevenueV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+impl WithdrawGameRevenueV1Accounts<'_> {
+    /// Returns the amount paid to each recipient token account, in the order
+    /// of the remaining accounts.
+    pub fn check<'a>(
+        &self,
+        args: &WithdrawGameRevenueV1Args,
+        remaining_accounts: &'a [AccountInfo<'a>],
+    ) -> Result<Vec<(u64, &'a AccountInfo<'a>)>, ProgramError> {
+        let Self {
+            game,
+            game_token_account,
+            publisher,
+            token_program,
+        } = self;
+
+        // Game
+        assert_game_derivation(game, args.collection_nonce, args.collection_bump)?;
+        let game_data = GameCollectionData::fetch(game)?;
+
+        // Game Token Account
+        // Refunds are paid from the same balance, keeping enough for them is
+        // up to the publisher.
+        assert_game_token_account(game, game_token_account)?;
+
+        // Remaining Accounts
+        // The token account of every recipient, one per team member in order
+        // or the publisher's own, followed by the approving team members.
+        let (recipients, amounts): (Vec<Pubkey>, Vec<u64>) = match fetch_team(publisher) {
+            Ok(team) => (
+                team.members().iter().map(|member| member.address).collect(),
+                team.split_revenue(args.amount)?,
+            ),
+            Err(_) => (vec![*publisher.key], vec![args.amount]),
+        };
+        if remaining_accounts.len() < recipients.len() {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        let (recipient_token_accounts, approvers) = remaining_accounts.split_at(recipients.len());
+
+        // Publisher
+        // Withdrawals are approved like any other administrative action.
+        assert_publisher(&game_data.publisher, publisher, approvers)?;
+
+        for (recipient, token_account) in recipients.iter().zip(recipient_token_accounts) {
+            assert_wallet_token_account(
+                recipient,
+                token_account,
+                BglCartridgeError::InvalidRevenueTokenAccount,
+            )?;
+        }
+
+        // Token Program
+        if !cmp_pubkeys(token_program.key, &spl_token::ID) {
+            return Err(BglCartridgeError::InvalidTokenProgram.into());
+        }
+
+        Ok(amounts
+            .into_iter()
+            .zip(recipient_token_accounts.iter())
+            .collect())
+    }
+}
+
+pub fn withdraw_game_revenue<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = WithdrawGameRevenueV1Accounts::context(accounts)?;
+    let args = WithdrawGameRevenueV1Args::unpack(args)?;
+
+    // All account guards and validations happen here.
+    let payouts = ctx.accounts.check(&args, ctx.remaining_accounts)?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Pay each recipient their share, signing as the game.
+    let collection = BaseCollectionV1::from_bytes(ctx.accounts.game.try_borrow_data()?.as_ref())?;
+    let game_seeds: &[&[u8]] = &[
+        GAME_PREFIX,
+        collection.name.as_bytes(),
+        &[args.collection_nonce],
+        &[args.collection_bump],
+    ];
+    for (amount, destination) in payouts {
+        if amount == 0 {
+            continue;
+        }
+
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                ctx.accounts.token_program.key,
+                ctx.accounts.game_token_account.key,
+                destination.key,
+                ctx.accounts.game.key,
+                &[],
+                amount,
+            )?,
+            &[
+                ctx.accounts.game_token_account.clone(),
+                destination.clone(),
+                ctx.accounts.game.clone(),
+            ],
+            &[game_seeds],
+        )?;
+    }
+
+    Ok(())
+}
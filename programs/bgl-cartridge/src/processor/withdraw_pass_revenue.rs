@@ -0,0 +1,105 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_core::accounts::BaseCollectionV1;
+use mpl_utils::{assert_signer, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed,
+    program_error::ProgramError,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::WithdrawPassRevenueV1Accounts,
+    processor::{assert_game_token_account, assert_wallet_token_account},
+    state::{assert_pass_collection_derivation, PassCollectionData, PASS_PREFIX},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct WithdrawPassRevenueV1Args {
+    /// The amount to withdraw from the pass token account
+    pub amount: u64,
+}
+
+impl WithdrawPassRevenueV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+impl WithdrawPassRevenueV1Accounts<'_> {
+    /// Returns the bump of the pass collection.
+    pub fn check(&self) -> Result<u8, ProgramError> {
+        let Self {
+            pass_collection,
+            pass_token_account,
+            authority,
+            authority_token_account,
+            token_program,
+        } = self;
+
+        // Pass Collection
+        let bump = assert_pass_collection_derivation(pass_collection)?;
+
+        // Pass Token Account
+        assert_game_token_account(pass_collection, pass_token_account)?;
+
+        // Authority
+        // Only the wallet that created the pass collects its revenue.
+        assert_signer(authority).map_err(|_| BglCartridgeError::AuthorityMustSign)?;
+        if PassCollectionData::fetch(pass_collection)?.authority != *authority.key {
+            return Err(BglCartridgeError::AuthorityMustSign.into());
+        }
+
+        // Authority Token Account
+        assert_wallet_token_account(
+            authority.key,
+            authority_token_account,
+            BglCartridgeError::InvalidRevenueTokenAccount,
+        )?;
+
+        // Token Program
+        if !cmp_pubkeys(token_program.key, &spl_token::ID) {
+            return Err(BglCartridgeError::InvalidTokenProgram.into());
+        }
+
+        Ok(bump)
+    }
+}
+
+pub fn withdraw_pass_revenue<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = WithdrawPassRevenueV1Accounts::context(accounts)?;
+    let args = WithdrawPassRevenueV1Args::unpack(args)?;
+
+    // All account guards and validations happen here.
+    let bump = ctx.accounts.check()?;
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // Pay the authority, signing as the pass collection.
+    let collection =
+        BaseCollectionV1::from_bytes(ctx.accounts.pass_collection.try_borrow_data()?.as_ref())?;
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            ctx.accounts.token_program.key,
+            ctx.accounts.pass_token_account.key,
+            ctx.accounts.authority_token_account.key,
+            ctx.accounts.pass_collection.key,
+            &[],
+            args.amount,
+        )?,
+        &[
+            ctx.accounts.pass_token_account.clone(),
+            ctx.accounts.authority_token_account.clone(),
+            ctx.accounts.pass_collection.clone(),
+        ],
+        &[&[
+            PASS_PREFIX,
+            ctx.accounts.authority.key.as_ref(),
+            collection.name.as_bytes(),
+            &[bump],
+        ]],
+    )
+}
@@ -0,0 +1,182 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_core::{
+    accounts::BaseCollectionV1,
+    instructions::{
+        AddExternalPluginAdapterV1Cpi, AddExternalPluginAdapterV1InstructionArgs,
+        WriteExternalPluginAdapterDataV1Cpi, WriteExternalPluginAdapterDataV1InstructionArgs,
+    },
+    types::{
+        AppDataInitInfo, ExternalPluginAdapterInitInfo, ExternalPluginAdapterKey,
+        ExternalPluginAdapterSchema, PluginAuthority,
+    },
+};
+use mpl_utils::{assert_signer, cmp_pubkeys};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, hash::hashv,
+    program_error::ProgramError, pubkey::Pubkey, system_program, sysvar::Sysvar,
+};
+
+use crate::{
+    error::BglCartridgeError,
+    instruction::accounts::WriteSaveDataV1Accounts,
+    state::{
+        assert_cartridge_of_game, GameCollectionData, SaveData, GAME_PREFIX, MAX_SAVE_DATA_LEN,
+        SAVE_DATA_VERSION,
+    },
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct WriteSaveDataV1Args {
+    /// The nonce for the game collection
+    pub collection_nonce: u8,
+    /// The bump for the game collection
+    pub collection_bump: u8,
+    /// The revision of the new save, one more than the current one
+    pub revision: u64,
+    /// The save blob
+    pub data: Vec<u8>,
+}
+
+impl WriteSaveDataV1Args {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        // Skip the discriminator
+        Self::try_from_slice(&input[1..]).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+
+    pub fn check(&self) -> ProgramResult {
+        // Data
+        if self.data.len() > MAX_SAVE_DATA_LEN {
+            return Err(BglCartridgeError::SaveDataTooLarge.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl WriteSaveDataV1Accounts<'_> {
+    pub fn check(&self) -> ProgramResult {
+        let Self {
+            cartridge,
+            game,
+            save_authority,
+            payer,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Cartridge
+        // Must be a cartridge of the game.
+        assert_cartridge_of_game(cartridge, game.key)?;
+
+        // Game
+        // SAFE: Checked by Core when writing as the game PDA.
+
+        // Save Authority
+        // The server key registered by the publisher.
+        assert_signer(save_authority).map_err(|_| BglCartridgeError::InvalidSaveAuthority)?;
+        let game_data = GameCollectionData::fetch(game)?;
+        if game_data.save_authority == Pubkey::default() {
+            return Err(BglCartridgeError::SaveDataDisabled.into());
+        }
+
+        if game_data.save_authority != *save_authority.key {
+            return Err(BglCartridgeError::InvalidSaveAuthority.into());
+        }
+
+        // Payer
+        assert_signer(payer).map_err(|_| BglCartridgeError::PayerMustSign)?;
+
+        // MPL Core Program
+        if !cmp_pubkeys(mpl_core_program.key, &mpl_core::ID) {
+            return Err(BglCartridgeError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if !cmp_pubkeys(system_program.key, &system_program::ID) {
+            return Err(BglCartridgeError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+pub fn write_save_data<'a>(accounts: &'a [AccountInfo<'a>], args: &[u8]) -> ProgramResult {
+    let ctx = WriteSaveDataV1Accounts::context(accounts)?;
+
+    let args = WriteSaveDataV1Args::unpack(args)?;
+    args.check()?;
+
+    // All account guards and validations happen here.
+    ctx.accounts.check()?;
+
+    // Saves are written in order, a write based on an old save is rejected.
+    let existing = SaveData::fetch(ctx.accounts.cartridge, ctx.accounts.save_authority.key)?;
+    let current_revision = existing.as_ref().map_or(0, |save| save.revision);
+    if current_revision.checked_add(1) != Some(args.revision) {
+        return Err(BglCartridgeError::StaleSaveRevision.into());
+    }
+
+    /*********************************************/
+    /****************** Actions ******************/
+    /*********************************************/
+    // The save plugin is added by the game on the first write, cartridges are
+    // printed without it. Its data belongs to the save authority, which signs
+    // every write, so saves are kept per server key.
+    if existing.is_none() {
+        let collection =
+            BaseCollectionV1::from_bytes(ctx.accounts.game.try_borrow_data()?.as_ref())?;
+        AddExternalPluginAdapterV1Cpi {
+            __program: ctx.accounts.mpl_core_program,
+            asset: ctx.accounts.cartridge,
+            collection: Some(ctx.accounts.game),
+            payer: ctx.accounts.payer,
+            authority: Some(ctx.accounts.game),
+            system_program: ctx.accounts.system_program,
+            log_wrapper: None,
+            __args: AddExternalPluginAdapterV1InstructionArgs {
+                init_info: ExternalPluginAdapterInitInfo::AppData(AppDataInitInfo {
+                    data_authority: PluginAuthority::Address {
+                        address: *ctx.accounts.save_authority.key,
+                    },
+                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
+                    schema: Some(ExternalPluginAdapterSchema::Binary),
+                }),
+            },
+        }
+        .invoke_signed(&[&[
+            GAME_PREFIX,
+            collection.name.as_bytes(),
+            &[args.collection_nonce],
+            &[args.collection_bump],
+        ]])?;
+    }
+
+    let save = SaveData {
+        version: SAVE_DATA_VERSION,
+        revision: args.revision,
+        updated_at: Clock::get()?.unix_timestamp,
+        content_hash: hashv(&[args.data.as_slice()]).to_bytes(),
+        data: args.data,
+    };
+    WriteExternalPluginAdapterDataV1Cpi {
+        __program: ctx.accounts.mpl_core_program,
+        asset: ctx.accounts.cartridge,
+        collection: Some(ctx.accounts.game),
+        payer: ctx.accounts.payer,
+        authority: Some(ctx.accounts.save_authority),
+        buffer: None,
+        system_program: ctx.accounts.system_program,
+        log_wrapper: None,
+        __args: WriteExternalPluginAdapterDataV1InstructionArgs {
+            key: ExternalPluginAdapterKey::AppData(PluginAuthority::Address {
+                address: *ctx.accounts.save_authority.key,
+            }),
+            data: Some(borsh::to_vec(&save)?),
+        },
+    }
+    .invoke()?;
+
+    Ok(())
+}
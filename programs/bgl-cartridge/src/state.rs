@@ -1,10 +1,41 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use bytemuck::{Pod, Zeroable};
+use bytemuck::{cast_slice_mut, from_bytes, from_bytes_mut, Pod, Zeroable};
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    errors::MplCoreError,
+    fetch_external_plugin_adapter_data_info,
+    types::{ExternalPluginAdapterKey, LinkedDataKey, PluginAuthority, UpdateAuthority},
+    DataBlob, SolanaAccount,
+};
+use mpl_utils::assert_derivation;
 use shank::ShankType;
-use solana_program::pubkey::{pubkey, Pubkey};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    hash::hashv,
+    program_error::ProgramError,
+    pubkey::{pubkey, Pubkey, PUBKEY_BYTES},
+};
+
+use crate::error::BglCartridgeError;
 
 pub const MACHINE_PREFIX: &[u8] = b"machine";
 pub const GAME_PREFIX: &[u8] = b"game";
+pub const ALLOWLIST_PREFIX: &[u8] = b"allowlist";
+pub const MINT_COUNT_PREFIX: &[u8] = b"mint_count";
+pub const VOUCHER_PREFIX: &[u8] = b"voucher";
+pub const BUNDLE_PREFIX: &[u8] = b"bundle";
+pub const LISTING_PREFIX: &[u8] = b"listing";
+pub const LOAN_PREFIX: &[u8] = b"loan";
+pub const RENTAL_PREFIX: &[u8] = b"rental";
+pub const PASS_PREFIX: &[u8] = b"pass";
+pub const ESCROW_PREFIX: &[u8] = b"escrow";
+pub const PRE_ORDER_PREFIX: &[u8] = b"pre_order";
+pub const TEAM_PREFIX: &[u8] = b"team";
+pub const VERIFICATION_PREFIX: &[u8] = b"verification";
+pub const NAME_PREFIX: &[u8] = b"name";
+pub const CATALOG_PREFIX: &[u8] = b"catalog";
+pub const DLC_PREFIX: &[u8] = b"dlc";
 
 pub const PAYMENT_TOKEN_MINT: Pubkey = pubkey!("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkPEiTQ1TzKpump");
 
@@ -12,16 +43,849 @@ pub const PAYMENT_TOKEN_MINT: Pubkey = pubkey!("BQDMYwgnWr9UBcUCvLX67yXriTVe1bkP
 // to upgrade a cartridge's recorded source from Unknown to Crypto/Stripe.
 pub const SOURCE_AUTHORITY: Pubkey = pubkey!("srcwh9Q87zK1DxeMmbDDesFxvFSNCN35rGrSkCrZkhk");
 
+// Protocol authority. Only this account can call set_verification to verify
+// publishers and games or delist scam games, and reserve_name to hold game
+// names for their rightful publishers.
+pub const VERIFIER_AUTHORITY: Pubkey = pubkey!("AZQU238PhjUYmM3jHVG3MQxEqhkGEycYCz4ttKXDdUhi");
+
 pub const LOCALNET_AUTHORITY: Pubkey = pubkey!("2gy9s5sUQY3icPwF5y1koz1UNkQW5EdAQxLm4BxiePM5");
 
+pub const GAME_COLLECTION_DATA_VERSION: u8 = 1;
+
+// Keeps the game data section small enough to be read on every insert.
+pub const MAX_COMPATIBLE_MACHINE_COLLECTIONS: usize = 16;
+
+// Referral rates and bundle shares are expressed in basis points.
+pub const MAX_BPS: u16 = 10_000;
+
+// Keeps most of every print for the game, so referring your own purchases
+// through a second wallet is never worth much.
+pub const MAX_REFERRAL_BPS: u16 = 2_000;
+
+/// Data stored in a game's LinkedAppData plugin.
+///
+/// Games released before the fields after `publisher` existed keep the version
+/// 0 layout until they are next updated, and decode with defaults.
 #[repr(C)]
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Default, Clone, ShankType)]
 pub struct GameCollectionData {
     pub version: u8,
     #[idl_type(PriceType)]
     pub price_type: u8,
     pub price: u64,
     pub publisher: Pubkey,
+    /// The machine collections cartridges of this game can be inserted into,
+    /// any machine when empty.
+    pub compatible_machine_collections: Vec<Pubkey>,
+    /// When cartridges can first be printed, immediately when none.
+    pub sale_start: Option<i64>,
+    /// When cartridges can no longer be printed, never when none.
+    pub sale_end: Option<i64>,
+    /// How the cartridge price is computed, `price` is used when flat.
+    pub pricing: PricingModel,
+    /// The wallets that can print through the allowlist.
+    pub allowlist: Allowlist,
+    /// How many cartridges can be printed to a single owner, unlimited when none.
+    pub max_per_wallet: Option<u32>,
+    /// The ed25519 key signing promo vouchers, the default key when the game
+    /// has no vouchers.
+    pub voucher_signer: Pubkey,
+    /// The share of the print price paid to the referrer, in basis points.
+    pub referral_bps: u16,
+    /// The game this game is an add-on for, the default key when it is not a
+    /// DLC.
+    pub base_game: Pubkey,
+    /// The game server key allowed to write cartridge save data, the default
+    /// key when the game has no save data.
+    pub save_authority: Pubkey,
+    /// How long after printing a cartridge can be refunded, in seconds, no
+    /// refunds when none.
+    pub refund_window: Option<i64>,
+    /// The terms of time-limited rental cartridges, no rentals when none.
+    pub rental: Option<RentalTerms>,
+    /// The pass collection whose valid passes can be inserted in place of a
+    /// cartridge, the default key when the game is not part of a pass.
+    pub pass: Pubkey,
+    /// When the pre-orders of a crowdfunded game close, cartridges are only
+    /// printed after it. None when the game was not crowdfunded.
+    pub crowdfund_deadline: Option<i64>,
+    /// Whether the verifier delisted the game, which stops every print.
+    /// Mirrors the verification of the game.
+    pub delisted: bool,
+}
+
+/// The GameCollectionData layout of version 0.
+#[derive(BorshDeserialize)]
+struct GameCollectionDataV0 {
+    version: u8,
+    price_type: u8,
+    price: u64,
+    publisher: Pubkey,
+}
+
+impl GameCollectionData {
+    /// Reads the game data from the game's LinkedAppData data section.
+    pub fn fetch(game: &AccountInfo) -> Result<Self, ProgramError> {
+        let (offset, length) = fetch_external_plugin_adapter_data_info::<BaseCollectionV1>(
+            game,
+            None,
+            &ExternalPluginAdapterKey::DataSection(LinkedDataKey::LinkedAppData(
+                PluginAuthority::UpdateAuthority,
+            )),
+        )?;
+
+        Self::from_bytes(&game.try_borrow_data()?[offset..offset + length])
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        match data.first() {
+            // Games released before the fields after `publisher` existed.
+            Some(0) => {
+                let v0 = GameCollectionDataV0::try_from_slice(data)
+                    .map_err(|_| BglCartridgeError::DeserializationError)?;
+                Ok(Self {
+                    version: v0.version,
+                    price_type: v0.price_type,
+                    price: v0.price,
+                    publisher: v0.publisher,
+                    ..Default::default()
+                })
+            }
+            Some(&GAME_COLLECTION_DATA_VERSION) => Self::try_from_slice(data)
+                .map_err(|_| BglCartridgeError::DeserializationError.into()),
+            _ => Err(BglCartridgeError::DeserializationError.into()),
+        }
+    }
+
+    /// Whether cartridges of this game can be inserted into machines of the
+    /// given collection.
+    pub fn is_compatible(&self, machine_collection: &Pubkey) -> bool {
+        self.compatible_machine_collections.is_empty()
+            || self
+                .compatible_machine_collections
+                .contains(machine_collection)
+    }
+
+    /// Whether the sale has started by the given time. Games without a
+    /// scheduled start are on sale from their release.
+    pub fn has_sale_started(&self, now: i64) -> bool {
+        self.sale_start.is_none_or(|sale_start| now >= sale_start)
+    }
+
+    /// Until when a cartridge printed at `now` can be refunded. Refunds are
+    /// paid from the game's revenue, so burned payments are never refundable.
+    pub fn refund_deadline(&self, now: i64) -> Option<i64> {
+        match (self.refund_window, PriceType::from(self.price_type)) {
+            (Some(refund_window), PriceType::Transfer) => Some(now.saturating_add(refund_window)),
+            _ => None,
+        }
+    }
+
+    /// Whether cartridges of this game are add-ons requiring a base cartridge.
+    pub fn is_dlc(&self) -> bool {
+        self.base_game != Pubkey::default()
+    }
+
+    /// Checks that cartridges of this game can be printed on their own.
+    pub fn assert_not_dlc(&self) -> ProgramResult {
+        if self.is_dlc() {
+            return Err(BglCartridgeError::BaseCartridgeRequired.into());
+        }
+
+        Ok(())
+    }
+
+    /// The price of the next cartridge at the given time, with
+    /// `num_minted` cartridges already printed.
+    pub fn current_price(&self, now: i64, num_minted: u32) -> Result<u64, ProgramError> {
+        self.pricing.price(self.price, now, num_minted)
+    }
+
+    /// The part of the given price paid to the referrer of a print.
+    pub fn referral_share(&self, price: u64) -> Result<u64, ProgramError> {
+        let share = (price as u128)
+            .checked_mul(self.referral_bps as u128)
+            .ok_or(BglCartridgeError::NumericalOverflow)?
+            / MAX_BPS as u128;
+
+        u64::try_from(share).map_err(|_| BglCartridgeError::NumericalOverflow.into())
+    }
+
+    /// Checks that cartridges can be printed at the given time.
+    pub fn assert_on_sale(&self, now: i64) -> ProgramResult {
+        // Backers get the first cartridges through their pre-orders.
+        if matches!(self.crowdfund_deadline, Some(deadline) if now < deadline) {
+            return Err(BglCartridgeError::CrowdfundActive.into());
+        }
+
+        if !self.has_sale_started(now) {
+            return Err(BglCartridgeError::SaleNotStarted.into());
+        }
+
+        if matches!(self.sale_end, Some(sale_end) if now >= sale_end) {
+            return Err(BglCartridgeError::SaleEnded.into());
+        }
+
+        Ok(())
+    }
+
+    /// Whether valid passes of the given pass collection grant access to
+    /// this game.
+    pub fn accepts_pass(&self, pass_collection: &Pubkey) -> bool {
+        self.pass != Pubkey::default() && self.pass == *pass_collection
+    }
+}
+
+/// Checks that a sale window ends after it starts.
+pub fn assert_sale_window(sale_start: Option<i64>, sale_end: Option<i64>) -> ProgramResult {
+    match (sale_start, sale_end) {
+        (Some(sale_start), Some(sale_end)) if sale_end <= sale_start => {
+            Err(BglCartridgeError::InvalidSaleWindow.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks that the collection is a game released by this program with the
+/// given nonce and bump.
+pub fn assert_game_derivation(
+    game: &AccountInfo,
+    collection_nonce: u8,
+    collection_bump: u8,
+) -> ProgramResult {
+    if game.owner != &mpl_core::ID {
+        return Err(BglCartridgeError::InvalidGamePdaDerivation.into());
+    }
+
+    let collection = BaseCollectionV1::from_bytes(game.try_borrow_data()?.as_ref())?;
+    let expected = Pubkey::create_program_address(
+        &[
+            GAME_PREFIX,
+            collection.name.as_bytes(),
+            &[collection_nonce],
+            &[collection_bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| BglCartridgeError::InvalidGamePdaDerivation)?;
+
+    if expected != *game.key {
+        return Err(BglCartridgeError::InvalidGamePdaDerivation.into());
+    }
+
+    Ok(())
+}
+
+/// The terms of a game's rental cartridges. Free rentals make trials.
+#[derive(
+    BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Default, Copy, Clone, ShankType,
+)]
+pub struct RentalTerms {
+    /// The price of a rental in the payment token.
+    pub price: u64,
+    /// How long a rental cartridge works after printing, in seconds.
+    pub duration: i64,
+}
+
+/// The PDA paying for the burn of a game's expired rental cartridges. It
+/// collects their rent on the way back to their payers.
+pub fn find_rental_authority(game: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RENTAL_PREFIX, game.as_ref()], &crate::ID)
+}
+
+/// The allowlist of a game, a merkle tree whose leaves are
+/// `sha256(wallet || quota)` with `quota` as a little-endian u32, zero for no
+/// quota. Pairs are sorted before hashing so proofs carry no directions.
+#[derive(
+    BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Default, Copy, Clone, ShankType,
+)]
+pub struct Allowlist {
+    /// The merkle root, all zero when the game has no allowlist.
+    pub merkle_root: [u8; 32],
+    /// The price for allowlisted wallets, the regular price when none.
+    pub price: Option<u64>,
+}
+
+impl Allowlist {
+    pub fn is_enabled(&self) -> bool {
+        self.merkle_root != [0; 32]
+    }
+
+    /// Whether the wallet and its quota are a leaf of the allowlist.
+    pub fn contains(&self, wallet: &Pubkey, quota: u32, proof: &[[u8; 32]]) -> bool {
+        let leaf = hashv(&[wallet.as_ref(), &quota.to_le_bytes()]).to_bytes();
+        let root = proof.iter().fold(leaf, |node, sibling| {
+            if node <= *sibling {
+                hashv(&[&node, sibling]).to_bytes()
+            } else {
+                hashv(&[sibling, &node]).to_bytes()
+            }
+        });
+
+        self.is_enabled() && root == self.merkle_root
+    }
+}
+
+/// Tracks how many cartridges a wallet printed through a game's allowlist.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct AllowlistReceipt {
+    /// The game the receipt belongs to
+    pub game: Pubkey,
+    /// The allowlisted wallet
+    pub wallet: Pubkey,
+    /// The number of cartridges printed through the allowlist
+    pub printed: u32,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 4],
+}
+
+/// Counts the cartridges of a game printed to an owner.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct MintCounter {
+    /// The game the counter belongs to
+    pub game: Pubkey,
+    /// The owner the cartridges were printed to
+    pub owner: Pubkey,
+    /// The number of cartridges printed to the owner
+    pub count: u32,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 4],
+}
+
+/// Marks a promo voucher nonce as used.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct VoucherRecord {
+    /// The game the voucher was issued for
+    pub game: Pubkey,
+    /// The wallet that redeemed the voucher
+    pub wallet: Pubkey,
+    /// The nonce of the voucher
+    pub nonce: u64,
+}
+
+// Caps the accounts a bundle print has to pass, four per member game. Must
+// match the size of `Bundle::games`.
+pub const MAX_BUNDLE_GAMES: usize = 8;
+
+/// A game included in a bundle.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct BundleMember {
+    /// The game Collection account
+    pub game: Pubkey,
+    /// The share of the bundle price paid to the game, in basis points
+    pub share_bps: u16,
+    /// The nonce for the game collection
+    pub collection_nonce: u8,
+    /// The bump for the game collection
+    pub collection_bump: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 4],
+}
+
+/// Several games sold together at a single price.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct Bundle {
+    /// The publisher of every member game
+    pub publisher: Pubkey,
+    /// The price of the bundle in the payment token
+    pub price: u64,
+    /// The member games, only the first `num_games` are used
+    pub games: [BundleMember; 8],
+    /// The number of member games
+    pub num_games: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+}
+
+impl Bundle {
+    pub fn members(&self) -> &[BundleMember] {
+        &self.games[..self.num_games as usize]
+    }
+
+    /// Splits the bundle price between the member games.
+    pub fn split_price(&self) -> Result<Vec<u64>, ProgramError> {
+        split_by_bps(
+            self.price,
+            self.members().iter().map(|member| member.share_bps),
+        )
+    }
+}
+
+/// Asserts the shares of a bundle pay out exactly its price.
+pub fn assert_bundle_shares(mut shares: impl Iterator<Item = u16>) -> ProgramResult {
+    let total = shares
+        .try_fold(0u16, |total, share_bps| total.checked_add(share_bps))
+        .ok_or(BglCartridgeError::InvalidBundleShares)?;
+    if total != MAX_BPS {
+        return Err(BglCartridgeError::InvalidBundleShares.into());
+    }
+
+    Ok(())
+}
+
+/// Splits an amount by basis-point shares. The last share gets the rounding
+/// remainder so the amounts always add up to the total.
+pub fn split_by_bps(
+    total: u64,
+    shares: impl ExactSizeIterator<Item = u16>,
+) -> Result<Vec<u64>, ProgramError> {
+    let count = shares.len();
+    let mut remaining = total;
+    let mut amounts = Vec::with_capacity(count);
+    for (i, share_bps) in shares.enumerate() {
+        let amount = if i + 1 == count {
+            remaining
+        } else {
+            let amount = (total as u128)
+                .checked_mul(share_bps as u128)
+                .ok_or(BglCartridgeError::NumericalOverflow)?
+                / MAX_BPS as u128;
+            u64::try_from(amount).map_err(|_| BglCartridgeError::NumericalOverflow)?
+        };
+        remaining = remaining
+            .checked_sub(amount)
+            .ok_or(BglCartridgeError::NumericalOverflow)?;
+        amounts.push(amount);
+    }
+
+    Ok(amounts)
+}
+
+/// The DLC printed for a base cartridge, which can only get it once.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct DlcReceipt {
+    /// The DLC game Collection account
+    pub dlc: Pubkey,
+    /// The base cartridge the DLC was printed for
+    pub base_cartridge: Pubkey,
+    /// The DLC cartridge
+    pub cartridge: Pubkey,
+}
+
+/// A cartridge offered for sale on the secondary market.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct Listing {
+    /// The listed cartridge
+    pub cartridge: Pubkey,
+    /// The game of the cartridge
+    pub game: Pubkey,
+    /// The owner who listed the cartridge
+    pub seller: Pubkey,
+    /// The ask price in the payment token
+    pub price: u64,
+}
+
+/// A cartridge lent to another wallet until `expires_at`.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct Loan {
+    /// The lent cartridge
+    pub cartridge: Pubkey,
+    /// The game of the cartridge
+    pub game: Pubkey,
+    /// The owner the cartridge returns to
+    pub lender: Pubkey,
+    /// The wallet holding the cartridge during the loan
+    pub borrower: Pubkey,
+    /// When anyone can return the cartridge to the lender
+    pub expires_at: i64,
+}
+
+/// The terms of a crowdfunded release.
+#[derive(
+    BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Default, Copy, Clone, ShankType,
+)]
+pub struct CrowdfundTerms {
+    /// The amount to raise in pre-orders, in the payment token.
+    pub goal: u64,
+    /// When pre-orders close.
+    pub deadline: i64,
+}
+
+/// The pre-order payments of a crowdfunded game, held until the goal is met
+/// or missed.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct Escrow {
+    /// The crowdfunded game
+    pub game: Pubkey,
+    /// The amount to raise in pre-orders
+    pub goal: u64,
+    /// When pre-orders close
+    pub deadline: i64,
+    /// The amount pre-ordered so far
+    pub raised: u64,
+}
+
+impl Escrow {
+    /// Whether the goal has been met, which releases the escrow to the
+    /// publisher and the pre-orders to their backers.
+    pub fn is_funded(&self) -> bool {
+        self.raised >= self.goal
+    }
+
+    /// Whether the deadline passed without meeting the goal, which opens
+    /// refunds.
+    pub fn has_failed(&self, now: i64) -> bool {
+        now >= self.deadline && !self.is_funded()
+    }
+}
+
+/// Checks that the account is the escrow of the game and returns its bump.
+pub fn assert_escrow(escrow: &AccountInfo, game: &Pubkey) -> Result<u8, ProgramError> {
+    if escrow.owner != &crate::ID || escrow.data_len() != core::mem::size_of::<Escrow>() {
+        return Err(BglCartridgeError::InvalidEscrow.into());
+    }
+
+    assert_derivation(
+        &crate::ID,
+        escrow,
+        &[ESCROW_PREFIX, game.as_ref()],
+        BglCartridgeError::InvalidEscrow,
+    )
+}
+
+/// The pre-orders of a backer in a crowdfunded game.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct PreOrder {
+    /// The crowdfunded game
+    pub game: Pubkey,
+    /// The wallet that paid for the pre-orders
+    pub backer: Pubkey,
+    /// The amount paid into the escrow
+    pub amount: u64,
+    /// How many cartridges were pre-ordered
+    pub quantity: u32,
+    /// How many cartridges were redeemed
+    pub redeemed: u32,
+}
+
+// Keeps the team account a fixed size.
+pub const MAX_TEAM_MEMBERS: usize = 8;
+
+/// A member of a publisher team.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct TeamMember {
+    /// The wallet of the member
+    pub address: Pubkey,
+    /// The share of the game revenue paid to the member, in basis points
+    pub share_bps: u16,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 6],
+}
+
+/// Several wallets publishing games together. Games released by the team
+/// record it as their publisher.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct PublisherTeam {
+    /// The members, only the first `num_members` are used
+    pub members: [TeamMember; 8],
+    /// The number of members
+    pub num_members: u8,
+    /// How many members must sign administrative actions
+    pub threshold: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 6],
+}
+
+impl PublisherTeam {
+    pub fn members(&self) -> &[TeamMember] {
+        &self.members[..self.num_members as usize]
+    }
+
+    /// Splits an amount of game revenue between the members.
+    pub fn split_revenue(&self, amount: u64) -> Result<Vec<u64>, ProgramError> {
+        split_by_bps(amount, self.members().iter().map(|member| member.share_bps))
+    }
+
+    /// Checks that at least `threshold` distinct members signed among the
+    /// approvers.
+    pub fn assert_approved(&self, approvers: &[AccountInfo]) -> ProgramResult {
+        let approvals = self
+            .members()
+            .iter()
+            .filter(|member| {
+                approvers
+                    .iter()
+                    .any(|approver| approver.is_signer && *approver.key == member.address)
+            })
+            .count();
+
+        if approvals < self.threshold as usize {
+            return Err(BglCartridgeError::TeamThresholdNotMet.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads a publisher team, failing when the account is not one.
+pub fn fetch_team(team: &AccountInfo) -> Result<PublisherTeam, ProgramError> {
+    if team.owner != &crate::ID || team.data_len() != core::mem::size_of::<PublisherTeam>() {
+        return Err(BglCartridgeError::InvalidPublisherTeam.into());
+    }
+
+    Ok(*from_bytes::<PublisherTeam>(&team.try_borrow_data()?))
+}
+
+/// Checks that the publisher of a game approves an administrative action. A
+/// wallet publisher signs itself, a team needs `threshold` of its members to
+/// sign among the approvers.
+pub fn assert_publisher(
+    expected: &Pubkey,
+    publisher: &AccountInfo,
+    approvers: &[AccountInfo],
+) -> ProgramResult {
+    if publisher.key != expected {
+        return Err(BglCartridgeError::PublisherMustSign.into());
+    }
+
+    if publisher.is_signer {
+        return Ok(());
+    }
+
+    // Teams are program accounts and can never sign.
+    match fetch_team(publisher) {
+        Ok(team) => team.assert_approved(approvers),
+        Err(_) => Err(BglCartridgeError::PublisherMustSign.into()),
+    }
+}
+
+/// The standing of a publisher or a game in the verification registry.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+pub enum VerificationStatus {
+    /// Not reviewed, or reviewed and reverted.
+    Unverified,
+    /// An official publisher or release.
+    Verified,
+    /// A scam or clone that can no longer be printed.
+    Delisted,
+}
+
+impl TryFrom<u8> for VerificationStatus {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(VerificationStatus::Unverified),
+            1 => Ok(VerificationStatus::Verified),
+            2 => Ok(VerificationStatus::Delisted),
+            _ => Err(BglCartridgeError::InvalidVerificationStatus.into()),
+        }
+    }
+}
+
+/// The verification flags of a publisher key or a game, set by the verifier
+/// authority.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct Verification {
+    /// The publisher key or game collection being flagged
+    pub subject: Pubkey,
+    /// The standing of the subject
+    #[idl_type(VerificationStatus)]
+    pub status: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+}
+
+/// Normalizes a game name for the name registry. ASCII letters are lowercased
+/// so names differing only by case belong to the same publisher.
+pub fn normalize_name(name: &str) -> String {
+    name.to_ascii_lowercase()
+}
+
+/// The publisher holding a game name. Only the holder can release games under
+/// the name through ReleaseGameV2, whatever the nonce.
+///
+/// The game PDA keeps its `u8` nonce, so a name has 256 games at most. Once a
+/// name is held its remaining nonces are only used by the holder, except
+/// through ReleaseGameV1, which predates the registry and does not check it.
+/// Games released that way are unregistered and can claim their name with
+/// ClaimNameV1 while it is free.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct NameRecord {
+    /// The publisher holding the name, a wallet or a team
+    pub owner: Pubkey,
+}
+
+/// Reads a name record, failing when the account is not one.
+pub fn fetch_name_record(name_record: &AccountInfo) -> Result<NameRecord, ProgramError> {
+    if name_record.owner != &crate::ID
+        || name_record.data_len() != core::mem::size_of::<NameRecord>()
+    {
+        return Err(BglCartridgeError::InvalidNameRecord.into());
+    }
+
+    Ok(*from_bytes::<NameRecord>(&name_record.try_borrow_data()?))
+}
+
+/// The games released by a publisher, followed in the account by one
+/// `CatalogEntry` per game in release order. The account grows with every
+/// release, and with games released before the catalog added afterwards.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct Catalog {
+    /// The publisher of every game in the catalog, a wallet or a team
+    pub publisher: Pubkey,
+    /// The number of entries following the header
+    pub num_entries: u32,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 4],
+}
+
+/// A game in a publisher catalog.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, ShankType)]
+pub struct CatalogEntry {
+    /// The game collection
+    pub game: Pubkey,
+    /// When the game was released, or listed for games added after their
+    /// release
+    pub released_at: i64,
+    /// Whether the publisher retired the game from the catalog
+    pub retired: u8,
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7],
+}
+
+impl Catalog {
+    /// The size of a catalog account holding the given number of entries.
+    pub fn size(num_entries: usize) -> usize {
+        core::mem::size_of::<Catalog>() + num_entries * core::mem::size_of::<CatalogEntry>()
+    }
+
+    /// Splits catalog account data into its header and entries.
+    pub fn split_mut(data: &mut [u8]) -> (&mut Catalog, &mut [CatalogEntry]) {
+        let (header, entries) = data.split_at_mut(core::mem::size_of::<Catalog>());
+        (from_bytes_mut(header), cast_slice_mut(entries))
+    }
+
+    /// The entry of the game in catalog account data, if listed.
+    pub fn entry_mut<'a>(data: &'a mut [u8], game: &Pubkey) -> Option<&'a mut CatalogEntry> {
+        let (header, entries) = Self::split_mut(data);
+        entries[..header.num_entries as usize]
+            .iter_mut()
+            .find(|entry| entry.game == *game)
+    }
+}
+
+/// Checks that the account is the catalog of the publisher and returns its
+/// bump. The catalog is created on the first release of the publisher.
+pub fn assert_catalog(catalog: &AccountInfo, publisher: &Pubkey) -> Result<u8, ProgramError> {
+    let bump = assert_derivation(
+        &crate::ID,
+        catalog,
+        &[CATALOG_PREFIX, publisher.as_ref()],
+        BglCartridgeError::InvalidCatalog,
+    )?;
+
+    if !catalog.data_is_empty()
+        && (catalog.owner != &crate::ID || catalog.data_len() < Catalog::size(0))
+    {
+        return Err(BglCartridgeError::InvalidCatalog.into());
+    }
+
+    Ok(bump)
+}
+
+/// How the price of a cartridge evolves over the life of a game.
+#[derive(
+    BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Default, Copy, Clone, ShankType,
+)]
+pub enum PricingModel {
+    /// Every cartridge costs the game's `price`.
+    #[default]
+    Flat,
+    /// The price falls linearly from `start_price` to `end_price` over
+    /// `duration` seconds from `start_time`, then stays at `end_price`.
+    LinearDecay {
+        start_price: u64,
+        end_price: u64,
+        start_time: i64,
+        duration: i64,
+    },
+    /// The price rises by `step_increment` every `step_size` cartridges
+    /// printed, starting from `base_price`.
+    SupplyStepped {
+        base_price: u64,
+        step_size: u32,
+        step_increment: u64,
+    },
+}
+
+impl PricingModel {
+    pub fn check(&self) -> ProgramResult {
+        match *self {
+            PricingModel::Flat => Ok(()),
+            PricingModel::LinearDecay {
+                start_price,
+                end_price,
+                duration,
+                ..
+            } if duration > 0 && start_price >= end_price => Ok(()),
+            PricingModel::SupplyStepped { step_size, .. } if step_size > 0 => Ok(()),
+            _ => Err(BglCartridgeError::InvalidPricingModel.into()),
+        }
+    }
+
+    /// Evaluates the model. `flat_price` is the game's fixed price.
+    pub fn price(&self, flat_price: u64, now: i64, num_minted: u32) -> Result<u64, ProgramError> {
+        match *self {
+            PricingModel::Flat => Ok(flat_price),
+            PricingModel::LinearDecay {
+                start_price,
+                end_price,
+                start_time,
+                duration,
+            } => {
+                if now <= start_time {
+                    return Ok(start_price);
+                }
+
+                let elapsed = now
+                    .checked_sub(start_time)
+                    .ok_or(BglCartridgeError::NumericalOverflow)?;
+                if elapsed >= duration {
+                    return Ok(end_price);
+                }
+
+                // (start - end) * elapsed / duration, in u128 so the product
+                // cannot overflow.
+                let discount = (start_price as u128)
+                    .checked_sub(end_price as u128)
+                    .and_then(|range| range.checked_mul(elapsed as u128))
+                    .and_then(|scaled| scaled.checked_div(duration as u128))
+                    .ok_or(BglCartridgeError::NumericalOverflow)?;
+
+                (start_price as u128)
+                    .checked_sub(discount)
+                    .and_then(|price| u64::try_from(price).ok())
+                    .ok_or(BglCartridgeError::NumericalOverflow.into())
+            }
+            PricingModel::SupplyStepped {
+                base_price,
+                step_size,
+                step_increment,
+            } => num_minted
+                .checked_div(step_size)
+                .and_then(|steps| step_increment.checked_mul(steps as u64))
+                .and_then(|increase| base_price.checked_add(increase))
+                .ok_or(BglCartridgeError::NumericalOverflow.into()),
+        }
+    }
 }
 
 #[repr(C)]
@@ -41,12 +905,205 @@ impl From<u8> for PriceType {
     }
 }
 
+pub const PASS_COLLECTION_DATA_VERSION: u8 = 0;
+
+/// Data stored in a pass collection's LinkedAppData plugin.
 #[repr(C)]
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Copy, Clone, ShankType)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Default, Clone, ShankType)]
+pub struct PassCollectionData {
+    pub version: u8,
+    /// The wallet that created the pass, withdrawing its revenue and
+    /// approving the games joining it. Pass names are only unique per
+    /// authority.
+    pub authority: Pubkey,
+    /// The price of a pass period in the payment token.
+    pub price: u64,
+    /// How long a purchase or renewal extends a pass, in seconds.
+    pub duration: i64,
+}
+
+impl PassCollectionData {
+    /// Reads the pass data from the pass collection's LinkedAppData data
+    /// section.
+    pub fn fetch(pass_collection: &AccountInfo) -> Result<Self, ProgramError> {
+        let (offset, length) = fetch_external_plugin_adapter_data_info::<BaseCollectionV1>(
+            pass_collection,
+            None,
+            &ExternalPluginAdapterKey::DataSection(LinkedDataKey::LinkedAppData(
+                PluginAuthority::UpdateAuthority,
+            )),
+        )?;
+
+        Self::try_from_slice(&pass_collection.try_borrow_data()?[offset..offset + length])
+            .map_err(|_| BglCartridgeError::DeserializationError.into())
+    }
+}
+
+/// Checks that the collection is a pass collection created by this program
+/// and returns its bump.
+pub fn assert_pass_collection_derivation(
+    pass_collection: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    if pass_collection.owner != &mpl_core::ID {
+        return Err(BglCartridgeError::InvalidPassCollection.into());
+    }
+
+    let collection = BaseCollectionV1::from_bytes(pass_collection.try_borrow_data()?.as_ref())?;
+    let authority = PassCollectionData::fetch(pass_collection)?.authority;
+    let (expected, bump) = Pubkey::find_program_address(
+        &[PASS_PREFIX, authority.as_ref(), collection.name.as_bytes()],
+        &crate::ID,
+    );
+
+    if expected != *pass_collection.key {
+        return Err(BglCartridgeError::InvalidPassCollection.into());
+    }
+
+    Ok(bump)
+}
+
+pub const PASS_DATA_VERSION: u8 = 0;
+
+/// Data stored in a pass's AppData plugin.
+#[repr(C)]
+#[derive(
+    BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Default, Copy, Clone, ShankType,
+)]
+pub struct PassData {
+    pub version: u8,
+    /// When the pass stops granting access.
+    pub expires_at: i64,
+}
+
+impl PassData {
+    pub fn fetch(pass: &AccountInfo) -> Result<Self, ProgramError> {
+        let (offset, length) = fetch_external_plugin_adapter_data_info::<BaseAssetV1>(
+            pass,
+            None,
+            &ExternalPluginAdapterKey::AppData(PluginAuthority::UpdateAuthority),
+        )?;
+
+        Self::try_from_slice(&pass.try_borrow_data()?[offset..offset + length])
+            .map_err(|_| BglCartridgeError::DeserializationError.into())
+    }
+
+    /// Whether the pass grants access at the given time.
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+
+    /// Extends the pass by one period, starting now if it has lapsed so
+    /// unused time is never charged for.
+    pub fn extend(&mut self, now: i64, duration: i64) -> ProgramResult {
+        self.version = PASS_DATA_VERSION;
+        self.expires_at = self
+            .expires_at
+            .max(now)
+            .checked_add(duration)
+            .ok_or(BglCartridgeError::NumericalOverflow)?;
+
+        Ok(())
+    }
+}
+
+pub const CARTRIDGE_DATA_VERSION: u8 = 1;
+
+/// Data stored in a cartridge's AppData plugin.
+///
+/// Cartridges printed before the fields after `source` existed keep the
+/// version 0 layout until they are next written, and decode with defaults.
+#[repr(C)]
+#[derive(
+    BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Default, Copy, Clone, ShankType,
+)]
 pub struct CartridgeData {
     pub version: u8,
     #[idl_type(Source)]
     pub source: u8,
+    /// The wallet credited with referring the print.
+    pub referrer: Option<Pubkey>,
+    /// The base game cartridge a DLC cartridge is bound to.
+    pub base_cartridge: Option<Pubkey>,
+    /// The price paid to the game for the cartridge in the payment token, net
+    /// of the referral share.
+    pub purchase_price: u64,
+    /// When the cartridge was printed.
+    pub printed_at: i64,
+    /// The owner who lent the cartridge, while it is on loan.
+    pub lender: Option<Pubkey>,
+    /// When a rental cartridge stops working, never when none.
+    pub expires_at: Option<i64>,
+    /// The wallet that paid the storage of a rental cartridge, refunded when
+    /// it is reclaimed.
+    pub rent_payer: Option<Pubkey>,
+    /// Until when the cartridge can be refunded, under the terms of the game
+    /// when it was printed. Never when none.
+    pub refundable_until: Option<i64>,
+    /// The storage fees the rent payer paid for a rental cartridge.
+    pub rent_paid: u64,
+}
+
+/// The CartridgeData layout of version 0.
+#[derive(BorshDeserialize)]
+struct CartridgeDataV0 {
+    version: u8,
+    source: u8,
+}
+
+impl CartridgeData {
+    /// A cartridge printed at `printed_at` for `purchase_price`.
+    pub fn new(source: Source, purchase_price: u64, printed_at: i64) -> Self {
+        Self {
+            version: CARTRIDGE_DATA_VERSION,
+            source: source as u8,
+            purchase_price,
+            printed_at,
+            ..Default::default()
+        }
+    }
+
+    pub fn fetch(cartridge: &AccountInfo) -> Result<Self, ProgramError> {
+        let (offset, length) = fetch_external_plugin_adapter_data_info::<BaseAssetV1>(
+            cartridge,
+            None,
+            &ExternalPluginAdapterKey::AppData(PluginAuthority::UpdateAuthority),
+        )?;
+
+        Self::from_bytes(&cartridge.try_borrow_data()?[offset..offset + length])
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        match data.first() {
+            // Cartridges printed before the fields after `source` existed.
+            Some(0) => {
+                let v0 = CartridgeDataV0::try_from_slice(data)
+                    .map_err(|_| BglCartridgeError::DeserializationError)?;
+                Ok(Self {
+                    version: v0.version,
+                    source: v0.source,
+                    ..Default::default()
+                })
+            }
+            Some(&CARTRIDGE_DATA_VERSION) => Self::try_from_slice(data)
+                .map_err(|_| BglCartridgeError::DeserializationError.into()),
+            _ => Err(BglCartridgeError::DeserializationError.into()),
+        }
+    }
+
+    /// Whether this is a rental cartridge past its expiry.
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if now >= expires_at)
+    }
+
+    /// Checks that the cartridge is owned outright, rentals being bound to
+    /// the wallet they were printed for.
+    pub fn assert_not_rental(&self) -> ProgramResult {
+        if self.expires_at.is_some() {
+            return Err(BglCartridgeError::RentalCartridge.into());
+        }
+
+        Ok(())
+    }
 }
 
 #[repr(C)]
@@ -55,6 +1112,7 @@ pub enum Source {
     Unknown,
     Crypto,
     Stripe,
+    PublisherGrant,
 }
 
 impl From<u8> for Source {
@@ -63,7 +1121,608 @@ impl From<u8> for Source {
             0 => Source::Unknown,
             1 => Source::Crypto,
             2 => Source::Stripe,
+            3 => Source::PublisherGrant,
             _ => panic!("Invalid source"),
         }
     }
 }
+
+pub const SAVE_DATA_VERSION: u8 = 0;
+
+// Keeps a save small enough to be rewritten in a single transaction.
+pub const MAX_SAVE_DATA_LEN: usize = 800;
+
+/// Game state following a cartridge, written by the game's save authority.
+///
+/// Stored in a second AppData plugin whose data authority is the save
+/// authority, so only the server key signing the writes can change it. A
+/// rotated key starts its saves in a plugin of its own.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Default, Clone, ShankType)]
+pub struct SaveData {
+    pub version: u8,
+    /// Incremented on every write, so a stale write cannot overwrite a newer
+    /// save.
+    pub revision: u64,
+    /// When the save was last written.
+    pub updated_at: i64,
+    /// The sha256 hash of `data`.
+    pub content_hash: [u8; 32],
+    /// The save blob, opaque to the program.
+    pub data: Vec<u8>,
+}
+
+impl SaveData {
+    /// Reads the save written by a save authority to a cartridge, none when
+    /// it has never saved it.
+    pub fn fetch(
+        cartridge: &AccountInfo,
+        save_authority: &Pubkey,
+    ) -> Result<Option<Self>, ProgramError> {
+        let Some((offset, length)) = fetch_optional_data_info::<BaseAssetV1>(
+            cartridge,
+            &ExternalPluginAdapterKey::AppData(PluginAuthority::Address {
+                address: *save_authority,
+            }),
+        )?
+        else {
+            return Ok(None);
+        };
+
+        if length == 0 {
+            return Ok(None);
+        }
+
+        Self::try_from_slice(&cartridge.try_borrow_data()?[offset..offset + length])
+            .map(Some)
+            .map_err(|_| BglCartridgeError::DeserializationError.into())
+    }
+}
+
+pub const MACHINE_DATA_VERSION: u8 = 0;
+
+/// A cartridge insertion into a machine.
+///
+/// The default record (default keys, zero timestamps) means no insertion.
+#[repr(C)]
+#[derive(
+    BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Default, Copy, Clone, ShankType,
+)]
+pub struct InsertionRecord {
+    /// The inserted cartridge.
+    pub cartridge: Pubkey,
+    /// The game collection of the cartridge.
+    pub game: Pubkey,
+    /// The signer that inserted the cartridge (its owner or the operator).
+    pub inserter: Pubkey,
+    /// When the cartridge was inserted.
+    pub inserted_at: i64,
+    /// When the cartridge was removed, zero while still inserted.
+    pub removed_at: i64,
+}
+
+/// A lock on cartridge removal set by the machine owner or its operator, for
+/// example for the duration of a paid session or a tournament.
+#[repr(C)]
+#[derive(
+    BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Default, Copy, Clone, ShankType,
+)]
+pub struct SessionLock {
+    /// Whether the lock is set.
+    pub locked: bool,
+    /// When the lock lapses on its own, zero for no expiry.
+    pub expires_at: i64,
+}
+
+impl SessionLock {
+    pub fn is_active(&self, now: i64) -> bool {
+        self.locked && (self.expires_at == 0 || now < self.expires_at)
+    }
+}
+
+/// Data stored in a machine's AppData plugin.
+///
+/// Binary layout so off-chain readers can index into the fixed-size history.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankType)]
+pub struct MachineData {
+    pub version: u8,
+    /// The operator delegate appointed by the machine owner, the default key
+    /// when there is none.
+    pub operator: Pubkey,
+    /// The current insertion, the default record when the machine is empty.
+    pub current: InsertionRecord,
+    /// The slot of the history ring buffer the next removal is written to.
+    pub history_head: u8,
+    /// The most recent past insertions, oldest overwritten first.
+    pub history: [InsertionRecord; 8],
+    /// The removal lock of the current session.
+    pub session_lock: SessionLock,
+}
+
+impl Default for MachineData {
+    fn default() -> Self {
+        Self {
+            version: MACHINE_DATA_VERSION,
+            operator: Pubkey::default(),
+            current: InsertionRecord::default(),
+            history_head: 0,
+            history: [InsertionRecord::default(); 8],
+            session_lock: SessionLock::default(),
+        }
+    }
+}
+
+impl MachineData {
+    /// Reads the machine data from the machine's AppData plugin.
+    pub fn fetch(machine: &AccountInfo) -> Result<Self, ProgramError> {
+        let (offset, length) = fetch_external_plugin_adapter_data_info::<BaseAssetV1>(
+            machine,
+            None,
+            &ExternalPluginAdapterKey::AppData(PluginAuthority::Address {
+                address: *machine.key,
+            }),
+        )?;
+
+        Self::from_bytes(&machine.try_borrow_data()?[offset..offset + length])
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        let mut machine_data = Self::default();
+        match data.len() {
+            // Empty machine that has never been written to.
+            0 => {}
+            // Machines written before MachineData existed only hold the raw
+            // key of the inserted cartridge.
+            PUBKEY_BYTES => {
+                machine_data.current.cartridge =
+                    Pubkey::try_from(data).map_err(|_| BglCartridgeError::DeserializationError)?;
+            }
+            _ if data[0] == MACHINE_DATA_VERSION => {
+                machine_data = Self::try_from_slice(data)
+                    .map_err(|_| BglCartridgeError::DeserializationError)?;
+            }
+            _ => return Err(BglCartridgeError::DeserializationError.into()),
+        }
+
+        Ok(machine_data)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.current.cartridge == Pubkey::default()
+    }
+
+    /// Records a new insertion in the current slot.
+    pub fn insert(&mut self, cartridge: Pubkey, game: Pubkey, inserter: Pubkey, now: i64) {
+        self.current = InsertionRecord {
+            cartridge,
+            game,
+            inserter,
+            inserted_at: now,
+            removed_at: 0,
+        };
+    }
+
+    /// Closes the current insertion and moves it to the history. The session
+    /// ends with it, so the lock is released.
+    pub fn remove(&mut self, now: i64) {
+        let head = self.history_head as usize % self.history.len();
+        self.history[head] = InsertionRecord {
+            removed_at: now,
+            ..self.current
+        };
+        self.history_head = ((head + 1) % self.history.len()) as u8;
+        self.current = InsertionRecord::default();
+        self.session_lock = SessionLock::default();
+    }
+
+    /// Whether the given key is the appointed operator delegate.
+    pub fn is_operator(&self, key: &Pubkey) -> bool {
+        self.operator != Pubkey::default() && self.operator == *key
+    }
+
+    /// Verifies that the machine holds the cartridge and that the cartridge
+    /// links back to the machine.
+    pub fn assert_inserted(&self, machine: &AccountInfo, cartridge: &AccountInfo) -> ProgramResult {
+        if self.is_empty() {
+            return Err(BglCartridgeError::CartridgeNotInserted.into());
+        }
+
+        if self.current.cartridge != *cartridge.key {
+            return Err(BglCartridgeError::MachineCartridgeMismatch.into());
+        }
+
+        // Cartridges inserted before the link was fixed point at themselves.
+        match fetch_cartridge_link(cartridge)? {
+            Some(link) if link == *machine.key || link == *cartridge.key => Ok(()),
+            _ => Err(BglCartridgeError::CartridgeMachineMismatch.into()),
+        }
+    }
+}
+
+/// Checks that the asset is a cartridge of the game and returns it.
+pub fn assert_cartridge_of_game(
+    cartridge: &AccountInfo,
+    game: &Pubkey,
+) -> Result<BaseAssetV1, ProgramError> {
+    if cartridge.owner != &mpl_core::ID {
+        return Err(BglCartridgeError::InvalidCartridge.into());
+    }
+
+    let asset = BaseAssetV1::from_bytes(cartridge.try_borrow_data()?.as_ref())?;
+    if asset.update_authority != UpdateAuthority::Collection(*game) {
+        return Err(BglCartridgeError::InvalidCartridge.into());
+    }
+
+    Ok(asset)
+}
+
+/// Checks that the asset is a pass of the pass collection and returns it.
+pub fn assert_pass_of_collection(
+    pass: &AccountInfo,
+    pass_collection: &Pubkey,
+) -> Result<BaseAssetV1, ProgramError> {
+    if pass.owner != &mpl_core::ID {
+        return Err(BglCartridgeError::InvalidPass.into());
+    }
+
+    let asset = BaseAssetV1::from_bytes(pass.try_borrow_data()?.as_ref())?;
+    if asset.update_authority != UpdateAuthority::Collection(*pass_collection) {
+        return Err(BglCartridgeError::InvalidPass.into());
+    }
+
+    Ok(asset)
+}
+
+/// Whether the cartridge has ever been inserted into a machine. Core creates
+/// the cartridge's data section on the first insert and removals only empty
+/// it.
+pub fn has_been_inserted(cartridge: &AccountInfo) -> Result<bool, ProgramError> {
+    fetch_optional_data_info::<BaseAssetV1>(
+        cartridge,
+        &ExternalPluginAdapterKey::DataSection(LinkedDataKey::LinkedAppData(
+            PluginAuthority::UpdateAuthority,
+        )),
+    )
+    .map(|info| info.is_some())
+}
+
+/// Reads the machine a cartridge is inserted into from the cartridge's
+/// LinkedAppData data section.
+pub fn fetch_cartridge_link(cartridge: &AccountInfo) -> Result<Option<Pubkey>, ProgramError> {
+    // Core only creates the data section on the first write, so a cartridge
+    // that has never been inserted has no data section at all.
+    let Some((offset, length)) = fetch_optional_data_info::<BaseAssetV1>(
+        cartridge,
+        &ExternalPluginAdapterKey::DataSection(LinkedDataKey::LinkedAppData(
+            PluginAuthority::UpdateAuthority,
+        )),
+    )?
+    else {
+        return Ok(None);
+    };
+
+    if length == 0 {
+        return Ok(None);
+    }
+
+    Pubkey::try_from(&cartridge.try_borrow_data()?[offset..offset + length])
+        .map(Some)
+        .map_err(|_| BglCartridgeError::DeserializationError.into())
+}
+
+/// Reads the offset and length of an external plugin adapter's data, none when
+/// the account has no such plugin. Any other failure means the account is not
+/// what it claims to be and is returned.
+fn fetch_optional_data_info<T: DataBlob + SolanaAccount>(
+    account: &AccountInfo,
+    plugin_key: &ExternalPluginAdapterKey,
+) -> Result<Option<(usize, usize)>, ProgramError> {
+    match fetch_external_plugin_adapter_data_info::<T>(account, None, plugin_key) {
+        Ok(info) => Ok(Some(info)),
+        Err(error)
+            if error.to_string() == MplCoreError::ExternalPluginAdapterNotFound.to_string() =>
+        {
+            Ok(None)
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn game_collection_data_round_trips() {
+        let game_data = GameCollectionData {
+            version: GAME_COLLECTION_DATA_VERSION,
+            price_type: PriceType::Burn as u8,
+            price: 42,
+            publisher: Pubkey::new_unique(),
+            compatible_machine_collections: vec![Pubkey::new_unique()],
+            sale_start: Some(10),
+            sale_end: Some(20),
+            pricing: PricingModel::SupplyStepped {
+                base_price: 1,
+                step_size: 2,
+                step_increment: 3,
+            },
+            max_per_wallet: Some(4),
+            referral_bps: 500,
+            refund_window: Some(60),
+            rental: Some(RentalTerms {
+                price: 5,
+                duration: 6,
+            }),
+            pass: Pubkey::new_unique(),
+            crowdfund_deadline: Some(30),
+            delisted: true,
+            ..Default::default()
+        };
+
+        let data = borsh::to_vec(&game_data).unwrap();
+        assert_eq!(GameCollectionData::from_bytes(&data).unwrap(), game_data);
+    }
+
+    #[test]
+    fn game_collection_data_reads_version_0() {
+        let publisher = Pubkey::new_unique();
+        let mut data = vec![0, PriceType::Transfer as u8];
+        data.extend_from_slice(&7u64.to_le_bytes());
+        data.extend_from_slice(publisher.as_ref());
+
+        let game_data = GameCollectionData::from_bytes(&data).unwrap();
+        assert_eq!(
+            game_data,
+            GameCollectionData {
+                price: 7,
+                publisher,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn game_collection_data_rejects_unknown_versions() {
+        let mut data = borsh::to_vec(&GameCollectionData::default()).unwrap();
+        data[0] = GAME_COLLECTION_DATA_VERSION + 1;
+        assert!(GameCollectionData::from_bytes(&data).is_err());
+
+        // A version 0 game with trailing bytes is corrupt.
+        data[0] = 0;
+        assert!(GameCollectionData::from_bytes(&data).is_err());
+    }
+
+    #[test]
+    fn flat_pricing_is_the_game_price() {
+        assert_eq!(PricingModel::Flat.price(42, 1_000, 7).unwrap(), 42);
+    }
+
+    #[test]
+    fn linear_decay_pricing_falls_over_the_duration() {
+        let pricing = PricingModel::LinearDecay {
+            start_price: 1_000,
+            end_price: 200,
+            start_time: 100,
+            duration: 400,
+        };
+
+        assert_eq!(pricing.price(0, 0, 0).unwrap(), 1_000);
+        assert_eq!(pricing.price(0, 100, 0).unwrap(), 1_000);
+        assert_eq!(pricing.price(0, 300, 0).unwrap(), 600);
+        assert_eq!(pricing.price(0, 101, 0).unwrap(), 998);
+        assert_eq!(pricing.price(0, 500, 0).unwrap(), 200);
+        assert_eq!(pricing.price(0, i64::MAX, 0).unwrap(), 200);
+
+        // The discount rounds down, in favor of the game.
+        let pricing = PricingModel::LinearDecay {
+            start_price: 10,
+            end_price: 0,
+            start_time: 0,
+            duration: 3,
+        };
+        assert_eq!(pricing.price(0, 1, 0).unwrap(), 7);
+    }
+
+    #[test]
+    fn linear_decay_pricing_does_not_overflow() {
+        let pricing = PricingModel::LinearDecay {
+            start_price: u64::MAX,
+            end_price: 0,
+            start_time: i64::MIN,
+            duration: i64::MAX,
+        };
+
+        let price = pricing.price(0, -2, 0).unwrap();
+        assert!(price > 0 && price < u64::MAX);
+    }
+
+    #[test]
+    fn supply_stepped_pricing_rises_every_step() {
+        let pricing = PricingModel::SupplyStepped {
+            base_price: 100,
+            step_size: 10,
+            step_increment: 5,
+        };
+
+        assert_eq!(pricing.price(0, 0, 0).unwrap(), 100);
+        assert_eq!(pricing.price(0, 0, 9).unwrap(), 100);
+        assert_eq!(pricing.price(0, 0, 10).unwrap(), 105);
+        assert_eq!(pricing.price(0, 0, 25).unwrap(), 110);
+
+        let pricing = PricingModel::SupplyStepped {
+            base_price: u64::MAX,
+            step_size: 1,
+            step_increment: 1,
+        };
+        assert!(pricing.price(0, 0, 1).is_err());
+    }
+
+    #[test]
+    fn pricing_models_are_checked() {
+        assert!(PricingModel::Flat.check().is_ok());
+        assert!(PricingModel::LinearDecay {
+            start_price: 1,
+            end_price: 2,
+            start_time: 0,
+            duration: 1,
+        }
+        .check()
+        .is_err());
+        assert!(PricingModel::LinearDecay {
+            start_price: 2,
+            end_price: 1,
+            start_time: 0,
+            duration: 0,
+        }
+        .check()
+        .is_err());
+        assert!(PricingModel::SupplyStepped {
+            base_price: 1,
+            step_size: 0,
+            step_increment: 1,
+        }
+        .check()
+        .is_err());
+    }
+
+    #[test]
+    fn refund_deadline_follows_the_price_type() {
+        let game_data = GameCollectionData {
+            price_type: PriceType::Transfer as u8,
+            refund_window: Some(60),
+            ..Default::default()
+        };
+        assert_eq!(game_data.refund_deadline(10), Some(70));
+        assert_eq!(game_data.refund_deadline(i64::MAX), Some(i64::MAX));
+
+        let burned = GameCollectionData {
+            price_type: PriceType::Burn as u8,
+            ..game_data.clone()
+        };
+        assert_eq!(burned.refund_deadline(10), None);
+
+        let no_refunds = GameCollectionData {
+            refund_window: None,
+            ..game_data
+        };
+        assert_eq!(no_refunds.refund_deadline(10), None);
+    }
+
+    #[test]
+    fn crowdfunded_games_are_on_sale_after_the_deadline() {
+        let game_data = GameCollectionData {
+            crowdfund_deadline: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(
+            game_data.assert_on_sale(9),
+            Err(BglCartridgeError::CrowdfundActive.into())
+        );
+        assert_eq!(game_data.assert_on_sale(10), Ok(()));
+    }
+
+    #[test]
+    fn split_by_bps_pays_every_share() {
+        assert_eq!(
+            split_by_bps(1_000, [7_000, 3_000].into_iter()).unwrap(),
+            vec![700, 300]
+        );
+    }
+
+    #[test]
+    fn split_by_bps_gives_the_remainder_to_the_last_share() {
+        let amounts = split_by_bps(10, [3_333, 3_333, 3_334].into_iter()).unwrap();
+        assert_eq!(amounts, vec![3, 3, 4]);
+        assert_eq!(amounts.iter().sum::<u64>(), 10);
+    }
+
+    #[test]
+    fn split_by_bps_does_not_overflow() {
+        let amounts = split_by_bps(u64::MAX, [5_000, 5_000].into_iter()).unwrap();
+        assert_eq!(amounts, vec![u64::MAX / 2, u64::MAX - u64::MAX / 2]);
+    }
+
+    #[test]
+    fn split_by_bps_rejects_shares_above_the_total() {
+        assert!(split_by_bps(100, [6_000, 6_000, 0].into_iter()).is_err());
+    }
+
+    #[test]
+    fn bundle_shares_must_add_up() {
+        assert!(assert_bundle_shares([7_000, 3_000].into_iter()).is_ok());
+        assert!(assert_bundle_shares([2_500, 2_500].into_iter()).is_err());
+        assert!(assert_bundle_shares([u16::MAX, 1].into_iter()).is_err());
+    }
+
+    #[test]
+    fn cartridge_data_round_trips() {
+        let cartridge_data = CartridgeData {
+            referrer: Some(Pubkey::new_unique()),
+            base_cartridge: Some(Pubkey::new_unique()),
+            lender: Some(Pubkey::new_unique()),
+            expires_at: Some(30),
+            rent_payer: Some(Pubkey::new_unique()),
+            refundable_until: Some(70),
+            rent_paid: 1_000,
+            ..CartridgeData::new(Source::Crypto, 42, 10)
+        };
+
+        let data = borsh::to_vec(&cartridge_data).unwrap();
+        assert_eq!(CartridgeData::from_bytes(&data).unwrap(), cartridge_data);
+    }
+
+    #[test]
+    fn cartridge_data_reads_version_0() {
+        let cartridge_data = CartridgeData::from_bytes(&[0, Source::Stripe as u8]).unwrap();
+        assert_eq!(
+            cartridge_data,
+            CartridgeData {
+                source: Source::Stripe as u8,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn cartridge_data_rejects_unknown_versions() {
+        assert!(CartridgeData::from_bytes(&[CARTRIDGE_DATA_VERSION + 1, 0]).is_err());
+        assert!(CartridgeData::from_bytes(&[]).is_err());
+    }
+
+    #[test]
+    fn catalog_size_counts_the_header_and_entries() {
+        assert_eq!(Catalog::size(0), 40);
+        assert_eq!(Catalog::size(1), 40 + 48);
+        assert_eq!(Catalog::size(3), 40 + 3 * 48);
+    }
+
+    #[test]
+    fn catalog_splits_into_header_and_entries() {
+        // Account data is 8-byte aligned on chain.
+        let mut buffer = [0u64; 17];
+        let data = &mut bytemuck::cast_slice_mut::<u64, u8>(&mut buffer)[..Catalog::size(2)];
+        let publisher = Pubkey::new_unique();
+        let games = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let (header, entries) = Catalog::split_mut(data);
+        assert_eq!(entries.len(), 2);
+        header.publisher = publisher;
+        header.num_entries = 1;
+        entries[0].game = games[0];
+        entries[0].released_at = 10;
+        entries[1].game = games[1];
+
+        let (header, entries) = Catalog::split_mut(data);
+        assert_eq!(header.publisher, publisher);
+        assert_eq!(header.num_entries, 1);
+        assert_eq!(entries[0].game, games[0]);
+        assert_eq!(entries[0].released_at, 10);
+
+        // Only the counted entries are listed.
+        Catalog::entry_mut(data, &games[0]).unwrap().retired = 1;
+        assert_eq!(Catalog::split_mut(data).1[0].retired, 1);
+        assert!(Catalog::entry_mut(data, &games[1]).is_none());
+    }
+}